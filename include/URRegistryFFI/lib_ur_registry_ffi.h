@@ -20,6 +20,8 @@ const char* parse_hd_path(struct ExternError*, const char* hd_path);
 // BTC
 const char* generate_crypto_psbt(struct ExternError*, const char* psbt_hex);
 const char* parse_crypto_psbt(struct ExternError*, const char* ur_type, const char* cbor_hex);
+const char* generate_utxo_psbt(struct ExternError*, const char* psbt_hex, const int coin_type, const int network);
+const char* parse_utxo_psbt(struct ExternError*, const char* ur_type, const char* cbor_hex);
 const char* generate_btc_sign_request(struct ExternError*, const char* request_id, const char* sign_data, const int data_type, const char* accounts, const char* origin);
 const char* parse_btc_signature(struct ExternError*, const char* ur_type, const char* cbor_hex);
 
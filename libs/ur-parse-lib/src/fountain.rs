@@ -0,0 +1,136 @@
+#[cfg(feature = "tokio")]
+use crate::keystone_ur_decoder::get_type;
+#[cfg(feature = "tokio")]
+use crate::ur::UR;
+#[cfg(feature = "tokio")]
+use alloc::string::{String, ToString};
+#[cfg(feature = "tokio")]
+use alloc::vec::Vec;
+#[cfg(feature = "tokio")]
+use tokio::sync::oneshot;
+#[cfg(feature = "tokio")]
+use ur::ur::Kind;
+#[cfg(feature = "tokio")]
+use ur_registry::error::{URError, URResult};
+
+/// Sizing of the fountain encoding done by
+/// [`to_ur_parts`](crate::keystone_ur_encoder::to_ur_parts) and
+/// [`cyclic_encode`](crate::keystone_ur_encoder::cyclic_encode).
+pub struct Encoder;
+
+impl Encoder {
+    /// The number of parts needed to transfer `data_len` bytes of CBOR once with
+    /// fragments of at most `max_fragment_len` bytes, so a host can size its
+    /// progress UI before encoding. The fountain splits the payload into equal
+    /// fragments padded up to the same length, and the sequence numbers, length
+    /// and checksum every part carries are encoded next to the fragment rather
+    /// than inside it, so they add to the size of a part but not to the count.
+    pub fn estimated_part_count(data_len: usize, max_fragment_len: usize) -> usize {
+        if data_len == 0 || max_fragment_len == 0 {
+            return 0;
+        }
+        let fragment_len = data_len.div_ceil(data_len.div_ceil(max_fragment_len));
+        data_len.div_ceil(fragment_len)
+    }
+}
+
+/// A fountain decoder for apps that scan the parts of an animated QR code on one
+/// task and wait for the payload on another. The decoded item is sent on the
+/// receiver returned by [`AsyncDecoder::new`] once enough parts are received.
+#[cfg(feature = "tokio")]
+pub struct AsyncDecoder<T> {
+    decoder: ur::Decoder,
+    sender: Option<oneshot::Sender<URResult<T>>>,
+}
+
+#[cfg(feature = "tokio")]
+impl<T: TryFrom<Vec<u8>, Error = URError>> AsyncDecoder<T> {
+    pub fn new() -> (Self, oneshot::Receiver<URResult<T>>) {
+        let (sender, receiver) = oneshot::channel();
+        let decoder = AsyncDecoder {
+            decoder: ur::Decoder::default(),
+            sender: Some(sender),
+        };
+        (decoder, receiver)
+    }
+
+    /// Feeds a scanned part and returns the progress. A part that fails to decode
+    /// is reported here and leaves the decoder as it was, so the camera loop can
+    /// go on with the next frame. Parts received after completion are ignored.
+    pub async fn receive(&mut self, part: String) -> URResult<u8> {
+        if self.sender.is_none() {
+            return Ok(100);
+        }
+        let part = part.to_lowercase();
+        let decoded = ur::decode(&part).map_err(|e| URError::UrDecodeError(e.to_string()))?;
+        let cbor = match decoded.0 {
+            Kind::SinglePart => decoded.1,
+            Kind::MultiPart => {
+                self.decoder
+                    .receive(&part)
+                    .map_err(|e| URError::UrDecodeError(e.to_string()))?;
+                if !self.decoder.complete() {
+                    return Ok(self.decoder.progress());
+                }
+                self.decoder
+                    .message()
+                    .map_err(|e| URError::UrDecodeError(e.to_string()))?
+                    .ok_or(URError::UrDecodeError("cbor is none".to_string()))?
+            }
+        };
+        let result = get_type(&part)
+            .and_then(|ur_type| UR::new(ur_type, cbor).parse())
+            .map(|(_, item)| item);
+        if let Some(sender) = self.sender.take() {
+            // nobody is waiting for the payload once the receiver is dropped
+            let _ = sender.send(result);
+        }
+        Ok(100)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::fountain::Encoder;
+    use crate::keystone_ur_encoder::to_ur_parts;
+    use alloc::string::ToString;
+    use alloc::vec;
+    use alloc::vec::Vec;
+    use ur_registry::crypto_psbt::CryptoPSBT;
+
+    #[test]
+    fn test_estimated_part_count() {
+        let psbt = CryptoPSBT::new(vec![0x42; 400]);
+        let cbor: Vec<u8> = psbt.try_into().unwrap();
+        assert_eq!(403, cbor.len());
+        assert_eq!(5, Encoder::estimated_part_count(cbor.len(), 100));
+        let parts = to_ur_parts(&cbor, 100, "crypto-psbt".to_string()).unwrap();
+        assert_eq!(5, parts.len());
+
+        assert_eq!(1, Encoder::estimated_part_count(cbor.len(), 403));
+        assert_eq!(0, Encoder::estimated_part_count(0, 100));
+    }
+
+    #[cfg(feature = "tokio")]
+    #[tokio::test]
+    async fn test_receive_from_task() {
+        let psbt = CryptoPSBT::new(vec![0x42; 400]);
+        let cbor: Vec<u8> = psbt.clone().try_into().unwrap();
+        let parts = to_ur_parts(&cbor, 100, "crypto-psbt".to_string()).unwrap();
+        assert!(parts.len() > 1);
+
+        let (mut decoder, receiver) = crate::fountain::AsyncDecoder::<CryptoPSBT>::new();
+        let camera = tokio::spawn(async move {
+            assert!(decoder
+                .receive("ur:crypto-psbt/1-5/xxx".to_string())
+                .await
+                .is_err());
+            for part in parts {
+                decoder.receive(part).await.unwrap();
+            }
+        });
+        let decoded = receiver.await.unwrap().unwrap();
+        assert_eq!(psbt.get_psbt(), decoded.get_psbt());
+        camera.await.unwrap();
+    }
+}
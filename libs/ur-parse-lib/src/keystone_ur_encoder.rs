@@ -1,4 +1,5 @@
 use alloc::string::{String, ToString};
+use alloc::vec::Vec;
 use core::fmt;
 use ur_registry::error::{URError, URResult};
 
@@ -52,6 +53,27 @@ pub fn probe_encode(
     }
 }
 
+/// Encodes the message into every part needed to transfer it once, a single
+/// part UR when it fits in one fragment.
+pub fn to_ur_parts(
+    message: &[u8],
+    max_fragment_length: usize,
+    ur_type: String,
+) -> URResult<Vec<String>> {
+    let mut encoder = ur::Encoder::new(message, max_fragment_length, ur_type.clone())
+        .map_err(|e| URError::CborEncodeError(e.to_string()))?;
+    if encoder.fragment_count() == 1 {
+        return Ok(alloc::vec![ur::encode(message, ur_type)]);
+    }
+    (0..encoder.fragment_count())
+        .map(|_| {
+            encoder
+                .next_part()
+                .map_err(|e| URError::UrEncodeError(e.to_string()))
+        })
+        .collect()
+}
+
 pub struct UREncodeResult {
     pub is_multi_part: bool,
     pub data: String,
@@ -99,7 +121,7 @@ impl KeystoneUREncoder {
 
 #[cfg(test)]
 mod tests {
-    use crate::keystone_ur_encoder::{cyclic_encode, probe_encode};
+    use crate::keystone_ur_encoder::{cyclic_encode, probe_encode, to_ur_parts};
     use alloc::vec::Vec;
     use hex::FromHex;
     use ur_registry::crypto_psbt::CryptoPSBT;
@@ -191,4 +213,23 @@ mod tests {
                         next);
         }
     }
+
+    #[test]
+    fn test_to_ur_parts() {
+        let data = "a3010002d90515a10182d90516a101d90130a10186182cf500f500f5d90516a201d90130a1018a182cf51901f5f500f500f500f502010400";
+        let data = Vec::from_hex(data).unwrap();
+        let parts =
+            to_ur_parts(&data, 400, QRHardwareCall::get_registry_type().get_type()).unwrap();
+        assert_eq!(
+            alloc::vec!["ur:qr-hardware-call/otadaeaotaahbzoyadlftaahcmoyadtaaddyoyadlncsdwykaeykaeyktaahcmoeadtaaddyoyadlecsdwykcfadykykaeykaeykaeykaoadaaaeyteyldre"],
+            parts
+        );
+
+        let crypto = CryptoPSBT::new(Vec::from_hex("aa".repeat(960)).unwrap());
+        let data: Vec<u8> = crypto.try_into().unwrap();
+        let parts = to_ur_parts(&data, 400, CryptoPSBT::get_registry_type().get_type()).unwrap();
+        assert_eq!(3, parts.len());
+        assert!(parts[0].starts_with("ur:crypto-psbt/1-3/"));
+        assert!(parts[2].starts_with("ur:crypto-psbt/3-3/"));
+    }
 }
@@ -1,6 +1,7 @@
 #![no_std]
 
 extern crate alloc;
+pub mod fountain;
 pub mod keystone_ur_decoder;
 pub mod keystone_ur_encoder;
-mod ur;
+pub mod ur;
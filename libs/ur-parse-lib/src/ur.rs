@@ -1,3 +1,5 @@
+use ::ur::ur::Kind;
+use alloc::string::{String, ToString};
 use alloc::vec::Vec;
 use ur_registry::error::{URError, URResult};
 use ur_registry::registry_types::URType;
@@ -17,3 +19,95 @@ impl UR {
         Ok((self.ur_type.clone(), result))
     }
 }
+
+pub fn encode_raw(ur_type: &str, cbor: &[u8]) -> String {
+    ::ur::encode(cbor, ur_type.to_string())
+}
+
+pub fn decode_raw(part: &str) -> URResult<(String, Vec<u8>)> {
+    let part = part.to_lowercase();
+    let strip_scheme = part.strip_prefix("ur:").ok_or(URError::NotAUr)?;
+    let (ur_type, _) = strip_scheme
+        .split_once('/')
+        .ok_or(URError::TypeUnspecified)?;
+    let decoded = ::ur::decode(&part).map_err(|e| URError::UrDecodeError(e.to_string()))?;
+    match decoded.0 {
+        Kind::SinglePart => Ok((ur_type.to_string(), decoded.1)),
+        Kind::MultiPart => Err(URError::UrDecodeError(
+            "multi part ur is not supported".to_string(),
+        )),
+    }
+}
+
+/// The outcome of feeding a batch of parts to a fountain decoder.
+#[derive(Debug, PartialEq)]
+pub enum RawParts {
+    /// The type and cbor of the complete UR.
+    Complete(String, Vec<u8>),
+    /// The percentage received so far, more parts are needed.
+    Progress(u8),
+}
+
+/// Decodes the parts scanned from an animated QR code in one go, a single
+/// part UR completes on its own.
+pub fn decode_raw_parts(parts: &[String]) -> URResult<RawParts> {
+    let first = parts
+        .first()
+        .ok_or(URError::UrDecodeError("no ur parts".to_string()))?;
+    let decoded =
+        ::ur::decode(&first.to_lowercase()).map_err(|e| URError::UrDecodeError(e.to_string()))?;
+    if let Kind::SinglePart = decoded.0 {
+        let (ur_type, cbor) = decode_raw(first)?;
+        return Ok(RawParts::Complete(ur_type, cbor));
+    }
+    let mut decoder = ::ur::Decoder::default();
+    for part in parts {
+        decoder
+            .receive(&part.to_lowercase())
+            .map_err(|e| URError::UrDecodeError(e.to_string()))?;
+        if decoder.complete() {
+            break;
+        }
+    }
+    if !decoder.complete() {
+        return Ok(RawParts::Progress(decoder.progress()));
+    }
+    let cbor = decoder
+        .message()
+        .map_err(|e| URError::UrDecodeError(e.to_string()))?
+        .ok_or(URError::UrDecodeError("cbor is none".to_string()))?;
+    let part = first.to_lowercase();
+    let ur_type = part
+        .strip_prefix("ur:")
+        .and_then(|rest| rest.split_once('/'))
+        .map(|(ur_type, _)| ur_type.to_string())
+        .ok_or(URError::TypeUnspecified)?;
+    Ok(RawParts::Complete(ur_type, cbor))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ur::{decode_raw, encode_raw};
+    use alloc::vec::Vec;
+    use hex::FromHex;
+
+    #[test]
+    fn test_encode_raw() {
+        let cbor = Vec::from_hex("a201000201").unwrap();
+        let ur = encode_raw("crypto-coin-info", &cbor);
+        assert_eq!("ur:crypto-coin-info/oeadaeaoadehfdbany", ur);
+
+        let (ur_type, data) = decode_raw(&ur).unwrap();
+        assert_eq!("crypto-coin-info", ur_type);
+        assert_eq!(cbor, data);
+    }
+
+    #[test]
+    fn test_encode_raw_custom_type() {
+        let cbor = Vec::from_hex("4401020304").unwrap();
+        let ur = encode_raw("x-experimental", &cbor);
+        let (ur_type, data) = decode_raw(&ur.to_uppercase()).unwrap();
+        assert_eq!("x-experimental", ur_type);
+        assert_eq!(cbor, data);
+    }
+}
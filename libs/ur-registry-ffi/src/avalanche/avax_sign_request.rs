@@ -0,0 +1,124 @@
+use hex;
+use serde_json::json;
+use ur_registry::avalanche::avax_sign_request::{AvaxChain, AvaxSignRequest};
+use ur_registry::crypto_key_path::CryptoKeyPath;
+use ur_registry::registry_types::AVAX_SIGN_REQUEST;
+use uuid::Uuid;
+
+use crate::export;
+use crate::util_internal::string_helper::remove_prefix_0x;
+
+export! {
+    @Java_com_keystone_sdk_KeystoneNativeSDK_generateAvaxSignRequest
+    fn generate_avax_sign_request(
+        request_id: &str,
+        sign_data: &str,
+        chain: u32,
+        xfp: &str,
+        xpub: &str,
+        wallet_index: u32,
+        derivation_path: &str, //optional
+        origin: &str //optional
+    ) -> String {
+        let request_id = match Uuid::parse_str(request_id) {
+            Ok(v) => v.as_bytes().to_vec(),
+            Err(_) => return json!({"error": "uuid is invalid"}).to_string(),
+        };
+
+        let sign_data = match hex::decode(remove_prefix_0x(sign_data)) {
+            Ok(v) if !v.is_empty() => v,
+            _ => return json!({"error": "sign data is invalid"}).to_string(),
+        };
+
+        let chain = match AvaxChain::from_u32(chain) {
+            Ok(v) => v,
+            Err(_) => return json!({"error": "chain is invalid"}).to_string(),
+        };
+
+        let master_fingerprint: [u8; 4] = match hex::decode(xfp).ok().and_then(|v| v.try_into().ok()) {
+            Some(v) => v,
+            None => return json!({"error": "xfp is invalid"}).to_string(),
+        };
+
+        let derivation_path = match derivation_path {
+            "" => None,
+            _path => match CryptoKeyPath::from_path(_path.to_string(), Some(master_fingerprint)) {
+                Ok(v) => Some(v),
+                Err(_) => return json!({"error": "path is invalid"}).to_string(),
+            },
+        };
+
+        let mut request = AvaxSignRequest::new(
+            request_id,
+            sign_data,
+            master_fingerprint,
+            xpub.to_string(),
+            wallet_index as u64,
+        );
+        request.set_chain(chain);
+        if let Some(path) = derivation_path {
+            request.set_derivation_paths(vec![path]);
+        }
+        if !origin.is_empty() {
+            request.set_origin(origin.to_string());
+        }
+
+        let cbor_bytes: Vec<u8> = match request.try_into() {
+            Ok(v) => v,
+            Err(_) => return json!({"error": "sign data is invalid"}).to_string(),
+        };
+        let cbor_hex = hex::encode(cbor_bytes);
+        let ur_type = AVAX_SIGN_REQUEST.get_type();
+        let ur = json!({
+            "type": ur_type,
+            "cbor": cbor_hex,
+        });
+        ur.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const XPUB: &str = "xpub6DXryz8Kd7XchtXvDnkjara83shGJH8ubu7KZhHhPfp4L1shvDEYiFZm32EKHnyo4bva4gxXjabFGqY7fNs8Ggd4khYz2oNs2KYLf56a9GX";
+
+    #[test]
+    fn test_generate_avax_sign_request() {
+        let request_id = "9b1deb4d-3b7d-4bad-9bdd-2b0d7b3dcb6d";
+        // a P-chain base tx
+        let sign_data = "000000000022000000050000000000000000000000000000000000000000000000000000000000000000000000023d9bdac0ed1d761330cf680efdeb1a42159eb387d6d2950c96f7d28f61bbe2aa0000000700000000000f42400000000000000000000000010000000132336f8715dd313a426155cccc15ba27c3033dae3d9bdac0ed1d761330cf680efdeb1a42159eb387d6d2950c96f7d28f61bbe2aa00000007000000004d58ade90000000000000000000000010000000132336f8715dd313a426155cccc15ba27c3033dae00000001410b47f7c7aa13f88122be58735c5e985edc65d86fb0baf0b016359c22253d75000000013d9bdac0ed1d761330cf680efdeb1a42159eb387d6d2950c96f7d28f61bbe2aa00000005000000004d680464000000010000000000000000";
+        let expect_result = "{\"cbor\":\"a801d825509b1deb4d3b7d4bad9bdd2b0d7b3dcb6d0259012e000000000022000000050000000000000000000000000000000000000000000000000000000000000000000000023d9bdac0ed1d761330cf680efdeb1a42159eb387d6d2950c96f7d28f61bbe2aa0000000700000000000f42400000000000000000000000010000000132336f8715dd313a426155cccc15ba27c3033dae3d9bdac0ed1d761330cf680efdeb1a42159eb387d6d2950c96f7d28f61bbe2aa00000007000000004d58ade90000000000000000000000010000000132336f8715dd313a426155cccc15ba27c3033dae00000001410b47f7c7aa13f88122be58735c5e985edc65d86fb0baf0b016359c22253d75000000013d9bdac0ed1d761330cf680efdeb1a42159eb387d6d2950c96f7d28f61bbe2aa00000005000000004d680464000000010000000000000000031af23f9fd206786f7870756236445872797a384b6437586368745876446e6b6a61726138337368474a4838756275374b5a684868506670344c3173687644455969465a6d3332454b486e796f34627661346778586a61624647715937664e7338476764346b68597a326f4e73324b594c66353661394758070008010981d90130a2018a182cf5192328f500f500f400f4021af23f9fd20a64436f7265\",\"type\":\"avax-sign-request\"}";
+
+        assert_eq!(
+            expect_result,
+            generate_avax_sign_request(
+                request_id,
+                sign_data,
+                1,
+                "f23f9fd2",
+                XPUB,
+                0,
+                "m/44'/9000'/0'/0/0",
+                "Core"
+            )
+        );
+    }
+
+    #[test]
+    fn test_generate_avax_sign_request_error() {
+        let request_id = "9b1deb4d-3b7d-4bad-9bdd-2b0d7b3dcb6d";
+        assert_eq!(
+            "{\"error\":\"chain is invalid\"}",
+            generate_avax_sign_request(request_id, "00", 3, "f23f9fd2", XPUB, 0, "", "")
+        );
+        assert_eq!(
+            "{\"error\":\"xfp is invalid\"}",
+            generate_avax_sign_request(request_id, "00", 1, "f23f9f", XPUB, 0, "", "")
+        );
+        assert_eq!(
+            "{\"error\":\"sign data is invalid\"}",
+            generate_avax_sign_request(request_id, "", 1, "f23f9fd2", XPUB, 0, "", "")
+        );
+    }
+}
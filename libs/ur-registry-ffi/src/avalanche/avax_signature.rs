@@ -0,0 +1,60 @@
+use anyhow::Error;
+use hex;
+use serde_json::json;
+use ur_registry::avalanche::avax_signature::AvaxSignature;
+use ur_registry::registry_types::AVAX_SIGNATURE;
+use uuid::Uuid;
+
+use crate::export;
+
+export! {
+    @Java_com_keystone_sdk_KeystoneNativeSDK_parseAvaxSignature
+    fn parse_avax_signature(ur_type: &str, cbor_hex: &str) -> String {
+        if AVAX_SIGNATURE.get_type() != ur_type {
+            return json!({"error": "type not match"}).to_string();
+        }
+
+        let parse_signature = || -> Result<(String, String), Error> {
+            let cbor = hex::decode(cbor_hex.to_string())?;
+            let sig = AvaxSignature::try_from(cbor)?;
+            let request_id = Uuid::from_slice(&sig.get_request_id())?.to_string();
+            let signature = hex::encode(sig.get_signature());
+            Ok((request_id, signature))
+        };
+        match parse_signature() {
+            Ok((request_id, signature)) => json!({
+                "request_id": request_id,
+                "signature": signature,
+            }).to_string(),
+            Err(_) => json!({"error": "signature is invalid"}).to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_avax_signature() {
+        let signature_cbor = "a201509b1deb4d3b7d4bad9bdd2b0d7b3dcb6d025841000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f202122232425262728292a2b2c2d2e2f303132333435363738393a3b3c3d3e3f40";
+        let expect_result = "{\"request_id\":\"9b1deb4d-3b7d-4bad-9bdd-2b0d7b3dcb6d\",\"signature\":\"000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f202122232425262728292a2b2c2d2e2f303132333435363738393a3b3c3d3e3f40\"}";
+
+        assert_eq!(
+            expect_result,
+            parse_avax_signature("avax-signature", signature_cbor)
+        );
+    }
+
+    #[test]
+    fn test_parse_avax_signature_error() {
+        assert_eq!(
+            "{\"error\":\"type not match\"}",
+            parse_avax_signature("eth-signature", "a201")
+        );
+        assert_eq!(
+            "{\"error\":\"signature is invalid\"}",
+            parse_avax_signature("avax-signature", "a201")
+        );
+    }
+}
@@ -0,0 +1,2 @@
+pub mod avax_sign_request;
+pub mod avax_signature;
@@ -76,7 +76,8 @@ export! {
             data_type,
             derivation_paths,
             Some(addresses),
-            origin
+            origin,
+            None
         ).try_into() {
             Ok(v) => v,
             Err(_) => return json!({"error": "sign data is invalid"}).to_string(),
@@ -1,3 +1,5 @@
 pub mod btc_sign_request;
 pub mod btc_signature;
 pub mod crypto_psbt;
+pub mod psbt_parser;
+pub mod utxo_psbt;
@@ -0,0 +1,365 @@
+use anyhow::{format_err, Error};
+use base64::{engine::general_purpose::STANDARD, Engine};
+use serde_json::{json, Value};
+
+use crate::export;
+use crate::util_internal::string_helper::remove_prefix_0x;
+
+const PSBT_MAGIC: [u8; 5] = [0x70, 0x73, 0x62, 0x74, 0xff];
+const PSBT_GLOBAL_UNSIGNED_TX: u8 = 0x00;
+const PSBT_IN_NON_WITNESS_UTXO: u8 = 0x00;
+const PSBT_IN_WITNESS_UTXO: u8 = 0x01;
+const PSBT_IN_BIP32_DERIVATION: u8 = 0x06;
+const PSBT_OUT_BIP32_DERIVATION: u8 = 0x02;
+const HARDENED_BIT: u32 = 0x8000_0000;
+
+// addresses are rendered for mainnet
+const P2PKH_VERSION: u8 = 0x00;
+const P2SH_VERSION: u8 = 0x05;
+const BECH32_HRP: &str = "bc";
+const BECH32_CHARSET: &[u8] = b"qpzry9x8gf2tvdw0s3jn54khce6mua7l";
+const BECH32_CONST: u32 = 1;
+const BECH32M_CONST: u32 = 0x2bc8_30a3;
+
+struct Reader<'a> {
+    data: &'a [u8],
+    offset: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn new(data: &'a [u8]) -> Self {
+        Reader { data, offset: 0 }
+    }
+
+    fn read(&mut self, len: usize) -> Result<&'a [u8], Error> {
+        let end = self
+            .offset
+            .checked_add(len)
+            .filter(|end| *end <= self.data.len())
+            .ok_or_else(|| format_err!("unexpected end of data"))?;
+        let slice = &self.data[self.offset..end];
+        self.offset = end;
+        Ok(slice)
+    }
+
+    fn read_u8(&mut self) -> Result<u8, Error> {
+        Ok(self.read(1)?[0])
+    }
+
+    fn read_u32(&mut self) -> Result<u32, Error> {
+        Ok(u32::from_le_bytes(self.read(4)?.try_into()?))
+    }
+
+    fn read_u64(&mut self) -> Result<u64, Error> {
+        Ok(u64::from_le_bytes(self.read(8)?.try_into()?))
+    }
+
+    fn read_compact_size(&mut self) -> Result<usize, Error> {
+        let size = match self.read_u8()? {
+            0xfd => u16::from_le_bytes(self.read(2)?.try_into()?) as u64,
+            0xfe => self.read_u32()? as u64,
+            0xff => self.read_u64()?,
+            v => v as u64,
+        };
+        usize::try_from(size).map_err(|_| format_err!("compact size is too large"))
+    }
+
+    fn read_vec(&mut self) -> Result<&'a [u8], Error> {
+        let len = self.read_compact_size()?;
+        self.read(len)
+    }
+}
+
+struct TxInput {
+    txid: String,
+    vout: u32,
+}
+
+struct TxOutput {
+    value: u64,
+    script_pubkey: Vec<u8>,
+}
+
+fn parse_tx(data: &[u8]) -> Result<(Vec<TxInput>, Vec<TxOutput>), Error> {
+    let mut reader = Reader::new(data);
+    reader.read(4)?;
+    let mut input_count = reader.read_compact_size()?;
+    // segwit marker and flag, only possible in a non-witness utxo
+    if input_count == 0 {
+        reader.read_u8()?;
+        input_count = reader.read_compact_size()?;
+    }
+    let mut inputs = vec![];
+    for _ in 0..input_count {
+        let mut txid = reader.read(32)?.to_vec();
+        txid.reverse();
+        let vout = reader.read_u32()?;
+        reader.read_vec()?;
+        reader.read(4)?;
+        inputs.push(TxInput {
+            txid: hex::encode(txid),
+            vout,
+        });
+    }
+    let mut outputs = vec![];
+    for _ in 0..reader.read_compact_size()? {
+        let value = reader.read_u64()?;
+        let script_pubkey = reader.read_vec()?.to_vec();
+        outputs.push(TxOutput {
+            value,
+            script_pubkey,
+        });
+    }
+    Ok((inputs, outputs))
+}
+
+fn parse_map<'a>(reader: &mut Reader<'a>) -> Result<Vec<(&'a [u8], &'a [u8])>, Error> {
+    let mut map = vec![];
+    loop {
+        let key = reader.read_vec()?;
+        if key.is_empty() {
+            return Ok(map);
+        }
+        let value = reader.read_vec()?;
+        map.push((key, value));
+    }
+}
+
+fn parse_bip32_derivation(pubkey: &[u8], value: &[u8]) -> Result<Value, Error> {
+    if value.len() < 4 || value.len() % 4 != 0 {
+        return Err(format_err!("bip32 derivation is invalid"));
+    }
+    let path = value[4..]
+        .chunks(4)
+        .map(|chunk| {
+            let index = u32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]);
+            if index & HARDENED_BIT != 0 {
+                format!("/{}'", index & !HARDENED_BIT)
+            } else {
+                format!("/{}", index)
+            }
+        })
+        .collect::<String>();
+    Ok(json!({
+        "pubkey": hex::encode(pubkey),
+        "master_fingerprint": hex::encode(&value[..4]),
+        "path": format!("m{}", path),
+    }))
+}
+
+fn bech32_polymod(values: &[u8]) -> u32 {
+    const GENERATOR: [u32; 5] = [0x3b6a_57b2, 0x2650_8e6d, 0x1ea1_19fa, 0x3d42_33dd, 0x2a14_62b3];
+    let mut checksum = 1u32;
+    for value in values {
+        let top = checksum >> 25;
+        checksum = ((checksum & 0x01ff_ffff) << 5) ^ *value as u32;
+        for (i, generator) in GENERATOR.iter().enumerate() {
+            if (top >> i) & 1 == 1 {
+                checksum ^= generator;
+            }
+        }
+    }
+    checksum
+}
+
+fn segwit_address(version: u8, program: &[u8]) -> String {
+    let mut data = vec![version];
+    let mut acc = 0u32;
+    let mut bits = 0;
+    for byte in program {
+        acc = ((acc << 8) | *byte as u32) & 0xfff;
+        bits += 8;
+        while bits >= 5 {
+            bits -= 5;
+            data.push((acc >> bits) as u8 & 0x1f);
+        }
+    }
+    if bits > 0 {
+        data.push((acc << (5 - bits)) as u8 & 0x1f);
+    }
+
+    let mut values = BECH32_HRP.bytes().map(|c| c >> 5).collect::<Vec<u8>>();
+    values.push(0);
+    values.extend(BECH32_HRP.bytes().map(|c| c & 0x1f));
+    values.extend(&data);
+    values.extend([0u8; 6]);
+    let constant = if version == 0 { BECH32_CONST } else { BECH32M_CONST };
+    let polymod = bech32_polymod(&values) ^ constant;
+    data.extend((0..6).map(|i| (polymod >> (5 * (5 - i))) as u8 & 0x1f));
+
+    let encoded = data
+        .iter()
+        .map(|v| BECH32_CHARSET[*v as usize] as char)
+        .collect::<String>();
+    format!("{}1{}", BECH32_HRP, encoded)
+}
+
+fn base58_address(version: u8, hash: &[u8]) -> String {
+    let mut payload = vec![version];
+    payload.extend_from_slice(hash);
+    bs58::encode(payload).with_check().into_string()
+}
+
+fn script_to_address(script: &[u8]) -> Option<String> {
+    match script {
+        [0x76, 0xa9, 0x14, hash @ .., 0x88, 0xac] if hash.len() == 20 => {
+            Some(base58_address(P2PKH_VERSION, hash))
+        }
+        [0xa9, 0x14, hash @ .., 0x87] if hash.len() == 20 => {
+            Some(base58_address(P2SH_VERSION, hash))
+        }
+        [0x00, len, program @ ..]
+            if (*len == 20 || *len == 32) && program.len() == *len as usize =>
+        {
+            Some(segwit_address(0, program))
+        }
+        [op @ 0x51..=0x60, len, program @ ..]
+            if (2..=40).contains(len) && program.len() == *len as usize =>
+        {
+            Some(segwit_address(op - 0x50, program))
+        }
+        _ => None,
+    }
+}
+
+fn decode_psbt(psbt: &str) -> Result<Vec<u8>, Error> {
+    let psbt = psbt.trim();
+    if let Ok(bytes) = hex::decode(remove_prefix_0x(psbt)) {
+        if bytes.starts_with(&PSBT_MAGIC) {
+            return Ok(bytes);
+        }
+    }
+    let bytes = STANDARD.decode(psbt)?;
+    if !bytes.starts_with(&PSBT_MAGIC) {
+        return Err(format_err!("magic bytes are invalid"));
+    }
+    Ok(bytes)
+}
+
+fn parse_psbt(data: &[u8]) -> Result<Value, Error> {
+    let mut reader = Reader::new(data);
+    reader.read(PSBT_MAGIC.len())?;
+
+    let global = parse_map(&mut reader)?;
+    let unsigned_tx = global
+        .iter()
+        .find(|(key, _)| key == &[PSBT_GLOBAL_UNSIGNED_TX])
+        .map(|(_, value)| *value)
+        .ok_or_else(|| format_err!("unsigned transaction is missing"))?;
+    let (tx_inputs, tx_outputs) = parse_tx(unsigned_tx)?;
+
+    let mut total_input: Option<u64> = Some(0);
+    let mut inputs = vec![];
+    for tx_input in tx_inputs.iter() {
+        let mut value = None;
+        let mut derivations = vec![];
+        for (key, data) in parse_map(&mut reader)? {
+            match key[0] {
+                PSBT_IN_WITNESS_UTXO if key.len() == 1 => {
+                    value = Some(Reader::new(data).read_u64()?);
+                }
+                PSBT_IN_NON_WITNESS_UTXO if key.len() == 1 && value.is_none() => {
+                    let (_, outputs) = parse_tx(data)?;
+                    value = outputs.get(tx_input.vout as usize).map(|output| output.value);
+                }
+                PSBT_IN_BIP32_DERIVATION => {
+                    derivations.push(parse_bip32_derivation(&key[1..], data)?);
+                }
+                _ => {}
+            }
+        }
+        total_input = total_input.zip(value).and_then(|(total, v)| total.checked_add(v));
+        inputs.push(json!({
+            "txid": tx_input.txid,
+            "vout": tx_input.vout,
+            "value": value,
+            "bip32_derivations": derivations,
+        }));
+    }
+
+    let mut total_output = 0u64;
+    let mut outputs = vec![];
+    for tx_output in tx_outputs.iter() {
+        let mut derivations = vec![];
+        for (key, data) in parse_map(&mut reader)? {
+            if key[0] == PSBT_OUT_BIP32_DERIVATION {
+                derivations.push(parse_bip32_derivation(&key[1..], data)?);
+            }
+        }
+        total_output = total_output.saturating_add(tx_output.value);
+        outputs.push(json!({
+            "address": script_to_address(&tx_output.script_pubkey),
+            "script_pubkey": hex::encode(&tx_output.script_pubkey),
+            "amount": tx_output.value,
+            "bip32_derivations": derivations,
+        }));
+    }
+
+    // the fee is unknown unless every input carries its utxo
+    let fee = total_input.and_then(|total| total.checked_sub(total_output));
+    Ok(json!({
+        "inputs": inputs,
+        "outputs": outputs,
+        "fee": fee,
+    }))
+}
+
+export! {
+    @Java_com_keystone_sdk_KeystoneNativeSDK_bitcoinParsePsbt
+    fn bitcoin_parse_psbt(psbt_base64: &str) -> String {
+        let psbt = match decode_psbt(psbt_base64) {
+            Ok(v) => v,
+            Err(_) => return json!({"error": "PSBT is invalid"}).to_string(),
+        };
+        match parse_psbt(&psbt) {
+            Ok(summary) => json!({"result": summary}).to_string(),
+            Err(e) => json!({"error": format!("PSBT is invalid: {}", e)}).to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PSBT_HEX: &str = "70736274ff0100520200000001000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f0000000000ffffffff01b882010000000000160014751e76e8199196d454941c45d1b3a323f1433bd6000000000001011fa086010000000000160014abababababababababababababababababababab22060330d54fd0dd420a6e5f8d3624f5f3482cae350f79d5f0753bf5beef9c2d91af3c1873c5da0a54000080000000800000008000000000000000000000";
+    const PSBT_BASE64: &str = "cHNidP8BAFICAAAAAQABAgMEBQYHCAkKCwwNDg8QERITFBUWFxgZGhscHR4fAAAAAAD/////AbiCAQAAAAAAFgAUdR526BmRltRUlBxF0bOjI/FDO9YAAAAAAAEBH6CGAQAAAAAAFgAUq6urq6urq6urq6urq6urq6urq6siBgMw1U/Q3UIKbl+NNiT180gsrjUPedXwdTv1vu+cLZGvPBhzxdoKVAAAgAAAAIAAAACAAAAAAAAAAAAAAA==";
+
+    #[test]
+    fn test_bitcoin_parse_psbt() {
+        let expect_result = r#"{"result":{"fee":1000,"inputs":[{"bip32_derivations":[{"master_fingerprint":"73c5da0a","path":"m/84'/0'/0'/0/0","pubkey":"0330d54fd0dd420a6e5f8d3624f5f3482cae350f79d5f0753bf5beef9c2d91af3c"}],"txid":"1f1e1d1c1b1a191817161514131211100f0e0d0c0b0a09080706050403020100","value":100000,"vout":0}],"outputs":[{"address":"bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4","amount":99000,"bip32_derivations":[],"script_pubkey":"0014751e76e8199196d454941c45d1b3a323f1433bd6"}]}}"#;
+
+        assert_eq!(expect_result, bitcoin_parse_psbt(PSBT_BASE64));
+        assert_eq!(expect_result, bitcoin_parse_psbt(PSBT_HEX));
+    }
+
+    #[test]
+    fn test_script_to_address() {
+        assert_eq!(
+            Some("1BvBMSEYstWetqTFn5Au4m4GFg7xJaNVN2".to_string()),
+            script_to_address(
+                &hex::decode("76a91477bff20c60e522dfaa3350c39b030a5d004e839a88ac").unwrap()
+            )
+        );
+        assert_eq!(
+            Some("bc1p5cyxnuxmeuwuvkwfem96lqzszd02n6xdcjrs20cac6yqjjwudpxqkedrcr".to_string()),
+            script_to_address(
+                &hex::decode(
+                    "5120a60869f0dbcf1dc659c9cecbaf8050135ea9e8cdc487053f1dc6880949dc684c"
+                )
+                .unwrap()
+            )
+        );
+        assert_eq!(None, script_to_address(&hex::decode("6a00").unwrap()));
+    }
+
+    #[test]
+    fn test_bitcoin_parse_psbt_error() {
+        assert_eq!(
+            r#"{"error":"PSBT is invalid"}"#,
+            bitcoin_parse_psbt("not a psbt")
+        );
+        let result: Value = serde_json::from_str(&bitcoin_parse_psbt(&PSBT_HEX[..60])).unwrap();
+        assert!(result["error"].as_str().unwrap().starts_with("PSBT is invalid"));
+    }
+}
@@ -0,0 +1,97 @@
+use anyhow::format_err;
+use anyhow::Error;
+use hex;
+use serde_json::json;
+use ur_registry::crypto_coin_info::{CoinType, CryptoCoinInfo, Network};
+use ur_registry::registry_types::UTXO_PSBT;
+use ur_registry::traits::From;
+use ur_registry::traits::To;
+use ur_registry::utxo_psbt::UtxoPSBT;
+
+use crate::export;
+use crate::util_internal::string_helper::remove_prefix_0x;
+
+export! {
+    @Java_com_keystone_sdk_KeystoneNativeSDK_parseUtxoPSBT
+    fn parse_utxo_psbt(ur_type: &str, cbor_hex: &str) -> String {
+        if UTXO_PSBT.get_type() != ur_type {
+            return json!({"error": "type not match"}).to_string();
+        }
+
+        let parse = || -> Result<UtxoPSBT, Error> {
+            let cbor = hex::decode(remove_prefix_0x(cbor_hex).to_string())?;
+            let utxo_psbt = UtxoPSBT::from_cbor(cbor).map_err(|_| format_err!(""))?;
+            Ok(utxo_psbt)
+        };
+        match parse() {
+            Ok(utxo_psbt) => json!({
+                "psbt": hex::encode(utxo_psbt.get_psbt()),
+                "coin_type": utxo_psbt.get_coin_info().get_coin_type() as u32,
+                "network": utxo_psbt.get_coin_info().get_network() as u32,
+            }).to_string(),
+            Err(_) => json!({"error": "PSBT is invalid"}).to_string(),
+        }
+    }
+
+    @Java_com_keystone_sdk_KeystoneNativeSDK_generateUtxoPSBT
+    fn generate_utxo_psbt(psbt_hex: &str, coin_type: u32, network: u32) -> String {
+        let coin_type = match coin_type {
+            2 | 3 => CoinType::from_u32(coin_type),
+            _ => return json!({"error": "coin type is not supported"}).to_string(),
+        };
+        let network = match network {
+            0 | 1 => Network::from_u32(network),
+            _ => return json!({"error": "network is invalid"}).to_string(),
+        };
+        let gen = || -> Result<String, Error> {
+            let psbt = hex::decode(remove_prefix_0x(psbt_hex).to_string())?;
+            let coin_info = CryptoCoinInfo::new(Some(coin_type), Some(network));
+            let utxo_psbt = UtxoPSBT::new(psbt, coin_info);
+            let cbor_hex = hex::encode(utxo_psbt.to_bytes()?);
+            Ok(cbor_hex)
+        };
+        match gen() {
+            Ok(v) => json!({
+                "type": "utxo-psbt",
+                "cbor": v,
+            }).to_string(),
+            Err(_) => json!({"error": "PSBT is invalid"}).to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[test]
+    fn test_generate_litecoin_psbt() {
+        let psbt_hex = "70736274ff01009a020000000258e87a21b56daf0c23be8e7070456c336f7cbaa5c8757924f545887bb2abdd750000000000ffffffff838d0427d0ec650a68aa46bb0b098aea4422c071b2ca78352a077959d07cea1d0100000000ffffffff0270aaf00800000000160014d85c2b71d0060b09c9886aeb815e50991dda124d00e1f5050000000016001400aea9a2e5f0f876a588df5546e8742d1d87008f000000000000000000";
+        let expect_result = "{\"cbor\":\"a20158a770736274ff01009a020000000258e87a21b56daf0c23be8e7070456c336f7cbaa5c8757924f545887bb2abdd750000000000ffffffff838d0427d0ec650a68aa46bb0b098aea4422c071b2ca78352a077959d07cea1d0100000000ffffffff0270aaf00800000000160014d85c2b71d0060b09c9886aeb815e50991dda124d00e1f5050000000016001400aea9a2e5f0f876a588df5546e8742d1d87008f00000000000000000002d90131a201020200\",\"type\":\"utxo-psbt\"}";
+
+        assert_eq!(expect_result, generate_utxo_psbt(psbt_hex, 2, 0));
+    }
+
+    #[test]
+    fn test_parse_litecoin_psbt() {
+        let cbor_hex = "a20158a770736274ff01009a020000000258e87a21b56daf0c23be8e7070456c336f7cbaa5c8757924f545887bb2abdd750000000000ffffffff838d0427d0ec650a68aa46bb0b098aea4422c071b2ca78352a077959d07cea1d0100000000ffffffff0270aaf00800000000160014d85c2b71d0060b09c9886aeb815e50991dda124d00e1f5050000000016001400aea9a2e5f0f876a588df5546e8742d1d87008f00000000000000000002d90131a201020200";
+        let expect_result = "{\"coin_type\":2,\"network\":0,\"psbt\":\"70736274ff01009a020000000258e87a21b56daf0c23be8e7070456c336f7cbaa5c8757924f545887bb2abdd750000000000ffffffff838d0427d0ec650a68aa46bb0b098aea4422c071b2ca78352a077959d07cea1d0100000000ffffffff0270aaf00800000000160014d85c2b71d0060b09c9886aeb815e50991dda124d00e1f5050000000016001400aea9a2e5f0f876a588df5546e8742d1d87008f000000000000000000\"}";
+
+        assert_eq!(expect_result, parse_utxo_psbt("utxo-psbt", cbor_hex));
+    }
+
+    #[test]
+    fn test_parse_utxo_psbt_type_error() {
+        let expect_result = "{\"error\":\"type not match\"}";
+
+        assert_eq!(expect_result, parse_utxo_psbt("crypto-psbt", "a0"));
+    }
+
+    #[test]
+    fn test_generate_utxo_psbt_unsupported_coin() {
+        let expect_result = "{\"error\":\"coin type is not supported\"}";
+
+        assert_eq!(expect_result, generate_utxo_psbt("70736274ff01", 0, 0));
+        assert_eq!(expect_result, generate_utxo_psbt("70736274ff01", 60, 0));
+    }
+}
@@ -138,7 +138,8 @@ export! {
             sign_data_bytes,
             utxos,
             cert_keys,
-            origin
+            origin,
+            None
         );
         let cbor: Vec<u8> = match result.try_into() {
             Ok(v) => v,
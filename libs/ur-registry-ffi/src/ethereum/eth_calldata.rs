@@ -0,0 +1,120 @@
+use ethabi::{Contract, Token, Uint};
+use serde_json::{json, Value};
+
+use crate::export;
+use crate::util_internal::string_helper::remove_prefix_0x;
+
+const SELECTOR_LENGTH: usize = 4;
+
+fn token_to_json(token: &Token) -> Value {
+    match token {
+        Token::Address(address) => json!(format!("0x{}", hex::encode(address.as_bytes()))),
+        Token::FixedBytes(bytes) | Token::Bytes(bytes) => {
+            json!(format!("0x{}", hex::encode(bytes)))
+        }
+        Token::Uint(value) => json!(value.to_string()),
+        Token::Int(value) => {
+            // ints are decoded as two's complement words
+            if value.bit(255) {
+                json!(format!("-{}", (!*value).overflowing_add(Uint::one()).0))
+            } else {
+                json!(value.to_string())
+            }
+        }
+        Token::Bool(value) => json!(value),
+        Token::String(value) => json!(value),
+        Token::FixedArray(tokens) | Token::Array(tokens) | Token::Tuple(tokens) => {
+            json!(tokens.iter().map(token_to_json).collect::<Vec<Value>>())
+        }
+    }
+}
+
+export! {
+    @Java_com_keystone_sdk_KeystoneNativeSDK_ethereumDecodeCalldata
+    fn ethereum_decode_calldata(data_hex: &str, abi_json: &str) -> String {
+        let data = match hex::decode(remove_prefix_0x(data_hex)) {
+            Ok(v) if v.len() >= SELECTOR_LENGTH => v,
+            _ => return json!({"error": "data is invalid"}).to_string(),
+        };
+        let contract = match Contract::load(abi_json.as_bytes()) {
+            Ok(v) => v,
+            Err(_) => return json!({"error": "abi is invalid"}).to_string(),
+        };
+        let (selector, input) = data.split_at(SELECTOR_LENGTH);
+        let selector_hex = format!("0x{}", hex::encode(selector));
+
+        let function = match contract.functions().find(|f| f.short_signature() == selector) {
+            Some(v) => v,
+            None => return json!({"result": {"selector": selector_hex}}).to_string(),
+        };
+        let tokens = match function.decode_input(input) {
+            Ok(v) => v,
+            Err(_) => return json!({"error": "data does not match abi"}).to_string(),
+        };
+        let types = function
+            .inputs
+            .iter()
+            .map(|param| param.kind.to_string())
+            .collect::<Vec<String>>();
+        let params = function
+            .inputs
+            .iter()
+            .zip(tokens.iter())
+            .map(|(param, token)| json!({
+                "name": param.name,
+                "type": param.kind.to_string(),
+                "value": token_to_json(token),
+            }))
+            .collect::<Vec<Value>>();
+        json!({
+            "result": {
+                "selector": selector_hex,
+                "name": function.name,
+                "signature": format!("{}({})", function.name, types.join(",")),
+                "params": params,
+            }
+        }).to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ERC20_ABI: &str = r#"[{"type":"function","name":"transfer","inputs":[{"name":"_to","type":"address"},{"name":"_value","type":"uint256"}],"outputs":[{"name":"","type":"bool"}],"stateMutability":"nonpayable"},{"type":"function","name":"approve","inputs":[{"name":"_spender","type":"address"},{"name":"_value","type":"uint256"}],"outputs":[{"name":"","type":"bool"}],"stateMutability":"nonpayable"}]"#;
+    const TRANSFER_CALLDATA: &str = "0xa9059cbb00000000000000000000000049ab56b91fc982fd6ec1ec7bb87d74efa6da30ab00000000000000000000000000000000000000000000000000000000000f4240";
+
+    #[test]
+    fn test_ethereum_decode_erc20_transfer() {
+        let expect_result = r#"{"result":{"name":"transfer","params":[{"name":"_to","type":"address","value":"0x49ab56b91fc982fd6ec1ec7bb87d74efa6da30ab"},{"name":"_value","type":"uint256","value":"1000000"}],"selector":"0xa9059cbb","signature":"transfer(address,uint256)"}}"#;
+
+        assert_eq!(
+            expect_result,
+            ethereum_decode_calldata(TRANSFER_CALLDATA, ERC20_ABI)
+        );
+    }
+
+    #[test]
+    fn test_ethereum_decode_unknown_selector() {
+        let calldata = TRANSFER_CALLDATA.replacen("a9059cbb", "23b872dd", 1);
+        let expect_result = r#"{"result":{"selector":"0x23b872dd"}}"#;
+
+        assert_eq!(expect_result, ethereum_decode_calldata(&calldata, ERC20_ABI));
+    }
+
+    #[test]
+    fn test_ethereum_decode_calldata_error() {
+        assert_eq!(
+            r#"{"error":"data is invalid"}"#,
+            ethereum_decode_calldata("0xa905", ERC20_ABI)
+        );
+        assert_eq!(
+            r#"{"error":"abi is invalid"}"#,
+            ethereum_decode_calldata(TRANSFER_CALLDATA, "{")
+        );
+        assert_eq!(
+            r#"{"error":"data does not match abi"}"#,
+            ethereum_decode_calldata(&TRANSFER_CALLDATA[..40], ERC20_ABI)
+        );
+    }
+}
@@ -1,7 +1,10 @@
 use hex;
+use serde::Deserialize;
 use serde_json::json;
+use ur_parse_lib::ur::encode_raw;
 use ur_registry::crypto_key_path::CryptoKeyPath;
 use ur_registry::ethereum::eth_sign_request::{DataType, EthSignRequest};
+use ur_registry::registry_types::ETH_SIGN_REQUEST;
 use ur_registry::traits::To;
 use uuid::Uuid;
 
@@ -73,6 +76,75 @@ export! {
     }
 }
 
+// the JSON accepted by `ethereum_generate_sign_request`, `xfp` is the optional
+// master fingerprint of `path`
+#[derive(Deserialize)]
+struct SignRequestJson {
+    request_id: String,
+    sign_data: String,
+    data_type: u32,
+    chain_id: Option<u64>,
+    path: String,
+    xfp: Option<String>,
+    address: Option<String>,
+    origin: Option<String>,
+}
+
+export! {
+    @Java_com_keystone_sdk_KeystoneNativeSDK_ethereumGenerateSignRequest
+    fn ethereum_generate_sign_request(json: &str) -> String {
+        let request = match serde_json::from_str::<SignRequestJson>(json) {
+            Ok(v) => v,
+            Err(_) => return json!({"error": "json is invalid"}).to_string(),
+        };
+        let xfp = match request.xfp.as_deref().map(hex::decode) {
+            None => None,
+            Some(Ok(v)) => match v.as_slice().try_into() {
+                Ok(v) => Some(v),
+                Err(_) => return json!({"error": "length of xfp must be exactly 8"}).to_string(),
+            },
+            Some(Err(_)) => return json!({"error": "xfp is invalid"}).to_string(),
+        };
+        let derivation_path = match CryptoKeyPath::from_path(request.path, xfp) {
+            Ok(v) => v,
+            Err(_) => return json!({"error": "path is invalid"}).to_string(),
+        };
+        let data_type = match DataType::from_u32(request.data_type) {
+            Ok(v) => v,
+            Err(_) => return json!({"error": "data type is invalid"}).to_string(),
+        };
+        let chain_id = match request.chain_id {
+            Some(0) => return json!({"error": "chain id is invalid"}).to_string(),
+            chain_id => chain_id.map(i128::from),
+        };
+        let request_id = match Uuid::parse_str(&request.request_id) {
+            Ok(v) => v,
+            Err(_) => return json!({"error": "uuid is invalid"}).to_string(),
+        }.as_bytes().to_vec();
+        let sign_data = match hex::decode(remove_prefix_0x(&request.sign_data)) {
+            Ok(v) => v,
+            Err(_) => return json!({"error": "sign_data is invalid"}).to_string(),
+        };
+        let address = request.address.filter(|a| !a.is_empty()).map(|a| a.as_bytes().to_vec());
+        let origin = request.origin.filter(|o| !o.is_empty());
+
+        let result = EthSignRequest::new(
+            Some(request_id),
+            sign_data,
+            data_type,
+            chain_id,
+            derivation_path,
+            address,
+            origin
+        );
+        let cbor = match result.to_bytes() {
+            Ok(v) => v,
+            Err(_) => return json!({"error": "cbor serialization failed"}).to_string(),
+        };
+        json!({"result": encode_raw(&ETH_SIGN_REQUEST.get_type(), &cbor)}).to_string()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -160,4 +232,53 @@ mod tests {
             )
         );
     }
+
+    #[test]
+    fn test_ethereum_generate_sign_request() {
+        let json = r#"{
+            "request_id": "9b1deb4d-3b7d-4bad-9bdd-2b0d7b3dcb6d",
+            "sign_data": "0xf849808609184e72a00082271094000000000000000000000000000000000000000080a47f7465737432000000000000000000000000000000000000000000000000000000600057808080",
+            "data_type": 1,
+            "chain_id": 1,
+            "path": "m/44'/1'/1'/0/1",
+            "xfp": "12345678",
+            "origin": "metamask"
+        }"#;
+
+        let expect_result = "{\"result\":\"ur:eth-sign-request/oladtpdagdndcawmgtfrkigrpmndutdnbtkgfssbjnaohdgryagalalnascsgljpnbaelfdibemwaeaeaeaeaeaeaeaeaeaeaeaeaeaeaeaeaeaeaeaelaoxlbjyihjkjyeyaeaeaeaeaeaeaeaeaeaeaeaeaeaeaeaeaeaeaeaeaeaeaeaeaeaeaehnaehglalalaaxadaaadahtaaddyoeadlecsdwykadykadykaewkadwkaocybgeehfksatisjnihjyhsjnhsjkjetlnndant\"}";
+
+        assert_eq!(expect_result, ethereum_generate_sign_request(json));
+    }
+
+    #[test]
+    fn test_ethereum_generate_sign_request_invalid_input() {
+        let json = r#"{
+            "request_id": "9b1deb4d-3b7d-4bad-9bdd-2b0d7b3dcb6d",
+            "sign_data": "f849",
+            "data_type": 9,
+            "chain_id": 1,
+            "path": "m/44'/60'/0'/0/0"
+        }"#;
+        assert_eq!(
+            "{\"error\":\"data type is invalid\"}",
+            ethereum_generate_sign_request(json)
+        );
+
+        let json = r#"{
+            "request_id": "9b1deb4d-3b7d-4bad-9bdd-2b0d7b3dcb6d",
+            "sign_data": "f849",
+            "data_type": 1,
+            "chain_id": 0,
+            "path": "m/44'/60'/0'/0/0"
+        }"#;
+        assert_eq!(
+            "{\"error\":\"chain id is invalid\"}",
+            ethereum_generate_sign_request(json)
+        );
+
+        assert_eq!(
+            "{\"error\":\"json is invalid\"}",
+            ethereum_generate_sign_request("{\"sign_data\": \"f849\"}")
+        );
+    }
 }
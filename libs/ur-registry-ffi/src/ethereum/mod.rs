@@ -1,2 +1,3 @@
+pub mod eth_calldata;
 pub mod eth_sign_request;
 pub mod eth_signature;
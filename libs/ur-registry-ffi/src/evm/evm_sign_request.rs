@@ -77,7 +77,8 @@ export! {
             custom_chain_identifier,
             path,
             address,
-            origin
+            origin,
+            None
         ).try_into() {
             Ok(v) => v,
             Err(_) => return json!({"error": "sign data is invalid"}).to_string(),
@@ -6,6 +6,10 @@ pub mod ios;
 pub use anyhow;
 pub use ffi_support;
 
+use serde_json::json;
+use std::any::Any;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+
 /// Trait for converting Rust types into FFI return values
 pub trait Return<'a>: Sized {
     type Ext;
@@ -23,13 +27,43 @@ pub trait Argument<'a> {
     fn convert(env: &Self::Env, val: Self::Ext) -> Self;
 }
 
+/// Trait for FFI return values that can carry an error to the caller
+pub trait ErrorEnvelope: Sized {
+    fn error(message: &str) -> Self;
+}
+
+impl ErrorEnvelope for String {
+    fn error(message: &str) -> Self {
+        json!({ "error": message }).to_string()
+    }
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> &str {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.as_str()
+    } else {
+        "unknown error"
+    }
+}
+
+/// Runs an export body, turning a panic into an error envelope so that it
+/// never unwinds across the FFI boundary.
+pub fn guard<R: ErrorEnvelope>(f: impl FnOnce() -> R) -> R {
+    catch_unwind(AssertUnwindSafe(f))
+        .unwrap_or_else(|payload| R::error(panic_message(&*payload)))
+}
+
 #[macro_export]
 macro_rules! export {
     ($( @$jname:ident fn $name:ident($( $a:ident : $t:ty ),*) -> $ret:ty $code:block )*) => {
         $(
             pub fn $name(
                 $( $a: $t ),*
-            ) -> $ret $code
+            ) -> $ret {
+                $crate::export::guard(move || -> $ret $code)
+            }
         )*
 
         #[cfg(feature = "jni")]
@@ -74,4 +108,32 @@ macro_rules! export {
 }
 
 #[cfg(test)]
-mod tests {}
+mod tests {
+    use super::*;
+
+    export! {
+        @Java_com_keystone_sdk_KeystoneNativeSDK_testPanic
+        fn test_panic(message: &str) -> String {
+            if message.is_empty() {
+                return "ok".to_string();
+            }
+            panic!("{}", message)
+        }
+    }
+
+    #[test]
+    fn test_guard_returns_value() {
+        assert_eq!("ok", test_panic(""));
+    }
+
+    #[test]
+    fn test_guard_returns_error_envelope() {
+        assert_eq!(r#"{"error":"index out of range"}"#, test_panic("index out of range"));
+    }
+
+    #[test]
+    fn test_guard_static_message() {
+        let result: String = guard(|| panic!("static message"));
+        assert_eq!(r#"{"error":"static message"}"#, result);
+    }
+}
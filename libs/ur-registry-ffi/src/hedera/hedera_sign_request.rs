@@ -0,0 +1,111 @@
+use hex;
+use serde_json::json;
+use ur_registry::crypto_key_path::CryptoKeyPath;
+use ur_registry::hedera::hedera_sign_request::{HederaSignRequest, KeyType};
+use ur_registry::registry_types::HEDERA_SIGN_REQUEST;
+use uuid::Uuid;
+
+use crate::export;
+use crate::util_internal::string_helper::remove_prefix_0x;
+
+export! {
+    @Java_com_keystone_sdk_KeystoneNativeSDK_generateHederaSignRequest
+    fn generate_hedera_sign_request(
+        request_id: &str,
+        sign_data: &str,
+        key_type: u32,
+        path: &str,
+        xfp: &str,
+        origin: &str
+    ) -> String {
+        let request_id = match Uuid::parse_str(request_id) {
+            Ok(v) => v.as_bytes().to_vec(),
+            Err(_) => return json!({"error": "uuid is invalid"}).to_string(),
+        };
+
+        let sign_data = match hex::decode(remove_prefix_0x(sign_data)) {
+            Ok(v) if !v.is_empty() => v,
+            _ => return json!({"error": "sign data is invalid"}).to_string(),
+        };
+
+        let key_type = match KeyType::from_u32(key_type) {
+            Ok(v) => v,
+            Err(_) => return json!({"error": "key type is invalid"}).to_string(),
+        };
+
+        let master_fingerprint: [u8; 4] = match hex::decode(xfp).ok().and_then(|v| v.try_into().ok()) {
+            Some(v) => v,
+            None => return json!({"error": "xfp is invalid"}).to_string(),
+        };
+
+        let derivation_path = match CryptoKeyPath::from_path(path.to_string(), Some(master_fingerprint)) {
+            Ok(v) => v,
+            Err(_) => return json!({"error": "path is invalid"}).to_string(),
+        };
+
+        let origin = if origin.is_empty() { None } else { Some(origin.to_string()) };
+
+        let request = HederaSignRequest::new(
+            Some(request_id),
+            sign_data,
+            key_type,
+            derivation_path,
+            origin,
+        );
+
+        let cbor_bytes: Vec<u8> = match request.try_into() {
+            Ok(v) => v,
+            Err(_) => return json!({"error": "cbor serialization failed"}).to_string(),
+        };
+        let cbor_hex = hex::encode(cbor_bytes);
+        let ur_type = HEDERA_SIGN_REQUEST.get_type();
+        let ur = json!({
+            "type": ur_type,
+            "cbor": cbor_hex,
+        });
+        ur.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generate_hedera_sign_request() {
+        let request_id = "9b1deb4d-3b7d-4bad-9bdd-2b0d7b3dcb6d";
+        // a crypto transfer of 1 hbar from 0.0.1001 to 0.0.1002
+        let sign_data = "0a0d0a060880a5b6c706120318e907120218031880c2d72f2202087832086b657973746f6e65721a0a180a0a0a0318e90710ff83af5f0a0a0a0318ea07108084af5f";
+        let expect_result = "{\"cbor\":\"a501d825509b1deb4d3b7d4bad9bdd2b0d7b3dcb6d0258420a0d0a060880a5b6c706120318e907120218031880c2d72f2202087832086b657973746f6e65721a0a180a0a0a0318e90710ff83af5f0a0a0a0318ea07108084af5f030104d90130a2018a182cf5190bd6f500f500f500f5021af23f9fd205686b657973746f6e65\",\"type\":\"hedera-sign-request\"}";
+
+        assert_eq!(
+            expect_result,
+            generate_hedera_sign_request(
+                request_id,
+                sign_data,
+                1,
+                "m/44'/3030'/0'/0'/0'",
+                "f23f9fd2",
+                "keystone"
+            )
+        );
+    }
+
+    #[test]
+    fn test_generate_hedera_sign_request_error() {
+        let request_id = "9b1deb4d-3b7d-4bad-9bdd-2b0d7b3dcb6d";
+        let path = "m/44'/3030'/0'/0'/0'";
+        assert_eq!(
+            "{\"error\":\"key type is invalid\"}",
+            generate_hedera_sign_request(request_id, "00", 3, path, "f23f9fd2", "")
+        );
+        assert_eq!(
+            "{\"error\":\"xfp is invalid\"}",
+            generate_hedera_sign_request(request_id, "00", 1, path, "f23f9f", "")
+        );
+        assert_eq!(
+            "{\"error\":\"sign data is invalid\"}",
+            generate_hedera_sign_request(request_id, "", 1, path, "f23f9fd2", "")
+        );
+    }
+}
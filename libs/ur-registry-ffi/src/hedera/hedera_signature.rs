@@ -0,0 +1,62 @@
+use anyhow::format_err;
+use anyhow::Error;
+use hex;
+use serde_json::json;
+use ur_registry::hedera::hedera_signature::HederaSignature;
+use ur_registry::registry_types::HEDERA_SIGNATURE;
+use uuid::Uuid;
+
+use crate::export;
+
+export! {
+    @Java_com_keystone_sdk_KeystoneNativeSDK_parseHederaSignature
+    fn parse_hedera_signature(ur_type: &str, cbor_hex: &str) -> String {
+        if HEDERA_SIGNATURE.get_type() != ur_type {
+            return json!({"error": "type not match"}).to_string();
+        }
+
+        let parse_signature = || -> Result<(String, String), Error> {
+            let cbor = hex::decode(cbor_hex.to_string())?;
+            let sig = HederaSignature::try_from(cbor)?;
+            let uuid = sig.get_request_id().ok_or(format_err!(""))?;
+            let request_id = Uuid::from_slice(&uuid)?.to_string();
+            let signature = hex::encode(sig.get_signature());
+            Ok((request_id, signature))
+        };
+        match parse_signature() {
+            Ok((request_id, signature)) => json!({
+                "request_id": request_id,
+                "signature": signature,
+            }).to_string(),
+            Err(_) => json!({"error": "signature is invalid"}).to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_hedera_signature() {
+        let signature_cbor = "a201d825509b1deb4d3b7d4bad9bdd2b0d7b3dcb6d025840000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f202122232425262728292a2b2c2d2e2f303132333435363738393a3b3c3d3e3f";
+        let expect_result = "{\"request_id\":\"9b1deb4d-3b7d-4bad-9bdd-2b0d7b3dcb6d\",\"signature\":\"000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f202122232425262728292a2b2c2d2e2f303132333435363738393a3b3c3d3e3f\"}";
+
+        assert_eq!(
+            expect_result,
+            parse_hedera_signature("hedera-signature", signature_cbor)
+        );
+    }
+
+    #[test]
+    fn test_parse_hedera_signature_error() {
+        assert_eq!(
+            "{\"error\":\"type not match\"}",
+            parse_hedera_signature("eth-signature", "a201")
+        );
+        assert_eq!(
+            "{\"error\":\"signature is invalid\"}",
+            parse_hedera_signature("hedera-signature", "a201")
+        );
+    }
+}
@@ -25,7 +25,7 @@ export! {
             let sign_result = unzip(ziped_sign_result).map_err(|_| format_err!(""))?;
             let sign_result_base = parse_protobuf::<Base>(sign_result).map_err(|_| format_err!(""))?;
             let payload = sign_result_base.data.unwrap_or_default();
-            let content = payload.content.unwrap();
+            let content = payload.content.ok_or_else(|| format_err!(""))?;
             match content {
                 Content::SignTxResult(sign_result) => Ok(sign_result),
                 _ => Err(format_err!(""))
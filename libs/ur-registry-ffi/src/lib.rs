@@ -1,11 +1,13 @@
 pub mod aptos;
 pub mod arweave;
+pub mod avalanche;
 pub mod bitcoin;
 pub mod cardano;
 pub mod cosmos;
 pub mod ethereum;
 pub mod evm;
 mod export;
+pub mod hedera;
 pub mod keystone;
 pub mod near;
 pub mod solana;
@@ -1,2 +1,3 @@
 pub mod sol_sign_request;
 pub mod sol_signature;
+pub mod sol_transaction;
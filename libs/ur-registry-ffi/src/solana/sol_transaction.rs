@@ -0,0 +1,258 @@
+use anyhow::{format_err, Error};
+use serde_json::{json, Value};
+
+use crate::export;
+use crate::util_internal::string_helper::remove_prefix_0x;
+
+const SYSTEM_PROGRAM_ID: [u8; 32] = [0; 32];
+const SYSTEM_TRANSFER: u32 = 2;
+const VERSION_PREFIX_MASK: u8 = 0x80;
+const SIGNATURE_LENGTH: usize = 64;
+const PUBKEY_LENGTH: usize = 32;
+
+struct Reader<'a> {
+    data: &'a [u8],
+    offset: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn new(data: &'a [u8]) -> Self {
+        Reader { data, offset: 0 }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.offset == self.data.len()
+    }
+
+    fn peek(&self) -> Result<u8, Error> {
+        self.data
+            .get(self.offset)
+            .copied()
+            .ok_or_else(|| format_err!("unexpected end of data"))
+    }
+
+    fn read(&mut self, len: usize) -> Result<&'a [u8], Error> {
+        let end = self
+            .offset
+            .checked_add(len)
+            .filter(|end| *end <= self.data.len())
+            .ok_or_else(|| format_err!("unexpected end of data"))?;
+        let slice = &self.data[self.offset..end];
+        self.offset = end;
+        Ok(slice)
+    }
+
+    fn read_u8(&mut self) -> Result<u8, Error> {
+        Ok(self.read(1)?[0])
+    }
+
+    // shortvec length prefix, at most three bytes
+    fn read_compact_u16(&mut self) -> Result<usize, Error> {
+        let mut value = 0usize;
+        for i in 0..3 {
+            let byte = self.read_u8()?;
+            value |= ((byte & 0x7f) as usize) << (i * 7);
+            if byte & 0x80 == 0 {
+                return Ok(value);
+            }
+        }
+        Err(format_err!("compact-u16 is too long"))
+    }
+
+    fn read_vec(&mut self) -> Result<&'a [u8], Error> {
+        let len = self.read_compact_u16()?;
+        self.read(len)
+    }
+}
+
+fn parse_system_instruction(accounts: &[String], data: &[u8]) -> Option<Value> {
+    let instruction = u32::from_le_bytes(data.get(0..4)?.try_into().ok()?);
+    if instruction != SYSTEM_TRANSFER || data.len() != 12 || accounts.len() < 2 {
+        return None;
+    }
+    let lamports = u64::from_le_bytes(data[4..12].try_into().ok()?);
+    Some(json!({
+        "type": "transfer",
+        "from": accounts[0],
+        "to": accounts[1],
+        "lamports": lamports.to_string(),
+    }))
+}
+
+fn parse_message(reader: &mut Reader) -> Result<Value, Error> {
+    let versioned = reader.peek()? & VERSION_PREFIX_MASK != 0;
+    let version = if versioned {
+        let version = reader.read_u8()? & !VERSION_PREFIX_MASK;
+        if version != 0 {
+            return Err(format_err!("unsupported message version {}", version));
+        }
+        json!(version)
+    } else {
+        json!("legacy")
+    };
+
+    let header = reader.read(3)?;
+    let account_keys = (0..reader.read_compact_u16()?)
+        .map(|_| Ok(bs58::encode(reader.read(PUBKEY_LENGTH)?).into_string()))
+        .collect::<Result<Vec<String>, Error>>()?;
+    let recent_blockhash = bs58::encode(reader.read(PUBKEY_LENGTH)?).into_string();
+
+    let system_program_id = bs58::encode(SYSTEM_PROGRAM_ID).into_string();
+    let instruction_count = reader.read_compact_u16()?;
+    let mut instructions = vec![];
+    for _ in 0..instruction_count {
+        let program_id_index = reader.read_u8()? as usize;
+        let program_id = account_keys
+            .get(program_id_index)
+            .ok_or_else(|| format_err!("program id index is out of range"))?;
+        // v0 messages may reference accounts loaded from a lookup table,
+        // which can not be resolved offline
+        let accounts = reader
+            .read_vec()?
+            .iter()
+            .map(|index| match account_keys.get(*index as usize) {
+                Some(key) => key.clone(),
+                None => format!("lookup:{}", index),
+            })
+            .collect::<Vec<String>>();
+        let data = reader.read_vec()?;
+
+        let mut instruction = json!({
+            "program_id": program_id,
+            "accounts": accounts,
+            "data": hex::encode(data),
+        });
+        if *program_id == system_program_id {
+            instruction["program"] = json!("system");
+            if let Some(parsed) = parse_system_instruction(&accounts, data) {
+                instruction["parsed"] = parsed;
+            }
+        }
+        instructions.push(instruction);
+    }
+
+    let mut message = json!({
+        "version": version,
+        "header": {
+            "num_required_signatures": header[0],
+            "num_readonly_signed_accounts": header[1],
+            "num_readonly_unsigned_accounts": header[2],
+        },
+        "account_keys": account_keys,
+        "recent_blockhash": recent_blockhash,
+        "instructions": instructions,
+    });
+    if versioned {
+        let lookups = (0..reader.read_compact_u16()?)
+            .map(|_| {
+                Ok(json!({
+                    "account_key": bs58::encode(reader.read(PUBKEY_LENGTH)?).into_string(),
+                    "writable_indexes": reader.read_vec()?,
+                    "readonly_indexes": reader.read_vec()?,
+                }))
+            })
+            .collect::<Result<Vec<Value>, Error>>()?;
+        message["address_table_lookups"] = json!(lookups);
+    }
+    if !reader.is_empty() {
+        return Err(format_err!("trailing bytes after message"));
+    }
+    Ok(message)
+}
+
+// accepts either a bare message or a transaction with its signatures
+fn parse_transaction(data: &[u8]) -> Result<Value, Error> {
+    if let Ok(message) = parse_message(&mut Reader::new(data)) {
+        return Ok(message);
+    }
+    let mut reader = Reader::new(data);
+    let signatures = (0..reader.read_compact_u16()?)
+        .map(|_| Ok(bs58::encode(reader.read(SIGNATURE_LENGTH)?).into_string()))
+        .collect::<Result<Vec<String>, Error>>()?;
+    let mut message = parse_message(&mut reader)?;
+    message["signatures"] = json!(signatures);
+    Ok(message)
+}
+
+export! {
+    @Java_com_keystone_sdk_KeystoneNativeSDK_solanaParseTransaction
+    fn solana_parse_transaction(tx_hex: &str) -> String {
+        let tx = match hex::decode(remove_prefix_0x(tx_hex)) {
+            Ok(v) => v,
+            Err(_) => return json!({"error": "transaction is invalid"}).to_string(),
+        };
+        match parse_transaction(&tx) {
+            Ok(message) => json!({"result": message}).to_string(),
+            Err(e) => json!({"error": format!("transaction is invalid: {}", e)}).to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TRANSFER_MESSAGE: &str = "01000103c8d842a2f17fd7aab608ce2ea535a6e958dffa20caf669b347b911c4171965530f957620b228bae2b94c82ddd4c093983a67365555b737ec7ddc1117e61c72e0000000000000000000000000000000000000000000000000000000000000000010295cc2f1f39f3604718496ea00676d6a72ec66ad09d926e3ece34f565f18d201020200010c0200000000e1f50500000000";
+
+    #[test]
+    fn test_solana_parse_system_transfer() {
+        let result: Value =
+            serde_json::from_str(&solana_parse_transaction(TRANSFER_MESSAGE)).unwrap();
+        let message = &result["result"];
+        assert_eq!("legacy", message["version"]);
+        assert_eq!(3, message["account_keys"].as_array().unwrap().len());
+
+        let instruction = &message["instructions"][0];
+        assert_eq!("11111111111111111111111111111111", instruction["program_id"]);
+        assert_eq!("system", instruction["program"]);
+        assert_eq!("0200000000e1f50500000000", instruction["data"]);
+        assert_eq!("transfer", instruction["parsed"]["type"]);
+        assert_eq!("100000000", instruction["parsed"]["lamports"]);
+        assert_eq!(message["account_keys"][0], instruction["parsed"]["from"]);
+        assert_eq!(message["account_keys"][1], instruction["parsed"]["to"]);
+    }
+
+    #[test]
+    fn test_solana_parse_signed_v0_transaction() {
+        let tx_hex = format!(
+            "01{}80{}00",
+            "11".repeat(SIGNATURE_LENGTH),
+            TRANSFER_MESSAGE
+        );
+        let result: Value = serde_json::from_str(&solana_parse_transaction(&tx_hex)).unwrap();
+        let message = &result["result"];
+        assert_eq!(0, message["version"]);
+        assert_eq!(1, message["signatures"].as_array().unwrap().len());
+        assert_eq!(0, message["address_table_lookups"].as_array().unwrap().len());
+        assert_eq!("transfer", message["instructions"][0]["parsed"]["type"]);
+    }
+
+    #[test]
+    fn test_solana_parse_unknown_program() {
+        // the transfer message with the program id key replaced by 0x01..01
+        let tx_hex = TRANSFER_MESSAGE.replacen(
+            &format!("e0{}", "00".repeat(32)),
+            &format!("e0{}", "01".repeat(32)),
+            1,
+        );
+        let result: Value = serde_json::from_str(&solana_parse_transaction(&tx_hex)).unwrap();
+        let instruction = &result["result"]["instructions"][0];
+        assert!(instruction.get("program").is_none());
+        assert!(instruction.get("parsed").is_none());
+        assert_eq!("0200000000e1f50500000000", instruction["data"]);
+    }
+
+    #[test]
+    fn test_solana_parse_transaction_error() {
+        assert_eq!(
+            r#"{"error":"transaction is invalid"}"#,
+            solana_parse_transaction("zz")
+        );
+        let result: Value =
+            serde_json::from_str(&solana_parse_transaction(&TRANSFER_MESSAGE[..100])).unwrap();
+        assert!(result["error"]
+            .as_str()
+            .unwrap()
+            .starts_with("transaction is invalid"));
+    }
+}
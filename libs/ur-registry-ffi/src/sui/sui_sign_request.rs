@@ -86,6 +86,9 @@ export! {
             derivation_paths,
             addresses,
             origin,
+            None,
+            None,
+            None,
         ).try_into() {
             Ok(v) => v,
             Err(_) => return json!({"error": "sign data is invalid"}).to_string(),
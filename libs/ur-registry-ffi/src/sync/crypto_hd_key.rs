@@ -22,12 +22,12 @@ pub struct AccountExtra {
 
 #[derive(Default, Clone, Debug, Serialize, Deserialize)]
 pub struct Account {
-    chain: String,
-    path: String,
+    pub(crate) chain: String,
+    pub(crate) path: String,
     public_key: String,
     name: String,
     chain_code: String,
-    extended_public_key: String,
+    pub(crate) extended_public_key: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     note: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
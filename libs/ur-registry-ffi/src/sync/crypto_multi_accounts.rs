@@ -37,6 +37,28 @@ impl Into<MultiAccounts> for CryptoMultiAccounts {
     }
 }
 
+impl MultiAccounts {
+    /// The accounts as wallets import them, one entry per chain and path.
+    pub fn to_import_json(&self) -> String {
+        let accounts: Vec<serde_json::Value> = self
+            .keys
+            .iter()
+            .map(|account| {
+                json!({
+                    "chain": account.chain,
+                    "path": account.path,
+                    "xpub": account.extended_public_key,
+                })
+            })
+            .collect();
+        json!({
+            "masterFingerprint": self.master_fingerprint,
+            "accounts": accounts,
+        })
+        .to_string()
+    }
+}
+
 export! {
     @Java_com_keystone_sdk_KeystoneNativeSDK_parseCryptoMultiAccounts
     fn parse_crypto_multi_accounts(ur_type: &str, cbor_hex: &str) -> String {
@@ -129,4 +151,25 @@ mod tests {
             parse_crypto_multi_accounts("crypto-multi-accounts", multi_accounts_cbor)
         );
     }
+
+    #[test]
+    fn test_to_import_json() {
+        let multi_accounts_cbor = "a3011aa424853c0282d9012fa4035821034af544244d31619d773521a1a366373c485ff89de50bea543c2b14cccfbb6a500458208dc2427d8ab23caab07729f88f089a3cfa2cfffcd7d1e507f983c0d44a5dbd3506d90130a10186182cf500f500f5081a149439dcd9012fa902f403582102cc6d7834204653ff10e0047a2395343cc6df081e76c88d5eee83f346f0b21cb7045820712a9187e5c60c573a5acce855445376e1b74c240e417fe8cb2a8fdfd78d2d9d05d90131a201183c020006d90130a30186182cf5183cf500f5021af23f9fd2030307d90130a2018400f480f40300081a483c932809684b657973746f6e650a706163636f756e742e7374616e6461726403686b657973746f6e65";
+        let multi_accounts: MultiAccounts =
+            CryptoMultiAccounts::from_cbor(hex::decode(multi_accounts_cbor).unwrap())
+                .unwrap()
+                .into();
+        let result: serde_json::Value =
+            serde_json::from_str(&multi_accounts.to_import_json()).unwrap();
+
+        assert_eq!("a424853c", result["masterFingerprint"]);
+        let accounts = result["accounts"].as_array().unwrap();
+        assert_eq!(2, accounts.len());
+        assert_eq!("BTC", accounts[0]["chain"]);
+        assert_eq!("m/44'/0'/0'", accounts[0]["path"]);
+        assert_eq!("xpub6BoYPFH1MivLdh2BWZuRu6LfuaVSkVak5wsDxjjkAWcUM2QPKyeCHXMgDfRJFvKZhqA4vM5vsgcD6C5ot9eThnFHstgPntNzBLUdLeKS7Zt", accounts[0]["xpub"]);
+        assert_eq!("ETH", accounts[1]["chain"]);
+        assert_eq!("m/44'/60'/0'", accounts[1]["path"]);
+        assert_eq!("xpub6CBZfsQuZgVnvTcScAAXSxtX5jdMHtX5LdRuygnTScMBbKyjsxznd8XMEqDntdY1jigmjunwRwHsQs3xusYQBVFbvLdN4YLzH8caLSSiAoV", accounts[1]["xpub"]);
+    }
 }
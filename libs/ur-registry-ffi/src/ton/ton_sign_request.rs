@@ -14,14 +14,14 @@ export! {
         request_id: &str, //optional
         sign_data: &str,
         data_type: u32,
-        address: &str,
+        address: &str, //optional
         derivation_path: &str, //optional
         xfp: &str, //optional
         origin: &str //optional
     ) -> String {
         let address = match address {
-            "" => return json!({"error": "address is required"}).to_string(),
-            _x => _x.to_string()
+            "" => None,
+            _x => Some(_x.to_string())
         };
 
         let sign_data = match sign_data {
@@ -126,14 +126,14 @@ mod tests {
     }
 
     #[test]
-    fn test_generate_ton_sign_request_err_address() {
+    fn test_generate_ton_sign_request_without_address() {
         let request_id = "9b1deb4d-3b7d-4bad-9bdd-2b0d7b3dcb6d";
         let sign_data = "b5ee9c7241010201004700011c29a9a317663b3ea500000008000301006842002b16732f1c05fdb4e8d3a78fd10dddef3f6067f311be539313b8a44a504d4da2a1dcd65000000000000000000000000000007072e06f";
         let address = "";
-        let data_type = 0;
+        let data_type = 1;
         let origin = "TonKeeper";
 
-        let expect_result = "{\"error\":\"address is required\"}";
+        let expect_result = "{\"cbor\":\"a401d825509b1deb4d3b7d4bad9bdd2b0d7b3dcb6d025856b5ee9c7241010201004700011c29a9a317663b3ea500000008000301006842002b16732f1c05fdb4e8d3a78fd10dddef3f6067f311be539313b8a44a504d4da2a1dcd65000000000000000000000000000007072e06f03010669546f6e4b6565706572\",\"type\":\"ton-sign-request\"}";
 
         assert_eq!(
             expect_result,
@@ -3,6 +3,7 @@ use hex;
 use serde_json::json;
 use ur_registry::registry_types::TON_SIGNATURE;
 use ur_registry::ton::ton_signature::TonSignature;
+use ur_registry::traits::DecodeWarnings;
 use uuid::Uuid;
 
 use crate::export;
@@ -38,6 +39,29 @@ export! {
             Err(_) => json!({"error": "signature is invalid"}).to_string(),
         }
     }
+
+    @Java_com_keystone_sdk_KeystoneNativeSDK_parseTonSignatureWithWarnings
+    fn parse_ton_signature_with_warnings(ur_type: &str, cbor_hex: &str) -> String {
+        if TON_SIGNATURE.get_type() != ur_type {
+            return json!({"error": "type not match"}).to_string();
+        }
+
+        let warnings = match hex::decode(cbor_hex.to_string()) {
+            Ok(cbor) => match TonSignature::decode_warnings(&cbor) {
+                Ok(warnings) => warnings,
+                Err(_) => return json!({"error": "signature is invalid"}).to_string(),
+            },
+            Err(_) => return json!({"error": "signature is invalid"}).to_string(),
+        };
+        let result: serde_json::Value = match serde_json::from_str(&parse_ton_signature(ur_type, cbor_hex)) {
+            Ok(v) => v,
+            Err(_) => return json!({"error": "signature is invalid"}).to_string(),
+        };
+        json!({
+            "result": result,
+            "warnings": warnings,
+        }).to_string()
+    }
 }
 
 #[cfg(test)]
@@ -76,4 +100,25 @@ mod tests {
             parse_ton_signature("ton-signature", signature_cbor)
         );
     }
+
+    #[test]
+    fn test_parse_ton_signature_with_warnings() {
+        let signature_cbor = "a401d825509b1deb4d3b7d4bad9bdd2b0d7b3dcb6d025840f4b79835417490958c72492723409289b444f3af18274ba484a9eeaca9e760520e453776e5975df058b537476932a45239685f694fc6362fe5af6ba714da650503684b657973746f6e65096178";
+        let expect_result = "{\"result\":{\"origin\":\"Keystone\",\"request_id\":\"9b1deb4d-3b7d-4bad-9bdd-2b0d7b3dcb6d\",\"signature\":\"f4b79835417490958c72492723409289b444f3af18274ba484a9eeaca9e760520e453776e5975df058b537476932a45239685f694fc6362fe5af6ba714da6505\"},\"warnings\":[\"unknown key `9` is ignored\"]}";
+
+        assert_eq!(
+            expect_result,
+            parse_ton_signature_with_warnings("ton-signature", signature_cbor)
+        );
+    }
+
+    #[test]
+    fn test_parse_ton_signature_with_warnings_error() {
+        let expect_result = "{\"error\":\"signature is invalid\"}";
+
+        assert_eq!(
+            expect_result,
+            parse_ton_signature_with_warnings("ton-signature", "a201")
+        );
+    }
 }
@@ -1,4 +1,5 @@
 pub mod tron_sign_request;
 pub mod tron_signature;
+pub mod tron_transaction;
 pub mod tron_transfer;
 pub mod types;
@@ -0,0 +1,119 @@
+use anyhow::{format_err, Error};
+use ethabi::ParamType;
+use protobuf::Message;
+use serde_json::{json, Value};
+
+use crate::export;
+use crate::tron::types::contract::TriggerSmartContract;
+use crate::tron::types::tron::transaction::{contract::ContractType, Raw};
+use crate::util_internal::string_helper::remove_prefix_0x;
+
+// keccak256("transfer(address,uint256)")[..4]
+const TRC20_TRANSFER_SELECTOR: [u8; 4] = [0xa9, 0x05, 0x9c, 0xbb];
+const TRON_ADDRESS_PREFIX: u8 = 0x41;
+
+fn format_address(address_bytes: &[u8]) -> String {
+    bs58::encode(address_bytes).with_check().into_string()
+}
+
+fn parse_trc20_transfer(contract: &TriggerSmartContract) -> Option<Value> {
+    let input = contract.data.strip_prefix(&TRC20_TRANSFER_SELECTOR)?;
+    let tokens = ethabi::decode(&[ParamType::Address, ParamType::Uint(256)], input).ok()?;
+    let mut to = vec![TRON_ADDRESS_PREFIX];
+    to.extend_from_slice(tokens[0].clone().into_address()?.as_bytes());
+    let amount = tokens[1].clone().into_uint()?;
+    Some(json!({
+        "type": "trc20_transfer",
+        "from": format_address(&contract.owner_address),
+        "contract_address": format_address(&contract.contract_address),
+        "to": format_address(&to),
+        "amount": amount.to_string(),
+    }))
+}
+
+fn parse_transaction(data: &[u8]) -> Result<Value, Error> {
+    let raw = Raw::parse_from_bytes(data).map_err(|e| format_err!("{}", e))?;
+    let contract = raw
+        .contract
+        .first()
+        .ok_or_else(|| format_err!("transaction has no contract"))?;
+    let contract_type = contract.type_.enum_value();
+    if contract_type == Ok(ContractType::TriggerSmartContract) {
+        let trigger =
+            TriggerSmartContract::parse_from_bytes(&contract.parameter.get_or_default().value)
+                .map_err(|e| format_err!("{}", e))?;
+        if let Some(transfer) = parse_trc20_transfer(&trigger) {
+            return Ok(transfer);
+        }
+    }
+    let name = match contract_type {
+        Ok(contract_type) => format!("{:?}", contract_type),
+        Err(value) => value.to_string(),
+    };
+    Ok(json!({ "type": name }))
+}
+
+export! {
+    @Java_com_keystone_sdk_KeystoneNativeSDK_tronParseTransaction
+    fn tron_parse_transaction(raw_hex: &str) -> String {
+        let raw = match hex::decode(remove_prefix_0x(raw_hex)) {
+            Ok(v) => v,
+            Err(_) => return json!({"error": "transaction is invalid"}).to_string(),
+        };
+        match parse_transaction(&raw) {
+            Ok(result) => json!({"result": result}).to_string(),
+            Err(e) => json!({"error": format!("transaction is invalid: {}", e)}).to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // a 10 USDT transfer, the raw data of the trc20 sign request test
+    const TRC20_TRANSFER: &str = "0a0207902208e1b9de559665c6714080c49789bb2c5aae01081f12a9010a31747970652e676f6f676c65617069732e636f6d2f70726f746f636f6c2e54726967676572536d617274436f6e747261637412740a15418dfec1cde1fe6a9ec38a16c7d67073e3020851c0121541a614f803b6fd780986a42c78ec9c7f77e6ded13c2244a9059cbb0000000000000000000000009c0279f1bda9fc40a85f1b53c306602864533e73000000000000000000000000000000000000000000000000000000000098968070c0b6e087bb2c90018094ebdc03";
+    const TRX_TRANSFER: &str = "0a02665c2208ec27b63954f5913d40d887bdd4f72d5a67080112630a2d747970652e676f6f676c65617069732e636f6d2f70726f746f636f6c2e5472616e73666572436f6e747261637412320a15418dfec1cde1fe6a9ec38a16c7d67073e3020851c01215419c0279f1bda9fc40a85f1b53c306602864533e7318eda201";
+
+    #[test]
+    fn test_tron_parse_trc20_transfer() {
+        let result: Value = serde_json::from_str(&tron_parse_transaction(TRC20_TRANSFER)).unwrap();
+        let transfer = &result["result"];
+        assert_eq!("trc20_transfer", transfer["type"]);
+        assert_eq!("TNv1TwJc2TR7gEnKgZMhueEwHVYbEKAzyA", transfer["from"]);
+        assert_eq!(
+            "TR7NHqjeKQxGTCi8q8ZY4pL8otSzgjLj6t",
+            transfer["contract_address"]
+        );
+        assert_eq!("TQC7P3WHrraVAYGbdaVx6os3LwNjt9Bgsd", transfer["to"]);
+        assert_eq!("10000000", transfer["amount"]);
+    }
+
+    #[test]
+    fn test_tron_parse_other_contract() {
+        assert_eq!(
+            r#"{"result":{"type":"TransferContract"}}"#,
+            tron_parse_transaction(TRX_TRANSFER)
+        );
+
+        // the trc20 transfer with the selector of approve(address,uint256)
+        let approve = TRC20_TRANSFER.replace("a9059cbb", "095ea7b3");
+        assert_eq!(
+            r#"{"result":{"type":"TriggerSmartContract"}}"#,
+            tron_parse_transaction(&approve)
+        );
+    }
+
+    #[test]
+    fn test_tron_parse_transaction_error() {
+        assert_eq!(
+            r#"{"error":"transaction is invalid"}"#,
+            tron_parse_transaction("zz")
+        );
+        let result: Value = serde_json::from_str(&tron_parse_transaction("0a02")).unwrap();
+        assert!(result["error"]
+            .as_str()
+            .unwrap()
+            .starts_with("transaction is invalid"));
+    }
+}
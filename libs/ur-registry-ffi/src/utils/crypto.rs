@@ -11,8 +11,10 @@ export! {
     fn get_uncompressed_key(
         compressed_key: &str
     ) -> String {
-        let slice = &compressed_key.clone()[2..];
-        let decoded_slice = hex::decode(slice).unwrap();
+        let decoded_slice = match compressed_key.get(2..).map(hex::decode) {
+            Some(Ok(v)) => v,
+            _ => return json!({"error": "compressed key is invalid"}).to_string(),
+        };
 
         let result = match XOnlyPublicKey::from_slice(&decoded_slice) {
             Ok(res) => res,
@@ -71,6 +73,14 @@ mod tests {
         assert_eq!(expect_result, get_uncompressed_key(compressed_key));
     }
 
+    #[test]
+    fn test_get_uncompressed_key_with_malformed_key() {
+        let expect_result = r#"{"error":"compressed key is invalid"}"#;
+
+        assert_eq!(expect_result, get_uncompressed_key("0"));
+        assert_eq!(expect_result, get_uncompressed_key("02zz"));
+    }
+
     #[test]
     fn test_derive_public_key() {
         let xpub = "xpub6DXryz8Kd7XchtXvDnkjara83shGJH8ubu7KZhHhPfp4L1shvDEYiFZm32EKHnyo4bva4gxXjabFGqY7fNs8Ggd4khYz2oNs2KYLf56a9GX";
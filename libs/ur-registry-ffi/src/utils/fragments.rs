@@ -0,0 +1,85 @@
+use serde::Deserialize;
+use serde_json::json;
+use ur_parse_lib::keystone_ur_encoder::to_ur_parts;
+
+use crate::export;
+use crate::util_internal::string_helper::remove_prefix_0x;
+
+// the `{"type": ..., "cbor": ...}` object returned by the `generate_*` functions
+#[derive(Deserialize)]
+struct EncodedItem {
+    #[serde(rename = "type")]
+    ur_type: Option<String>,
+    cbor: String,
+}
+
+export! {
+    @Java_com_keystone_sdk_KeystoneNativeSDK_encodeFragments
+    fn keystone_sdk_encode_fragments(ur_type: &str, json: &str, max_fragment_len: u32) -> String {
+        let item = match serde_json::from_str::<EncodedItem>(json) {
+            Ok(v) => v,
+            Err(_) => return json!({"error": "json is invalid"}).to_string(),
+        };
+        if item.ur_type.is_some_and(|t| t != ur_type) {
+            return json!({"error": "type not match"}).to_string();
+        }
+        if max_fragment_len == 0 {
+            return json!({"error": "max fragment length is invalid"}).to_string();
+        }
+        let cbor = match hex::decode(remove_prefix_0x(&item.cbor)) {
+            Ok(v) => v,
+            Err(_) => return json!({"error": "cbor is invalid"}).to_string(),
+        };
+        match to_ur_parts(&cbor, max_fragment_len as usize, ur_type.to_string()) {
+            Ok(parts) => json!(parts).to_string(),
+            Err(_) => json!({"error": "ur encode failed"}).to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encode_single_fragment() {
+        let json = r#"{"type":"qr-hardware-call","cbor":"a3010002d90515a10182d90516a101d90130a10186182cf500f500f5d90516a201d90130a1018a182cf51901f5f500f500f500f502010400"}"#;
+        let expect_result = r#"["ur:qr-hardware-call/otadaeaotaahbzoyadlftaahcmoyadtaaddyoyadlncsdwykaeykaeyktaahcmoeadtaaddyoyadlecsdwykcfadykykaeykaeykaeykaoadaaaeyteyldre"]"#;
+
+        assert_eq!(
+            expect_result,
+            keystone_sdk_encode_fragments("qr-hardware-call", json, 400)
+        );
+    }
+
+    #[test]
+    fn test_encode_multiple_fragments() {
+        let json = json!({"cbor": format!("5903c0{}", "aa".repeat(960))}).to_string();
+        let result: Vec<String> =
+            serde_json::from_str(&keystone_sdk_encode_fragments("crypto-psbt", &json, 400))
+                .unwrap();
+
+        assert_eq!(3, result.len());
+        assert!(result[0].starts_with("ur:crypto-psbt/1-3/"));
+        assert!(result[1].starts_with("ur:crypto-psbt/2-3/"));
+        assert!(result[2].starts_with("ur:crypto-psbt/3-3/"));
+    }
+
+    #[test]
+    fn test_encode_fragments_error() {
+        let json = r#"{"type":"crypto-psbt","cbor":"4100"}"#;
+
+        assert_eq!(
+            r#"{"error":"type not match"}"#,
+            keystone_sdk_encode_fragments("bytes", json, 400)
+        );
+        assert_eq!(
+            r#"{"error":"max fragment length is invalid"}"#,
+            keystone_sdk_encode_fragments("crypto-psbt", json, 0)
+        );
+        assert_eq!(
+            r#"{"error":"json is invalid"}"#,
+            keystone_sdk_encode_fragments("crypto-psbt", "4100", 400)
+        );
+    }
+}
@@ -1,2 +1,4 @@
 pub mod crypto;
+pub mod fragments;
 pub mod hd_path;
+pub mod ur_parser;
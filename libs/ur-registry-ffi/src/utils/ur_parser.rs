@@ -0,0 +1,232 @@
+use serde_json::{json, Value};
+use ur_parse_lib::ur::{decode_raw, decode_raw_parts, RawParts};
+use ur_registry::registry_types::{
+    RegistryType, APTOS_SIGNATURE, ARWEAVE_CRYPTO_ACCOUNT, ARWEAVE_SIGNATURE, BTC_SIGNATURE,
+    CARDANO_SIGNATURE, COSMOS_SIGNATURE, CRYPTO_ACCOUNT, CRYPTO_HDKEY, CRYPTO_MULTI_ACCOUNTS,
+    CRYPTO_PSBT, ETH_SIGNATURE, EVM_SIGNATURE, KEYSTONE_SIGN_RESULT, NEAR_SIGNATURE, SOL_SIGNATURE,
+    STELLAR_SIGNATURE, SUI_SIGNATURE, TON_SIGNATURE, UTXO_PSBT, ZCASH_PCZT,
+};
+use ur_registry::util::check_cbor;
+
+use crate::aptos::aptos_signature::parse_aptos_signature;
+use crate::arweave::arweave_account::parse_arweave_account;
+use crate::arweave::arweave_signature::parse_arweave_signature;
+use crate::bitcoin::btc_signature::parse_btc_signature;
+use crate::bitcoin::crypto_psbt::parse_crypto_psbt;
+use crate::bitcoin::utxo_psbt::parse_utxo_psbt;
+use crate::cardano::cardano_signature::parse_cardano_signature;
+use crate::cosmos::cosmos_signature::parse_cosmos_signature;
+use crate::ethereum::eth_signature::parse_eth_signature;
+use crate::evm::evm_signature::parse_evm_signature;
+use crate::export;
+use crate::keystone::keystone_sign_result::parse_keystone_sign_result;
+use crate::near::near_signature::parse_near_signature;
+use crate::solana::sol_signature::parse_sol_signature;
+use crate::stellar::stellar_signature::parse_stellar_signature;
+use crate::sui::sui_signature::parse_sui_signature;
+use crate::sync::crypto_account::parse_crypto_account;
+use crate::sync::crypto_hd_key::parse_crypto_hd_key;
+use crate::sync::crypto_multi_accounts::parse_crypto_multi_accounts;
+use crate::ton::ton_signature::parse_ton_signature;
+use crate::zcash::zcash_pczt::parse_zcash_pczt;
+
+type Parser = fn(&str, &str) -> String;
+
+const PARSERS: [(RegistryType, Parser); 20] = [
+    (APTOS_SIGNATURE, parse_aptos_signature),
+    (ARWEAVE_CRYPTO_ACCOUNT, parse_arweave_account),
+    (ARWEAVE_SIGNATURE, parse_arweave_signature),
+    (BTC_SIGNATURE, parse_btc_signature),
+    (CARDANO_SIGNATURE, parse_cardano_signature),
+    (COSMOS_SIGNATURE, parse_cosmos_signature),
+    (CRYPTO_ACCOUNT, parse_crypto_account),
+    (CRYPTO_HDKEY, parse_crypto_hd_key),
+    (CRYPTO_MULTI_ACCOUNTS, parse_crypto_multi_accounts),
+    (CRYPTO_PSBT, parse_crypto_psbt),
+    (ETH_SIGNATURE, parse_eth_signature),
+    (EVM_SIGNATURE, parse_evm_signature),
+    (KEYSTONE_SIGN_RESULT, parse_keystone_sign_result),
+    (NEAR_SIGNATURE, parse_near_signature),
+    (SOL_SIGNATURE, parse_sol_signature),
+    (STELLAR_SIGNATURE, parse_stellar_signature),
+    (SUI_SIGNATURE, parse_sui_signature),
+    (TON_SIGNATURE, parse_ton_signature),
+    (UTXO_PSBT, parse_utxo_psbt),
+    (ZCASH_PCZT, parse_zcash_pczt),
+];
+
+fn find_parser(ur_type: &str) -> Option<Parser> {
+    PARSERS
+        .iter()
+        .find(|(registry_type, _)| registry_type.get_type() == ur_type)
+        .map(|(_, parser)| *parser)
+}
+
+export! {
+    @Java_com_keystone_sdk_KeystoneNativeSDK_parseUR
+    fn parse_ur(ur_string: &str) -> String {
+        let (ur_type, cbor) = match decode_raw(ur_string.trim()) {
+            Ok(v) => v,
+            Err(_) => return json!({"error": "ur is invalid"}).to_string(),
+        };
+        let cbor_hex = hex::encode(cbor);
+        let parser = match find_parser(&ur_type) {
+            Some(v) => v,
+            None => return json!({"type": ur_type, "cbor": cbor_hex}).to_string(),
+        };
+        let payload = match serde_json::from_str::<Value>(&parser(&ur_type, &cbor_hex)) {
+            Ok(v) => v,
+            Err(_) => return json!({"error": "payload is invalid"}).to_string(),
+        };
+        if payload.get("error").is_some() {
+            return payload.to_string();
+        }
+        json!({
+            "type": ur_type,
+            "payload": payload,
+        }).to_string()
+    }
+}
+
+export! {
+    @Java_com_keystone_sdk_KeystoneNativeSDK_decodeURParts
+    fn decode_ur_parts(parts_json: &str) -> String {
+        let parts = match serde_json::from_str::<Vec<String>>(parts_json) {
+            Ok(v) => v,
+            Err(_) => return json!({"error": "parts is invalid"}).to_string(),
+        };
+        match decode_raw_parts(&parts) {
+            Ok(RawParts::Complete(ur_type, cbor)) => json!({
+                "type": ur_type,
+                "cbor": hex::encode(cbor),
+            }).to_string(),
+            Ok(RawParts::Progress(progress)) => json!({"progress": progress}).to_string(),
+            Err(_) => json!({"error": "ur is invalid"}).to_string(),
+        }
+    }
+}
+
+export! {
+    @Java_com_keystone_sdk_KeystoneNativeSDK_validateUR
+    fn validate_ur(ur_string: &str, expected_type: &str) -> String {
+        // checks the scheme, the bytewords checksum and the cbor, the typed item
+        // is left to `parse_ur`
+        let (ur_type, cbor) = match decode_raw(ur_string.trim()) {
+            Ok(v) => v,
+            Err(_) => return json!({"error": "ur is invalid"}).to_string(),
+        };
+        if ur_type != expected_type {
+            return json!({"error": "type not match"}).to_string();
+        }
+        match check_cbor(&cbor) {
+            Ok(_) => json!({"result": true}).to_string(),
+            Err(_) => json!({"error": "cbor is invalid"}).to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ur_parse_lib::keystone_ur_encoder::to_ur_parts;
+    use ur_parse_lib::ur::encode_raw;
+
+    #[test]
+    fn test_parse_ur_eth_signature() {
+        let cbor = hex::decode("a301d825509b1deb4d3b7d4bad9bdd2b0d7b3dcb6d025841d4f0a7bcd95bba1fbb1051885054730e3f47064288575aacc102fbbf6a9a14daa066991e360d3e3406c20c00a40973eff37c7d641e5b351ec4a99bfe86f335f71303686b657973746f6e65").unwrap();
+        let expect_result = "{\"payload\":{\"request_id\":\"9b1deb4d-3b7d-4bad-9bdd-2b0d7b3dcb6d\",\"signature\":\"d4f0a7bcd95bba1fbb1051885054730e3f47064288575aacc102fbbf6a9a14daa066991e360d3e3406c20c00a40973eff37c7d641e5b351ec4a99bfe86f335f713\"},\"type\":\"eth-signature\"}";
+
+        assert_eq!(expect_result, parse_ur(&encode_raw("eth-signature", &cbor)));
+    }
+
+    #[test]
+    fn test_parse_ur_sui_signature() {
+        let cbor = hex::decode("A301D825509B1DEB4D3B7D4BAD9BDD2B0D7B3DCB6D025840F4B79835417490958C72492723409289B444F3AF18274BA484A9EEACA9E760520E453776E5975DF058B537476932A45239685F694FC6362FE5AF6BA714DA6505035820AEB28ECACE5C664C080E71B9EFD3D071B3DAC119A26F4E830DD6BD06712ED93F").unwrap();
+        let expect_result = "{\"payload\":{\"public_key\":\"aeb28ecace5c664c080e71b9efd3d071b3dac119a26f4e830dd6bd06712ed93f\",\"request_id\":\"9b1deb4d-3b7d-4bad-9bdd-2b0d7b3dcb6d\",\"signature\":\"f4b79835417490958c72492723409289b444f3af18274ba484a9eeaca9e760520e453776e5975df058b537476932a45239685f694fc6362fe5af6ba714da6505\"},\"type\":\"sui-signature\"}";
+
+        assert_eq!(
+            expect_result,
+            parse_ur(&encode_raw("sui-signature", &cbor).to_uppercase())
+        );
+    }
+
+    #[test]
+    fn test_parse_ur_unknown_type() {
+        let ur = encode_raw("sui-sign-request", &hex::decode("a10244deadbeef").unwrap());
+        let expect_result = "{\"cbor\":\"a10244deadbeef\",\"type\":\"sui-sign-request\"}";
+
+        assert_eq!(expect_result, parse_ur(&ur));
+    }
+
+    #[test]
+    fn test_parse_ur_error() {
+        assert_eq!("{\"error\":\"ur is invalid\"}", parse_ur("eth-signature"));
+
+        let ur = encode_raw("eth-signature", &hex::decode("a201").unwrap());
+        assert_eq!("{\"error\":\"signature is invalid\"}", parse_ur(&ur));
+    }
+
+    #[test]
+    fn test_decode_ur_parts() {
+        let cbor = hex::decode(format!("5903c0{}", "aa".repeat(960))).unwrap();
+        let parts = to_ur_parts(&cbor, 400, "crypto-psbt".to_string()).unwrap();
+        assert_eq!(3, parts.len());
+
+        let expect_result = json!({"type": "crypto-psbt", "cbor": hex::encode(&cbor)}).to_string();
+        assert_eq!(expect_result, decode_ur_parts(&json!(parts).to_string()));
+
+        let progress: Value =
+            serde_json::from_str(&decode_ur_parts(&json!(parts[..1]).to_string())).unwrap();
+        assert!(progress["progress"].as_u64().unwrap() < 100);
+    }
+
+    #[test]
+    fn test_decode_ur_parts_single() {
+        let ur = encode_raw("eth-signature", &hex::decode("a10244deadbeef").unwrap());
+        let expect_result = "{\"cbor\":\"a10244deadbeef\",\"type\":\"eth-signature\"}";
+
+        assert_eq!(expect_result, decode_ur_parts(&json!([ur]).to_string()));
+    }
+
+    #[test]
+    fn test_decode_ur_parts_error() {
+        assert_eq!(
+            "{\"error\":\"parts is invalid\"}",
+            decode_ur_parts("ur:bytes")
+        );
+        assert_eq!("{\"error\":\"ur is invalid\"}", decode_ur_parts("[]"));
+    }
+
+    #[test]
+    fn test_validate_ur() {
+        let ur = encode_raw("eth-signature", &hex::decode("a10244deadbeef").unwrap());
+        assert_eq!("{\"result\":true}", validate_ur(&ur, "eth-signature"));
+        assert_eq!(
+            "{\"result\":true}",
+            validate_ur(&ur.to_uppercase(), "eth-signature")
+        );
+        assert_eq!(
+            "{\"error\":\"type not match\"}",
+            validate_ur(&ur, "sol-signature")
+        );
+    }
+
+    #[test]
+    fn test_validate_ur_corrupted() {
+        let ur = encode_raw("eth-signature", &hex::decode("a10244deadbeef").unwrap());
+        // a changed byteword fails the checksum
+        let corrupted = ur.replacen("/oy", "/oe", 1);
+        assert_ne!(ur, corrupted);
+        assert_eq!(
+            "{\"error\":\"ur is invalid\"}",
+            validate_ur(&corrupted, "eth-signature")
+        );
+
+        // a map missing its value, with a valid checksum
+        let ur = encode_raw("eth-signature", &hex::decode("a102").unwrap());
+        assert_eq!(
+            "{\"error\":\"cbor is invalid\"}",
+            validate_ur(&ur, "eth-signature")
+        );
+    }
+}
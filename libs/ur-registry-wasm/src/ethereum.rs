@@ -0,0 +1,68 @@
+use serde::Deserialize;
+use serde_json::json;
+use ur_parse_lib::ur::encode_raw;
+use ur_registry::crypto_key_path::CryptoKeyPath;
+use ur_registry::ethereum::eth_sign_request::{DataType, EthSignRequest};
+use ur_registry::ethereum::eth_signature::EthSignature;
+use ur_registry::registry_types::{ETH_SIGNATURE, ETH_SIGN_REQUEST};
+use wasm_bindgen::prelude::*;
+
+use crate::util::{decode_ur, error, format_request_id, parse_request_id, remove_prefix_0x};
+
+#[derive(Deserialize)]
+struct EthSignRequestParams {
+    request_id: String,
+    sign_data: String,
+    data_type: u32,
+    chain_id: Option<i64>,
+    path: String,
+    xfp: String,
+    address: Option<String>,
+    origin: Option<String>,
+}
+
+#[wasm_bindgen]
+pub fn generate_eth_sign_request(json: &str) -> Result<String, JsValue> {
+    let params: EthSignRequestParams =
+        serde_json::from_str(json).map_err(|_| error("params is invalid"))?;
+    let xfp: [u8; 4] = hex::decode(&params.xfp)
+        .map_err(|_| error("xfp is invalid"))?
+        .try_into()
+        .map_err(|_| error("length of xfp must be exactly 8"))?;
+    let derivation_path =
+        CryptoKeyPath::from_path(params.path, Some(xfp)).map_err(|_| error("path is invalid"))?;
+    let data_type =
+        DataType::from_u32(params.data_type).map_err(|_| error("data type is invalid"))?;
+    let request_id = parse_request_id(&params.request_id)?;
+    let sign_data = hex::decode(remove_prefix_0x(&params.sign_data))
+        .map_err(|_| error("sign_data is invalid"))?;
+    let address = params
+        .address
+        .filter(|v| !v.is_empty())
+        .map(|v| v.into_bytes());
+    let origin = params.origin.filter(|v| !v.is_empty());
+
+    let cbor: Vec<u8> = EthSignRequest::new(
+        Some(request_id),
+        sign_data,
+        data_type,
+        params.chain_id.filter(|v| *v != 0).map(i128::from),
+        derivation_path,
+        address,
+        origin,
+    )
+    .try_into()
+    .map_err(|_| error("cbor serialization failed"))?;
+    Ok(encode_raw(&ETH_SIGN_REQUEST.get_type(), &cbor))
+}
+
+#[wasm_bindgen]
+pub fn parse_eth_signature(ur: &str) -> Result<String, JsValue> {
+    let cbor = decode_ur(ur, ETH_SIGNATURE)?;
+    let sig = EthSignature::try_from(cbor).map_err(|_| error("signature is invalid"))?;
+    Ok(json!({
+        "request_id": format_request_id(sig.get_request_id())?,
+        "signature": hex::encode(sig.get_signature()),
+    })
+    .to_string())
+}
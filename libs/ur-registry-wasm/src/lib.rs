@@ -0,0 +1,7 @@
+//! wasm-bindgen surface for web wallets, mirroring the string based functions of
+//! `ur-registry-ffi`. Requests take a JSON string and return the UR string ready for
+//! QR rendering, parsers take a UR string and return JSON.
+
+pub mod ethereum;
+pub mod sui;
+mod util;
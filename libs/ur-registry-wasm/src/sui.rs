@@ -0,0 +1,87 @@
+use serde::Deserialize;
+use serde_json::json;
+use ur_parse_lib::ur::encode_raw;
+use ur_registry::crypto_key_path::CryptoKeyPath;
+use ur_registry::registry_types::{SUI_SIGNATURE, SUI_SIGN_REQUEST};
+use ur_registry::sui::sui_sign_request::SuiSignRequest;
+use ur_registry::sui::sui_signature::SuiSignature;
+use wasm_bindgen::prelude::*;
+
+use crate::util::{decode_ur, error, format_request_id, parse_request_id, remove_prefix_0x};
+
+#[derive(Deserialize)]
+struct Account {
+    path: String,
+    xfp: String,
+    address: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct SuiSignRequestParams {
+    request_id: String,
+    intent_message: String,
+    accounts: Vec<Account>,
+    origin: Option<String>,
+}
+
+#[wasm_bindgen]
+pub fn generate_sui_sign_request(json: &str) -> Result<String, JsValue> {
+    let params: SuiSignRequestParams =
+        serde_json::from_str(json).map_err(|_| error("params is invalid"))?;
+    let request_id = parse_request_id(&params.request_id)?;
+    let intent_message = hex::decode(remove_prefix_0x(&params.intent_message))
+        .map_err(|_| error("intent message is invalid"))?;
+
+    if params.accounts.is_empty() {
+        return Err(error("accounts is invalid"));
+    }
+    let mut derivation_paths = vec![];
+    let mut addresses = vec![];
+    for account in params.accounts {
+        let xfp: [u8; 4] = hex::decode(&account.xfp)
+            .ok()
+            .and_then(|v| v.try_into().ok())
+            .ok_or_else(|| error("accounts is invalid"))?;
+        derivation_paths.push(
+            CryptoKeyPath::from_path(account.path, Some(xfp))
+                .map_err(|_| error("accounts is invalid"))?,
+        );
+        if let Some(address) = account.address {
+            addresses.push(
+                hex::decode(remove_prefix_0x(&address))
+                    .map_err(|_| error("accounts is invalid"))?,
+            );
+        }
+    }
+    if !addresses.is_empty() && addresses.len() != derivation_paths.len() {
+        return Err(error("account and path count must match"));
+    }
+    let addresses = if addresses.is_empty() { None } else { Some(addresses) };
+    let origin = params.origin.filter(|v| !v.is_empty());
+
+    let cbor: Vec<u8> = SuiSignRequest::new(
+        Some(request_id),
+        intent_message,
+        derivation_paths,
+        addresses,
+        origin,
+        None,
+        None,
+        None,
+    )
+    .try_into()
+    .map_err(|_| error("sign data is invalid"))?;
+    Ok(encode_raw(&SUI_SIGN_REQUEST.get_type(), &cbor))
+}
+
+#[wasm_bindgen]
+pub fn parse_sui_signature(ur: &str) -> Result<String, JsValue> {
+    let cbor = decode_ur(ur, SUI_SIGNATURE)?;
+    let sig = SuiSignature::try_from(cbor).map_err(|_| error("signature is invalid"))?;
+    Ok(json!({
+        "request_id": format_request_id(sig.get_request_id())?,
+        "signature": hex::encode(sig.get_signature()),
+        "public_key": sig.get_public_key().map(hex::encode).unwrap_or_default(),
+    })
+    .to_string())
+}
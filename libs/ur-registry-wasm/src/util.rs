@@ -0,0 +1,35 @@
+use ur_parse_lib::ur::decode_raw;
+use ur_registry::registry_types::RegistryType;
+use uuid::Uuid;
+use wasm_bindgen::JsValue;
+
+pub(crate) fn error(message: &str) -> JsValue {
+    JsValue::from_str(message)
+}
+
+pub(crate) fn remove_prefix_0x(s: &str) -> &str {
+    s.strip_prefix("0x").unwrap_or(s)
+}
+
+pub(crate) fn parse_request_id(request_id: &str) -> Result<Vec<u8>, JsValue> {
+    Uuid::parse_str(request_id)
+        .map(|v| v.as_bytes().to_vec())
+        .map_err(|_| error("uuid is invalid"))
+}
+
+pub(crate) fn format_request_id(request_id: Option<Vec<u8>>) -> Result<String, JsValue> {
+    match request_id {
+        Some(id) => Uuid::from_slice(&id)
+            .map(|v| v.to_string())
+            .map_err(|_| error("uuid is invalid")),
+        None => Ok(String::from("")),
+    }
+}
+
+pub(crate) fn decode_ur(ur: &str, registry_type: RegistryType) -> Result<Vec<u8>, JsValue> {
+    let (ur_type, cbor) = decode_raw(ur).map_err(|_| error("ur is invalid"))?;
+    if registry_type.get_type() != ur_type {
+        return Err(error("type not match"));
+    }
+    Ok(cbor)
+}
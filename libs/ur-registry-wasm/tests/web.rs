@@ -0,0 +1,88 @@
+//! Run with `wasm-pack test --node libs/ur-registry-wasm`.
+#![cfg(target_arch = "wasm32")]
+
+use ur_parse_lib::ur::{decode_raw, encode_raw};
+use ur_registry_wasm::ethereum::{generate_eth_sign_request, parse_eth_signature};
+use ur_registry_wasm::sui::{generate_sui_sign_request, parse_sui_signature};
+use wasm_bindgen_test::*;
+
+#[wasm_bindgen_test]
+fn test_generate_sui_sign_request() {
+    let params = r#"{
+        "request_id": "9b1deb4d-3b7d-4bad-9bdd-2b0d7b3dcb6d",
+        "intent_message": "00000000000200201ff915a5e9e32fdbe0135535b6c69a00a9809aaf7f7c0275d3239ca79db20d6400081027000000000000020200010101000101020000010000ebe623e33b7307f1350f8934beb3fb16baef0fc1b3f1b92868eec3944093886901a2e3e42930675d9571a467eb5d4b22553c93ccb84e9097972e02c490b4e7a22ab73200000000000020176c4727433105da34209f04ac3f22e192a2573d7948cb2fabde7d13a7f4f149ebe623e33b7307f1350f8934beb3fb16baef0fc1b3f1b92868eec39440938869e803000000000000640000000000000000",
+        "accounts": [
+            {
+                "path": "m/44'/784'/0'/0'/0'",
+                "xfp": "78230804",
+                "address": "0xebe623e33b7307f1350f8934beb3fb16baef0fc1b3f1b92868eec39440938869"
+            }
+        ],
+        "origin": "Sui Wallet"
+    }"#;
+    let ur = generate_sui_sign_request(params).unwrap();
+    assert!(ur.starts_with("ur:sui-sign-request/"));
+
+    let (ur_type, cbor) = decode_raw(&ur).unwrap();
+    assert_eq!("sui-sign-request", ur_type);
+    assert_eq!(
+        "a501d825509b1deb4d3b7d4bad9bdd2b0d7b3dcb6d0258dc00000000000200201ff915a5e9e32fdbe0135535b6c69a00a9809aaf7f7c0275d3239ca79db20d6400081027000000000000020200010101000101020000010000ebe623e33b7307f1350f8934beb3fb16baef0fc1b3f1b92868eec3944093886901a2e3e42930675d9571a467eb5d4b22553c93ccb84e9097972e02c490b4e7a22ab73200000000000020176c4727433105da34209f04ac3f22e192a2573d7948cb2fabde7d13a7f4f149ebe623e33b7307f1350f8934beb3fb16baef0fc1b3f1b92868eec39440938869e8030000000000006400000000000000000381d90130a2018a182cf5190310f500f500f500f5021a7823080404815820ebe623e33b7307f1350f8934beb3fb16baef0fc1b3f1b92868eec39440938869056a5375692057616c6c6574",
+        hex::encode(cbor)
+    );
+}
+
+#[wasm_bindgen_test]
+fn test_generate_sui_sign_request_error() {
+    let params = r#"{"request_id": "invalid", "intent_message": "00", "accounts": []}"#;
+    assert_eq!(
+        Some("uuid is invalid".to_string()),
+        generate_sui_sign_request(params).unwrap_err().as_string()
+    );
+}
+
+#[wasm_bindgen_test]
+fn test_parse_sui_signature() {
+    let cbor = hex::decode("a301d825509b1deb4d3b7d4bad9bdd2b0d7b3dcb6d025840f4b79835417490958c72492723409289b444f3af18274ba484a9eeaca9e760520e453776e5975df058b537476932a45239685f694fc6362fe5af6ba714da6505035820aeb28ecace5c664c080e71b9efd3d071b3dac119a26f4e830dd6bd06712ed93f").unwrap();
+    let ur = encode_raw("sui-signature", &cbor);
+    assert_eq!(
+        "{\"public_key\":\"aeb28ecace5c664c080e71b9efd3d071b3dac119a26f4e830dd6bd06712ed93f\",\"request_id\":\"9b1deb4d-3b7d-4bad-9bdd-2b0d7b3dcb6d\",\"signature\":\"f4b79835417490958c72492723409289b444f3af18274ba484a9eeaca9e760520e453776e5975df058b537476932a45239685f694fc6362fe5af6ba714da6505\"}",
+        parse_sui_signature(&ur).unwrap()
+    );
+}
+
+#[wasm_bindgen_test]
+fn test_generate_eth_sign_request() {
+    let params = r#"{
+        "request_id": "9b1deb4d-3b7d-4bad-9bdd-2b0d7b3dcb6d",
+        "sign_data": "f849808609184e72a00082271094000000000000000000000000000000000000000080a47f7465737432000000000000000000000000000000000000000000000000000000600057808080",
+        "data_type": 1,
+        "chain_id": 1,
+        "path": "m/44'/1'/1'/0/1",
+        "xfp": "12345678",
+        "origin": "metamask"
+    }"#;
+    let ur = generate_eth_sign_request(params).unwrap();
+    assert!(ur.starts_with("ur:eth-sign-request/"));
+
+    let (_, cbor) = decode_raw(&ur).unwrap();
+    assert_eq!(
+        "a601d825509b1deb4d3b7d4bad9bdd2b0d7b3dcb6d02584bf849808609184e72a00082271094000000000000000000000000000000000000000080a47f74657374320000000000000000000000000000000000000000000000000000006000578080800301040105d90130a2018a182cf501f501f500f401f4021a1234567807686d6574616d61736b",
+        hex::encode(cbor)
+    );
+}
+
+#[wasm_bindgen_test]
+fn test_parse_eth_signature() {
+    let cbor = hex::decode("a301d825509b1deb4d3b7d4bad9bdd2b0d7b3dcb6d025841d4f0a7bcd95bba1fbb1051885054730e3f47064288575aacc102fbbf6a9a14daa066991e360d3e3406c20c00a40973eff37c7d641e5b351ec4a99bfe86f335f71303686b657973746f6e65").unwrap();
+    let ur = encode_raw("eth-signature", &cbor);
+    assert_eq!(
+        "{\"request_id\":\"9b1deb4d-3b7d-4bad-9bdd-2b0d7b3dcb6d\",\"signature\":\"d4f0a7bcd95bba1fbb1051885054730e3f47064288575aacc102fbbf6a9a14daa066991e360d3e3406c20c00a40973eff37c7d641e5b351ec4a99bfe86f335f713\"}",
+        parse_eth_signature(&ur).unwrap()
+    );
+
+    let ur = encode_raw("sui-signature", &cbor);
+    assert_eq!(
+        Some("type not match".to_string()),
+        parse_eth_signature(&ur).unwrap_err().as_string()
+    );
+}
@@ -0,0 +1,61 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion, Throughput};
+use ur_registry::crypto_key_path::CryptoKeyPath;
+use ur_registry::crypto_psbt::CryptoPSBT;
+use ur_registry::ergo::{ergo_hint_bag::ErgoHintBag, ergo_signature::ErgoSignature};
+use ur_registry::sui::sui_sign_request::SuiSignRequest;
+
+const REQUEST_ID: [u8; 16] = [
+    0x9b, 0x1d, 0xeb, 0x4d, 0x3b, 0x7d, 0x4b, 0xad, 0x9b, 0xdd, 0x2b, 0x0d, 0x7b, 0x3d, 0xcb, 0x6d,
+];
+const LARGE_PSBT_LENGTH: usize = 100 * 1024;
+
+fn sui_sign_request() -> SuiSignRequest {
+    let path = CryptoKeyPath::from_path("m/44'/784'/0'/0'/0'".to_string(), None).unwrap();
+    SuiSignRequest::new(
+        Some(REQUEST_ID.to_vec()),
+        vec![0u8; 512],
+        vec![path],
+        None,
+        Some("Sui Wallet".to_string()),
+        Some(vec![vec![0u8; 73]; 16]),
+        None,
+        None,
+    )
+}
+
+fn ergo_signature() -> ErgoSignature {
+    ErgoSignature::new(
+        Some(REQUEST_ID.to_vec()),
+        vec![0u8; 56],
+        Some(ErgoHintBag::new(vec![0u8; 256])),
+    )
+}
+
+fn large_psbt() -> CryptoPSBT {
+    CryptoPSBT::new(vec![0u8; LARGE_PSBT_LENGTH])
+}
+
+macro_rules! bench_codec {
+    ($c: expr, $name: expr, $t: ty, $item: expr) => {{
+        let item = $item;
+        let bytes: Vec<u8> = item.clone().try_into().unwrap();
+        let mut group = $c.benchmark_group($name);
+        group.throughput(Throughput::Bytes(bytes.len() as u64));
+        group.bench_function("encode", |b| {
+            b.iter(|| -> Vec<u8> { black_box(item.clone()).try_into().unwrap() })
+        });
+        group.bench_function("decode", |b| {
+            b.iter(|| <$t>::try_from(black_box(bytes.clone())).unwrap())
+        });
+        group.finish();
+    }};
+}
+
+fn codec(c: &mut Criterion) {
+    bench_codec!(c, "sui-sign-request", SuiSignRequest, sui_sign_request());
+    bench_codec!(c, "ergo-signature", ErgoSignature, ergo_signature());
+    bench_codec!(c, "crypto-psbt-100k", CryptoPSBT, large_psbt());
+}
+
+criterion_group!(benches, codec);
+criterion_main!(benches);
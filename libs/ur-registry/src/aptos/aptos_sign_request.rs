@@ -1,14 +1,20 @@
 use alloc::format;
 use alloc::string::{String, ToString};
 use alloc::vec::Vec;
-use minicbor::data::{Int, Tag};
+use minicbor::data::{Int, Tag, Type};
 
-use crate::cbor::{cbor_array, cbor_map};
+use crate::cbor::{
+    cbor_array, cbor_bytes, cbor_decode, cbor_expect_type, cbor_map, cbor_origin, cbor_paths,
+    cbor_tag, cbor_unknown_field, cbor_unknown_fields,
+};
 use crate::crypto_key_path::CryptoKeyPath;
+use crate::decode::DecodeContext;
 use crate::error::{URError, URResult};
+use crate::impl_with_new_request_id;
 use crate::registry_types::{RegistryType, APTOS_SIGN_REQUEST, UUID};
-use crate::traits::{From, RegistryItem, To};
-use crate::types::Bytes;
+use crate::traits::{Expiry, From, RegistryItem, To, Validate};
+use crate::types::{Bytes, UnknownFields};
+use crate::validate::{validate_paths, validate_request_id, validate_sign_data};
 
 const REQUEST_ID: u8 = 1;
 const SIGN_DATA: u8 = 2;
@@ -16,8 +22,12 @@ const AUTHENTICATION_KEY_DERIVATION_PATHS: u8 = 3;
 const ACCOUNTS: u8 = 4;
 const ORIGIN: u8 = 5;
 const SIGN_TYPE: u8 = 6;
+const EXPIRES_AT: u8 = 7;
+
+const APTOS_COIN_TYPE: u32 = 637;
 
 #[derive(Clone, Debug, Default)]
+#[repr(i32)]
 pub enum SignType {
     #[default]
     Single = 1,
@@ -37,6 +47,11 @@ impl SignType {
             )),
         }
     }
+
+    // the discriminant is always encoded as a cbor int, repr(i32) keeps the cast lossless
+    pub fn as_i32(&self) -> i32 {
+        self.clone() as i32
+    }
 }
 
 #[derive(Clone, Debug, Default)]
@@ -47,8 +62,15 @@ pub struct AptosSignRequest {
     accounts: Option<Vec<Bytes>>,
     origin: Option<String>,
     sign_type: SignType,
+    // the payloads of a `SignType::Multi` request, encoded in place of `sign_data`,
+    // which is left empty so that only one of the two holds the data
+    sign_data_list: Option<Vec<Bytes>>,
+    expires_at: Option<u64>,
+    unknown_fields: UnknownFields,
 }
 
+impl_with_new_request_id!(AptosSignRequest, required);
+
 impl AptosSignRequest {
     pub fn default() -> Self {
         Default::default()
@@ -60,6 +82,12 @@ impl AptosSignRequest {
 
     pub fn set_sign_data(&mut self, data: Bytes) {
         self.sign_data = data;
+        self.sign_data_list = None;
+    }
+
+    pub fn set_sign_data_list(&mut self, sign_data_list: Vec<Bytes>) {
+        self.sign_data = Vec::new();
+        self.sign_data_list = Some(sign_data_list);
     }
 
     pub fn set_sign_type(&mut self, sign_type: SignType) {
@@ -81,6 +109,10 @@ impl AptosSignRequest {
         self.origin = Some(origin)
     }
 
+    pub fn set_expires_at(&mut self, expires_at: u64) {
+        self.expires_at = Some(expires_at)
+    }
+
     pub fn new(
         request_id: Bytes,
         sign_data: Bytes,
@@ -96,6 +128,29 @@ impl AptosSignRequest {
             authentication_key_derivation_paths,
             accounts,
             origin,
+            sign_data_list: None,
+            expires_at: None,
+            unknown_fields: UnknownFields::new(),
+        }
+    }
+
+    pub fn new_multi(
+        request_id: Bytes,
+        sign_data_list: Vec<Bytes>,
+        authentication_key_derivation_paths: Vec<CryptoKeyPath>,
+        accounts: Option<Vec<Bytes>>,
+        origin: Option<String>,
+    ) -> AptosSignRequest {
+        AptosSignRequest {
+            request_id,
+            sign_data: Vec::new(),
+            sign_type: SignType::Multi,
+            authentication_key_derivation_paths,
+            accounts,
+            origin,
+            sign_data_list: Some(sign_data_list),
+            expires_at: None,
+            unknown_fields: UnknownFields::new(),
         }
     }
     pub fn get_request_id(&self) -> Bytes {
@@ -116,6 +171,19 @@ impl AptosSignRequest {
     pub fn get_origin(&self) -> Option<String> {
         self.origin.clone()
     }
+    /// Every payload of the request, the single `sign_data` unless it's a batch.
+    pub fn get_sign_data_list(&self) -> Vec<Bytes> {
+        match &self.sign_data_list {
+            Some(sign_data_list) => sign_data_list.clone(),
+            None => alloc::vec![self.sign_data.clone()],
+        }
+    }
+    pub fn is_multi(&self) -> bool {
+        self.sign_data_list.is_some()
+    }
+    pub fn get_unknown_fields(&self) -> UnknownFields {
+        self.unknown_fields.clone()
+    }
 }
 
 impl RegistryItem for AptosSignRequest {
@@ -124,6 +192,42 @@ impl RegistryItem for AptosSignRequest {
     }
 }
 
+impl Expiry for AptosSignRequest {
+    fn expires_at(&self) -> Option<u64> {
+        self.expires_at
+    }
+}
+
+impl Validate for AptosSignRequest {
+    fn validate_schema(&self) -> URResult<()> {
+        validate_request_id(&Some(self.request_id.clone()))?;
+        match &self.sign_data_list {
+            Some(sign_data_list) => {
+                if !matches!(self.sign_type, SignType::Multi) {
+                    return Err(URError::ValidationError(
+                        "sign_data_list requires sign_type multi".to_string(),
+                    ));
+                }
+                if sign_data_list.is_empty() {
+                    return Err(URError::ValidationError(
+                        "sign_data_list is empty".to_string(),
+                    ));
+                }
+                for sign_data in sign_data_list {
+                    validate_sign_data("sign_data_list", sign_data)?;
+                }
+            }
+            None => validate_sign_data("sign_data", &self.sign_data)?,
+        }
+        validate_paths(
+            "authentication_key_derivation_paths",
+            &self.authentication_key_derivation_paths,
+            Some(APTOS_COIN_TYPE),
+        )?;
+        Ok(())
+    }
+}
+
 impl<C> minicbor::Encode<C> for AptosSignRequest {
     fn encode<W: minicbor::encode::Write>(
         &self,
@@ -137,7 +241,10 @@ impl<C> minicbor::Encode<C> for AptosSignRequest {
         if self.origin.is_some() {
             size += 1;
         }
-        e.map(size)?;
+        if self.expires_at.is_some() {
+            size += 1;
+        }
+        e.map(size + self.unknown_fields.len() as u64)?;
         e.int(
             Int::try_from(REQUEST_ID)
                 .map_err(|e| minicbor::encode::Error::message(e.to_string()))?,
@@ -147,26 +254,29 @@ impl<C> minicbor::Encode<C> for AptosSignRequest {
         e.int(
             Int::try_from(SIGN_DATA)
                 .map_err(|e| minicbor::encode::Error::message(e.to_string()))?,
-        )?
-        .bytes(&self.get_sign_data())?;
-
-        let authentication_key_derivation_paths = self.get_authentication_key_derivation_paths();
-        if authentication_key_derivation_paths.is_empty() {
-            return Err(minicbor::encode::Error::message(
-                "authentication key derivation paths is invalid",
-            ));
+        )?;
+        match &self.sign_data_list {
+            Some(sign_data_list) => {
+                e.array(sign_data_list.len() as u64)?;
+                for sign_data in sign_data_list {
+                    e.bytes(sign_data)?;
+                }
+            }
+            None => {
+                e.bytes(&self.sign_data)?;
+            }
         }
+
         e.int(
             Int::try_from(AUTHENTICATION_KEY_DERIVATION_PATHS)
                 .map_err(|e| minicbor::encode::Error::message(e.to_string()))?,
-        )?
-        .array(authentication_key_derivation_paths.len() as u64)?;
-        for path in authentication_key_derivation_paths {
-            e.tag(Tag::Unassigned(
-                CryptoKeyPath::get_registry_type().get_tag(),
-            ))?;
-            CryptoKeyPath::encode(&path, e, ctx)?;
-        }
+        )?;
+        cbor_paths(
+            e,
+            &self.authentication_key_derivation_paths,
+            "authentication key derivation paths",
+            ctx,
+        )?;
 
         if let Some(accounts) = self.get_accounts() {
             e.int(
@@ -189,15 +299,16 @@ impl<C> minicbor::Encode<C> for AptosSignRequest {
             Int::try_from(SIGN_TYPE)
                 .map_err(|e| minicbor::encode::Error::message(e.to_string()))?,
         )?
-        .int(
-            Int::try_from(self.get_sign_type() as u8)
-                .map_err(|e| minicbor::encode::Error::message(e.to_string()))?,
-        )?;
+        .int(Int::from(self.get_sign_type().as_i32()))?;
+        if let Some(expires_at) = self.expires_at {
+            e.int(Int::from(EXPIRES_AT))?.u64(expires_at)?;
+        }
+        cbor_unknown_fields(e, &self.unknown_fields)?;
         Ok(())
     }
 }
 
-impl<'b, C> minicbor::Decode<'b, C> for AptosSignRequest {
+impl<'b, C: DecodeContext> minicbor::Decode<'b, C> for AptosSignRequest {
     fn decode(d: &mut minicbor::Decoder<'b>, ctx: &mut C) -> Result<Self, minicbor::decode::Error> {
         let mut result = AptosSignRequest::default();
 
@@ -206,28 +317,34 @@ impl<'b, C> minicbor::Decode<'b, C> for AptosSignRequest {
                 u8::try_from(key).map_err(|e| minicbor::decode::Error::message(e.to_string()))?;
             match key {
                 REQUEST_ID => {
-                    let tag = d.tag()?;
-                    if !tag.eq(&Tag::Unassigned(UUID.get_tag())) {
-                        return Err(minicbor::decode::Error::message("UUID tag is invalid"));
-                    }
-                    obj.request_id = d.bytes()?.to_vec();
-                }
-                SIGN_DATA => {
-                    obj.sign_data = d.bytes()?.to_vec();
+                    cbor_tag(d, ctx, UUID.get_tag(), "UUID tag is invalid")?;
+                    obj.request_id = cbor_bytes(d)?;
                 }
+                SIGN_DATA => match d.datatype()? {
+                    Type::Array | Type::ArrayIndef => {
+                        let mut sign_data_list = Vec::new();
+                        cbor_array(d, &mut sign_data_list, |_index, obj, d| {
+                            obj.push(cbor_bytes(d)?);
+                            Ok(())
+                        })?;
+                        obj.sign_data_list = Some(sign_data_list);
+                    }
+                    _ => {
+                        cbor_expect_type(d, ctx, Type::Bytes)?;
+                        obj.sign_data = cbor_bytes(d)?;
+                    }
+                },
                 AUTHENTICATION_KEY_DERIVATION_PATHS => {
                     cbor_array(
                         d,
                         &mut obj.authentication_key_derivation_paths,
                         |_key, obj, d| {
-                            let tag = d.tag()?;
-                            if !tag.eq(&Tag::Unassigned(
+                            cbor_tag(
+                                d,
+                                ctx,
                                 CryptoKeyPath::get_registry_type().get_tag(),
-                            )) {
-                                return Err(minicbor::decode::Error::message(
-                                    "CryptoKeyPath tag is invalid",
-                                ));
-                            }
+                                "CryptoKeyPath tag is invalid",
+                            )?;
                             obj.push(CryptoKeyPath::decode(d, ctx)?);
                             Ok(())
                         },
@@ -239,20 +356,27 @@ impl<'b, C> minicbor::Decode<'b, C> for AptosSignRequest {
                     }
                     cbor_array(d, &mut obj.accounts, |_key, obj, d| {
                         match obj {
-                            Some(v) => v.push(d.bytes()?.to_vec()),
+                            Some(v) => v.push(cbor_bytes(d)?),
                             None => {}
                         }
                         Ok(())
                     })?;
                 }
                 ORIGIN => {
-                    obj.origin = Some(d.str()?.to_string());
+                    obj.origin = Some(cbor_origin(d)?);
                 }
                 SIGN_TYPE => {
+                    cbor_expect_type(d, ctx, Type::U32)?;
                     obj.sign_type =
                         SignType::from_u32(d.u32()?).map_err(minicbor::decode::Error::message)?;
                 }
-                _ => {}
+                EXPIRES_AT => {
+                    cbor_expect_type(d, ctx, Type::U64)?;
+                    obj.expires_at = Some(d.u64()?);
+                }
+                _ => {
+                    cbor_unknown_field(d, key, &mut obj.unknown_fields)?;
+                }
             }
             Ok(())
         })?;
@@ -268,6 +392,96 @@ impl To for AptosSignRequest {
 
 impl From<AptosSignRequest> for AptosSignRequest {
     fn from_cbor(bytes: Vec<u8>) -> URResult<AptosSignRequest> {
-        minicbor::decode(&bytes).map_err(|e| URError::CborDecodeError(e.to_string()))
+        cbor_decode(&bytes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    #[test]
+    fn test_multi_round_trip() {
+        let path = CryptoKeyPath::from_path("m/44'/637'/0'/0'/0'".to_string(), None).unwrap();
+        let payloads = vec![
+            hex::decode("b5e97db07fa0bd0e5598aa3643a9bc6f6693bddc1a9fec9e674a461eaa00b193")
+                .unwrap(),
+            hex::decode("b5e97db07fa0bd0e5598aa3643a9bc6f6693bddc1a9fec9e674a461eaa00b194")
+                .unwrap(),
+            hex::decode("b5e97db07fa0bd0e5598aa3643a9bc6f6693bddc1a9fec9e674a461eaa00b195")
+                .unwrap(),
+        ];
+        let request = AptosSignRequest::new_multi(
+            hex::decode("9b1deb4d3b7d4bad9bdd2b0d7b3dcb6d").unwrap(),
+            payloads.clone(),
+            vec![path],
+            None,
+            None,
+        );
+        assert!(request.validate_schema().is_ok());
+
+        let bytes = request.to_bytes().unwrap();
+        let decoded = AptosSignRequest::from_cbor(bytes.clone()).unwrap();
+        assert!(decoded.is_multi());
+        assert_eq!(2, decoded.get_sign_type().as_i32());
+        assert_eq!(payloads, decoded.get_sign_data_list());
+        assert!(decoded.get_sign_data().is_empty());
+        assert!(decoded.validate_schema().is_ok());
+        assert_eq!(bytes, decoded.to_bytes().unwrap());
+
+        let mut single = decoded.clone();
+        single.set_sign_data(payloads[0].clone());
+        single.set_sign_type(SignType::Single);
+        assert!(!single.is_multi());
+        assert_eq!(vec![payloads[0].clone()], single.get_sign_data_list());
+
+        let mut mismatched = decoded;
+        mismatched.set_sign_type(SignType::Single);
+        assert_eq!(
+            Err(URError::ValidationError(
+                "sign_data_list requires sign_type multi".to_string()
+            )),
+            mismatched.validate_schema()
+        );
+    }
+
+    #[test]
+    fn test_sign_type_as_i32() {
+        for sign_type in [SignType::Single, SignType::Multi, SignType::Message] {
+            let value = sign_type.as_i32();
+            assert_eq!(
+                value,
+                SignType::from_u32(u32::try_from(value).unwrap())
+                    .unwrap()
+                    .as_i32()
+            );
+        }
+        assert_eq!(1, SignType::Single.as_i32());
+        assert_eq!(3, SignType::Message.as_i32());
+
+        // a large discriminant is not a supported sign type, it must not wrap around to a valid one
+        assert!(SignType::from_u32(257).is_err());
+        assert!(SignType::from_u32(u32::MAX).is_err());
+    }
+
+    #[test]
+    fn test_expiry() {
+        let path = CryptoKeyPath::from_path("m/44'/637'/0'/0'/0'".to_string(), None).unwrap();
+        let mut request = AptosSignRequest::new(
+            hex::decode("9b1deb4d3b7d4bad9bdd2b0d7b3dcb6d").unwrap(),
+            hex::decode("deadbeef").unwrap(),
+            vec![path],
+            None,
+            None,
+            SignType::Single,
+        );
+        assert!(!request.is_expired(u64::MAX));
+
+        request.set_expires_at(1760400000);
+        let decoded = AptosSignRequest::from_cbor(request.to_bytes().unwrap()).unwrap();
+        assert_eq!(Some(1760400000), decoded.expires_at());
+        assert!(!decoded.is_expired(1760399999));
+        assert!(decoded.is_expired(1760400001));
     }
 }
@@ -2,23 +2,34 @@ use alloc::string::ToString;
 use alloc::vec::Vec;
 use minicbor::data::{Int, Tag};
 
-use crate::cbor::cbor_map;
+use crate::cbor::{
+    cbor_bytes, cbor_map, cbor_tag, cbor_tx_hash, cbor_unknown_field, cbor_unknown_fields,
+    cbor_write_tx_hash,
+};
+use crate::decode::DecodeContext;
 use crate::error::{URError, URResult};
+use crate::impl_validate_signature;
 use crate::registry_types::{RegistryType, APTOS_SIGNATURE, UUID};
 use crate::traits::{From, RegistryItem, To};
-use crate::types::Bytes;
+use crate::types::{Bytes, UnknownFields};
 
 const REQUEST_ID: u8 = 1;
 const SIGNATURE: u8 = 2;
 const AUTHENTICATION_PUBLIC_KEY: u8 = 3;
+const TX_HASH: u8 = 4;
 
 #[derive(Clone, Debug, Default)]
 pub struct AptosSignature {
     request_id: Bytes,
     signature: Bytes,
     authentication_public_key: Bytes,
+    // hash of the transaction as computed by the device
+    tx_hash: Option<Bytes>,
+    unknown_fields: UnknownFields,
 }
 
+impl_validate_signature!(AptosSignature, required);
+
 impl AptosSignature {
     pub fn default() -> Self {
         Default::default()
@@ -36,11 +47,17 @@ impl AptosSignature {
         self.authentication_public_key = public_key;
     }
 
+    pub fn set_tx_hash(&mut self, tx_hash: Bytes) {
+        self.tx_hash = Some(tx_hash)
+    }
+
     pub fn new(request_id: Bytes, signature: Bytes, public_key: Bytes) -> Self {
         AptosSignature {
             request_id,
             signature,
             authentication_public_key: public_key,
+            tx_hash: None,
+            unknown_fields: UnknownFields::new(),
         }
     }
 
@@ -53,6 +70,12 @@ impl AptosSignature {
     pub fn get_authentication_public_key(&self) -> Bytes {
         self.authentication_public_key.clone()
     }
+    pub fn get_tx_hash(&self) -> Option<Bytes> {
+        self.tx_hash.clone()
+    }
+    pub fn get_unknown_fields(&self) -> UnknownFields {
+        self.unknown_fields.clone()
+    }
 }
 
 impl RegistryItem for AptosSignature {
@@ -67,7 +90,11 @@ impl<C> minicbor::Encode<C> for AptosSignature {
         e: &mut minicbor::Encoder<W>,
         _ctx: &mut C,
     ) -> Result<(), minicbor::encode::Error<W::Error>> {
-        e.map(3)?;
+        let mut size = 3;
+        if self.tx_hash.is_some() {
+            size += 1;
+        }
+        e.map(size + self.unknown_fields.len() as u64)?;
         e.int(
             Int::try_from(REQUEST_ID)
                 .map_err(|e| minicbor::encode::Error::message(e.to_string()))?,
@@ -84,15 +111,14 @@ impl<C> minicbor::Encode<C> for AptosSignature {
                 .map_err(|e| minicbor::encode::Error::message(e.to_string()))?,
         )?
         .bytes(&self.get_authentication_public_key())?;
+        cbor_write_tx_hash(e, TX_HASH, &self.tx_hash)?;
+        cbor_unknown_fields(e, &self.unknown_fields)?;
         Ok(())
     }
 }
 
-impl<'b, C> minicbor::Decode<'b, C> for AptosSignature {
-    fn decode(
-        d: &mut minicbor::Decoder<'b>,
-        _ctx: &mut C,
-    ) -> Result<Self, minicbor::decode::Error> {
+impl<'b, C: DecodeContext> minicbor::Decode<'b, C> for AptosSignature {
+    fn decode(d: &mut minicbor::Decoder<'b>, ctx: &mut C) -> Result<Self, minicbor::decode::Error> {
         let mut result = AptosSignature::default();
 
         cbor_map(d, &mut result, |key, obj, d| {
@@ -100,21 +126,21 @@ impl<'b, C> minicbor::Decode<'b, C> for AptosSignature {
                 u8::try_from(key).map_err(|e| minicbor::decode::Error::message(e.to_string()))?;
             match key {
                 REQUEST_ID => {
-                    let tag = d.tag()?;
-                    if !tag.eq(&Tag::Unassigned(UUID.get_tag())) {
-                        return Result::Err(minicbor::decode::Error::message(
-                            "UUID tag is invalid",
-                        ));
-                    }
-                    obj.request_id = d.bytes()?.to_vec();
+                    cbor_tag(d, ctx, UUID.get_tag(), "UUID tag is invalid")?;
+                    obj.request_id = cbor_bytes(d)?;
                 }
                 SIGNATURE => {
-                    obj.signature = d.bytes()?.to_vec();
+                    obj.signature = cbor_bytes(d)?;
                 }
                 AUTHENTICATION_PUBLIC_KEY => {
-                    obj.authentication_public_key = d.bytes()?.to_vec();
+                    obj.authentication_public_key = cbor_bytes(d)?;
+                }
+                TX_HASH => {
+                    obj.tx_hash = Some(cbor_tx_hash(d)?);
+                }
+                _ => {
+                    cbor_unknown_field(d, key, &mut obj.unknown_fields)?;
                 }
-                _ => {}
             }
             Ok(())
         })?;
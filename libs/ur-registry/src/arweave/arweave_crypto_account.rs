@@ -1,4 +1,4 @@
-use crate::cbor::cbor_map;
+use crate::cbor::{cbor_bytes, cbor_map};
 use crate::error::{URError, URResult};
 use crate::registry_types::{RegistryType, ARWEAVE_CRYPTO_ACCOUNT};
 use crate::traits::{From as FromCbor, RegistryItem, To};
@@ -109,7 +109,7 @@ impl<'b, C> minicbor::Decode<'b, C> for ArweaveCryptoAccount {
                     obj.master_fingerprint = u32::to_be_bytes(mfp?);
                 }
                 KEY_DATA => {
-                    obj.key_data = d.bytes()?.to_vec();
+                    obj.key_data = cbor_bytes(d)?;
                 }
                 DEVICE => {
                     obj.device = Some(d.str()?.to_string());
@@ -1,12 +1,18 @@
-use crate::cbor::cbor_map;
+use crate::cbor::{
+    cbor_bytes, cbor_decode, cbor_expect_type, cbor_map, cbor_origin, cbor_tag, cbor_unknown_field,
+    cbor_unknown_fields,
+};
+use crate::decode::DecodeContext;
 use crate::error::{URError, URResult};
+use crate::impl_with_new_request_id;
 use crate::registry_types::{RegistryType, NEAR_SIGN_REQUEST, UUID};
-use crate::traits::{From as FromCbor, RegistryItem, To};
-use crate::types::{Bytes, Fingerprint};
+use crate::traits::{From as FromCbor, RegistryItem, To, Validate};
+use crate::types::{Bytes, Fingerprint, UnknownFields};
+use crate::validate::{validate_request_id, validate_sign_data};
 use alloc::format;
 use alloc::string::{String, ToString};
 use alloc::vec::Vec;
-use minicbor::data::{Int, Tag};
+use minicbor::data::{Int, Tag, Type};
 use minicbor::encode::Write;
 use minicbor::{Decoder, Encoder};
 
@@ -69,8 +75,11 @@ pub struct ArweaveSignRequest {
     salt_len: SaltLen,
     account: Option<Bytes>,
     origin: Option<String>,
+    unknown_fields: UnknownFields,
 }
 
+impl_with_new_request_id!(ArweaveSignRequest);
+
 impl ArweaveSignRequest {
     pub fn default() -> Self {
         Default::default()
@@ -121,6 +130,7 @@ impl ArweaveSignRequest {
             salt_len,
             account,
             origin,
+            unknown_fields: UnknownFields::new(),
         }
     }
     pub fn get_master_fingerprint(&self) -> Fingerprint {
@@ -144,6 +154,9 @@ impl ArweaveSignRequest {
     pub fn get_origin(&self) -> Option<String> {
         self.origin.clone()
     }
+    pub fn get_unknown_fields(&self) -> UnknownFields {
+        self.unknown_fields.clone()
+    }
 
     fn get_map_size(&self) -> u64 {
         let mut size = 4;
@@ -156,7 +169,7 @@ impl ArweaveSignRequest {
         if self.origin.is_some() {
             size += 1;
         }
-        size
+        size + self.unknown_fields.len() as u64
     }
 }
 
@@ -166,6 +179,14 @@ impl RegistryItem for ArweaveSignRequest {
     }
 }
 
+impl Validate for ArweaveSignRequest {
+    fn validate_schema(&self) -> URResult<()> {
+        validate_request_id(&self.request_id)?;
+        validate_sign_data("sign_data", &self.sign_data)?;
+        Ok(())
+    }
+}
+
 impl<C> minicbor::Encode<C> for ArweaveSignRequest {
     fn encode<W: Write>(
         &self,
@@ -201,12 +222,13 @@ impl<C> minicbor::Encode<C> for ArweaveSignRequest {
             e.int(Int::from(ORIGIN))?.str(origin)?;
         }
 
+        cbor_unknown_fields(e, &self.unknown_fields)?;
         Ok(())
     }
 }
 
-impl<'b, C> minicbor::Decode<'b, C> for ArweaveSignRequest {
-    fn decode(d: &mut Decoder<'b>, _ctx: &mut C) -> Result<Self, minicbor::decode::Error> {
+impl<'b, C: DecodeContext> minicbor::Decode<'b, C> for ArweaveSignRequest {
+    fn decode(d: &mut Decoder<'b>, ctx: &mut C) -> Result<Self, minicbor::decode::Error> {
         let mut result = ArweaveSignRequest::default();
         cbor_map(d, &mut result, |key, obj, d| {
             let key =
@@ -218,13 +240,15 @@ impl<'b, C> minicbor::Decode<'b, C> for ArweaveSignRequest {
                     obj.master_fingerprint = u32::to_be_bytes(mfp?);
                 }
                 REQUEST_ID => {
-                    d.tag()?;
-                    obj.request_id = Some(d.bytes()?.to_vec());
+                    cbor_tag(d, ctx, UUID.get_tag(), "UUID tag is invalid")?;
+                    obj.request_id = Some(cbor_bytes(d)?);
                 }
                 SIGN_DATA => {
-                    obj.sign_data = d.bytes()?.to_vec();
+                    cbor_expect_type(d, ctx, Type::Bytes)?;
+                    obj.sign_data = cbor_bytes(d)?;
                 }
                 SIGN_TYPE => {
+                    cbor_expect_type(d, ctx, Type::U32)?;
                     obj.sign_type = SignType::from_u32(
                         u32::try_from(d.int()?)
                             .map_err(|e| minicbor::decode::Error::message(e.to_string()))?,
@@ -239,12 +263,14 @@ impl<'b, C> minicbor::Decode<'b, C> for ArweaveSignRequest {
                     .map_err(minicbor::decode::Error::message)?;
                 }
                 ACCOUNT => {
-                    obj.account = Some(d.bytes()?.to_vec());
+                    obj.account = Some(cbor_bytes(d)?);
                 }
                 ORIGIN => {
-                    obj.origin = Some(d.str()?.to_string());
+                    obj.origin = Some(cbor_origin(d)?);
+                }
+                _ => {
+                    cbor_unknown_field(d, key, &mut obj.unknown_fields)?;
                 }
-                _ => {}
             }
             Ok(())
         })?;
@@ -260,15 +286,17 @@ impl To for ArweaveSignRequest {
 
 impl FromCbor<ArweaveSignRequest> for ArweaveSignRequest {
     fn from_cbor(bytes: Vec<u8>) -> URResult<ArweaveSignRequest> {
-        minicbor::decode(&bytes).map_err(|e| URError::CborDecodeError(e.to_string()))
+        cbor_decode(&bytes)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use crate::arweave::arweave_sign_request::{ArweaveSignRequest, SaltLen, SignType};
-    use crate::traits::{From, To};
+    use crate::error::URError;
+    use crate::traits::{From, To, Validate};
     use alloc::string::ToString;
+    use alloc::vec;
     use alloc::vec::Vec;
     use hex::FromHex;
 
@@ -329,4 +357,25 @@ mod tests {
         assert_eq!(SaltLen::Zero, sign_request.get_salt_len());
         assert_eq!(Some("arconnect".to_string()), sign_request.get_origin());
     }
+
+    #[test]
+    fn test_validate_schema() {
+        let mut sign_request = ArweaveSignRequest::new(
+            [233, 24, 28, 243],
+            Some(hex::decode("9b1deb4d3b7d4bad9bdd2b0d7b3dcb6d").unwrap()),
+            hex::decode("af78f85b29d88a61ee49d36e84139ec8511c558f14612413f1503b8e6959adca")
+                .unwrap(),
+            SignType::Transaction,
+            SaltLen::Zero,
+            None,
+            None,
+        );
+        assert!(sign_request.validate_schema().is_ok());
+
+        sign_request.set_sign_data(vec![]);
+        assert_eq!(
+            Err(URError::ValidationError("sign_data is empty".to_string())),
+            sign_request.validate_schema()
+        );
+    }
 }
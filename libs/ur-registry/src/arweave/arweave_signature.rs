@@ -1,8 +1,10 @@
-use crate::cbor::cbor_map;
+use crate::cbor::{cbor_bytes, cbor_map, cbor_tag, cbor_unknown_field, cbor_unknown_fields};
+use crate::decode::DecodeContext;
 use crate::error::{URError, URResult};
+use crate::impl_validate_signature;
 use crate::registry_types::{RegistryType, ARWEAVE_SIGNATURE, UUID};
 use crate::traits::{From as FromCbor, RegistryItem, To};
-use crate::types::Bytes;
+use crate::types::{Bytes, UnknownFields};
 use alloc::string::ToString;
 use alloc::vec::Vec;
 use minicbor::data::{Int, Tag};
@@ -16,8 +18,11 @@ const SIGNATURE: u8 = 2;
 pub struct ArweaveSignature {
     request_id: Option<Bytes>,
     signature: Bytes,
+    unknown_fields: UnknownFields,
 }
 
+impl_validate_signature!(ArweaveSignature);
+
 impl ArweaveSignature {
     pub fn default() -> Self {
         Default::default()
@@ -35,6 +40,7 @@ impl ArweaveSignature {
         ArweaveSignature {
             request_id,
             signature,
+            unknown_fields: UnknownFields::new(),
         }
     }
 
@@ -44,6 +50,9 @@ impl ArweaveSignature {
     pub fn get_signature(&self) -> Bytes {
         self.signature.clone()
     }
+    pub fn get_unknown_fields(&self) -> UnknownFields {
+        self.unknown_fields.clone()
+    }
 }
 
 impl RegistryItem for ArweaveSignature {
@@ -62,32 +71,35 @@ impl<C> minicbor::Encode<C> for ArweaveSignature {
         if self.request_id.is_some() {
             size += 1;
         }
-        e.map(size)?;
+        e.map(size + self.unknown_fields.len() as u64)?;
         if let Some(request_id) = &self.request_id {
             e.int(Int::from(REQUEST_ID))?
                 .tag(Tag::Unassigned(UUID.get_tag()))?
                 .bytes(request_id)?;
         }
         e.int(Int::from(SIGNATURE))?.bytes(&self.signature)?;
+        cbor_unknown_fields(e, &self.unknown_fields)?;
         Ok(())
     }
 }
 
-impl<'b, C> minicbor::Decode<'b, C> for ArweaveSignature {
-    fn decode(d: &mut Decoder<'b>, _ctx: &mut C) -> Result<Self, minicbor::decode::Error> {
+impl<'b, C: DecodeContext> minicbor::Decode<'b, C> for ArweaveSignature {
+    fn decode(d: &mut Decoder<'b>, ctx: &mut C) -> Result<Self, minicbor::decode::Error> {
         let mut result = ArweaveSignature::default();
         cbor_map(d, &mut result, |key, obj, d| {
             let key =
                 u8::try_from(key).map_err(|e| minicbor::decode::Error::message(e.to_string()))?;
             match key {
                 REQUEST_ID => {
-                    d.tag()?;
-                    obj.request_id = Some(d.bytes()?.to_vec());
+                    cbor_tag(d, ctx, UUID.get_tag(), "UUID tag is invalid")?;
+                    obj.request_id = Some(cbor_bytes(d)?);
                 }
                 SIGNATURE => {
-                    obj.signature = d.bytes()?.to_vec();
+                    obj.signature = cbor_bytes(d)?;
+                }
+                _ => {
+                    cbor_unknown_field(d, key, &mut obj.unknown_fields)?;
                 }
-                _ => {}
             }
             Ok(())
         })?;
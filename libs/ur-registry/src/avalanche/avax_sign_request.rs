@@ -1,11 +1,19 @@
-use crate::cbor::{cbor_array, cbor_map};
+use crate::cbor::{
+    cbor_array, cbor_bytes, cbor_expect_type, cbor_map, cbor_origin, cbor_paths, cbor_tag,
+    cbor_unknown_field, cbor_unknown_fields,
+};
+use crate::crypto_key_path::CryptoKeyPath;
+use crate::decode::DecodeContext;
 use crate::error::{URError, URResult};
+use crate::impl_with_new_request_id;
 use crate::registry_types::{RegistryType, AVAX_SIGN_REQUEST, UUID};
-use crate::traits::{From as FromCbor, RegistryItem, To};
-use crate::types::{Bytes, Fingerprint};
+use crate::traits::{From as FromCbor, RegistryItem, To, Validate};
+use crate::types::{Bytes, Fingerprint, UnknownFields};
+use crate::validate::{validate_paths, validate_request_id, validate_sign_data};
+use alloc::format;
 use alloc::string::{String, ToString};
 use alloc::vec::Vec;
-use minicbor::data::{Int, Tag};
+use minicbor::data::{Int, Tag, Type};
 use minicbor::encode::Write;
 use minicbor::{Decoder, Encoder};
 
@@ -14,6 +22,32 @@ const SIGN_DATA: u8 = 2;
 const MASTER_FINGERPRINT: u8 = 3;
 const XPUB: u8 = 6;
 const WALLET_INDEX: u8 = 7;
+const CHAIN: u8 = 8;
+const DERIVATION_PATHS: u8 = 9;
+const ORIGIN: u8 = 10;
+
+/// The chain of the transaction. The C-chain is EVM compatible, X-chain and
+/// P-chain transactions are serialized with the Avalanche codec.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AvaxChain {
+    XChain = 0,
+    PChain = 1,
+    CChain = 2,
+}
+
+impl AvaxChain {
+    pub fn from_u32(i: u32) -> Result<Self, String> {
+        match i {
+            0 => Ok(AvaxChain::XChain),
+            1 => Ok(AvaxChain::PChain),
+            2 => Ok(AvaxChain::CChain),
+            x => Err(format!(
+                "invalid value for chain in avax-sign-request, expected (0, 1, 2), received {:?}",
+                x
+            )),
+        }
+    }
+}
 
 #[derive(Debug, Clone, Default)]
 pub struct AvaxSignRequest {
@@ -22,8 +56,14 @@ pub struct AvaxSignRequest {
     master_fingerprint: Fingerprint,
     xpub: String,
     wallet_index: u64,
+    chain: Option<AvaxChain>,
+    derivation_paths: Option<Vec<CryptoKeyPath>>,
+    origin: Option<String>,
+    unknown_fields: UnknownFields,
 }
 
+impl_with_new_request_id!(AvaxSignRequest, required);
+
 impl AvaxSignRequest {
     pub fn new(
         request_id: Bytes,
@@ -38,6 +78,7 @@ impl AvaxSignRequest {
             master_fingerprint,
             xpub,
             wallet_index,
+            ..Default::default()
         }
     }
 
@@ -76,6 +117,33 @@ impl AvaxSignRequest {
     pub fn set_wallet_index(&mut self, index: u64) {
         self.wallet_index = index;
     }
+
+    pub fn get_chain(&self) -> Option<AvaxChain> {
+        self.chain
+    }
+
+    pub fn set_chain(&mut self, chain: AvaxChain) {
+        self.chain = Some(chain);
+    }
+
+    pub fn get_derivation_paths(&self) -> Option<Vec<CryptoKeyPath>> {
+        self.derivation_paths.clone()
+    }
+
+    pub fn set_derivation_paths(&mut self, derivation_paths: Vec<CryptoKeyPath>) {
+        self.derivation_paths = Some(derivation_paths);
+    }
+
+    pub fn get_origin(&self) -> Option<String> {
+        self.origin.clone()
+    }
+
+    pub fn set_origin(&mut self, origin: String) {
+        self.origin = Some(origin);
+    }
+    pub fn get_unknown_fields(&self) -> UnknownFields {
+        self.unknown_fields.clone()
+    }
 }
 
 impl RegistryItem for AvaxSignRequest {
@@ -84,13 +152,34 @@ impl RegistryItem for AvaxSignRequest {
     }
 }
 
+impl Validate for AvaxSignRequest {
+    fn validate_schema(&self) -> URResult<()> {
+        validate_request_id(&Some(self.request_id.clone()))?;
+        validate_sign_data("sign_data", &self.sign_data)?;
+        if let Some(derivation_paths) = &self.derivation_paths {
+            validate_paths("derivation_paths", derivation_paths, None)?;
+        }
+        Ok(())
+    }
+}
+
 impl<C> minicbor::Encode<C> for AvaxSignRequest {
     fn encode<W: Write>(
         &self,
         e: &mut Encoder<W>,
-        _ctx: &mut C,
+        ctx: &mut C,
     ) -> Result<(), minicbor::encode::Error<W::Error>> {
-        e.map(5)?;
+        let mut size = 5;
+        if self.chain.is_some() {
+            size += 1;
+        }
+        if self.derivation_paths.is_some() {
+            size += 1;
+        }
+        if self.origin.is_some() {
+            size += 1;
+        }
+        e.map(size + self.unknown_fields.len() as u64)?;
         e.int(Int::from(REQUEST_ID))?
             .tag(Tag::Unassigned(UUID.get_tag()))?
             .bytes(&self.request_id)?;
@@ -101,12 +190,23 @@ impl<C> minicbor::Encode<C> for AvaxSignRequest {
         )?;
         e.int(Int::from(XPUB))?.str(&self.xpub)?;
         e.int(Int::from(WALLET_INDEX))?.u64(self.wallet_index)?;
+        if let Some(chain) = self.chain {
+            e.int(Int::from(CHAIN))?.u32(chain as u32)?;
+        }
+        if let Some(derivation_paths) = &self.derivation_paths {
+            e.int(Int::from(DERIVATION_PATHS))?;
+            cbor_paths(e, derivation_paths, "derivation paths", ctx)?;
+        }
+        if let Some(origin) = &self.origin {
+            e.int(Int::from(ORIGIN))?.str(origin)?;
+        }
+        cbor_unknown_fields(e, &self.unknown_fields)?;
         Ok(())
     }
 }
 
-impl<'b, C> minicbor::Decode<'b, C> for AvaxSignRequest {
-    fn decode(d: &mut Decoder<'b>, _ctx: &mut C) -> Result<Self, minicbor::decode::Error> {
+impl<'b, C: DecodeContext> minicbor::Decode<'b, C> for AvaxSignRequest {
+    fn decode(d: &mut Decoder<'b>, ctx: &mut C) -> Result<Self, minicbor::decode::Error> {
         let mut result = AvaxSignRequest::default();
 
         cbor_map(d, &mut result, |key, obj, d| {
@@ -114,13 +214,15 @@ impl<'b, C> minicbor::Decode<'b, C> for AvaxSignRequest {
                 u8::try_from(key).map_err(|e| minicbor::decode::Error::message(e.to_string()))?;
             match key {
                 REQUEST_ID => {
-                    d.tag()?;
-                    obj.request_id = d.bytes()?.to_vec();
+                    cbor_tag(d, ctx, UUID.get_tag(), "UUID tag is invalid")?;
+                    obj.request_id = cbor_bytes(d)?;
                 }
                 SIGN_DATA => {
-                    obj.sign_data = d.bytes()?.to_vec();
+                    cbor_expect_type(d, ctx, Type::Bytes)?;
+                    obj.sign_data = cbor_bytes(d)?;
                 }
                 MASTER_FINGERPRINT => {
+                    cbor_expect_type(d, ctx, Type::U32)?;
                     let mfp = u32::try_from(d.int()?)
                         .map_err(|e| minicbor::decode::Error::message(e.to_string()));
                     obj.master_fingerprint = u32::to_be_bytes(mfp?);
@@ -129,9 +231,35 @@ impl<'b, C> minicbor::Decode<'b, C> for AvaxSignRequest {
                     obj.xpub = d.str()?.to_string();
                 }
                 WALLET_INDEX => {
+                    cbor_expect_type(d, ctx, Type::U64)?;
                     obj.wallet_index = d.u64()?;
                 }
-                _ => {}
+                CHAIN => {
+                    cbor_expect_type(d, ctx, Type::U32)?;
+                    obj.chain = Some(
+                        AvaxChain::from_u32(d.u32()?).map_err(minicbor::decode::Error::message)?,
+                    );
+                }
+                DERIVATION_PATHS => {
+                    let mut derivation_paths = Vec::new();
+                    cbor_array(d, &mut derivation_paths, |_key, obj, d| {
+                        cbor_tag(
+                            d,
+                            ctx,
+                            CryptoKeyPath::get_registry_type().get_tag(),
+                            "CryptoKeyPath tag is invalid",
+                        )?;
+                        obj.push(CryptoKeyPath::decode(d, ctx)?);
+                        Ok(())
+                    })?;
+                    obj.derivation_paths = Some(derivation_paths);
+                }
+                ORIGIN => {
+                    obj.origin = Some(cbor_origin(d)?);
+                }
+                _ => {
+                    cbor_unknown_field(d, key, &mut obj.unknown_fields)?;
+                }
             }
             Ok(())
         })?;
@@ -155,6 +283,7 @@ impl FromCbor<AvaxSignRequest> for AvaxSignRequest {
 mod tests {
     use super::*;
     use crate::traits::RegistryItem;
+    use alloc::vec;
     use alloc::vec::Vec;
     use hex::FromHex;
     extern crate std;
@@ -169,6 +298,7 @@ mod tests {
             master_fingerprint: [0, 0, 0, 0],
             xpub: "xpub6DXryz8Kd7XchtXvDnkjara83shGJH8ubu7KZhHhPfp4L1shvDEYiFZm32EKHnyo4bva4gxXjabFGqY7fNs8Ggd4khYz2oNs2KYLf56a9GX".to_string(),
             wallet_index: 0,
+            ..Default::default()
         };
         let result: Vec<u8> = unsigned_data.try_into().unwrap();
         println!("result = {:?}", hex::encode(&result));
@@ -188,4 +318,82 @@ mod tests {
                 .unwrap()
         );
     }
+
+    #[test]
+    fn test_avax_p_chain_round_trip() {
+        // a P-chain base tx
+        let sign_data = Vec::from_hex("000000000022000000050000000000000000000000000000000000000000000000000000000000000000000000023d9bdac0ed1d761330cf680efdeb1a42159eb387d6d2950c96f7d28f61bbe2aa0000000700000000000f42400000000000000000000000010000000132336f8715dd313a426155cccc15ba27c3033dae3d9bdac0ed1d761330cf680efdeb1a42159eb387d6d2950c96f7d28f61bbe2aa00000007000000004d58ade90000000000000000000000010000000132336f8715dd313a426155cccc15ba27c3033dae00000001410b47f7c7aa13f88122be58735c5e985edc65d86fb0baf0b016359c22253d75000000013d9bdac0ed1d761330cf680efdeb1a42159eb387d6d2950c96f7d28f61bbe2aa00000005000000004d680464000000010000000000000000")
+            .unwrap();
+        let path = CryptoKeyPath::from_path(
+            "m/44'/9000'/0'/0/0".to_string(),
+            Some([0xf2, 0x3f, 0x9f, 0xd2]),
+        )
+        .unwrap();
+        let mut request = AvaxSignRequest::new(
+            Vec::from_hex("9b1deb4d3b7d4bad9bdd2b0d7b3dcb6d").unwrap(),
+            sign_data.clone(),
+            [0xf2, 0x3f, 0x9f, 0xd2],
+            "xpub6DXryz8Kd7XchtXvDnkjara83shGJH8ubu7KZhHhPfp4L1shvDEYiFZm32EKHnyo4bva4gxXjabFGqY7fNs8Ggd4khYz2oNs2KYLf56a9GX".to_string(),
+            0,
+        );
+        request.set_chain(AvaxChain::PChain);
+        request.set_derivation_paths(vec![path.clone()]);
+        request.set_origin("Core".to_string());
+
+        let bytes: Vec<u8> = request.try_into().unwrap();
+        let encoded = hex::encode(&bytes);
+        assert!(encoded.starts_with("a801d825509b1deb4d3b7d4bad9bdd2b0d7b3dcb6d"));
+        assert!(encoded
+            .ends_with("08010981d90130a2018a182cf5192328f500f500f400f4021af23f9fd20a64436f7265"));
+
+        let decoded = AvaxSignRequest::try_from(bytes).unwrap();
+        assert_eq!(sign_data, decoded.get_tx_data());
+        assert_eq!(Some(AvaxChain::PChain), decoded.get_chain());
+        assert_eq!(Some(vec![path]), decoded.get_derivation_paths());
+        assert_eq!(Some("Core".to_string()), decoded.get_origin());
+        assert_eq!(0, decoded.get_wallet_index());
+    }
+
+    #[test]
+    fn test_decode_unexpected_type() {
+        // wallet_index is the float 1.0
+        let bytes = Vec::from_hex("a202410007fa3f800000").unwrap();
+        assert_eq!(
+            Err(URError::UnexpectedType {
+                expected: "u64".to_string(),
+                got: "f32".to_string(),
+            }),
+            AvaxSignRequest::try_from(bytes).map(|_| ())
+        );
+
+        // chain is the text string "1"
+        let bytes = Vec::from_hex("a2024100086131").unwrap();
+        assert_eq!(
+            Err(URError::UnexpectedType {
+                expected: "u32".to_string(),
+                got: "string".to_string(),
+            }),
+            AvaxSignRequest::try_from(bytes).map(|_| ())
+        );
+    }
+
+    #[test]
+    fn test_validate_schema() {
+        let mut request = AvaxSignRequest::new(
+            hex::decode("9b1deb4d3b7d4bad9bdd2b0d7b3dcb6d").unwrap(),
+            hex::decode("0000000000220000000500").unwrap(),
+            [0, 0, 0, 0],
+            "xpub6DXryz8Kd7XchtXvDnkjara83shGJH8ubu7KZhHhPfp4L1shvDEYiFZm32EKHnyo4bva4gxXjabFGqY7fNs8Ggd4khYz2oNs2KYLf56a9GX".to_string(),
+            0,
+        );
+        assert!(request.validate_schema().is_ok());
+
+        request.set_request_id(vec![12, 34, 56, 78]);
+        assert_eq!(
+            Err(URError::ValidationError(
+                "request_id must be 16 bytes, received 4".to_string()
+            )),
+            request.validate_schema()
+        );
+    }
 }
@@ -1,8 +1,9 @@
-use crate::cbor::cbor_map;
+use crate::cbor::{cbor_bytes, cbor_map, cbor_unknown_field, cbor_unknown_fields};
 use crate::error::{URError, URResult};
+use crate::impl_validate_signature;
 use crate::registry_types::{RegistryType, AVAX_SIGNATURE, UUID};
 use crate::traits::{From as FromCbor, RegistryItem, To};
-use crate::types::Bytes;
+use crate::types::{Bytes, UnknownFields};
 use alloc::string::ToString;
 use alloc::vec::Vec;
 use minicbor::data::{Int, Tag};
@@ -16,8 +17,11 @@ const SIGNATURE: u8 = 2;
 pub struct AvaxSignature {
     request_id: Bytes,
     signature: Bytes,
+    unknown_fields: UnknownFields,
 }
 
+impl_validate_signature!(AvaxSignature, required);
+
 impl AvaxSignature {
     pub fn default() -> Self {
         Default::default()
@@ -35,6 +39,7 @@ impl AvaxSignature {
         AvaxSignature {
             request_id,
             signature,
+            unknown_fields: UnknownFields::new(),
         }
     }
 
@@ -44,6 +49,9 @@ impl AvaxSignature {
     pub fn get_signature(&self) -> Bytes {
         self.signature.clone()
     }
+    pub fn get_unknown_fields(&self) -> UnknownFields {
+        self.unknown_fields.clone()
+    }
 }
 
 impl RegistryItem for AvaxSignature {
@@ -59,9 +67,10 @@ impl<C> minicbor::Encode<C> for AvaxSignature {
         _ctx: &mut C,
     ) -> Result<(), minicbor::encode::Error<W::Error>> {
         let mut size = 2;
-        e.map(size)?;
+        e.map(size + self.unknown_fields.len() as u64)?;
         e.int(Int::from(REQUEST_ID))?.bytes(&self.request_id)?;
         e.int(Int::from(SIGNATURE))?.bytes(&self.signature)?;
+        cbor_unknown_fields(e, &self.unknown_fields)?;
         Ok(())
     }
 }
@@ -74,12 +83,14 @@ impl<'b, C> minicbor::Decode<'b, C> for AvaxSignature {
                 u8::try_from(key).map_err(|e| minicbor::decode::Error::message(e.to_string()))?;
             match key {
                 REQUEST_ID => {
-                    obj.request_id = d.bytes()?.to_vec();
+                    obj.request_id = cbor_bytes(d)?;
                 }
                 SIGNATURE => {
-                    obj.signature = d.bytes()?.to_vec();
+                    obj.signature = cbor_bytes(d)?;
+                }
+                _ => {
+                    cbor_unknown_field(d, key, &mut obj.unknown_fields)?;
                 }
-                _ => {}
             }
             Ok(())
         })?;
@@ -0,0 +1,84 @@
+use alloc::string::{String, ToString};
+
+use base64::alphabet::URL_SAFE;
+use base64::engine::{DecodePaddingMode, GeneralPurpose, GeneralPurposeConfig};
+use base64::Engine;
+
+use crate::error::{URError, URResult};
+use crate::traits::RegistryItem;
+
+// unpadded as in RFC 7515, padded input is accepted too
+const BASE64URL: GeneralPurpose = GeneralPurpose::new(
+    &URL_SAFE,
+    GeneralPurposeConfig::new()
+        .with_encode_padding(false)
+        .with_decode_padding_mode(DecodePaddingMode::Indifferent),
+);
+
+/// Encodes the CBOR of `item` as unpadded base64url, for transports that carry
+/// registry items without the UR envelope.
+pub fn to_base64url<T>(item: &T) -> URResult<String>
+where
+    T: RegistryItem + minicbor::Encode<()>,
+{
+    let bytes = minicbor::to_vec(item).map_err(|e| URError::CborEncodeError(e.to_string()))?;
+    Ok(BASE64URL.encode(bytes))
+}
+
+/// Decodes a registry item from base64url encoded CBOR.
+pub fn from_base64url<T>(s: &str) -> URResult<T>
+where
+    T: RegistryItem + for<'a> TryFrom<&'a [u8], Error = URError>,
+{
+    let bytes = BASE64URL
+        .decode(s)
+        .map_err(|e| URError::Base64DecodeError(e.to_string()))?;
+    T::try_from(bytes.as_slice())
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+
+    use super::*;
+    use crate::crypto_key_path::CryptoKeyPath;
+    use crate::sui::sui_sign_request::SuiSignRequest;
+
+    #[test]
+    fn test_sui_sign_request_round_trip() {
+        let path = CryptoKeyPath::from_path("m/44'/784'/0'/0'/0'".to_string(), None).unwrap();
+        let request = SuiSignRequest::new(
+            Some(hex::decode("9b1deb4d3b7d4bad9bdd2b0d7b3dcb6d").unwrap()),
+            hex::decode("0000000000020020").unwrap(),
+            vec![path],
+            None,
+            Some("Sui Wallet".to_string()),
+            None,
+            None,
+            None,
+        );
+        let encoded = to_base64url(&request).unwrap();
+        assert_eq!(
+            "pAHYJVCbHetNO31LrZvdKw17PcttAkgAAAAAAAIAIAOB2QEwoQGKGCz1GQMQ9QD1APUA9QVqU3VpIFdhbGxldA",
+            encoded
+        );
+
+        let decoded: SuiSignRequest = from_base64url(&encoded).unwrap();
+        assert_eq!(request.to_cbor().unwrap(), decoded.to_cbor().unwrap());
+
+        let padded = alloc::format!("{}==", encoded);
+        assert!(from_base64url::<SuiSignRequest>(&padded).is_ok());
+    }
+
+    #[test]
+    fn test_from_base64url_error() {
+        assert!(matches!(
+            from_base64url::<SuiSignRequest>("pAHY+JVC"),
+            Err(URError::Base64DecodeError(_))
+        ));
+        assert!(matches!(
+            from_base64url::<SuiSignRequest>("pAHY"),
+            Err(URError::CborDecodeError(_))
+        ));
+    }
+}
@@ -1,13 +1,20 @@
-use crate::cbor::{cbor_array, cbor_map};
+use crate::cbor::{
+    cbor_array, cbor_bytes, cbor_expect_type, cbor_map, cbor_origin, cbor_paths, cbor_tag,
+    cbor_unknown_field, cbor_unknown_fields,
+};
 use crate::crypto_key_path::CryptoKeyPath;
+use crate::decode::DecodeContext;
+use crate::error::{URError, URResult};
 use crate::impl_template_struct;
+use crate::impl_with_new_request_id;
 use crate::registry_types::{RegistryType, BTC_SIGN_REQUEST, UUID};
-use crate::traits::{MapSize, RegistryItem};
+use crate::traits::{MapSize, RegistryItem, Validate};
 use crate::types::Bytes;
+use crate::validate::{validate_paths, validate_request_id, validate_sign_data};
 use alloc::format;
 use alloc::string::{String, ToString};
 use alloc::vec::Vec;
-use minicbor::data::{Int, Tag};
+use minicbor::data::{Int, Tag, Type};
 
 const REQUEST_ID: u8 = 1;
 const SIGN_DATA: u8 = 2;
@@ -15,6 +22,7 @@ const DATA_TYPE: u8 = 3;
 const DERIVATION_PATHS: u8 = 4;
 const ADDRESSES: u8 = 5;
 const ORIGIN: u8 = 6;
+const NETWORK: u8 = 7;
 
 #[derive(Clone, Debug, Default)]
 pub enum DataType {
@@ -34,14 +42,53 @@ impl DataType {
     }
 }
 
-impl_template_struct!(BtcSignRequest {
-    request_id: Bytes,
-    sign_data: Bytes,
-    data_type: DataType,
-    derivation_paths: Vec<CryptoKeyPath>,
-    addresses: Option<Vec<String>>,
-    origin: Option<String>
-});
+/// The network the requested addresses belong to, so that the device checks
+/// them against the right prefixes.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub enum BtcNetwork {
+    #[default]
+    Mainnet = 0,
+    Testnet = 1,
+    Signet = 2,
+}
+
+impl BtcNetwork {
+    pub fn from_u32(i: u32) -> Result<Self, String> {
+        match i {
+            0 => Ok(BtcNetwork::Mainnet),
+            1 => Ok(BtcNetwork::Testnet),
+            2 => Ok(BtcNetwork::Signet),
+            x => Err(format!(
+                "invalid value for network in btc-sign-request, expected (0, 1, 2), received {:?}",
+                x
+            )),
+        }
+    }
+
+    /// The leading characters of P2PKH, P2SH and segwit addresses, signet
+    /// shares the testnet ones.
+    pub fn address_prefixes(&self) -> &'static [&'static str] {
+        match self {
+            BtcNetwork::Mainnet => &["1", "3", "bc1"],
+            BtcNetwork::Testnet | BtcNetwork::Signet => &["m", "n", "2", "tb1"],
+        }
+    }
+}
+
+impl_template_struct!(
+    BtcSignRequest {
+        request_id: Bytes,
+        sign_data: Bytes,
+        data_type: DataType,
+        derivation_paths: Vec<CryptoKeyPath>,
+        addresses: Option<Vec<String>>,
+        origin: Option<String>,
+        network: Option<BtcNetwork>
+    },
+    unknown_fields
+);
+
+impl_with_new_request_id!(BtcSignRequest, required);
 
 impl RegistryItem for BtcSignRequest {
     fn get_registry_type() -> RegistryType<'static> {
@@ -58,7 +105,31 @@ impl MapSize for BtcSignRequest {
         if self.origin.is_some() {
             size += 1;
         }
-        size
+        if self.network.is_some() {
+            size += 1;
+        }
+        size + self.unknown_fields.len() as u64
+    }
+}
+
+impl Validate for BtcSignRequest {
+    fn validate_schema(&self) -> URResult<()> {
+        validate_request_id(&Some(self.request_id.clone()))?;
+        validate_sign_data("sign_data", &self.sign_data)?;
+        validate_paths("derivation_paths", &self.derivation_paths, None)?;
+        if let (Some(network), Some(addresses)) = (&self.network, &self.addresses) {
+            let prefixes = network.address_prefixes();
+            // an empty address stands for an account whose address is unknown
+            for address in addresses.iter().filter(|address| !address.is_empty()) {
+                if !prefixes.iter().any(|prefix| address.starts_with(prefix)) {
+                    return Err(URError::ValidationError(format!(
+                        "address {} does not belong to {:?}",
+                        address, network
+                    )));
+                }
+            }
+        }
+        Ok(())
     }
 }
 
@@ -89,23 +160,11 @@ impl<C> minicbor::Encode<C> for BtcSignRequest {
                 .map_err(|e| minicbor::encode::Error::message(e.to_string()))?,
         )?;
 
-        let derivation_paths = self.get_derivation_paths();
-        if derivation_paths.is_empty() {
-            return Result::Err(minicbor::encode::Error::message(
-                "derivation_paths is invalid",
-            ));
-        }
         e.int(
             Int::try_from(DERIVATION_PATHS)
                 .map_err(|e| minicbor::encode::Error::message(e.to_string()))?,
-        )?
-        .array(derivation_paths.len() as u64)?;
-        for path in derivation_paths {
-            e.tag(Tag::Unassigned(
-                CryptoKeyPath::get_registry_type().get_tag(),
-            ))?;
-            CryptoKeyPath::encode(&path, e, ctx)?;
-        }
+        )?;
+        cbor_paths(e, &self.derivation_paths, "derivation_paths", ctx)?;
 
         if let Some(addresses) = self.get_addresses() {
             e.int(
@@ -124,11 +183,22 @@ impl<C> minicbor::Encode<C> for BtcSignRequest {
             )?
             .str(&origin)?;
         }
+        if let Some(network) = self.get_network() {
+            e.int(
+                Int::try_from(NETWORK)
+                    .map_err(|e| minicbor::encode::Error::message(e.to_string()))?,
+            )?
+            .int(
+                Int::try_from(network as u8)
+                    .map_err(|e| minicbor::encode::Error::message(e.to_string()))?,
+            )?;
+        }
+        cbor_unknown_fields(e, &self.unknown_fields)?;
         Ok(())
     }
 }
 
-impl<'b, C> minicbor::Decode<'b, C> for BtcSignRequest {
+impl<'b, C: DecodeContext> minicbor::Decode<'b, C> for BtcSignRequest {
     fn decode(d: &mut minicbor::Decoder<'b>, ctx: &mut C) -> Result<Self, minicbor::decode::Error> {
         let mut result = BtcSignRequest::default();
 
@@ -137,31 +207,26 @@ impl<'b, C> minicbor::Decode<'b, C> for BtcSignRequest {
                 u8::try_from(key).map_err(|e| minicbor::decode::Error::message(e.to_string()))?;
             match key {
                 REQUEST_ID => {
-                    let tag = d.tag()?;
-                    if !tag.eq(&Tag::Unassigned(UUID.get_tag())) {
-                        return Result::Err(minicbor::decode::Error::message(
-                            "UUID tag is invalid",
-                        ));
-                    }
-                    obj.request_id = d.bytes()?.to_vec();
+                    cbor_tag(d, ctx, UUID.get_tag(), "UUID tag is invalid")?;
+                    obj.request_id = cbor_bytes(d)?;
                 }
                 SIGN_DATA => {
-                    obj.sign_data = d.bytes()?.to_vec();
+                    cbor_expect_type(d, ctx, Type::Bytes)?;
+                    obj.sign_data = cbor_bytes(d)?;
                 }
                 DATA_TYPE => {
+                    cbor_expect_type(d, ctx, Type::U32)?;
                     obj.data_type =
                         DataType::from_u32(d.u32()?).map_err(minicbor::decode::Error::message)?;
                 }
                 DERIVATION_PATHS => {
                     cbor_array(d, &mut obj.derivation_paths, |_key, obj, d| {
-                        let tag = d.tag()?;
-                        if !tag.eq(&Tag::Unassigned(
+                        cbor_tag(
+                            d,
+                            ctx,
                             CryptoKeyPath::get_registry_type().get_tag(),
-                        )) {
-                            return Result::Err(minicbor::decode::Error::message(
-                                "CryptoKeyPath tag is invalid",
-                            ));
-                        }
+                            "CryptoKeyPath tag is invalid",
+                        )?;
                         obj.push(CryptoKeyPath::decode(d, ctx)?);
                         Ok(())
                     })?;
@@ -179,12 +244,108 @@ impl<'b, C> minicbor::Decode<'b, C> for BtcSignRequest {
                     })?;
                 }
                 ORIGIN => {
-                    obj.origin = Some(d.str()?.to_string());
+                    obj.origin = Some(cbor_origin(d)?);
+                }
+                NETWORK => {
+                    cbor_expect_type(d, ctx, Type::U32)?;
+                    obj.network = Some(
+                        BtcNetwork::from_u32(d.u32()?).map_err(minicbor::decode::Error::message)?,
+                    );
+                }
+                _ => {
+                    cbor_unknown_field(d, key, &mut obj.unknown_fields)?;
                 }
-                _ => {}
             }
             Ok(())
         })?;
         Ok(result)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    const REQUEST_ID_HEX: &str = "7afd5e09926743fba02e08c4a09417ec";
+
+    fn sign_request(network: BtcNetwork, path: &str, address: &str) -> BtcSignRequest {
+        let path =
+            CryptoKeyPath::from_path(path.to_string(), Some([0xf2, 0x3f, 0x9f, 0xd2])).unwrap();
+        BtcSignRequest::new(
+            hex::decode(REQUEST_ID_HEX).unwrap(),
+            b"Hello crypto world!".to_vec(),
+            DataType::Message,
+            vec![path],
+            Some(vec![address.to_string()]),
+            None,
+            Some(network),
+        )
+    }
+
+    #[test]
+    fn test_mainnet() {
+        let request = sign_request(
+            BtcNetwork::Mainnet,
+            "m/44'/0'/0'/0/0",
+            "1X5vtf4FeK8e8nvhVuvBg7Khkez7Sp3bd",
+        );
+        let bytes: Vec<u8> = request.clone().try_into().unwrap();
+        assert_eq!(
+            "a601d825507afd5e09926743fba02e08c4a09417ec025348656c6c6f2063727970746f20776f726c642103010481d90130a2018a182cf500f500f500f400f4021af23f9fd2058178213158357674663446654b3865386e76685675764267374b686b657a375370336264070000",
+            hex::encode(&bytes)
+        );
+        let decoded = BtcSignRequest::try_from(bytes).unwrap();
+        assert_eq!(Some(BtcNetwork::Mainnet), decoded.get_network());
+        assert!(request.validate_schema().is_ok());
+    }
+
+    #[test]
+    fn test_testnet() {
+        let request = sign_request(
+            BtcNetwork::Testnet,
+            "m/84'/1'/0'/0/0",
+            "tb1qw508d6qejxtdg4y5r3zarvary0c5xw7kxpjzsx",
+        );
+        let bytes: Vec<u8> = request.clone().try_into().unwrap();
+        let decoded = BtcSignRequest::try_from(bytes).unwrap();
+        assert_eq!(Some(BtcNetwork::Testnet), decoded.get_network());
+        assert!(request.validate_schema().is_ok());
+
+        // a mainnet address in a testnet request is rejected
+        let request = sign_request(
+            BtcNetwork::Testnet,
+            "m/44'/0'/0'/0/0",
+            "1X5vtf4FeK8e8nvhVuvBg7Khkez7Sp3bd",
+        );
+        assert_eq!(
+            Err(URError::ValidationError(
+                "address 1X5vtf4FeK8e8nvhVuvBg7Khkez7Sp3bd does not belong to Testnet".to_string()
+            )),
+            request.validate_schema()
+        );
+    }
+
+    #[test]
+    fn test_decode_unexpected_type() {
+        // data_type is the float 1.0
+        let bytes = hex::decode("a202410003fa3f800000").unwrap();
+        assert_eq!(
+            Err(URError::UnexpectedType {
+                expected: "u32".to_string(),
+                got: "f32".to_string(),
+            }),
+            BtcSignRequest::try_from(bytes).map(|_| ())
+        );
+
+        // network is the text string "1"
+        let bytes = hex::decode("a2024100076131").unwrap();
+        assert_eq!(
+            Err(URError::UnexpectedType {
+                expected: "u32".to_string(),
+                got: "string".to_string(),
+            }),
+            BtcSignRequest::try_from(bytes).map(|_| ())
+        );
+    }
+}
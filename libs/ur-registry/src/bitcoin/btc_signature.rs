@@ -1,5 +1,7 @@
-use crate::cbor::cbor_map;
+use crate::cbor::{cbor_bytes, cbor_map, cbor_tag, cbor_unknown_field, cbor_unknown_fields};
+use crate::decode::DecodeContext;
 use crate::impl_template_struct;
+use crate::impl_validate_signature;
 use crate::registry_types::{RegistryType, BTC_SIGNATURE, UUID};
 use crate::traits::RegistryItem;
 use crate::types::Bytes;
@@ -10,11 +12,16 @@ const REQUEST_ID: u8 = 1;
 const SIGNATURE: u8 = 2;
 const PUBLIC_KEY: u8 = 3;
 
-impl_template_struct!(BtcSignature {
-    request_id: Bytes,
-    signature: Bytes,
-    public_key: Bytes
-});
+impl_template_struct!(
+    BtcSignature {
+        request_id: Bytes,
+        signature: Bytes,
+        public_key: Bytes
+    },
+    unknown_fields
+);
+
+impl_validate_signature!(BtcSignature, required);
 
 impl RegistryItem for BtcSignature {
     fn get_registry_type() -> RegistryType<'static> {
@@ -28,7 +35,7 @@ impl<C> minicbor::Encode<C> for BtcSignature {
         e: &mut minicbor::Encoder<W>,
         _ctx: &mut C,
     ) -> Result<(), minicbor::encode::Error<W::Error>> {
-        e.map(3)?;
+        e.map(3 + self.unknown_fields.len() as u64)?;
         e.int(
             Int::try_from(REQUEST_ID)
                 .map_err(|e| minicbor::encode::Error::message(e.to_string()))?,
@@ -45,15 +52,13 @@ impl<C> minicbor::Encode<C> for BtcSignature {
                 .map_err(|e| minicbor::encode::Error::message(e.to_string()))?,
         )?
         .bytes(&self.get_public_key())?;
+        cbor_unknown_fields(e, &self.unknown_fields)?;
         Ok(())
     }
 }
 
-impl<'b, C> minicbor::Decode<'b, C> for BtcSignature {
-    fn decode(
-        d: &mut minicbor::Decoder<'b>,
-        _ctx: &mut C,
-    ) -> Result<Self, minicbor::decode::Error> {
+impl<'b, C: DecodeContext> minicbor::Decode<'b, C> for BtcSignature {
+    fn decode(d: &mut minicbor::Decoder<'b>, ctx: &mut C) -> Result<Self, minicbor::decode::Error> {
         let mut result = BtcSignature::default();
 
         cbor_map(d, &mut result, |key, obj, d| {
@@ -61,21 +66,18 @@ impl<'b, C> minicbor::Decode<'b, C> for BtcSignature {
                 u8::try_from(key).map_err(|e| minicbor::decode::Error::message(e.to_string()))?;
             match key {
                 REQUEST_ID => {
-                    let tag = d.tag()?;
-                    if !tag.eq(&Tag::Unassigned(UUID.get_tag())) {
-                        return Result::Err(minicbor::decode::Error::message(
-                            "UUID tag is invalid",
-                        ));
-                    }
-                    obj.request_id = d.bytes()?.to_vec();
+                    cbor_tag(d, ctx, UUID.get_tag(), "UUID tag is invalid")?;
+                    obj.request_id = cbor_bytes(d)?;
                 }
                 SIGNATURE => {
-                    obj.signature = d.bytes()?.to_vec();
+                    obj.signature = cbor_bytes(d)?;
                 }
                 PUBLIC_KEY => {
-                    obj.public_key = d.bytes()?.to_vec();
+                    obj.public_key = cbor_bytes(d)?;
+                }
+                _ => {
+                    cbor_unknown_field(d, key, &mut obj.unknown_fields)?;
                 }
-                _ => {}
             }
             Ok(())
         })?;
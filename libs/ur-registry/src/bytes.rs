@@ -1,3 +1,4 @@
+use crate::cbor::cbor_bytes;
 use crate::error::{URError, URResult};
 use crate::registry_types::RegistryType;
 use crate::registry_types::BYTES as BYTES_TYPE;
@@ -45,7 +46,7 @@ impl<C> minicbor::Encode<C> for Bytes {
 
 impl<'b, C> minicbor::Decode<'b, C> for Bytes {
     fn decode(d: &mut Decoder<'b>, _ctx: &mut C) -> Result<Self, minicbor::decode::Error> {
-        Ok(Self(d.bytes()?.to_vec()))
+        Ok(Self(cbor_bytes(d)?))
     }
 }
 
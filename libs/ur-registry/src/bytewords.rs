@@ -0,0 +1,81 @@
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+use crate::error::{URError, URResult};
+
+// the codec of the `ur` crate, which `ur::encode` and `ur::decode` already use,
+// so that the bytewords written here match those of a `ur:` string
+pub use ur::bytewords::Style;
+
+/// Encodes `data` followed by its CRC-32 checksum as bytewords.
+pub fn encode(data: &[u8], style: Style) -> String {
+    ur::bytewords::encode(data, style)
+}
+
+/// Decodes bytewords written by [`encode`], checking and removing the trailing checksum.
+/// A checksum that doesn't match, usually from a misread QR code, returns
+/// [`URError::ChecksumMismatch`] so that scanners can retry. Upper case input, as read
+/// from alphanumeric QR codes, is accepted.
+pub fn decode(s: &str, style: Style) -> URResult<Vec<u8>> {
+    ur::bytewords::decode(&s.to_ascii_lowercase(), style).map_err(|e| match e {
+        ur::bytewords::Error::InvalidChecksum => URError::ChecksumMismatch,
+        e => URError::UrDecodeError(e.to_string()),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // the test vector of the bytewords spec
+    const DATA: &str = "d9012ca20150c7098580125e2ab0981253468b2dbc5202d8641947da";
+    const STANDARD: &str = "tuna acid draw oboe acid good slot axis limp lava brag holy door puff monk brag guru frog luau drop roof grim also trip idle chef fuel twin tied draw grim ramp";
+    const MINIMAL: &str = "taaddwoeadgdstaslplabghydrpfmkbggufgludprfgmaotpiecffltntddwgmrp";
+
+    #[test]
+    fn test_encode() {
+        let data = hex::decode(DATA).unwrap();
+        assert_eq!(STANDARD, encode(&data, Style::Standard));
+        assert_eq!(STANDARD.replace(' ', "-"), encode(&data, Style::Uri));
+        assert_eq!(MINIMAL, encode(&data, Style::Minimal));
+    }
+
+    #[test]
+    fn test_decode() {
+        let data = hex::decode(DATA).unwrap();
+        assert_eq!(data, decode(STANDARD, Style::Standard).unwrap());
+        assert_eq!(
+            data,
+            decode(&STANDARD.replace(' ', "-"), Style::Uri).unwrap()
+        );
+        assert_eq!(data, decode(MINIMAL, Style::Minimal).unwrap());
+        assert_eq!(
+            data,
+            decode(&MINIMAL.to_uppercase(), Style::Minimal).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_decode_error() {
+        assert!(matches!(
+            decode("able acid quux", Style::Standard),
+            Err(URError::UrDecodeError(_))
+        ));
+        assert!(matches!(
+            decode("tad", Style::Minimal),
+            Err(URError::UrDecodeError(_))
+        ));
+        assert!(decode("", Style::Standard).is_err());
+        assert!(decode("t\u{e5}ad", Style::Minimal).is_err());
+    }
+
+    #[test]
+    fn test_decode_checksum_mismatch() {
+        // the last word of the checksum changed from `ramp` to `able`
+        let corrupted = STANDARD.replace("grim ramp", "grim able");
+        assert_eq!(
+            Err(URError::ChecksumMismatch),
+            decode(&corrupted, Style::Standard)
+        );
+    }
+}
@@ -1,5 +1,7 @@
-use crate::cbor::cbor_map;
+use crate::cbor::{cbor_bytes, cbor_map, cbor_tag, cbor_unknown_field, cbor_unknown_fields};
+use crate::decode::DecodeContext;
 use crate::impl_template_struct;
+use crate::impl_validate_signature;
 use crate::registry_types::{RegistryType, CARDANO_CATALYST_VOTING_REGISTRATION_SIGNATURE, UUID};
 use crate::traits::{MapSize, RegistryItem};
 use crate::types::Bytes;
@@ -12,10 +14,15 @@ use minicbor::{Decoder, Encoder};
 const REQUEST_ID: u8 = 1;
 const SIGNATURE: u8 = 2;
 
-impl_template_struct!(CardanoCatalystSignature {
-    request_id: Option<Bytes>,
-    signature: Bytes
-});
+impl_template_struct!(
+    CardanoCatalystSignature {
+        request_id: Option<Bytes>,
+        signature: Bytes
+    },
+    unknown_fields
+);
+
+impl_validate_signature!(CardanoCatalystSignature);
 
 impl RegistryItem for CardanoCatalystSignature {
     fn get_registry_type() -> RegistryType<'static> {
@@ -29,7 +36,7 @@ impl MapSize for CardanoCatalystSignature {
         if self.request_id.is_some() {
             size += 1;
         }
-        size
+        size + self.unknown_fields.len() as u64
     }
 }
 
@@ -45,25 +52,28 @@ impl<C> minicbor::Encode<C> for CardanoCatalystSignature {
 
         e.int(Int::from(SIGNATURE))?.bytes(&self.signature)?;
 
+        cbor_unknown_fields(e, &self.unknown_fields)?;
         Ok(())
     }
 }
 
-impl<'b, C> minicbor::Decode<'b, C> for CardanoCatalystSignature {
-    fn decode(d: &mut Decoder<'b>, _ctx: &mut C) -> Result<Self, minicbor::decode::Error> {
+impl<'b, C: DecodeContext> minicbor::Decode<'b, C> for CardanoCatalystSignature {
+    fn decode(d: &mut Decoder<'b>, ctx: &mut C) -> Result<Self, minicbor::decode::Error> {
         let mut cardano_catalyst_signature = CardanoCatalystSignature::default();
         cbor_map(d, &mut cardano_catalyst_signature, |key, obj, d| {
             let key =
                 u8::try_from(key).map_err(|e| minicbor::decode::Error::message(e.to_string()))?;
             match key {
                 REQUEST_ID => {
-                    d.tag()?;
-                    obj.set_request_id(Some(d.bytes()?.to_vec()));
+                    cbor_tag(d, ctx, UUID.get_tag(), "UUID tag is invalid")?;
+                    obj.set_request_id(Some(cbor_bytes(d)?));
                 }
                 SIGNATURE => {
-                    obj.set_signature(d.bytes()?.to_vec());
+                    obj.set_signature(cbor_bytes(d)?);
+                }
+                _ => {
+                    cbor_unknown_field(d, key, &mut obj.unknown_fields)?;
                 }
-                _ => {}
             }
             Ok(())
         })?;
@@ -1,13 +1,20 @@
 use crate::cardano::cardano_delegation::CardanoDelegation;
-use crate::cbor::{cbor_array, cbor_map};
+use crate::cardano::CARDANO_COIN_TYPE;
+use crate::cbor::{
+    cbor_array, cbor_bytes, cbor_map, cbor_origin, cbor_tag, cbor_unknown_field,
+    cbor_unknown_fields,
+};
 use crate::crypto_key_path::CryptoKeyPath;
+use crate::decode::DecodeContext;
 use crate::error::{URError, URResult};
 use crate::impl_template_struct;
+use crate::impl_with_new_request_id;
 use crate::registry_types::{
-    RegistryType, CARDANO_CATALYST_VOTING_REGISTRATION, CRYPTO_KEYPATH, UUID,
+    RegistryType, CARDANO_CATALYST_VOTING_REGISTRATION, CARDANO_DELEGSTION, CRYPTO_KEYPATH, UUID,
 };
-use crate::traits::{From as FromCbor, MapSize, RegistryItem, To};
+use crate::traits::{From as FromCbor, MapSize, RegistryItem, To, Validate};
 use crate::types::Bytes;
+use crate::validate::{validate_path, validate_request_id, validate_sign_data};
 use alloc::format;
 use alloc::string::{String, ToString};
 use alloc::vec::Vec;
@@ -25,17 +32,22 @@ const DERIVATION_PATH: u8 = 7;
 const ORIGIN: u8 = 8;
 const SIGN_TYPE: u8 = 9;
 
-impl_template_struct!(CardanoCatalystVotingRegistrationRequest {
-    request_id: Option<Bytes>,
-    delegations: Vec<CardanoDelegation>,
-    stake_pub: Bytes,
-    payment_address: Bytes,
-    nonce: u64,
-    voting_purpose: u8,
-    derivation_path: CryptoKeyPath,
-    origin: Option<String>,
-    sign_type: u8
-});
+impl_template_struct!(
+    CardanoCatalystVotingRegistrationRequest {
+        request_id: Option<Bytes>,
+        delegations: Vec<CardanoDelegation>,
+        stake_pub: Bytes,
+        payment_address: Bytes,
+        nonce: u64,
+        voting_purpose: u8,
+        derivation_path: CryptoKeyPath,
+        origin: Option<String>,
+        sign_type: u8
+    },
+    unknown_fields
+);
+
+impl_with_new_request_id!(CardanoCatalystVotingRegistrationRequest);
 
 impl MapSize for CardanoCatalystVotingRegistrationRequest {
     fn map_size(&self) -> u64 {
@@ -46,7 +58,7 @@ impl MapSize for CardanoCatalystVotingRegistrationRequest {
         if self.origin.is_some() {
             size += 1;
         }
-        size
+        size + self.unknown_fields.len() as u64
     }
 }
 
@@ -56,6 +68,20 @@ impl RegistryItem for CardanoCatalystVotingRegistrationRequest {
     }
 }
 
+impl Validate for CardanoCatalystVotingRegistrationRequest {
+    fn validate_schema(&self) -> URResult<()> {
+        validate_request_id(&self.request_id)?;
+        validate_sign_data("stake_pub", &self.stake_pub)?;
+        validate_sign_data("payment_address", &self.payment_address)?;
+        validate_path(
+            "derivation_path",
+            &self.derivation_path,
+            Some(CARDANO_COIN_TYPE),
+        )?;
+        Ok(())
+    }
+}
+
 impl<C> minicbor::Encode<C> for CardanoCatalystVotingRegistrationRequest {
     fn encode<W: Write>(
         &self,
@@ -72,6 +98,7 @@ impl<C> minicbor::Encode<C> for CardanoCatalystVotingRegistrationRequest {
         e.int(Int::from(DELEGATIONS))?
             .array(self.delegations.len() as u64)?;
         for delegation in &self.delegations {
+            e.tag(Tag::Unassigned(CARDANO_DELEGSTION.get_tag()))?;
             delegation.encode(e, _ctx)?;
         }
 
@@ -92,12 +119,13 @@ impl<C> minicbor::Encode<C> for CardanoCatalystVotingRegistrationRequest {
         }
 
         e.int(Int::from(SIGN_TYPE))?.u8(self.sign_type)?;
+        cbor_unknown_fields(e, &self.unknown_fields)?;
         Ok(())
     }
 }
 
-impl<'b, C> minicbor::Decode<'b, C> for CardanoCatalystVotingRegistrationRequest {
-    fn decode(d: &mut Decoder<'b>, _ctx: &mut C) -> Result<Self, minicbor::decode::Error> {
+impl<'b, C: DecodeContext> minicbor::Decode<'b, C> for CardanoCatalystVotingRegistrationRequest {
+    fn decode(d: &mut Decoder<'b>, ctx: &mut C) -> Result<Self, minicbor::decode::Error> {
         let mut result: CardanoCatalystVotingRegistrationRequest =
             CardanoCatalystVotingRegistrationRequest::default();
         cbor_map(d, &mut result, |key, obj, d: &mut Decoder| {
@@ -105,22 +133,27 @@ impl<'b, C> minicbor::Decode<'b, C> for CardanoCatalystVotingRegistrationRequest
                 u8::try_from(key).map_err(|e| minicbor::decode::Error::message(e.to_string()))?;
             match key {
                 REQUEST_ID => {
-                    d.tag()?;
-                    obj.set_request_id(Some(d.bytes()?.to_vec()));
+                    cbor_tag(d, ctx, UUID.get_tag(), "UUID tag is invalid")?;
+                    obj.set_request_id(Some(cbor_bytes(d)?));
                 }
                 DELEGATIONS => {
                     cbor_array(d, &mut obj.delegations, |_index, array, d| {
-                        d.tag()?;
-                        let item = CardanoDelegation::decode(d, _ctx)?;
+                        cbor_tag(
+                            d,
+                            ctx,
+                            CardanoDelegation::get_registry_type().get_tag(),
+                            "CardanoDelegation tag is invalid",
+                        )?;
+                        let item = CardanoDelegation::decode(d, ctx)?;
                         array.push(item);
                         Ok(())
                     })?;
                 }
                 STAKE_PUB => {
-                    obj.set_stake_pub(d.bytes()?.to_vec());
+                    obj.set_stake_pub(cbor_bytes(d)?);
                 }
                 PAYMENT_ADDRESS => {
-                    obj.set_payment_address(d.bytes()?.to_vec());
+                    obj.set_payment_address(cbor_bytes(d)?);
                 }
                 NONCE => {
                     obj.nonce = d.u64()?;
@@ -129,13 +162,23 @@ impl<'b, C> minicbor::Decode<'b, C> for CardanoCatalystVotingRegistrationRequest
                     obj.voting_purpose = d.u8()?;
                 }
                 DERIVATION_PATH => {
-                    d.tag()?;
-                    obj.set_derivation_path(CryptoKeyPath::decode(d, _ctx)?);
+                    cbor_tag(
+                        d,
+                        ctx,
+                        CryptoKeyPath::get_registry_type().get_tag(),
+                        "CryptoKeyPath tag is invalid",
+                    )?;
+                    obj.set_derivation_path(CryptoKeyPath::decode(d, ctx)?);
                 }
                 ORIGIN => {
-                    obj.origin = Some(d.str()?.to_string());
+                    obj.origin = Some(cbor_origin(d)?);
+                }
+                SIGN_TYPE => {
+                    obj.sign_type = d.u8()?;
+                }
+                _ => {
+                    cbor_unknown_field(d, key, &mut obj.unknown_fields)?;
                 }
-                _ => {}
             }
             Ok(())
         })?;
@@ -187,4 +230,17 @@ mod tests {
         assert_eq!(request.nonce, 100);
         assert_eq!(request.voting_purpose, 0);
     }
+
+    #[test]
+    fn test_validate_schema() {
+        let cbor = hex::decode("a801d825509b1deb4d3b7d4bad9bdd2b0d7b3dcb6d0281d908a1a2015820a6a3c0447aeb9cc54cf6422ba32b294e5e1c3ef6d782f2acff4a70694c4d16630201035820ca0e65d9bb8d0dca5e88adc5e1c644cc7d62e5a139350330281ed7e3a6938d2c0458390069fa1bd9338574702283d8fb71f8cce1831c3ea4854563f5e4043aea33a4f1f468454744b2ff3644b2ab79d48e76a3187f902fe8a1bcfaad051864060007d90130a2018a19073cf5190717f500f502f400f4021a52744703086e63617264616e6f2d77616c6c6574").unwrap();
+        let mut request = CardanoCatalystVotingRegistrationRequest::from_cbor(cbor).unwrap();
+        assert!(request.validate_schema().is_ok());
+
+        request.set_stake_pub(vec![]);
+        assert_eq!(
+            Err(URError::ValidationError("stake_pub is empty".to_string())),
+            request.validate_schema()
+        );
+    }
 }
@@ -1,5 +1,6 @@
-use crate::cbor::cbor_map;
+use crate::cbor::{cbor_bytes, cbor_map, cbor_tag};
 use crate::crypto_key_path::CryptoKeyPath;
+use crate::decode::DecodeContext;
 use crate::error::{URError, URResult};
 use crate::registry_types::{RegistryType, CARDANO_CERT_KEY, CRYPTO_KEYPATH};
 use crate::traits::{From as FromCbor, MapSize, RegistryItem, To};
@@ -48,19 +49,24 @@ impl<C> minicbor::Encode<C> for CardanoCertKey {
     }
 }
 
-impl<'b, C> minicbor::Decode<'b, C> for CardanoCertKey {
-    fn decode(d: &mut Decoder<'b>, _ctx: &mut C) -> Result<Self, minicbor::decode::Error> {
+impl<'b, C: DecodeContext> minicbor::Decode<'b, C> for CardanoCertKey {
+    fn decode(d: &mut Decoder<'b>, ctx: &mut C) -> Result<Self, minicbor::decode::Error> {
         let mut cardano_cert_key = CardanoCertKey::default();
         cbor_map(d, &mut cardano_cert_key, |key, obj, d| {
             let key =
                 u8::try_from(key).map_err(|e| minicbor::decode::Error::message(e.to_string()))?;
             match key {
                 KEY_HASH => {
-                    obj.set_key_hash(d.bytes()?.to_vec());
+                    obj.set_key_hash(cbor_bytes(d)?);
                 }
                 KEY_PATH => {
-                    d.tag()?;
-                    obj.set_key_path(CryptoKeyPath::decode(d, _ctx)?);
+                    cbor_tag(
+                        d,
+                        ctx,
+                        CryptoKeyPath::get_registry_type().get_tag(),
+                        "CryptoKeyPath tag is invalid",
+                    )?;
+                    obj.set_key_path(CryptoKeyPath::decode(d, ctx)?);
                 }
                 _ => {}
             }
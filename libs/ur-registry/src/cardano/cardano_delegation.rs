@@ -1,4 +1,4 @@
-use crate::cbor::cbor_map;
+use crate::cbor::{cbor_bytes, cbor_map};
 use crate::crypto_key_path::CryptoKeyPath;
 use crate::error::{URError, URResult};
 use crate::impl_template_struct;
@@ -50,7 +50,7 @@ impl<'b, C> minicbor::Decode<'b, C> for CardanoDelegation {
                 u8::try_from(key).map_err(|e| minicbor::decode::Error::message(e.to_string()))?;
             match key {
                 PUBKEY => {
-                    obj.set_pub_key(d.bytes()?.to_vec());
+                    obj.set_pub_key(cbor_bytes(d)?);
                 }
                 WEIDTH => {
                     obj.weidth = d.u8()?;
@@ -1,10 +1,16 @@
-use crate::cbor::cbor_map;
+use crate::cardano::CARDANO_COIN_TYPE;
+use crate::cbor::{
+    cbor_bytes, cbor_map, cbor_origin, cbor_tag, cbor_unknown_field, cbor_unknown_fields,
+};
 use crate::crypto_key_path::CryptoKeyPath;
+use crate::decode::DecodeContext;
 use crate::error::{URError, URResult};
 use crate::impl_template_struct;
+use crate::impl_with_new_request_id;
 use crate::registry_types::{RegistryType, CARDANO_SIGN_CIP8_DATA_REQUEST, CRYPTO_KEYPATH, UUID};
-use crate::traits::{From as FromCbor, MapSize, RegistryItem, To};
+use crate::traits::{From as FromCbor, MapSize, RegistryItem, To, Validate};
 use crate::types::Bytes;
+use crate::validate::{validate_path, validate_request_id, validate_sign_data};
 use alloc::format;
 use alloc::string::{String, ToString};
 use alloc::vec::Vec;
@@ -49,20 +55,25 @@ impl From<&str> for Cip8AddressType {
     }
 }
 
-impl_template_struct!(CardanoSignCip8DataRequest {
-    request_id: Option<Bytes>,
-    sign_data: Bytes,
-    derivation_path: CryptoKeyPath,
-    origin: Option<String>,
-    xpub: Bytes,
-    hash_payload: bool,
-    address_bench32: Option<String>,
-    address_type: Cip8AddressType
-});
+impl_template_struct!(
+    CardanoSignCip8DataRequest {
+        request_id: Option<Bytes>,
+        sign_data: Bytes,
+        derivation_path: CryptoKeyPath,
+        origin: Option<String>,
+        xpub: Bytes,
+        hash_payload: bool,
+        address_bench32: Option<String>,
+        address_type: Cip8AddressType
+    },
+    unknown_fields
+);
+
+impl_with_new_request_id!(CardanoSignCip8DataRequest);
 
 impl MapSize for CardanoSignCip8DataRequest {
     fn map_size(&self) -> u64 {
-        let mut size = 4;
+        let mut size = 5;
         if self.request_id.is_some() {
             size += 1;
         }
@@ -72,7 +83,7 @@ impl MapSize for CardanoSignCip8DataRequest {
         if self.address_bench32.is_some() {
             size += 1;
         }
-        size
+        size + self.unknown_fields.len() as u64
     }
 }
 
@@ -82,6 +93,19 @@ impl RegistryItem for CardanoSignCip8DataRequest {
     }
 }
 
+impl Validate for CardanoSignCip8DataRequest {
+    fn validate_schema(&self) -> URResult<()> {
+        validate_request_id(&self.request_id)?;
+        validate_sign_data("sign_data", &self.sign_data)?;
+        validate_path(
+            "derivation_path",
+            &self.derivation_path,
+            Some(CARDANO_COIN_TYPE),
+        )?;
+        Ok(())
+    }
+}
+
 impl<C> minicbor::Encode<C> for CardanoSignCip8DataRequest {
     fn encode<W: Write>(
         &self,
@@ -97,8 +121,10 @@ impl<C> minicbor::Encode<C> for CardanoSignCip8DataRequest {
 
         e.int(Int::from(SIGN_DATA))?.bytes(&self.sign_data)?;
 
-        e.int(Int::from(DERIVATION_PATH))?;
-        e.tag(Tag::Unassigned(CRYPTO_KEYPATH.get_tag()))?;
+        e.int(Int::from(DERIVATION_PATH))?
+            .tag(Tag::Unassigned(CRYPTO_KEYPATH.get_tag()))?;
+        CryptoKeyPath::encode(&self.derivation_path, e, _ctx)?;
+
         e.int(Int::from(XPUB))?.bytes(&self.xpub)?;
         e.int(Int::from(HASH_PAYLOAD))?.bool(self.hash_payload)?;
 
@@ -107,41 +133,45 @@ impl<C> minicbor::Encode<C> for CardanoSignCip8DataRequest {
         }
 
         e.int(Int::from(ADDRESS_TYPE))?
-            .str(&self.address_type.as_str())?;
-
-        CryptoKeyPath::encode(&self.derivation_path, e, _ctx)?;
+            .str(self.address_type.as_str())?;
 
         if let Some(origin) = &self.origin {
             e.int(Int::from(ORIGIN))?.str(origin)?;
         }
 
+        cbor_unknown_fields(e, &self.unknown_fields)?;
         Ok(())
     }
 }
 
-impl<'b, C> minicbor::Decode<'b, C> for CardanoSignCip8DataRequest {
-    fn decode(d: &mut Decoder<'b>, _ctx: &mut C) -> Result<Self, minicbor::decode::Error> {
+impl<'b, C: DecodeContext> minicbor::Decode<'b, C> for CardanoSignCip8DataRequest {
+    fn decode(d: &mut Decoder<'b>, ctx: &mut C) -> Result<Self, minicbor::decode::Error> {
         let mut result: CardanoSignCip8DataRequest = CardanoSignCip8DataRequest::default();
         cbor_map(d, &mut result, |key, obj, d: &mut Decoder| {
             let key =
                 u8::try_from(key).map_err(|e| minicbor::decode::Error::message(e.to_string()))?;
             match key {
                 REQUEST_ID => {
-                    d.tag()?;
-                    obj.set_request_id(Some(d.bytes()?.to_vec()));
+                    cbor_tag(d, ctx, UUID.get_tag(), "UUID tag is invalid")?;
+                    obj.set_request_id(Some(cbor_bytes(d)?));
                 }
                 SIGN_DATA => {
-                    obj.set_sign_data(d.bytes()?.to_vec());
+                    obj.set_sign_data(cbor_bytes(d)?);
                 }
                 DERIVATION_PATH => {
-                    d.tag()?;
-                    obj.derivation_path = CryptoKeyPath::decode(d, _ctx)?;
+                    cbor_tag(
+                        d,
+                        ctx,
+                        CryptoKeyPath::get_registry_type().get_tag(),
+                        "CryptoKeyPath tag is invalid",
+                    )?;
+                    obj.derivation_path = CryptoKeyPath::decode(d, ctx)?;
                 }
                 XPUB => {
-                    obj.set_xpub(d.bytes()?.to_vec());
+                    obj.set_xpub(cbor_bytes(d)?);
                 }
                 ORIGIN => {
-                    obj.origin = Some(d.str()?.to_string());
+                    obj.origin = Some(cbor_origin(d)?);
                 }
                 HASH_PAYLOAD => {
                     obj.hash_payload = d.bool()?;
@@ -152,7 +182,9 @@ impl<'b, C> minicbor::Decode<'b, C> for CardanoSignCip8DataRequest {
                 ADDRESS_TYPE => {
                     obj.address_type = Cip8AddressType::from(d.str()?);
                 }
-                _ => {}
+                _ => {
+                    cbor_unknown_field(d, key, &mut obj.unknown_fields)?;
+                }
             }
             Ok(())
         })?;
@@ -171,3 +203,80 @@ impl FromCbor<CardanoSignCip8DataRequest> for CardanoSignCip8DataRequest {
         minicbor::decode(&bytes).map_err(|e| URError::CborDecodeError(e.to_string()))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cbor::with_unknown_key;
+    use crate::types::UnknownFields;
+    use alloc::vec;
+
+    #[test]
+    fn test_round_trip() {
+        let path = CryptoKeyPath::from_path("m/1852'/1815'/0'/0/0".to_string(), None).unwrap();
+        let request = CardanoSignCip8DataRequest::new(
+            Some(hex::decode("9b1deb4d3b7d4bad9bdd2b0d7b3dcb6d").unwrap()),
+            hex::decode("48656c6c6f").unwrap(),
+            path,
+            None,
+            vec![0x11; 64],
+            true,
+            Some("addr1qxyz".to_string()),
+            Cip8AddressType::KeyHash,
+        );
+        let bytes = request.to_bytes().unwrap();
+        let decoded = CardanoSignCip8DataRequest::from_cbor(bytes.clone()).unwrap();
+        assert_eq!(
+            Some("1852'/1815'/0'/0/0".to_string()),
+            decoded.get_derivation_path().get_path()
+        );
+        assert_eq!(vec![0x11; 64], decoded.get_xpub());
+        assert!(decoded.get_hash_payload());
+        assert_eq!("KEY_HASH", decoded.get_address_type().as_str());
+        assert_eq!(bytes, decoded.to_bytes().unwrap());
+    }
+
+    #[test]
+    fn test_unknown_fields_round_trip() {
+        let path = CryptoKeyPath::from_path("m/1852'/1815'/0'/0/0".to_string(), None).unwrap();
+        let request = CardanoSignCip8DataRequest::new(
+            None,
+            hex::decode("48656c6c6f").unwrap(),
+            path,
+            None,
+            vec![0x11; 64],
+            false,
+            None,
+            Cip8AddressType::Address,
+        );
+        let bytes = with_unknown_key(request.to_bytes().unwrap());
+        let decoded = CardanoSignCip8DataRequest::from_cbor(bytes.clone()).unwrap();
+        assert_eq!(
+            UnknownFields::from([(15, vec![0x82, 0x01, 0x02])]),
+            decoded.get_unknown_fields()
+        );
+        assert_eq!(bytes, decoded.to_bytes().unwrap());
+    }
+
+    #[test]
+    fn test_validate_schema() {
+        let path = CryptoKeyPath::from_path("m/1852'/1815'/0'/0/0".to_string(), None).unwrap();
+        let mut request = CardanoSignCip8DataRequest::new(
+            Some(hex::decode("9b1deb4d3b7d4bad9bdd2b0d7b3dcb6d").unwrap()),
+            hex::decode("48656c6c6f").unwrap(),
+            path,
+            None,
+            vec![0x11; 64],
+            false,
+            None,
+            Cip8AddressType::Address,
+        );
+        assert!(request.validate_schema().is_ok());
+
+        request.set_sign_data(vec![]);
+        assert_eq!(
+            Err(URError::ValidationError("sign_data is empty".to_string())),
+            request.validate_schema()
+        );
+    }
+}
@@ -1,14 +1,22 @@
 use crate::cardano::cardano_cert_key::CardanoCertKey;
 use crate::cardano::cardano_utxo::CardanoUTXO;
-use crate::cbor::{cbor_array, cbor_map};
+use crate::cardano::CARDANO_COIN_TYPE;
+use crate::cbor::{
+    cbor_array, cbor_bytes, cbor_map, cbor_origin, cbor_tag, cbor_unknown_field,
+    cbor_unknown_fields,
+};
+use crate::crypto_key_path::CryptoKeyPath;
+use crate::decode::DecodeContext;
 use crate::error::{URError, URResult};
 
 use crate::impl_template_struct;
+use crate::impl_with_new_request_id;
 use crate::registry_types::{
-    RegistryType, CARDANO_CERT_KEY, CARDANO_SIGN_REQUEST, CARDANO_UTXO, UUID,
+    RegistryType, CARDANO_CERT_KEY, CARDANO_SIGN_REQUEST, CARDANO_UTXO, CRYPTO_KEYPATH, UUID,
 };
-use crate::traits::{MapSize, RegistryItem, To};
+use crate::traits::{MapSize, RegistryItem, To, Validate};
 use crate::types::Bytes;
+use crate::validate::{validate_path, validate_request_id, validate_sign_data};
 use alloc::string::{String, ToString};
 use alloc::vec::Vec;
 use minicbor::data::{Int, Tag};
@@ -20,8 +28,28 @@ const SIGN_DATA: u8 = 2;
 const UTXOS: u8 = 3;
 const CERT_KEYS: u8 = 4;
 const ORIGIN: u8 = 5;
+const EXTRA_SIGNERS: u8 = 6;
+
+impl_template_struct!(CardanoSignRequest {request_id: Option<Bytes>, sign_data: Bytes, utxos: Vec<CardanoUTXO>, cert_keys: Vec<CardanoCertKey>, origin: Option<String>, extra_signers: Option<Vec<CryptoKeyPath>>}, unknown_fields);
 
-impl_template_struct!(CardanoSignRequest {request_id: Option<Bytes>, sign_data: Bytes, utxos: Vec<CardanoUTXO>, cert_keys: Vec<CardanoCertKey>, origin: Option<String>});
+impl_with_new_request_id!(CardanoSignRequest);
+
+impl CardanoSignRequest {
+    /// All key paths the device has to sign with: utxo owners, cert keys and
+    /// extra signers, deduplicated and in that order.
+    pub fn get_signing_paths(&self) -> Vec<CryptoKeyPath> {
+        let mut paths: Vec<CryptoKeyPath> = Vec::new();
+        let utxo_paths = self.utxos.iter().map(|utxo| utxo.get_key_path());
+        let cert_key_paths = self.cert_keys.iter().map(|cert_key| cert_key.get_key_path());
+        let extra_signers = self.extra_signers.clone().unwrap_or_default();
+        for path in utxo_paths.chain(cert_key_paths).chain(extra_signers) {
+            if !paths.contains(&path) {
+                paths.push(path);
+            }
+        }
+        paths
+    }
+}
 
 impl MapSize for CardanoSignRequest {
     fn map_size(&self) -> u64 {
@@ -32,7 +60,10 @@ impl MapSize for CardanoSignRequest {
         if self.origin.is_some() {
             size += 1;
         }
-        size
+        if self.extra_signers.is_some() {
+            size += 1;
+        }
+        size + self.unknown_fields.len() as u64
     }
 }
 
@@ -48,6 +79,17 @@ impl To for CardanoSignRequest {
     }
 }
 
+impl Validate for CardanoSignRequest {
+    fn validate_schema(&self) -> URResult<()> {
+        validate_request_id(&self.request_id)?;
+        validate_sign_data("sign_data", &self.sign_data)?;
+        for path in self.extra_signers.iter().flatten() {
+            validate_path("extra_signers", path, Some(CARDANO_COIN_TYPE))?;
+        }
+        Ok(())
+    }
+}
+
 impl<C> minicbor::Encode<C> for CardanoSignRequest {
     fn encode<W: Write>(&self, e: &mut Encoder<W>, _ctx: &mut C) -> Result<(), Error<W::Error>> {
         e.map(self.map_size())?;
@@ -77,40 +119,76 @@ impl<C> minicbor::Encode<C> for CardanoSignRequest {
             e.int(Int::from(ORIGIN))?.str(origin)?;
         }
 
+        if let Some(extra_signers) = &self.extra_signers {
+            e.int(Int::from(EXTRA_SIGNERS))?
+                .array(extra_signers.len() as u64)?;
+            for path in extra_signers {
+                e.tag(Tag::Unassigned(CRYPTO_KEYPATH.get_tag()))?;
+                path.encode(e, _ctx)?;
+            }
+        }
+
+        cbor_unknown_fields(e, &self.unknown_fields)?;
         Ok(())
     }
 }
 
-impl<'b, C> minicbor::Decode<'b, C> for CardanoSignRequest {
-    fn decode(d: &mut Decoder<'b>, _ctx: &mut C) -> Result<Self, minicbor::decode::Error> {
+impl<'b, C: DecodeContext> minicbor::Decode<'b, C> for CardanoSignRequest {
+    fn decode(d: &mut Decoder<'b>, ctx: &mut C) -> Result<Self, minicbor::decode::Error> {
         let mut cardano_sign_request = CardanoSignRequest::default();
         cbor_map(d, &mut cardano_sign_request, |key, obj, d| {
             let key =
                 u8::try_from(key).map_err(|e| minicbor::decode::Error::message(e.to_string()))?;
             match key {
                 REQUEST_ID => {
-                    d.tag()?;
-                    obj.set_request_id(Some(d.bytes()?.to_vec()));
+                    cbor_tag(d, ctx, UUID.get_tag(), "UUID tag is invalid")?;
+                    obj.set_request_id(Some(cbor_bytes(d)?));
                 }
                 SIGN_DATA => {
-                    obj.set_sign_data(d.bytes()?.to_vec());
+                    obj.set_sign_data(cbor_bytes(d)?);
                 }
                 UTXOS => {
                     cbor_array(d, &mut obj.utxos, |_index, array, d| {
-                        d.tag()?;
-                        array.push(CardanoUTXO::decode(d, _ctx)?);
+                        cbor_tag(
+                            d,
+                            ctx,
+                            CardanoUTXO::get_registry_type().get_tag(),
+                            "CardanoUTXO tag is invalid",
+                        )?;
+                        array.push(CardanoUTXO::decode(d, ctx)?);
                         Ok(())
                     })?;
                 }
                 CERT_KEYS => {
                     cbor_array(d, &mut obj.cert_keys, |_index, array, d| {
-                        d.tag()?;
-                        array.push(CardanoCertKey::decode(d, _ctx)?);
+                        cbor_tag(
+                            d,
+                            ctx,
+                            CardanoCertKey::get_registry_type().get_tag(),
+                            "CardanoCertKey tag is invalid",
+                        )?;
+                        array.push(CardanoCertKey::decode(d, ctx)?);
                         Ok(())
                     })?;
                 }
-                ORIGIN => obj.set_origin(Some(d.str()?.to_string())),
-                _ => {}
+                ORIGIN => obj.set_origin(Some(cbor_origin(d)?)),
+                EXTRA_SIGNERS => {
+                    let mut extra_signers: Vec<CryptoKeyPath> = Vec::new();
+                    cbor_array(d, &mut extra_signers, |_index, array, d| {
+                        cbor_tag(
+                            d,
+                            ctx,
+                            CRYPTO_KEYPATH.get_tag(),
+                            "CryptoKeyPath tag is invalid",
+                        )?;
+                        array.push(CryptoKeyPath::decode(d, ctx)?);
+                        Ok(())
+                    })?;
+                    obj.set_extra_signers(Some(extra_signers));
+                }
+                _ => {
+                    cbor_unknown_field(d, key, &mut obj.unknown_fields)?;
+                }
             }
             Ok(())
         })?;
@@ -197,9 +275,76 @@ mod tests {
             utxos,
             cert_keys,
             Some("cardano-wallet".to_string()),
+            None,
         );
 
         let sign_request: Vec<u8> = cardano_sign_request.try_into().unwrap();
         assert_eq!("a501d825509b1deb4d3b7d4bad9bdd2b0d7b3dcb6d0258a184a400828258204e3a6e7fdcb0d0efa17bf79c13aed2b4cb9baf37fb1aa2e39553d5bd720c5c99038258204e3a6e7fdcb0d0efa17bf79c13aed2b4cb9baf37fb1aa2e39553d5bd720c5c99040182a200581d6179df4c75f7616d7d1fd39cbc1a6ea6b40a0d7b89fea62fc0909b6c370119c350a200581d61c9b0c9761fd1dc0404abd55efc895026628b5035ac623c614fbad0310119c35002198ecb0300a0f5f60382d90899a50158204e3a6e7fdcb0d0efa17bf79c13aed2b4cb9baf37fb1aa2e39553d5bd720c5c9902030368313030303030303004d90130a2018a19073cf5190717f500f500f400f4021a73c5da0a0578676164647231717938616337717179307674756c796c37776e746d737863367765783830677663796a79333371666672686d37736839323779737835736674757730646c66743035647a3363377265767066376a7830786e6c636a7a336736396d71346166646876d90899a50158204e3a6e7fdcb0d0efa17bf79c13aed2b4cb9baf37fb1aa2e39553d5bd720c5c9902040368313830323030303004d90130a2018a19073cf5190717f500f500f401f4021a73c5da0a057867616464723171797a383536393367346672386335356d667978686165386a3275303470796478726771723733766d77707833617a763464676b797267796c6a35796c326d306a6c70647065737779797a6a7330766877766e6c367867396637737372786b7a39300481d9089ca201581ce557890352095f1cf6fd2b7d1a28e3c3cb029f48cf34ff890a28d17602d90130a2018a19073cf5190717f500f502f400f4021a73c5da0a056e63617264616e6f2d77616c6c6574", hex::encode(sign_request))
     }
+
+    #[test]
+    fn test_extra_signers_round_trip() {
+        let utxo_path = CryptoKeyPath::from_path(
+            "m/1852'/1815'/0'/0/0".to_string(),
+            Some([0x73, 0xc5, 0xda, 0x0a]),
+        )
+        .unwrap();
+        let stake_path = CryptoKeyPath::from_path(
+            "m/1852'/1815'/0'/2/0".to_string(),
+            Some([0x73, 0xc5, 0xda, 0x0a]),
+        )
+        .unwrap();
+        let utxos = vec![CardanoUTXO::new(
+            hex::decode("4e3a6e7fdcb0d0efa17bf79c13aed2b4cb9baf37fb1aa2e39553d5bd720c5c99").unwrap(),
+            0,
+            "10000000".to_string(),
+            utxo_path.clone(),
+            "addr1qy8ac7qqy0vtulyl7wntmsxc6wex80gvcyjy33qffrhm7sh927ysx5sftuw0dlft05dz3c7revpf7jx0xnlcjz3g69mq4afdhv".to_string(),
+        )];
+        let cardano_sign_request = CardanoSignRequest::new(
+            Some(hex::decode("9b1deb4d3b7d4bad9bdd2b0d7b3dcb6d").unwrap()),
+            hex::decode("84a400818258204e3a6e7fdcb0d0efa17bf79c13aed2b4cb9baf37fb1aa2e39553d5bd720c5c9900").unwrap(),
+            utxos,
+            vec![],
+            Some("cardano-wallet".to_string()),
+            Some(vec![stake_path.clone(), utxo_path.clone()]),
+        );
+
+        let cbor: Vec<u8> = cardano_sign_request.try_into().unwrap();
+        let decoded = CardanoSignRequest::try_from(cbor).unwrap();
+
+        assert_eq!(
+            Some(vec![stake_path.clone(), utxo_path.clone()]),
+            decoded.get_extra_signers()
+        );
+        assert_eq!(vec![utxo_path, stake_path], decoded.get_signing_paths());
+        assert_eq!(Some("cardano-wallet".to_string()), decoded.get_origin());
+    }
+
+    #[test]
+    fn test_validate_schema() {
+        let stake_path =
+            CryptoKeyPath::from_path("m/1852'/1815'/0'/2/0".to_string(), None).unwrap();
+        let mut request = CardanoSignRequest::new(
+            Some(hex::decode("9b1deb4d3b7d4bad9bdd2b0d7b3dcb6d").unwrap()),
+            hex::decode(
+                "84a400818258204e3a6e7fdcb0d0efa17bf79c13aed2b4cb9baf37fb1aa2e39553d5bd720c5c9900",
+            )
+            .unwrap(),
+            vec![],
+            vec![],
+            None,
+            Some(vec![stake_path]),
+        );
+        assert!(request.validate_schema().is_ok());
+
+        let eth_path = CryptoKeyPath::from_path("m/44'/60'/0'/0/0".to_string(), None).unwrap();
+        request.set_extra_signers(Some(vec![eth_path]));
+        assert_eq!(
+            Err(URError::ValidationError(
+                "extra_signers does not match coin type 1815".to_string()
+            )),
+            request.validate_schema()
+        );
+    }
 }
@@ -1,4 +1,4 @@
-use crate::cbor::cbor_array;
+use crate::cbor::{cbor_array, cbor_bytes};
 use crate::crypto_key_path::CryptoKeyPath;
 use crate::error::{URError, URResult};
 use crate::registry_types::{RegistryType, CARDANO_CERT_KEY, CRYPTO_KEYPATH};
@@ -42,10 +42,10 @@ impl<'b, C> minicbor::Decode<'b, C> for CardanoSignStructure {
 
             match _index {
                 KEY_CONTEXT => obj.context = d.str()?.to_string().encode_to_vec(),
-                KEY_PROTECTED_HEADER => obj.protected_header = d.bytes()?.to_vec(),
-                KEY_EXTERNAL_AAD => obj.external_aad = d.bytes()?.to_vec(),
+                KEY_PROTECTED_HEADER => obj.protected_header = cbor_bytes(d)?,
+                KEY_EXTERNAL_AAD => obj.external_aad = cbor_bytes(d)?,
                 KEY_PAYLOAD => {
-                    obj.payload = hex::encode(d.bytes()?);
+                    obj.payload = hex::encode(cbor_bytes(d)?);
                 }
                 _ => {
                     d.skip()?;
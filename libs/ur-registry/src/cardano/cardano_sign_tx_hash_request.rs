@@ -1,10 +1,17 @@
-use crate::cbor::{cbor_array, cbor_map};
+use crate::cardano::CARDANO_COIN_TYPE;
+use crate::cbor::{
+    cbor_array, cbor_bytes, cbor_map, cbor_origin, cbor_paths, cbor_tag, cbor_unknown_field,
+    cbor_unknown_fields,
+};
 use crate::crypto_key_path::CryptoKeyPath;
+use crate::decode::DecodeContext;
 use crate::error::{URError, URResult};
 use crate::impl_template_struct;
+use crate::impl_with_new_request_id;
 use crate::registry_types::{RegistryType, CARDANO_SIGN_TX_HASH_REQUEST, UUID};
-use crate::traits::{MapSize, RegistryItem, To};
+use crate::traits::{MapSize, RegistryItem, To, Validate};
 use crate::types::Bytes;
+use crate::validate::{validate_paths, validate_request_id, validate_sign_data};
 use alloc::string::{String, ToString};
 use alloc::vec::Vec;
 use minicbor::data::{Int, Tag};
@@ -24,9 +31,11 @@ impl_template_struct!(
         paths: Vec<CryptoKeyPath>,
         origin: Option<String>,
         address_list: Vec<String>
-    }
+    }, unknown_fields
 );
 
+impl_with_new_request_id!(CardanoSignTxHashRequest);
+
 impl MapSize for CardanoSignTxHashRequest {
     fn map_size(&self) -> u64 {
         let mut size = 3;
@@ -36,7 +45,7 @@ impl MapSize for CardanoSignTxHashRequest {
         if self.origin.is_some() {
             size += 1;
         }
-        size
+        size + self.unknown_fields.len() as u64
     }
 }
 
@@ -52,6 +61,15 @@ impl To for CardanoSignTxHashRequest {
     }
 }
 
+impl Validate for CardanoSignTxHashRequest {
+    fn validate_schema(&self) -> URResult<()> {
+        validate_request_id(&self.request_id)?;
+        validate_sign_data("tx_hash", self.tx_hash.as_bytes())?;
+        validate_paths("paths", &self.paths, Some(CARDANO_COIN_TYPE))?;
+        Ok(())
+    }
+}
+
 impl<C> minicbor::Encode<C> for CardanoSignTxHashRequest {
     fn encode<W: Write>(&self, e: &mut Encoder<W>, _ctx: &mut C) -> Result<(), Error<W::Error>> {
         e.map(self.map_size())?;
@@ -61,13 +79,8 @@ impl<C> minicbor::Encode<C> for CardanoSignTxHashRequest {
                 .bytes(request_id)?;
         }
         e.int(Int::from(TX_HASH))?.str(&self.tx_hash)?;
-        e.int(Int::from(PATHS))?.array(self.paths.len() as u64)?;
-        for x in &self.paths {
-            e.tag(Tag::Unassigned(
-                CryptoKeyPath::get_registry_type().get_tag(),
-            ))?;
-            x.encode(e, _ctx)?;
-        }
+        e.int(Int::from(PATHS))?;
+        cbor_paths(e, &self.paths, "paths", _ctx)?;
         if let Some(origin) = &self.origin {
             e.int(Int::from(ORIGIN))?.str(origin)?;
         }
@@ -78,33 +91,39 @@ impl<C> minicbor::Encode<C> for CardanoSignTxHashRequest {
                 e.str(x)?;
             }
         }
+        cbor_unknown_fields(e, &self.unknown_fields)?;
         Ok(())
     }
 }
 
-impl<'b, C> minicbor::Decode<'b, C> for CardanoSignTxHashRequest {
-    fn decode(d: &mut Decoder<'b>, _ctx: &mut C) -> Result<Self, minicbor::decode::Error> {
+impl<'b, C: DecodeContext> minicbor::Decode<'b, C> for CardanoSignTxHashRequest {
+    fn decode(d: &mut Decoder<'b>, ctx: &mut C) -> Result<Self, minicbor::decode::Error> {
         let mut cardano_sign_request = CardanoSignTxHashRequest::default();
         cbor_map(d, &mut cardano_sign_request, |key, obj, d| {
             let key =
                 u8::try_from(key).map_err(|e| minicbor::decode::Error::message(e.to_string()))?;
             match key {
                 REQUEST_ID => {
-                    d.tag()?;
-                    obj.set_request_id(Some(d.bytes()?.to_vec()));
+                    cbor_tag(d, ctx, UUID.get_tag(), "UUID tag is invalid")?;
+                    obj.set_request_id(Some(cbor_bytes(d)?));
                 }
                 TX_HASH => {
                     obj.set_tx_hash(d.str()?.to_string());
                 }
                 PATHS => {
                     cbor_array(d, &mut obj.paths, |_index, array, d| {
-                        d.tag()?;
-                        array.push(CryptoKeyPath::decode(d, _ctx)?);
+                        cbor_tag(
+                            d,
+                            ctx,
+                            CryptoKeyPath::get_registry_type().get_tag(),
+                            "CryptoKeyPath tag is invalid",
+                        )?;
+                        array.push(CryptoKeyPath::decode(d, ctx)?);
                         Ok(())
                     })?;
                 }
                 ORIGIN => {
-                    obj.set_origin(Some(d.str()?.to_string()));
+                    obj.set_origin(Some(cbor_origin(d)?));
                 }
                 ADDRESS_LIST => {
                     cbor_array(d, &mut obj.address_list, |_index, array, d| {
@@ -112,7 +131,9 @@ impl<'b, C> minicbor::Decode<'b, C> for CardanoSignTxHashRequest {
                         Ok(())
                     })?;
                 }
-                _ => {}
+                _ => {
+                    cbor_unknown_field(d, key, &mut obj.unknown_fields)?;
+                }
             }
             Ok(())
         })?;
@@ -164,6 +185,7 @@ mod tests {
             paths,
             origin: Some(origin),
             address_list,
+            ..Default::default()
         };
         let expect_result = CardanoSignTxHashRequest::try_from(hex::decode("a501d8255052090a1c29394842a9adba0bc021a58b027840353261316635353936663331333538303330663064396433613264623262313139623866373636333836303731363834643236643064333734333963313434650382d90130a2018a19073cf5190717f500f500f400f4021a1250b6bcd90130a2018a19073cf5190717f500f502f400f4021a1250b6bc0466657465726e6c058278676164647231717938616337717179307674756c796c37776e746d737863367765783830677663796a79333371666672686d37736839323779737835736674757730646c66743035647a3363377265767066376a7830786e6c636a7a336736396d713461666468767867616464723171797a383536393367346672386335356d667978686165386a3275303470796478726771723733766d77707833617a763464676b797267796c6a35796c326d306a6c70647065737779797a6a7330766877766e6c367867396637737372786b7a3930").unwrap()).unwrap();
         assert_eq!(expect_result.request_id, request.request_id);
@@ -172,4 +194,58 @@ mod tests {
         assert_eq!(expect_result.origin, request.origin);
         assert_eq!(expect_result.address_list, request.address_list);
     }
+
+    #[test]
+    fn test_encode_without_paths() {
+        let request = CardanoSignTxHashRequest {
+            request_id: None,
+            tx_hash: "52a1f5596f31358030f0d9d3a2db2b119b8f766386071684d26d0d37439c144e".to_string(),
+            paths: vec![],
+            origin: None,
+            address_list: vec![],
+            ..Default::default()
+        };
+        let result: URResult<Vec<u8>> = request.try_into();
+        assert!(matches!(
+            result,
+            Err(URError::CborEncodeError(message)) if message.contains("paths is invalid")
+        ));
+    }
+
+    #[test]
+    fn test_decode_rejects_long_origin() {
+        let path = CryptoKeyPath::from_path("m/1852'/1815'/0'/0/0".to_string(), None).unwrap();
+        let request = CardanoSignTxHashRequest {
+            request_id: None,
+            tx_hash: "52a1f5596f31358030f0d9d3a2db2b119b8f766386071684d26d0d37439c144e".to_string(),
+            paths: vec![path],
+            origin: Some("a".repeat(crate::cbor::MAX_ORIGIN_LENGTH + 1)),
+            address_list: vec![],
+            ..Default::default()
+        };
+        let result: Vec<u8> = request.try_into().unwrap();
+        assert!(matches!(
+            CardanoSignTxHashRequest::try_from(result),
+            Err(URError::CborDecodeError(message)) if message.contains("origin exceeds 256 bytes")
+        ));
+    }
+
+    #[test]
+    fn test_validate_schema() {
+        let path = CryptoKeyPath::from_path("m/1852'/1815'/0'/0/0".to_string(), None).unwrap();
+        let mut request = CardanoSignTxHashRequest::new(
+            None,
+            "52a1f5596f31358030f0d9d3a2db2b119b8f766386071684d26d0d37439c144e".to_string(),
+            vec![path],
+            None,
+            vec![],
+        );
+        assert!(request.validate_schema().is_ok());
+
+        request.set_paths(vec![]);
+        assert_eq!(
+            Err(URError::ValidationError("paths is empty".to_string())),
+            request.validate_schema()
+        );
+    }
 }
@@ -1,21 +1,78 @@
-use crate::cbor::cbor_map;
+use crate::cbor::{
+    cbor_array, cbor_bytes, cbor_map, cbor_tag, cbor_unknown_field, cbor_unknown_fields,
+};
+use crate::decode::DecodeContext;
+use crate::error::{URError, URResult};
 use crate::impl_template_struct;
+use crate::impl_validate_signature;
 use crate::registry_types::{RegistryType, CARDANO_SIGNATURE, UUID};
 use crate::traits::{MapSize, RegistryItem};
 use crate::types::Bytes;
 use alloc::string::ToString;
-use minicbor::data::{Int, Tag};
+use alloc::vec::Vec;
+use minicbor::data::{Int, Tag, Type};
 use minicbor::encode::{Error, Write};
 use minicbor::{Decoder, Encoder};
 
 const REQUEST_ID: u8 = 1;
 const WITNESS_SET: u8 = 2;
 
-impl_template_struct!(CardanoSignature {
-    request_id: Option<Bytes>,
-    witness_set: Bytes
+// key of the vkey witness array inside a transaction_witness_set
+const VKEY_WITNESSES: u8 = 0;
+
+impl_template_struct!(
+    CardanoSignature {
+        request_id: Option<Bytes>,
+        witness_set: Bytes
+    },
+    unknown_fields
+);
+
+impl_validate_signature!(CardanoSignature);
+
+impl_template_struct!(CardanoVKeyWitness {
+    vkey: Bytes,
+    signature: Bytes
 });
 
+impl CardanoSignature {
+    /// Extract the vkey witnesses (one per signing key) from the witness set.
+    /// Other witness kinds in the set are skipped.
+    pub fn get_vkey_witnesses(&self) -> URResult<Vec<CardanoVKeyWitness>> {
+        let mut witnesses: Vec<CardanoVKeyWitness> = Vec::new();
+        let mut d = Decoder::new(&self.witness_set);
+        cbor_map(&mut d, &mut witnesses, |key, obj, d| {
+            let key =
+                u8::try_from(key).map_err(|e| minicbor::decode::Error::message(e.to_string()))?;
+            match key {
+                VKEY_WITNESSES => {
+                    // conway era witness sets may wrap the array in the set tag (258)
+                    if let Type::Tag = d.datatype()? {
+                        d.tag()?;
+                    }
+                    cbor_array(d, obj, |_index, obj, d| {
+                        if d.array()? != Some(2) {
+                            return Err(minicbor::decode::Error::message(
+                                "vkey witness must be an array of [vkey, signature]",
+                            ));
+                        }
+                        let vkey = cbor_bytes(d)?;
+                        let signature = cbor_bytes(d)?;
+                        obj.push(CardanoVKeyWitness::new(vkey, signature));
+                        Ok(())
+                    })?;
+                }
+                _ => {
+                    d.skip()?;
+                }
+            }
+            Ok(())
+        })
+        .map_err(|e| URError::CborDecodeError(e.to_string()))?;
+        Ok(witnesses)
+    }
+}
+
 impl RegistryItem for CardanoSignature {
     fn get_registry_type() -> RegistryType<'static> {
         CARDANO_SIGNATURE
@@ -28,7 +85,7 @@ impl MapSize for CardanoSignature {
         if self.request_id.is_some() {
             size += 1;
         }
-        size
+        size + self.unknown_fields.len() as u64
     }
 }
 
@@ -45,28 +102,69 @@ impl<C> minicbor::Encode<C> for CardanoSignature {
         e.int(Int::from(WITNESS_SET))?
             .bytes(self.get_witness_set().as_ref())?;
 
+        cbor_unknown_fields(e, &self.unknown_fields)?;
         Ok(())
     }
 }
 
-impl<'b, C> minicbor::Decode<'b, C> for CardanoSignature {
-    fn decode(d: &mut Decoder<'b>, _ctx: &mut C) -> Result<Self, minicbor::decode::Error> {
+impl<'b, C: DecodeContext> minicbor::Decode<'b, C> for CardanoSignature {
+    fn decode(d: &mut Decoder<'b>, ctx: &mut C) -> Result<Self, minicbor::decode::Error> {
         let mut cardano_signature = CardanoSignature::default();
         cbor_map(d, &mut cardano_signature, |key, obj, d| {
             let key =
                 u8::try_from(key).map_err(|e| minicbor::decode::Error::message(e.to_string()))?;
             match key {
                 REQUEST_ID => {
-                    d.tag()?;
-                    obj.set_request_id(Some(d.bytes()?.to_vec()));
+                    cbor_tag(d, ctx, UUID.get_tag(), "UUID tag is invalid")?;
+                    obj.set_request_id(Some(cbor_bytes(d)?));
                 }
                 WITNESS_SET => {
-                    obj.set_witness_set(d.bytes()?.to_vec());
+                    obj.set_witness_set(cbor_bytes(d)?);
+                }
+                _ => {
+                    cbor_unknown_field(d, key, &mut obj.unknown_fields)?;
                 }
-                _ => {}
             }
             Ok(())
         })?;
         Ok(cardano_signature)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    // {0: [[vkey_1, sig_1], [vkey_2, sig_2]]}
+    const WITNESS_SET_HEX: &str = "a1008282582011111111111111111111111111111111111111111111111111111111111111115840222222222222222222222222222222222222222222222222222222222222222222222222222222222222222222222222222222222222222222222222222222228258203333333333333333333333333333333333333333333333333333333333333333584044444444444444444444444444444444444444444444444444444444444444444444444444444444444444444444444444444444444444444444444444444444";
+
+    #[test]
+    fn test_round_trip() {
+        let signature = CardanoSignature::new(
+            Some(hex::decode("9b1deb4d3b7d4bad9bdd2b0d7b3dcb6d").unwrap()),
+            hex::decode(WITNESS_SET_HEX).unwrap(),
+        );
+        let cbor: Vec<u8> = signature.try_into().unwrap();
+        let decoded = CardanoSignature::try_from(cbor).unwrap();
+        assert_eq!(
+            Some(hex::decode("9b1deb4d3b7d4bad9bdd2b0d7b3dcb6d").unwrap()),
+            decoded.get_request_id()
+        );
+
+        let witnesses = decoded.get_vkey_witnesses().unwrap();
+        assert_eq!(2, witnesses.len());
+        assert_eq!(vec![0x11; 32], witnesses[0].get_vkey());
+        assert_eq!(vec![0x22; 64], witnesses[0].get_signature());
+        assert_eq!(vec![0x33; 32], witnesses[1].get_vkey());
+        assert_eq!(vec![0x44; 64], witnesses[1].get_signature());
+    }
+
+    #[test]
+    fn test_invalid_witness() {
+        // {0: [[vkey]]}
+        let witness_set = hex::decode("a100818158201111111111111111111111111111111111111111111111111111111111111111").unwrap();
+        let signature = CardanoSignature::new(None, witness_set);
+        assert!(signature.get_vkey_witnesses().is_err());
+    }
+}
@@ -1,5 +1,6 @@
-use crate::cbor::cbor_map;
+use crate::cbor::{cbor_bytes, cbor_map, cbor_tag};
 use crate::crypto_key_path::CryptoKeyPath;
+use crate::decode::DecodeContext;
 use crate::error::{URError, URResult};
 use crate::impl_template_struct;
 use crate::registry_types::{RegistryType, CARDANO_UTXO, CRYPTO_KEYPATH};
@@ -59,19 +60,24 @@ impl<C> minicbor::Encode<C> for CardanoUTXO {
     }
 }
 
-impl<'b, C> minicbor::Decode<'b, C> for CardanoUTXO {
-    fn decode(d: &mut Decoder<'b>, _ctx: &mut C) -> Result<Self, minicbor::decode::Error> {
+impl<'b, C: DecodeContext> minicbor::Decode<'b, C> for CardanoUTXO {
+    fn decode(d: &mut Decoder<'b>, ctx: &mut C) -> Result<Self, minicbor::decode::Error> {
         let mut cardano_utxo = CardanoUTXO::default();
         cbor_map(d, &mut cardano_utxo, |key, obj, d| {
             let key =
                 u8::try_from(key).map_err(|e| minicbor::decode::Error::message(e.to_string()))?;
             match key {
-                TRANSACTION_HASH => obj.set_transaction_hash(d.bytes()?.to_vec()),
+                TRANSACTION_HASH => obj.set_transaction_hash(cbor_bytes(d)?),
                 INDEX => obj.set_index(d.u32()?),
                 AMOUNT => obj.set_amount(d.str()?.to_string()),
                 KEY_PATH => {
-                    d.tag()?;
-                    obj.set_key_path(CryptoKeyPath::decode(d, _ctx)?);
+                    cbor_tag(
+                        d,
+                        ctx,
+                        CryptoKeyPath::get_registry_type().get_tag(),
+                        "CryptoKeyPath tag is invalid",
+                    )?;
+                    obj.set_key_path(CryptoKeyPath::decode(d, ctx)?);
                 }
                 ADDRESS => {
                     obj.set_address(d.str()?.to_string());
@@ -12,3 +12,6 @@ pub mod cardano_sign_tx_hash_request;
 pub mod cardano_signature;
 pub mod cardano_utxo;
 pub mod governance;
+
+// the SLIP-44 coin type at index 1 of the Cardano key paths
+pub(crate) const CARDANO_COIN_TYPE: u32 = 1815;
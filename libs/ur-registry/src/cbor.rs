@@ -1,5 +1,17 @@
-use minicbor::data::{Int, Type};
-use minicbor::Decoder;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use minicbor::data::{Int, Tag, Type};
+use minicbor::encode::Write;
+use minicbor::{Decoder, Encoder};
+
+use crate::crypto_key_path::CryptoKeyPath;
+use crate::decode::DecodeContext;
+use crate::error::{URError, URResult};
+use crate::registry_types::CRYPTO_KEYPATH;
+use crate::types::{Bytes, UnknownFields, TX_HASH_LENGTH};
+
+pub(crate) const MAX_ORIGIN_LENGTH: usize = 256;
 
 pub(crate) fn cbor_map<'b, F, T>(
     d: &mut Decoder<'b>,
@@ -13,7 +25,9 @@ where
     let mut index = 0;
     loop {
         let key = d.int()?;
+        let position = d.position();
         (cb)(key, obj, d)?;
+        cbor_skip_unread(d, position)?;
         index += 1;
         if let Some(len) = entries {
             if len == index {
@@ -28,6 +42,58 @@ where
     Ok(())
 }
 
+// like `cbor_map`, but a value that fails to decode is recorded and skipped so that
+// the remaining entries are still checked, only malformed CBOR stops the walk
+pub(crate) fn cbor_map_collect_errors<'b, F, T>(
+    d: &mut Decoder<'b>,
+    obj: &mut T,
+    mut cb: F,
+) -> Vec<URError>
+where
+    F: FnMut(Int, &mut T, &mut Decoder<'b>) -> Result<(), minicbor::decode::Error>,
+{
+    let mut errors = Vec::new();
+    let mut walk = || -> Result<(), minicbor::decode::Error> {
+        let entries = d.map()?;
+        let mut index = 0;
+        while entries != Some(index) {
+            if entries.is_none() && d.datatype()? == Type::Break {
+                d.skip()?;
+                break;
+            }
+            let key = d.int()?;
+            let position = d.position();
+            match (cb)(key, obj, d) {
+                Ok(()) => cbor_skip_unread(d, position)?,
+                Err(e) => {
+                    errors.push(URError::CborDecodeError(format!(
+                        "key `{}`: {}",
+                        i128::from(key),
+                        e
+                    )));
+                    d.set_position(position);
+                    d.skip()?;
+                }
+            }
+            index += 1;
+        }
+        Ok(())
+    };
+    if let Err(e) = walk() {
+        errors.push(URError::CborDecodeError(e.to_string()));
+    }
+    errors
+}
+
+// skips the value of a key the callback left unread, such as a key it doesn't
+// know, so that the value isn't read as the next key
+fn cbor_skip_unread(d: &mut Decoder, position: usize) -> Result<(), minicbor::decode::Error> {
+    if d.position() == position {
+        d.skip()?;
+    }
+    Ok(())
+}
+
 pub(crate) fn cbor_array<'b, F, T>(
     d: &mut Decoder<'b>,
     obj: &mut T,
@@ -37,26 +103,193 @@ where
     F: FnMut(u64, &mut T, &mut Decoder<'b>) -> Result<(), minicbor::decode::Error>,
 {
     let entries = d.array()?;
-    if let Some(0) = entries {
-        return Ok(());
-    }
     let mut index = 0;
-    loop {
+    while entries != Some(index) {
+        if entries.is_none() && d.datatype()? == Type::Break {
+            d.skip()?;
+            break;
+        }
         (cb)(index, obj, d)?;
         index += 1;
-        if let Some(len) = entries {
-            if len == index {
-                break;
-            }
+    }
+    Ok(())
+}
+
+// reads a byte string, an indefinite-length one from a non-canonical encoder is
+// joined from its chunks
+pub(crate) fn cbor_bytes(d: &mut Decoder) -> Result<Vec<u8>, minicbor::decode::Error> {
+    let mut bytes = Vec::new();
+    for chunk in d.bytes_iter()? {
+        bytes.extend_from_slice(chunk?);
+    }
+    Ok(bytes)
+}
+
+// reads the tag in front of a value, a lenient context skips the check and
+// also accepts an untagged value
+pub(crate) fn cbor_tag<C: DecodeContext>(
+    d: &mut Decoder,
+    ctx: &C,
+    tag: u64,
+    message: &'static str,
+) -> Result<(), minicbor::decode::Error> {
+    if !ctx.strict_tags() {
+        if let Type::Tag = d.datatype()? {
+            d.tag()?;
         }
-        if let Type::Break = d.datatype()? {
-            d.skip()?;
-            break;
+        return Ok(());
+    }
+    if d.tag()? != Tag::Unassigned(tag) {
+        return Err(minicbor::decode::Error::message(message));
+    }
+    Ok(())
+}
+
+// the origin is displayed on the device, so a hostile UR must not be able to
+// embed an arbitrarily long one
+pub(crate) fn cbor_origin(d: &mut Decoder) -> Result<String, minicbor::decode::Error> {
+    let origin = d.str()?;
+    if origin.len() > MAX_ORIGIN_LENGTH {
+        return Err(minicbor::decode::Error::message(format!(
+            "origin exceeds {} bytes",
+            MAX_ORIGIN_LENGTH
+        )));
+    }
+    Ok(origin.to_string())
+}
+
+// writes the tagged paths of a request, a request without them leaves the
+// device with no key to sign with, so an empty array is refused here rather
+// than on the device
+pub(crate) fn cbor_paths<C, W: Write>(
+    e: &mut Encoder<W>,
+    paths: &[CryptoKeyPath],
+    field: &str,
+    ctx: &mut C,
+) -> Result<(), minicbor::encode::Error<W::Error>> {
+    if paths.is_empty() {
+        return Err(minicbor::encode::Error::message(format!(
+            "{} is invalid",
+            field
+        )));
+    }
+    e.array(paths.len() as u64)?;
+    for path in paths {
+        e.tag(Tag::Unassigned(CRYPTO_KEYPATH.get_tag()))?;
+        minicbor::Encode::encode(path, e, ctx)?;
+    }
+    Ok(())
+}
+
+// keeps the value of a key the decoder doesn't know as it is, so that it
+// survives a decode and re-encode
+pub(crate) fn cbor_unknown_field(
+    d: &mut Decoder,
+    key: u8,
+    fields: &mut UnknownFields,
+) -> Result<(), minicbor::decode::Error> {
+    let start = d.position();
+    d.skip()?;
+    fields.insert(key, d.input()[start..d.position()].to_vec());
+    Ok(())
+}
+
+// writes the fields kept by `cbor_unknown_field`, they count towards the map size
+pub(crate) fn cbor_unknown_fields<W: Write>(
+    e: &mut Encoder<W>,
+    fields: &UnknownFields,
+) -> Result<(), minicbor::encode::Error<W::Error>> {
+    for (key, value) in fields {
+        e.u8(*key)?;
+        e.writer_mut()
+            .write_all(value)
+            .map_err(minicbor::encode::Error::write)?;
+    }
+    Ok(())
+}
+
+// reads the hash of the signed transaction that a signature echoes
+pub(crate) fn cbor_tx_hash(d: &mut Decoder) -> Result<Bytes, minicbor::decode::Error> {
+    let tx_hash = cbor_bytes(d)?;
+    if tx_hash.len() != TX_HASH_LENGTH {
+        return Err(minicbor::decode::Error::message("tx_hash must be 32 bytes"));
+    }
+    Ok(tx_hash)
+}
+
+// writes the hash read by `cbor_tx_hash` at `key`, when the signature has one
+pub(crate) fn cbor_write_tx_hash<W: Write>(
+    e: &mut Encoder<W>,
+    key: u8,
+    tx_hash: &Option<Bytes>,
+) -> Result<(), minicbor::encode::Error<W::Error>> {
+    if let Some(tx_hash) = tx_hash {
+        if tx_hash.len() != TX_HASH_LENGTH {
+            return Err(minicbor::encode::Error::message("tx_hash must be 32 bytes"));
         }
+        e.u8(key)?.bytes(tx_hash)?;
+    }
+    Ok(())
+}
+
+// checks the type of the next value before reading it, so that a float where
+// an integer is expected is reported with both types
+pub(crate) fn cbor_expect_type<C: DecodeContext>(
+    d: &Decoder,
+    ctx: &mut C,
+    expected: Type,
+) -> Result<(), minicbor::decode::Error> {
+    let got = d.datatype()?;
+    let matches = match expected {
+        Type::Bytes => matches!(got, Type::Bytes | Type::BytesIndef),
+        expected => cbor_type(got) == cbor_type(expected),
+    };
+    if !matches {
+        let error = URError::UnexpectedType {
+            expected: expected.to_string(),
+            got: got.to_string(),
+        };
+        let message = error.to_string();
+        ctx.unexpected_type(error);
+        return Err(minicbor::decode::Error::message(message));
     }
     Ok(())
 }
 
+// minicbor errors carry no payload without `std`, so the typed error of
+// `cbor_expect_type` is kept in the context until the decode fails
+#[derive(Default)]
+pub(crate) struct TypedErrorContext {
+    error: Option<URError>,
+}
+
+impl DecodeContext for TypedErrorContext {
+    fn unexpected_type(&mut self, error: URError) {
+        self.error = Some(error);
+    }
+}
+
+pub(crate) fn cbor_decode<T>(bytes: &[u8]) -> URResult<T>
+where
+    T: for<'b> minicbor::Decode<'b, TypedErrorContext>,
+{
+    let mut ctx = TypedErrorContext::default();
+    minicbor::decode_with(bytes, &mut ctx).map_err(|e| {
+        ctx.error
+            .take()
+            .unwrap_or_else(|| URError::CborDecodeError(e.to_string()))
+    })
+}
+
+// the encoded map with the array [1, 2] added at the unused key 15, as a newer
+// version of the format would write it, for maps of fewer than 23 entries
+#[cfg(test)]
+pub(crate) fn with_unknown_key(mut bytes: Vec<u8>) -> Vec<u8> {
+    bytes[0] += 1;
+    bytes.extend_from_slice(&[0x0f, 0x82, 0x01, 0x02]);
+    bytes
+}
+
 pub(crate) fn cbor_type(data_type: Type) -> Type {
     match data_type {
         Type::U8
@@ -1,14 +1,20 @@
 use alloc::format;
 use alloc::string::{String, ToString};
 use alloc::vec::Vec;
-use minicbor::data::{Int, Tag};
+use minicbor::data::{Int, Tag, Type};
 
-use crate::cbor::{cbor_array, cbor_map};
+use crate::cbor::{
+    cbor_array, cbor_bytes, cbor_expect_type, cbor_map, cbor_origin, cbor_paths, cbor_tag,
+    cbor_unknown_field, cbor_unknown_fields,
+};
 use crate::crypto_key_path::CryptoKeyPath;
+use crate::decode::DecodeContext;
 use crate::error::{URError, URResult};
+use crate::impl_with_new_request_id;
 use crate::registry_types::{RegistryType, COSMOS_SIGN_REQUEST, UUID};
-use crate::traits::{From, RegistryItem, To};
-use crate::types::Bytes;
+use crate::traits::{Expiry, From, RegistryItem, To, Validate};
+use crate::types::{Bytes, UnknownFields};
+use crate::validate::{validate_paths, validate_request_id, validate_sign_data};
 
 const REQUEST_ID: u8 = 1;
 const SIGN_DATA: u8 = 2;
@@ -16,13 +22,22 @@ const DATA_TYPE: u8 = 3;
 const DERIVATION_PATHS: u8 = 4;
 const ADDRESSES: u8 = 5;
 const ORIGIN: u8 = 6;
+const AUTHZ_GRANTEE: u8 = 7;
+const AUTHZ_MSG_TYPES: u8 = 8;
+const EXPIRES_AT: u8 = 9;
 
-#[derive(Clone, Debug, Default)]
+/// The sign mode `sign_data` is serialized in. Requests without a data type are
+/// legacy Amino requests.
+#[derive(Clone, Debug, Default, PartialEq)]
 pub enum DataType {
+    /// SIGN_MODE_LEGACY_AMINO_JSON
     #[default]
     Amino = 1,
+    /// SIGN_MODE_DIRECT, a protobuf `SignDoc`
     Direct = 2,
+    /// SIGN_MODE_TEXTUAL
     Textual = 3,
+    /// an arbitrary message (ADR-036)
     Message = 4,
 }
 
@@ -49,8 +64,15 @@ pub struct CosmosSignRequest {
     derivation_paths: Vec<CryptoKeyPath>,
     addresses: Option<Vec<String>>,
     origin: Option<String>,
+    // preview of an authz `MsgGrant`/`MsgExec`, shown before signing
+    authz_grantee: Option<String>,
+    authz_msg_types: Option<Vec<String>>,
+    expires_at: Option<u64>,
+    unknown_fields: UnknownFields,
 }
 
+impl_with_new_request_id!(CosmosSignRequest, required);
+
 impl CosmosSignRequest {
     pub fn default() -> Self {
         Default::default()
@@ -80,6 +102,18 @@ impl CosmosSignRequest {
         self.origin = Some(origin)
     }
 
+    pub fn set_authz_grantee(&mut self, authz_grantee: String) {
+        self.authz_grantee = Some(authz_grantee)
+    }
+
+    pub fn set_authz_msg_types(&mut self, authz_msg_types: Vec<String>) {
+        self.authz_msg_types = Some(authz_msg_types)
+    }
+
+    pub fn set_expires_at(&mut self, expires_at: u64) {
+        self.expires_at = Some(expires_at)
+    }
+
     pub fn new(
         request_id: Bytes,
         sign_data: Bytes,
@@ -95,6 +129,10 @@ impl CosmosSignRequest {
             derivation_paths,
             addresses,
             origin,
+            authz_grantee: None,
+            authz_msg_types: None,
+            expires_at: None,
+            unknown_fields: UnknownFields::new(),
         }
     }
     pub fn get_request_id(&self) -> Bytes {
@@ -115,6 +153,15 @@ impl CosmosSignRequest {
     pub fn get_origin(&self) -> Option<String> {
         self.origin.clone()
     }
+    pub fn get_authz_grantee(&self) -> Option<String> {
+        self.authz_grantee.clone()
+    }
+    pub fn get_authz_msg_types(&self) -> Option<Vec<String>> {
+        self.authz_msg_types.clone()
+    }
+    pub fn get_unknown_fields(&self) -> UnknownFields {
+        self.unknown_fields.clone()
+    }
 }
 
 impl RegistryItem for CosmosSignRequest {
@@ -123,6 +170,21 @@ impl RegistryItem for CosmosSignRequest {
     }
 }
 
+impl Expiry for CosmosSignRequest {
+    fn expires_at(&self) -> Option<u64> {
+        self.expires_at
+    }
+}
+
+impl Validate for CosmosSignRequest {
+    fn validate_schema(&self) -> URResult<()> {
+        validate_request_id(&Some(self.request_id.clone()))?;
+        validate_sign_data("sign_data", &self.sign_data)?;
+        validate_paths("derivation_paths", &self.derivation_paths, None)?;
+        Ok(())
+    }
+}
+
 impl<C> minicbor::Encode<C> for CosmosSignRequest {
     fn encode<W: minicbor::encode::Write>(
         &self,
@@ -136,7 +198,16 @@ impl<C> minicbor::Encode<C> for CosmosSignRequest {
         if self.origin.is_some() {
             size += 1;
         }
-        e.map(size)?;
+        if self.authz_grantee.is_some() {
+            size += 1;
+        }
+        if self.authz_msg_types.is_some() {
+            size += 1;
+        }
+        if self.expires_at.is_some() {
+            size += 1;
+        }
+        e.map(size + self.unknown_fields.len() as u64)?;
         e.int(
             Int::try_from(REQUEST_ID)
                 .map_err(|e| minicbor::encode::Error::message(e.to_string()))?,
@@ -157,23 +228,11 @@ impl<C> minicbor::Encode<C> for CosmosSignRequest {
                 .map_err(|e| minicbor::encode::Error::message(e.to_string()))?,
         )?;
 
-        let derivation_paths = self.get_derivation_paths();
-        if derivation_paths.is_empty() {
-            return Result::Err(minicbor::encode::Error::message(
-                "derivation_paths is invalid",
-            ));
-        }
         e.int(
             Int::try_from(DERIVATION_PATHS)
                 .map_err(|e| minicbor::encode::Error::message(e.to_string()))?,
-        )?
-        .array(derivation_paths.len() as u64)?;
-        for path in derivation_paths {
-            e.tag(Tag::Unassigned(
-                CryptoKeyPath::get_registry_type().get_tag(),
-            ))?;
-            CryptoKeyPath::encode(&path, e, ctx)?;
-        }
+        )?;
+        cbor_paths(e, &self.derivation_paths, "derivation_paths", ctx)?;
 
         if let Some(addresses) = self.get_addresses() {
             e.int(
@@ -192,11 +251,32 @@ impl<C> minicbor::Encode<C> for CosmosSignRequest {
             )?
             .str(&origin)?;
         }
+        if let Some(authz_grantee) = self.get_authz_grantee() {
+            e.int(
+                Int::try_from(AUTHZ_GRANTEE)
+                    .map_err(|e| minicbor::encode::Error::message(e.to_string()))?,
+            )?
+            .str(&authz_grantee)?;
+        }
+        if let Some(authz_msg_types) = self.get_authz_msg_types() {
+            e.int(
+                Int::try_from(AUTHZ_MSG_TYPES)
+                    .map_err(|e| minicbor::encode::Error::message(e.to_string()))?,
+            )?
+            .array(authz_msg_types.len() as u64)?;
+            for msg_type in authz_msg_types {
+                e.str(&msg_type)?;
+            }
+        }
+        if let Some(expires_at) = self.expires_at {
+            e.int(Int::from(EXPIRES_AT))?.u64(expires_at)?;
+        }
+        cbor_unknown_fields(e, &self.unknown_fields)?;
         Ok(())
     }
 }
 
-impl<'b, C> minicbor::Decode<'b, C> for CosmosSignRequest {
+impl<'b, C: DecodeContext> minicbor::Decode<'b, C> for CosmosSignRequest {
     fn decode(d: &mut minicbor::Decoder<'b>, ctx: &mut C) -> Result<Self, minicbor::decode::Error> {
         let mut result = CosmosSignRequest::default();
 
@@ -205,31 +285,26 @@ impl<'b, C> minicbor::Decode<'b, C> for CosmosSignRequest {
                 u8::try_from(key).map_err(|e| minicbor::decode::Error::message(e.to_string()))?;
             match key {
                 REQUEST_ID => {
-                    let tag = d.tag()?;
-                    if !tag.eq(&Tag::Unassigned(UUID.get_tag())) {
-                        return Result::Err(minicbor::decode::Error::message(
-                            "UUID tag is invalid",
-                        ));
-                    }
-                    obj.request_id = d.bytes()?.to_vec();
+                    cbor_tag(d, ctx, UUID.get_tag(), "UUID tag is invalid")?;
+                    obj.request_id = cbor_bytes(d)?;
                 }
                 SIGN_DATA => {
-                    obj.sign_data = d.bytes()?.to_vec();
+                    cbor_expect_type(d, ctx, Type::Bytes)?;
+                    obj.sign_data = cbor_bytes(d)?;
                 }
                 DATA_TYPE => {
+                    cbor_expect_type(d, ctx, Type::U32)?;
                     obj.data_type =
                         DataType::from_u32(d.u32()?).map_err(minicbor::decode::Error::message)?;
                 }
                 DERIVATION_PATHS => {
                     cbor_array(d, &mut obj.derivation_paths, |_key, obj, d| {
-                        let tag = d.tag()?;
-                        if !tag.eq(&Tag::Unassigned(
+                        cbor_tag(
+                            d,
+                            ctx,
                             CryptoKeyPath::get_registry_type().get_tag(),
-                        )) {
-                            return Result::Err(minicbor::decode::Error::message(
-                                "CryptoKeyPath tag is invalid",
-                            ));
-                        }
+                            "CryptoKeyPath tag is invalid",
+                        )?;
                         obj.push(CryptoKeyPath::decode(d, ctx)?);
                         Ok(())
                     })?;
@@ -247,9 +322,26 @@ impl<'b, C> minicbor::Decode<'b, C> for CosmosSignRequest {
                     })?;
                 }
                 ORIGIN => {
-                    obj.origin = Some(d.str()?.to_string());
+                    obj.origin = Some(cbor_origin(d)?);
+                }
+                AUTHZ_GRANTEE => {
+                    obj.authz_grantee = Some(d.str()?.to_string());
+                }
+                AUTHZ_MSG_TYPES => {
+                    let mut msg_types = Vec::new();
+                    cbor_array(d, &mut msg_types, |_key, obj, d| {
+                        obj.push(d.str()?.to_string());
+                        Ok(())
+                    })?;
+                    obj.authz_msg_types = Some(msg_types);
+                }
+                EXPIRES_AT => {
+                    cbor_expect_type(d, ctx, Type::U64)?;
+                    obj.expires_at = Some(d.u64()?);
+                }
+                _ => {
+                    cbor_unknown_field(d, key, &mut obj.unknown_fields)?;
                 }
-                _ => {}
             }
             Ok(())
         })?;
@@ -268,3 +360,219 @@ impl From<CosmosSignRequest> for CosmosSignRequest {
         minicbor::decode(&bytes).map_err(|e| URError::CborDecodeError(e.to_string()))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::crypto_key_path::PathComponent;
+    use alloc::vec;
+
+    fn key_path() -> CryptoKeyPath {
+        let components = vec![
+            PathComponent::new(Some(44), true).unwrap(),
+            PathComponent::new(Some(118), true).unwrap(),
+            PathComponent::new(Some(0), true).unwrap(),
+            PathComponent::new(Some(0), false).unwrap(),
+            PathComponent::new(Some(0), false).unwrap(),
+        ];
+        CryptoKeyPath::new(components, Some([0xf2, 0x3f, 0x9f, 0xd2]), None)
+    }
+
+    #[test]
+    fn test_authz_preview_round_trip() {
+        let mut sign_request = CosmosSignRequest::new(
+            hex::decode("9b1deb4d3b7d4bad9bdd2b0d7b3dcb6d").unwrap(),
+            hex::decode("deadbeef").unwrap(),
+            DataType::Amino,
+            vec![key_path()],
+            None,
+            None,
+        );
+        sign_request.set_authz_grantee("cosmos1grantee".to_string());
+        sign_request.set_authz_msg_types(vec![
+            "/cosmos.bank.v1beta1.MsgSend".to_string(),
+            "/cosmos.staking.v1beta1.MsgDelegate".to_string(),
+        ]);
+        let result: Vec<u8> = sign_request.try_into().unwrap();
+        assert_eq!(
+            "a601d825509b1deb4d3b7d4bad9bdd2b0d7b3dcb6d0244deadbeef03010481d90130a2018a182cf51876f500f500f400f4021af23f9fd2076e636f736d6f73316772616e7465650882781c2f636f736d6f732e62616e6b2e763162657461312e4d736753656e6478232f636f736d6f732e7374616b696e672e763162657461312e4d736744656c6567617465",
+            hex::encode(&result)
+        );
+
+        let decoded = CosmosSignRequest::try_from(result).unwrap();
+        assert_eq!(
+            Some("cosmos1grantee".to_string()),
+            decoded.get_authz_grantee()
+        );
+        assert_eq!(
+            Some(vec![
+                "/cosmos.bank.v1beta1.MsgSend".to_string(),
+                "/cosmos.staking.v1beta1.MsgDelegate".to_string(),
+            ]),
+            decoded.get_authz_msg_types()
+        );
+        assert_eq!(
+            "44'/118'/0'/0/0",
+            decoded.get_derivation_paths()[0].get_path().unwrap()
+        );
+    }
+
+    #[test]
+    fn test_multi_signer_round_trip() {
+        let components = vec![
+            PathComponent::new(Some(44), true).unwrap(),
+            PathComponent::new(Some(118), true).unwrap(),
+            PathComponent::new(Some(1), true).unwrap(),
+            PathComponent::new(Some(0), false).unwrap(),
+            PathComponent::new(Some(0), false).unwrap(),
+        ];
+        let second_signer = CryptoKeyPath::new(components, Some([0xf2, 0x3f, 0x9f, 0xd2]), None);
+        let sign_request = CosmosSignRequest::new(
+            hex::decode("9b1deb4d3b7d4bad9bdd2b0d7b3dcb6d").unwrap(),
+            hex::decode("deadbeef").unwrap(),
+            DataType::Direct,
+            vec![key_path(), second_signer],
+            Some(vec![
+                "cosmos1signer0".to_string(),
+                "cosmos1signer1".to_string(),
+            ]),
+            None,
+        );
+        assert!(sign_request.validate_schema().is_ok());
+        let result: Vec<u8> = sign_request.try_into().unwrap();
+        assert_eq!(
+            "a501d825509b1deb4d3b7d4bad9bdd2b0d7b3dcb6d0244deadbeef03020482d90130a2018a182cf51876f500f500f400f4021af23f9fd2d90130a2018a182cf51876f501f500f400f4021af23f9fd205826e636f736d6f73317369676e6572306e636f736d6f73317369676e657231",
+            hex::encode(&result)
+        );
+
+        let decoded = CosmosSignRequest::try_from(result).unwrap();
+        let paths = decoded.get_derivation_paths();
+        assert_eq!(2, paths.len());
+        assert_eq!("44'/118'/0'/0/0", paths[0].get_path().unwrap());
+        assert_eq!("44'/118'/1'/0/0", paths[1].get_path().unwrap());
+        assert_eq!(
+            Some(vec![
+                "cosmos1signer0".to_string(),
+                "cosmos1signer1".to_string(),
+            ]),
+            decoded.get_addresses()
+        );
+    }
+
+    #[test]
+    fn test_with_new_request_id() {
+        let sign_request = CosmosSignRequest::new(
+            hex::decode("9b1deb4d3b7d4bad9bdd2b0d7b3dcb6d").unwrap(),
+            hex::decode("deadbeef").unwrap(),
+            DataType::Amino,
+            vec![key_path()],
+            None,
+            Some("Keplr".to_string()),
+        );
+        let retry = sign_request.with_new_request_id([1; 16]);
+        assert_eq!(vec![1; 16], retry.get_request_id());
+        assert_eq!(sign_request.get_sign_data(), retry.get_sign_data());
+        assert_eq!(sign_request.get_origin(), retry.get_origin());
+        assert_eq!(
+            hex::decode("9b1deb4d3b7d4bad9bdd2b0d7b3dcb6d").unwrap(),
+            sign_request.get_request_id()
+        );
+    }
+
+    #[test]
+    fn test_sign_mode() {
+        let amino = hex::decode(
+            "a401d825509b1deb4d3b7d4bad9bdd2b0d7b3dcb6d0244deadbeef03010481d90130a2018a182cf51876f500f500f400f4021af23f9fd2",
+        )
+        .unwrap();
+        let decoded = CosmosSignRequest::try_from(amino).unwrap();
+        assert_eq!(DataType::Amino, decoded.get_data_type());
+
+        let mut direct = decoded;
+        direct.set_data_type(DataType::Direct);
+        let result: Vec<u8> = direct.try_into().unwrap();
+        assert_eq!(
+            "a401d825509b1deb4d3b7d4bad9bdd2b0d7b3dcb6d0244deadbeef03020481d90130a2018a182cf51876f500f500f400f4021af23f9fd2",
+            hex::encode(&result)
+        );
+        let decoded = CosmosSignRequest::try_from(result).unwrap();
+        assert_eq!(DataType::Direct, decoded.get_data_type());
+
+        // older requests don't carry a data type
+        let legacy = hex::decode(
+            "a301d825509b1deb4d3b7d4bad9bdd2b0d7b3dcb6d0244deadbeef0481d90130a2018a182cf51876f500f500f400f4021af23f9fd2",
+        )
+        .unwrap();
+        let decoded = CosmosSignRequest::try_from(legacy).unwrap();
+        assert_eq!(DataType::Amino, decoded.get_data_type());
+    }
+
+    #[test]
+    fn test_decode_without_authz_preview() {
+        let bytes = hex::decode(
+            "a401d825509b1deb4d3b7d4bad9bdd2b0d7b3dcb6d0244deadbeef03010481d90130a2018a182cf51876f500f500f400f4021af23f9fd2",
+        )
+        .unwrap();
+        let decoded = CosmosSignRequest::try_from(bytes.clone()).unwrap();
+        assert_eq!(None, decoded.get_authz_grantee());
+        assert_eq!(None, decoded.get_authz_msg_types());
+
+        let result: Vec<u8> = decoded.try_into().unwrap();
+        assert_eq!(bytes, result);
+    }
+
+    #[test]
+    fn test_encode_without_derivation_paths() {
+        let sign_request = CosmosSignRequest::new(
+            hex::decode("9b1deb4d3b7d4bad9bdd2b0d7b3dcb6d").unwrap(),
+            hex::decode("deadbeef").unwrap(),
+            DataType::Amino,
+            vec![],
+            None,
+            None,
+        );
+        let result: URResult<Vec<u8>> = sign_request.try_into();
+        assert!(matches!(
+            result,
+            Err(URError::CborEncodeError(message)) if message.contains("derivation_paths is invalid")
+        ));
+    }
+
+    #[test]
+    fn test_decode_unexpected_type() {
+        // data_type is the float 1.0
+        let bytes = hex::decode("a202410003fa3f800000").unwrap();
+        assert_eq!(
+            Err(URError::UnexpectedType {
+                expected: "u32".to_string(),
+                got: "f32".to_string(),
+            }),
+            CosmosSignRequest::try_from(bytes).map(|_| ())
+        );
+    }
+
+    #[test]
+    fn test_expiry() {
+        let mut sign_request = CosmosSignRequest::new(
+            hex::decode("9b1deb4d3b7d4bad9bdd2b0d7b3dcb6d").unwrap(),
+            hex::decode("deadbeef").unwrap(),
+            DataType::Amino,
+            vec![key_path()],
+            None,
+            None,
+        );
+        assert!(!sign_request.is_expired(u64::MAX));
+
+        sign_request.set_expires_at(1760400000);
+        let result: Vec<u8> = sign_request.try_into().unwrap();
+        assert_eq!(
+            "a501d825509b1deb4d3b7d4bad9bdd2b0d7b3dcb6d0244deadbeef03010481d90130a2018a182cf51876f500f500f400f4021af23f9fd2091a68ed9280",
+            hex::encode(&result)
+        );
+
+        let decoded = CosmosSignRequest::try_from(result).unwrap();
+        assert_eq!(Some(1760400000), decoded.expires_at());
+        assert!(!decoded.is_expired(1760399999));
+        assert!(decoded.is_expired(1760400001));
+    }
+}
@@ -1,14 +1,21 @@
 use alloc::format;
 use alloc::string::{String, ToString};
 use core::convert::From;
-use minicbor::data::{Int, Tag};
+use minicbor::data::{Int, Tag, Type};
 
-use crate::cbor::cbor_map;
+use crate::cbor::{
+    cbor_bytes, cbor_expect_type, cbor_map, cbor_origin, cbor_tag, cbor_unknown_field,
+    cbor_unknown_fields,
+};
 use crate::crypto_key_path::CryptoKeyPath;
+use crate::decode::DecodeContext;
+use crate::error::URResult;
 use crate::impl_template_struct;
+use crate::impl_with_new_request_id;
 use crate::registry_types::{RegistryType, CRYPTO_KEYPATH, EVM_SIGN_REQUEST, UUID};
-use crate::traits::{MapSize, RegistryItem};
+use crate::traits::{Expiry, MapSize, RegistryItem, Validate};
 use crate::types::Bytes;
+use crate::validate::{validate_path, validate_request_id, validate_sign_data};
 
 const REQUEST_ID: u8 = 1;
 const SIGN_DATA: u8 = 2;
@@ -17,8 +24,11 @@ const CUSTOM_CHAIN_IDENTIFIER: u8 = 4;
 const DERIVATION_PATH: u8 = 5;
 const ADDRESS: u8 = 6;
 const ORIGIN: u8 = 7;
+const EXPIRES_AT: u8 = 8;
 
-impl_template_struct!(EvmSignRequest {request_id: Bytes, sign_data: Bytes, data_type: SignDataType, custom_chain_identifier: u32, derivation_path: CryptoKeyPath, address: Option<Bytes>, origin: Option<String>});
+impl_template_struct!(EvmSignRequest {request_id: Bytes, sign_data: Bytes, data_type: SignDataType, custom_chain_identifier: u32, derivation_path: CryptoKeyPath, address: Option<Bytes>, origin: Option<String>, expires_at: Option<u64>}, unknown_fields);
+
+impl_with_new_request_id!(EvmSignRequest, required);
 
 #[derive(Clone, Debug, Default)]
 pub enum SignDataType {
@@ -57,7 +67,25 @@ impl MapSize for EvmSignRequest {
         if self.origin.is_some() {
             size += 1;
         }
-        size
+        if self.expires_at.is_some() {
+            size += 1;
+        }
+        size + self.unknown_fields.len() as u64
+    }
+}
+
+impl Expiry for EvmSignRequest {
+    fn expires_at(&self) -> Option<u64> {
+        self.expires_at
+    }
+}
+
+impl Validate for EvmSignRequest {
+    fn validate_schema(&self) -> URResult<()> {
+        validate_request_id(&Some(self.request_id.clone()))?;
+        validate_sign_data("sign_data", &self.sign_data)?;
+        validate_path("derivation_path", &self.derivation_path, None)?;
+        Ok(())
     }
 }
 
@@ -91,11 +119,16 @@ impl<C> minicbor::Encode<C> for EvmSignRequest {
             e.int(Int::from(ORIGIN))?.str(origin)?;
         }
 
+        if let Some(expires_at) = self.expires_at {
+            e.int(Int::from(EXPIRES_AT))?.u64(expires_at)?;
+        }
+
+        cbor_unknown_fields(e, &self.unknown_fields)?;
         Ok(())
     }
 }
 
-impl<'b, C> minicbor::Decode<'b, C> for EvmSignRequest {
+impl<'b, C: DecodeContext> minicbor::Decode<'b, C> for EvmSignRequest {
     fn decode(d: &mut minicbor::Decoder<'b>, ctx: &mut C) -> Result<Self, minicbor::decode::Error> {
         let mut result = EvmSignRequest::default();
 
@@ -104,31 +137,47 @@ impl<'b, C> minicbor::Decode<'b, C> for EvmSignRequest {
                 u8::try_from(key).map_err(|e| minicbor::decode::Error::message(e.to_string()))?;
             match key {
                 REQUEST_ID => {
-                    d.tag()?;
-                    obj.request_id = d.bytes()?.to_vec();
+                    cbor_tag(d, ctx, UUID.get_tag(), "UUID tag is invalid")?;
+                    obj.request_id = cbor_bytes(d)?;
                 }
                 SIGN_DATA => {
-                    obj.sign_data = d.bytes()?.to_vec();
+                    cbor_expect_type(d, ctx, Type::Bytes)?;
+                    obj.sign_data = cbor_bytes(d)?;
                 }
                 DATA_TYPE => {
+                    cbor_expect_type(d, ctx, Type::U8)?;
                     obj.data_type = SignDataType::from_u8(
                         d.u8()
                             .map_err(|e| minicbor::decode::Error::message(e.to_string()))?,
                     )
                     .map_err(minicbor::decode::Error::message)?;
                 }
-                CUSTOM_CHAIN_IDENTIFIER => obj.custom_chain_identifier = d.u32()?,
+                CUSTOM_CHAIN_IDENTIFIER => {
+                    cbor_expect_type(d, ctx, Type::U32)?;
+                    obj.custom_chain_identifier = d.u32()?;
+                }
                 DERIVATION_PATH => {
-                    d.tag()?;
+                    cbor_tag(
+                        d,
+                        ctx,
+                        CryptoKeyPath::get_registry_type().get_tag(),
+                        "CryptoKeyPath tag is invalid",
+                    )?;
                     obj.derivation_path = CryptoKeyPath::decode(d, ctx)?;
                 }
                 ADDRESS => {
-                    obj.address = Some(d.bytes()?.to_vec());
+                    obj.address = Some(cbor_bytes(d)?);
                 }
                 ORIGIN => {
-                    obj.origin = Some(d.str()?.to_string());
+                    obj.origin = Some(cbor_origin(d)?);
+                }
+                EXPIRES_AT => {
+                    cbor_expect_type(d, ctx, Type::U64)?;
+                    obj.expires_at = Some(d.u64()?);
+                }
+                _ => {
+                    cbor_unknown_field(d, key, &mut obj.unknown_fields)?;
                 }
-                _ => {}
             }
             Ok(())
         })?;
@@ -140,6 +189,7 @@ impl<'b, C> minicbor::Decode<'b, C> for EvmSignRequest {
 mod tests {
     use super::*;
     use crate::crypto_key_path::PathComponent;
+    use crate::error::URError;
     use alloc::vec;
     use alloc::vec::Vec;
     use hex::FromHex;
@@ -169,6 +219,7 @@ mod tests {
             crypto_key_path,
             Some(address.to_vec()),
             Some("evm wallet".to_string()),
+            None,
         );
         let result: Vec<u8> = sign_request.try_into().unwrap();
         assert_eq!(
@@ -200,4 +251,77 @@ mod tests {
         assert_eq!(sign_data, sign_request.get_sign_data());
         assert_eq!(Some(address.to_vec()), sign_request.get_address());
     }
+
+    #[test]
+    fn test_decode_unexpected_type() {
+        // data_type is the float 1.0
+        let bytes = Vec::from_hex("a202410003fa3f800000").unwrap();
+        assert_eq!(
+            Err(URError::UnexpectedType {
+                expected: "u8".to_string(),
+                got: "f32".to_string(),
+            }),
+            EvmSignRequest::try_from(bytes).map(|_| ())
+        );
+
+        // custom_chain_identifier is the text string "1"
+        let bytes = Vec::from_hex("a2024100046131").unwrap();
+        assert_eq!(
+            Err(URError::UnexpectedType {
+                expected: "u32".to_string(),
+                got: "string".to_string(),
+            }),
+            EvmSignRequest::try_from(bytes).map(|_| ())
+        );
+    }
+
+    #[test]
+    fn test_validate_schema() {
+        let crypto_key_path =
+            CryptoKeyPath::from_path("m/44'/9000'/0'/0/0".to_string(), None).unwrap();
+        let mut sign_request = EvmSignRequest::new(
+            hex::decode("9b1deb4d3b7d4bad9bdd2b0d7b3dcb6d").unwrap(),
+            hex::decode("8e53e7b10656816de70824e3016fc1a277e77825e12825dc4f239f418ab2e04e")
+                .unwrap(),
+            SignDataType::CosmosAmino,
+            9000,
+            crypto_key_path,
+            None,
+            None,
+            None,
+        );
+        assert!(sign_request.validate_schema().is_ok());
+
+        sign_request.set_derivation_path(CryptoKeyPath::new(vec![], None, None));
+        assert_eq!(
+            Err(URError::ValidationError(
+                "derivation_path is empty".to_string()
+            )),
+            sign_request.validate_schema()
+        );
+    }
+
+    #[test]
+    fn test_expiry() {
+        let crypto_key_path =
+            CryptoKeyPath::from_path("m/44'/9000'/0'/0/0".to_string(), None).unwrap();
+        let mut sign_request = EvmSignRequest::new(
+            hex::decode("9b1deb4d3b7d4bad9bdd2b0d7b3dcb6d").unwrap(),
+            hex::decode("deadbeef").unwrap(),
+            SignDataType::Arbitrary,
+            9000,
+            crypto_key_path,
+            None,
+            None,
+            None,
+        );
+        assert!(!sign_request.is_expired(u64::MAX));
+
+        sign_request.set_expires_at(Some(1760400000));
+        let result: Vec<u8> = sign_request.try_into().unwrap();
+        let decoded = EvmSignRequest::try_from(result).unwrap();
+        assert_eq!(Some(1760400000), decoded.expires_at());
+        assert!(!decoded.is_expired(1760399999));
+        assert!(decoded.is_expired(1760400001));
+    }
 }
@@ -1,19 +1,32 @@
 use alloc::string::ToString;
 use minicbor::data::{Int, Tag};
 
-use crate::cbor::cbor_map;
+use crate::cbor::{
+    cbor_bytes, cbor_map, cbor_tag, cbor_tx_hash, cbor_unknown_field, cbor_unknown_fields,
+    cbor_write_tx_hash,
+};
+use crate::decode::DecodeContext;
 use crate::impl_template_struct;
+use crate::impl_validate_signature;
 use crate::registry_types::{RegistryType, EVM_SIGNATURE, UUID};
 use crate::traits::{From as FromCbor, MapSize, RegistryItem, To};
 use crate::types::Bytes;
 
 const REQUEST_ID: u8 = 1;
 const SIGNATURE: u8 = 2;
+const TX_HASH: u8 = 3;
 
-impl_template_struct!(EvmSignature {
-    request_id: Bytes,
-    signature: Bytes
-});
+impl_template_struct!(
+    EvmSignature {
+        request_id: Bytes,
+        signature: Bytes,
+        // hash of the transaction as computed by the device
+        tx_hash: Option<Bytes>
+    },
+    unknown_fields
+);
+
+impl_validate_signature!(EvmSignature, required);
 
 impl RegistryItem for EvmSignature {
     fn get_registry_type() -> RegistryType<'static> {
@@ -23,7 +36,11 @@ impl RegistryItem for EvmSignature {
 
 impl MapSize for EvmSignature {
     fn map_size(&self) -> u64 {
-        2
+        let mut size = 2;
+        if self.tx_hash.is_some() {
+            size += 1;
+        }
+        size + self.unknown_fields.len() as u64
     }
 }
 
@@ -45,15 +62,14 @@ impl<C> minicbor::Encode<C> for EvmSignature {
                 .map_err(|e| minicbor::encode::Error::message(e.to_string()))?,
         )?
         .bytes(&self.get_signature())?;
+        cbor_write_tx_hash(e, TX_HASH, &self.tx_hash)?;
+        cbor_unknown_fields(e, &self.unknown_fields)?;
         Ok(())
     }
 }
 
-impl<'b, C> minicbor::Decode<'b, C> for EvmSignature {
-    fn decode(
-        d: &mut minicbor::Decoder<'b>,
-        _ctx: &mut C,
-    ) -> Result<Self, minicbor::decode::Error> {
+impl<'b, C: DecodeContext> minicbor::Decode<'b, C> for EvmSignature {
+    fn decode(d: &mut minicbor::Decoder<'b>, ctx: &mut C) -> Result<Self, minicbor::decode::Error> {
         let mut result = EvmSignature::default();
 
         cbor_map(d, &mut result, |key, obj, d| {
@@ -61,16 +77,18 @@ impl<'b, C> minicbor::Decode<'b, C> for EvmSignature {
                 u8::try_from(key).map_err(|e| minicbor::decode::Error::message(e.to_string()))?;
             match key {
                 REQUEST_ID => {
-                    let tag = d.tag()?;
-                    if !tag.eq(&Tag::Unassigned(UUID.get_tag())) {
-                        return Err(minicbor::decode::Error::message("UUID tag is invalid"));
-                    }
-                    obj.request_id = d.bytes()?.to_vec();
+                    cbor_tag(d, ctx, UUID.get_tag(), "UUID tag is invalid")?;
+                    obj.request_id = cbor_bytes(d)?;
                 }
                 SIGNATURE => {
-                    obj.signature = d.bytes()?.to_vec();
+                    obj.signature = cbor_bytes(d)?;
+                }
+                TX_HASH => {
+                    obj.tx_hash = Some(cbor_tx_hash(d)?);
+                }
+                _ => {
+                    cbor_unknown_field(d, key, &mut obj.unknown_fields)?;
                 }
-                _ => {}
             }
             Ok(())
         })?;
@@ -89,7 +107,7 @@ mod tests {
         let request_id = hex::decode("9b1deb4d3b7d4bad9bdd2b0d7b3dcb6d").unwrap();
         let signature =
             hex::decode("47e7b510784406dfa14d9fd13c3834128b49c56ddfc28edb02c5047219779adeed12017e2f9f116e83762e86f805c7311ea88fb403ff21900e069142b1fb310e").unwrap();
-        let evm_signature = EvmSignature::new(request_id, signature);
+        let evm_signature = EvmSignature::new(request_id, signature, None);
         let result: Vec<u8> = evm_signature.try_into().unwrap();
         assert_eq!(
             "a201d825509b1deb4d3b7d4bad9bdd2b0d7b3dcb6d02584047e7b510784406dfa14d9fd13c3834128b49c56ddfc28edb02c5047219779adeed12017e2f9f116e83762e86f805c7311ea88fb403ff21900e069142b1fb310e",
@@ -107,4 +125,23 @@ mod tests {
         assert_eq!(request_id, evm_signature.get_request_id());
         assert_eq!(signature, evm_signature.get_signature());
     }
+
+    #[test]
+    fn test_tx_hash_round_trip() {
+        let tx_hash = (0..32).collect::<Vec<u8>>();
+        let evm_signature = EvmSignature::new(
+            hex::decode("9b1deb4d3b7d4bad9bdd2b0d7b3dcb6d").unwrap(),
+            hex::decode("deadbeef").unwrap(),
+            Some(tx_hash.clone()),
+        );
+        let result: Vec<u8> = evm_signature.try_into().unwrap();
+        assert_eq!(
+            "a301d825509b1deb4d3b7d4bad9bdd2b0d7b3dcb6d0244deadbeef035820000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f",
+            hex::encode(&result)
+        );
+        assert_eq!(
+            Some(tx_hash),
+            EvmSignature::try_from(result).unwrap().get_tx_hash()
+        );
+    }
 }
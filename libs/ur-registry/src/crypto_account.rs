@@ -1,9 +1,13 @@
-use crate::cbor::{cbor_array, cbor_map};
+use crate::cbor::{cbor_array, cbor_map, cbor_unknown_field, cbor_unknown_fields};
+use crate::crypto_hd_key::CryptoHDKey;
 use crate::crypto_output::CryptoOutput;
+use crate::decode::DecodeContext;
 use crate::error::{URError, URResult};
 use crate::registry_types::{RegistryType, CRYPTO_ACCOUNT, CRYPTO_OUTPUT};
+use crate::script_expression::ScriptExpression;
 use crate::traits::{From as FromCbor, RegistryItem, To};
-use crate::types::Fingerprint;
+use crate::types::{Fingerprint, UnknownFields};
+use alloc::format;
 use alloc::string::ToString;
 use alloc::vec;
 use alloc::vec::Vec;
@@ -18,6 +22,7 @@ const OUTPUT_DESCRIPTORS: u8 = 2;
 pub struct CryptoAccount {
     master_fingerprint: Fingerprint,
     output_descriptors: Vec<CryptoOutput>,
+    unknown_fields: UnknownFields,
 }
 
 impl CryptoAccount {
@@ -25,6 +30,7 @@ impl CryptoAccount {
         CryptoAccount {
             master_fingerprint,
             output_descriptors,
+            unknown_fields: UnknownFields::new(),
         }
     }
 
@@ -36,6 +42,10 @@ impl CryptoAccount {
         self.output_descriptors.clone()
     }
 
+    pub fn get_unknown_fields(&self) -> UnknownFields {
+        self.unknown_fields.clone()
+    }
+
     pub fn set_master_fingerprint(&mut self, fingerprint: Fingerprint) {
         self.master_fingerprint = fingerprint;
     }
@@ -43,6 +53,55 @@ impl CryptoAccount {
     pub fn set_output_descriptors(&mut self, outputs: Vec<CryptoOutput>) {
         self.output_descriptors = outputs;
     }
+
+    /// The HD keys of the output descriptors, in order. Outputs of EC keys or
+    /// multisig have none.
+    pub fn hd_keys(&self) -> Vec<&CryptoHDKey> {
+        self.output_descriptors
+            .iter()
+            .filter_map(|output| output.hd_key_ref())
+            .collect()
+    }
+
+    /// The HD keys of the outputs with exactly `script_expressions`, such as
+    /// `[WitnessPublicKeyHash]` for native segwit, which `sh(wpkh(...))` doesn't match.
+    pub fn by_script_type(&self, script_expressions: &[ScriptExpression]) -> Vec<&CryptoHDKey> {
+        self.output_descriptors
+            .iter()
+            .filter(|output| output.get_script_expressions() == script_expressions)
+            .filter_map(|output| output.hd_key_ref())
+            .collect()
+    }
+
+    /// Adds the output descriptors of `other` that are missing here, as when an
+    /// account is exported in one QR code per script type. Both accounts must be
+    /// of the same master fingerprint.
+    pub fn merge(&mut self, other: CryptoAccount) -> URResult<()> {
+        if self.master_fingerprint != other.master_fingerprint {
+            return Err(URError::ValidationError(format!(
+                "master fingerprint {} does not match {}",
+                hex::encode(other.master_fingerprint),
+                hex::encode(self.master_fingerprint)
+            )));
+        }
+        // outputs have no equality of their own, equal outputs have equal cbor
+        let encode = |output: &CryptoOutput| {
+            minicbor::to_vec(output).map_err(|e| URError::CborEncodeError(e.to_string()))
+        };
+        let mut known = self
+            .output_descriptors
+            .iter()
+            .map(encode)
+            .collect::<URResult<Vec<_>>>()?;
+        for output in other.output_descriptors {
+            let bytes = encode(&output)?;
+            if !known.contains(&bytes) {
+                known.push(bytes);
+                self.output_descriptors.push(output);
+            }
+        }
+        Ok(())
+    }
 }
 
 impl RegistryItem for CryptoAccount {
@@ -57,7 +116,7 @@ impl<C> minicbor::Encode<C> for CryptoAccount {
         e: &mut Encoder<W>,
         ctx: &mut C,
     ) -> Result<(), minicbor::encode::Error<W::Error>> {
-        e.map(2)?;
+        e.map(2 + self.unknown_fields.len() as u64)?;
         e.int(
             Int::try_from(MASTER_FINGERPRINT)
                 .map_err(|e| minicbor::encode::Error::message(e.to_string()))?,
@@ -76,11 +135,12 @@ impl<C> minicbor::Encode<C> for CryptoAccount {
             //e.tag(Tag::Unassigned(CryptoOutput::get_registry_type().get_tag()))?;
             CryptoOutput::encode(output_descriptor, e, ctx)?;
         }
+        cbor_unknown_fields(e, &self.unknown_fields)?;
         Ok(())
     }
 }
 
-impl<'b, C> minicbor::Decode<'b, C> for CryptoAccount {
+impl<'b, C: DecodeContext> minicbor::Decode<'b, C> for CryptoAccount {
     fn decode(d: &mut Decoder<'b>, ctx: &mut C) -> Result<Self, minicbor::decode::Error> {
         let mut result = CryptoAccount::default();
 
@@ -107,7 +167,9 @@ impl<'b, C> minicbor::Decode<'b, C> for CryptoAccount {
                     })?;
                     obj.output_descriptors = output_descriptors;
                 }
-                _ => {}
+                _ => {
+                    cbor_unknown_field(d, key, &mut obj.unknown_fields)?;
+                }
             }
             Ok(())
         })?;
@@ -130,12 +192,18 @@ impl FromCbor<CryptoAccount> for CryptoAccount {
 
 #[cfg(test)]
 mod tests {
+    use crate::cbor::with_unknown_key;
     use crate::crypto_account::CryptoAccount;
     use crate::crypto_ec_key::CryptoECKey;
+    use crate::crypto_hd_key::CryptoHDKey;
+    use crate::crypto_key_path::CryptoKeyPath;
     use crate::crypto_output::CryptoOutput;
+    use crate::error::URError;
     use crate::multi_key::MultiKey;
     use crate::script_expression::ScriptExpression;
     use crate::traits::{From as FromCbor, RegistryItem, To};
+    use crate::types::UnknownFields;
+    use alloc::string::ToString;
     use alloc::vec;
     use alloc::vec::Vec;
     use hex::FromHex;
@@ -281,4 +349,174 @@ mod tests {
             .to_lowercase()
         );
     }
+
+    #[test]
+    fn test_by_script_type() {
+        let hd_key = |key: &str, path: &str| {
+            CryptoHDKey::new_extended_key(
+                Some(false),
+                Vec::from_hex(key).unwrap(),
+                Some(vec![0; 32]),
+                None,
+                Some(CryptoKeyPath::from_path(path.to_string(), Some([120, 35, 8, 4])).unwrap()),
+                None,
+                None,
+                None,
+                None,
+            )
+        };
+        let legacy = CryptoOutput::new(
+            vec![ScriptExpression::PublicKeyHash],
+            None,
+            Some(hd_key(
+                "03eb3e2863911826374de86c231a4b76f0b89dfa174afb78d7f478199884d9dd32",
+                "m/44'/0'/0'",
+            )),
+            None,
+        );
+        let nested_segwit = CryptoOutput::new(
+            vec![
+                ScriptExpression::ScriptHash,
+                ScriptExpression::WitnessPublicKeyHash,
+            ],
+            None,
+            Some(hd_key(
+                "02f1f347891b20f7568eae3ec9869fbfb67bcab6f358326f10ecc42356bd55939d",
+                "m/49'/0'/0'",
+            )),
+            None,
+        );
+        let native_segwit = CryptoOutput::new(
+            vec![ScriptExpression::WitnessPublicKeyHash],
+            None,
+            Some(hd_key(
+                "03fd433450b6924b4f7efdd5d1ed017d364be95ab2b592dc8bddb3b00c1c24f63f",
+                "m/84'/0'/0'",
+            )),
+            None,
+        );
+        let ec_key = CryptoOutput::new(
+            vec![ScriptExpression::WitnessPublicKeyHash],
+            Some(CryptoECKey::new(
+                None,
+                None,
+                Vec::from_hex("02c6047f9441ed7d6d3045406e95c07cd85c778e4b8cef3ca7abac09b95c709ee5")
+                    .unwrap(),
+            )),
+            None,
+            None,
+        );
+        let account = CryptoAccount::new(
+            [120, 35, 8, 4],
+            vec![legacy, nested_segwit, native_segwit, ec_key],
+        );
+        let ur = ur::encode(
+            &account.to_bytes().unwrap(),
+            CryptoAccount::get_registry_type().get_type(),
+        );
+
+        let (_, bytes) = ur::decode(&ur).unwrap();
+        let account = CryptoAccount::from_cbor(bytes).unwrap();
+        let origins = |keys: Vec<&CryptoHDKey>| {
+            keys.iter()
+                .map(|key| key.get_origin().unwrap().get_path().unwrap())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            vec!["44'/0'/0'", "49'/0'/0'", "84'/0'/0'"],
+            origins(account.hd_keys())
+        );
+        assert_eq!(
+            vec!["84'/0'/0'"],
+            origins(account.by_script_type(&[ScriptExpression::WitnessPublicKeyHash]))
+        );
+        assert_eq!(
+            vec!["49'/0'/0'"],
+            origins(account.by_script_type(&[
+                ScriptExpression::ScriptHash,
+                ScriptExpression::WitnessPublicKeyHash
+            ]))
+        );
+        assert!(account
+            .by_script_type(&[ScriptExpression::Taproot])
+            .is_empty());
+    }
+
+    #[test]
+    fn test_merge() {
+        let output = |script_expressions: Vec<ScriptExpression>, key: &str, path: &str| {
+            let hd_key = CryptoHDKey::new_extended_key(
+                Some(false),
+                Vec::from_hex(key).unwrap(),
+                Some(vec![0; 32]),
+                None,
+                Some(CryptoKeyPath::from_path(path.to_string(), Some([120, 35, 8, 4])).unwrap()),
+                None,
+                None,
+                None,
+                None,
+            );
+            CryptoOutput::new(script_expressions, None, Some(hd_key), None)
+        };
+        let legacy = || {
+            output(
+                vec![ScriptExpression::PublicKeyHash],
+                "03eb3e2863911826374de86c231a4b76f0b89dfa174afb78d7f478199884d9dd32",
+                "m/44'/0'/0'",
+            )
+        };
+        let native_segwit = output(
+            vec![ScriptExpression::WitnessPublicKeyHash],
+            "03fd433450b6924b4f7efdd5d1ed017d364be95ab2b592dc8bddb3b00c1c24f63f",
+            "m/84'/0'/0'",
+        );
+
+        let mut account = CryptoAccount::new([120, 35, 8, 4], vec![legacy()]);
+        account
+            .merge(CryptoAccount::new(
+                [120, 35, 8, 4],
+                vec![legacy(), native_segwit.clone()],
+            ))
+            .unwrap();
+        let origins = account
+            .hd_keys()
+            .iter()
+            .map(|key| key.get_origin().unwrap().get_path().unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(vec!["44'/0'/0'", "84'/0'/0'"], origins);
+
+        let other = CryptoAccount::new([1, 2, 3, 4], vec![native_segwit]);
+        assert_eq!(
+            Err(URError::ValidationError(
+                "master fingerprint 01020304 does not match 78230804".to_string()
+            )),
+            account.merge(other)
+        );
+        assert_eq!(2, account.get_output_descriptors().len());
+    }
+
+    #[test]
+    fn test_unknown_fields_round_trip() {
+        let ec_key = CryptoECKey::new(
+            None,
+            None,
+            Vec::from_hex("02c6047f9441ed7d6d3045406e95c07cd85c778e4b8cef3ca7abac09b95c709ee5")
+                .unwrap(),
+        );
+        let output = CryptoOutput::new(
+            vec![ScriptExpression::PublicKeyHash],
+            Some(ec_key),
+            None,
+            None,
+        );
+        let account = CryptoAccount::new([120, 35, 8, 4], vec![output]);
+        let bytes = with_unknown_key(account.to_bytes().unwrap());
+        let account = CryptoAccount::from_cbor(bytes.clone()).unwrap();
+        assert_eq!(
+            UnknownFields::from([(15, vec![0x82, 0x01, 0x02])]),
+            account.get_unknown_fields()
+        );
+        assert_eq!(1, account.get_output_descriptors().len());
+        assert_eq!(bytes, account.to_bytes().unwrap());
+    }
 }
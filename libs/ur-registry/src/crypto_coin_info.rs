@@ -1,7 +1,8 @@
-use crate::cbor::cbor_map;
+use crate::cbor::{cbor_map, cbor_unknown_field, cbor_unknown_fields};
 use crate::error::{URError, URResult};
 use crate::registry_types::{RegistryType, CRYPTO_COIN_INFO};
 use crate::traits::{From as FromCbor, RegistryItem, To};
+use crate::types::UnknownFields;
 use alloc::string::ToString;
 use alloc::vec::Vec;
 use minicbor::data::Int;
@@ -10,10 +11,13 @@ use minicbor::{Decoder, Encoder};
 
 const COIN_TYPE: u8 = 1;
 const NETWORK: u8 = 2;
+const COIN_VARIANT: u8 = 3;
 
 #[derive(Clone, Debug, PartialEq)]
 pub enum CoinType {
     Bitcoin = 0,
+    Litecoin = 2,
+    Dogecoin = 3,
     Ethereum = 60,
 }
 
@@ -21,6 +25,8 @@ impl CoinType {
     pub fn from_u32(i: u32) -> CoinType {
         match i {
             0 => CoinType::Bitcoin,
+            2 => CoinType::Litecoin,
+            3 => CoinType::Dogecoin,
             60 => CoinType::Ethereum,
             _ => CoinType::Bitcoin,
         }
@@ -47,6 +53,10 @@ impl Network {
 pub struct CryptoCoinInfo {
     coin_type: Option<CoinType>,
     network: Option<Network>,
+    // tells apart chains that share a SLIP-44 coin type, such as the EVM
+    // chains using 60 or the bitcoin forks using 0
+    coin_variant: Option<u32>,
+    unknown_fields: UnknownFields,
 }
 
 impl CryptoCoinInfo {
@@ -62,8 +72,17 @@ impl CryptoCoinInfo {
         self.network = Some(network)
     }
 
+    pub fn set_coin_variant(&mut self, coin_variant: u32) {
+        self.coin_variant = Some(coin_variant)
+    }
+
     pub fn new(coin_type: Option<CoinType>, network: Option<Network>) -> CryptoCoinInfo {
-        CryptoCoinInfo { coin_type, network }
+        CryptoCoinInfo {
+            coin_type,
+            network,
+            coin_variant: None,
+            unknown_fields: UnknownFields::new(),
+        }
     }
     pub fn get_coin_type(&self) -> CoinType {
         self.coin_type.clone().unwrap_or(CoinType::Bitcoin)
@@ -71,6 +90,23 @@ impl CryptoCoinInfo {
     pub fn get_network(&self) -> Network {
         self.network.clone().unwrap_or(Network::MainNet)
     }
+    pub fn get_coin_variant(&self) -> Option<u32> {
+        self.coin_variant
+    }
+    pub fn get_unknown_fields(&self) -> UnknownFields {
+        self.unknown_fields.clone()
+    }
+
+    /// Human readable part of segwit addresses, `None` when the coin has no bech32 format.
+    pub fn get_bech32_hrp(&self) -> Option<&'static str> {
+        match (self.get_coin_type(), self.get_network()) {
+            (CoinType::Bitcoin, Network::MainNet) => Some("bc"),
+            (CoinType::Bitcoin, Network::TestNet) => Some("tb"),
+            (CoinType::Litecoin, Network::MainNet) => Some("ltc"),
+            (CoinType::Litecoin, Network::TestNet) => Some("tltc"),
+            (CoinType::Dogecoin, _) | (CoinType::Ethereum, _) => None,
+        }
+    }
 }
 
 impl RegistryItem for CryptoCoinInfo {
@@ -92,7 +128,10 @@ impl<C> minicbor::Encode<C> for CryptoCoinInfo {
         if let Some(_network) = &self.network {
             size += 1;
         }
-        e.map(size)?;
+        if self.coin_variant.is_some() {
+            size += 1;
+        }
+        e.map(size + self.unknown_fields.len() as u64)?;
         if let Some(coin_type) = &self.coin_type {
             e.int(Int::from(COIN_TYPE))?
                 .int(Int::from(coin_type.clone() as u8))?;
@@ -102,6 +141,11 @@ impl<C> minicbor::Encode<C> for CryptoCoinInfo {
             e.int(Int::from(NETWORK))?
                 .int(Int::from(network.clone() as u8))?;
         }
+
+        if let Some(coin_variant) = self.coin_variant {
+            e.int(Int::from(COIN_VARIANT))?.u32(coin_variant)?;
+        }
+        cbor_unknown_fields(e, &self.unknown_fields)?;
         Ok(())
     }
 }
@@ -126,7 +170,12 @@ impl<'b, C> minicbor::Decode<'b, C> for CryptoCoinInfo {
                             minicbor::decode::Error::message(e.to_string())
                         })?));
                 }
-                _ => {}
+                COIN_VARIANT => {
+                    obj.coin_variant = Some(d.u32()?);
+                }
+                _ => {
+                    cbor_unknown_field(d, key, &mut obj.unknown_fields)?;
+                }
             }
             Ok(())
         })?;
@@ -149,8 +198,11 @@ impl FromCbor<CryptoCoinInfo> for CryptoCoinInfo {
 
 #[cfg(test)]
 mod tests {
+    use crate::cbor::with_unknown_key;
     use crate::crypto_coin_info::{CoinType, CryptoCoinInfo, Network};
     use crate::traits::{From as FromCbor, RegistryItem, To};
+    use crate::types::UnknownFields;
+    use alloc::vec;
     use alloc::vec::Vec;
     use hex::FromHex;
 
@@ -176,4 +228,56 @@ mod tests {
         assert_eq!(crypto.get_network(), Network::TestNet);
         assert_eq!(crypto.get_coin_type(), CoinType::Bitcoin);
     }
+
+    #[test]
+    fn test_decode_ur() {
+        // the example of the crypto-coin-info spec, bitcoin testnet
+        let (_, bytes) = ur::decode("ur:crypto-coin-info/oeadaeaoadehfdbany").unwrap();
+        let crypto = CryptoCoinInfo::from_cbor(bytes).unwrap();
+        assert_eq!(crypto.get_coin_type(), CoinType::Bitcoin);
+        assert_eq!(crypto.get_network(), Network::TestNet);
+
+        let (_, bytes) = ur::decode("ur:crypto-coin-info/oeadcsfnaoaegmnseswt").unwrap();
+        let crypto = CryptoCoinInfo::from_cbor(bytes).unwrap();
+        assert_eq!(crypto.get_coin_type(), CoinType::Ethereum);
+        assert_eq!(crypto.get_network(), Network::MainNet);
+    }
+
+    #[test]
+    fn test_coin_variant_round_trip() {
+        // an EVM chain told apart by its chain id
+        let mut crypto = CryptoCoinInfo::new(Some(CoinType::Ethereum), Some(Network::MainNet));
+        crypto.set_coin_variant(137);
+        let bytes = crypto.to_bytes().unwrap();
+        assert_eq!("a301183c0200031889", hex::encode(&bytes));
+        let decoded = CryptoCoinInfo::from_cbor(bytes).unwrap();
+        assert_eq!(CoinType::Ethereum, decoded.get_coin_type());
+        assert_eq!(Some(137), decoded.get_coin_variant());
+
+        let decoded = CryptoCoinInfo::from_cbor(Vec::from_hex("a201000201").unwrap()).unwrap();
+        assert_eq!(None, decoded.get_coin_variant());
+    }
+
+    #[test]
+    fn test_utxo_forks() {
+        let crypto = CryptoCoinInfo::new(Some(CoinType::from_u32(2)), Some(Network::MainNet));
+        assert_eq!("a201020200", hex::encode(crypto.to_bytes().unwrap()));
+        assert_eq!(Some("ltc"), crypto.get_bech32_hrp());
+
+        let crypto = CryptoCoinInfo::from_cbor(Vec::from_hex("a10103").unwrap()).unwrap();
+        assert_eq!(crypto.get_coin_type(), CoinType::Dogecoin);
+        assert_eq!(None, crypto.get_bech32_hrp());
+    }
+
+    #[test]
+    fn test_unknown_fields_round_trip() {
+        let bytes = with_unknown_key(Vec::from_hex("a201000201").unwrap());
+        let crypto = CryptoCoinInfo::from_cbor(bytes.clone()).unwrap();
+        assert_eq!(
+            UnknownFields::from([(15, vec![0x82, 0x01, 0x02])]),
+            crypto.get_unknown_fields()
+        );
+        assert_eq!(crypto.get_network(), Network::TestNet);
+        assert_eq!(bytes, crypto.to_bytes().unwrap());
+    }
 }
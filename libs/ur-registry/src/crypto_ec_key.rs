@@ -1,8 +1,8 @@
-use crate::cbor::cbor_map;
+use crate::cbor::{cbor_bytes, cbor_map, cbor_unknown_field, cbor_unknown_fields};
 use crate::error::{URError, URResult};
 use crate::registry_types::{RegistryType, CRYPTO_ECKEY};
 use crate::traits::{From as FromCbor, RegistryItem, To};
-use crate::types::Bytes;
+use crate::types::{Bytes, UnknownFields};
 use alloc::string::ToString;
 use alloc::vec;
 use alloc::vec::Vec;
@@ -18,6 +18,7 @@ pub struct CryptoECKey {
     curve: Option<i128>,
     is_private_key: Option<bool>,
     data: Bytes,
+    unknown_fields: UnknownFields,
 }
 
 impl CryptoECKey {
@@ -30,6 +31,7 @@ impl CryptoECKey {
             curve,
             is_private_key,
             data,
+            unknown_fields: UnknownFields::new(),
         }
     }
 
@@ -56,6 +58,10 @@ impl CryptoECKey {
     pub fn get_data(&self) -> Vec<u8> {
         self.data.clone()
     }
+
+    pub fn get_unknown_fields(&self) -> UnknownFields {
+        self.unknown_fields.clone()
+    }
 }
 
 impl RegistryItem for CryptoECKey {
@@ -78,7 +84,7 @@ impl<C> minicbor::Encode<C> for CryptoECKey {
         if let Some(_data) = self.is_private_key {
             size += 1;
         }
-        e.map(size)?;
+        e.map(size + self.unknown_fields.len() as u64)?;
         if let Some(data) = self.curve {
             e.int(Int::from(CURVE))?.int(
                 Int::try_from(data).map_err(|e| minicbor::encode::Error::message(e.to_string()))?,
@@ -89,6 +95,7 @@ impl<C> minicbor::Encode<C> for CryptoECKey {
             e.int(Int::from(PRIVATE))?.bool(data)?;
         }
         e.int(Int::from(DATA))?.bytes(&self.data)?;
+        cbor_unknown_fields(e, &self.unknown_fields)?;
         Ok(())
     }
 }
@@ -102,6 +109,7 @@ impl<'b, C> minicbor::Decode<'b, C> for CryptoECKey {
             curve: None,
             is_private_key: None,
             data: vec![],
+            unknown_fields: UnknownFields::new(),
         };
         cbor_map(d, &mut result, |key, obj, d| {
             let key =
@@ -114,9 +122,11 @@ impl<'b, C> minicbor::Decode<'b, C> for CryptoECKey {
                     obj.is_private_key = Some(d.bool()?);
                 }
                 DATA => {
-                    obj.data = d.bytes()?.to_vec();
+                    obj.data = cbor_bytes(d)?;
+                }
+                _ => {
+                    cbor_unknown_field(d, key, &mut obj.unknown_fields)?;
                 }
-                _ => {}
             }
             Ok(())
         })?;
@@ -138,8 +148,11 @@ impl FromCbor<CryptoECKey> for CryptoECKey {
 
 #[cfg(test)]
 mod tests {
+    use crate::cbor::with_unknown_key;
     use crate::crypto_ec_key::CryptoECKey;
     use crate::traits::{From as FromCbor, RegistryItem, To};
+    use crate::types::UnknownFields;
+    use alloc::vec;
     use alloc::vec::Vec;
     use hex::FromHex;
 
@@ -178,4 +191,39 @@ mod tests {
                 .unwrap()
         );
     }
+
+    #[test]
+    fn test_decode_public_key() {
+        // the key of the `pkh(...)` example of BCR-2020-010, with the default curve
+        // (secp256k1) and private flag written out
+        let bytes = Vec::from_hex(
+            "a3010002f403582102c6047f9441ed7d6d3045406e95c07cd85c778e4b8cef3ca7abac09b95c709ee5",
+        )
+        .unwrap();
+        let crypto_ec_key = CryptoECKey::from_cbor(bytes.clone()).unwrap();
+        assert_eq!(0, crypto_ec_key.get_curve());
+        assert!(!crypto_ec_key.get_is_private_key());
+        assert_eq!(
+            "02c6047f9441ed7d6d3045406e95c07cd85c778e4b8cef3ca7abac09b95c709ee5",
+            hex::encode(crypto_ec_key.get_data())
+        );
+        assert_eq!(bytes, crypto_ec_key.to_bytes().unwrap());
+    }
+
+    #[test]
+    fn test_unknown_fields_round_trip() {
+        let bytes = with_unknown_key(
+            Vec::from_hex(
+                "A202F50358208C05C4B4F3E88840A4F4B5F155CFD69473EA169F3D0431B7A6787A23777F08AA",
+            )
+            .unwrap(),
+        );
+        let crypto_ec_key = CryptoECKey::from_cbor(bytes.clone()).unwrap();
+        assert_eq!(
+            UnknownFields::from([(15, vec![0x82, 0x01, 0x02])]),
+            crypto_ec_key.get_unknown_fields()
+        );
+        assert!(crypto_ec_key.get_is_private_key());
+        assert_eq!(bytes, crypto_ec_key.to_bytes().unwrap());
+    }
 }
@@ -1,8 +1,10 @@
-use crate::cbor::cbor_map;
-use crate::crypto_coin_info::CryptoCoinInfo;
+use crate::cbor::{cbor_bytes, cbor_map, cbor_tag};
+use crate::crypto_coin_info::{CryptoCoinInfo, Network};
 use crate::crypto_key_path::CryptoKeyPath;
+use crate::decode::DecodeContext;
 use crate::error::{URError, URResult};
 use crate::registry_types::{RegistryType, CRYPTO_HDKEY};
+use crate::script_expression::ScriptExpression;
 use crate::traits::{From as FromCbor, RegistryItem, To};
 use crate::types::{Bytes, Fingerprint};
 use alloc::string::{String, ToString};
@@ -104,36 +106,69 @@ impl CryptoHDKey {
     }
 
     pub fn get_bip32_key(&self) -> String {
-        let mut version: Bytes;
+        let version = if self.is_master() || self.is_private_key() {
+            [0x04, 0x88, 0xAD, 0xE4]
+        } else {
+            [0x04, 0x88, 0xB2, 0x1E]
+        };
+        self.encode_bip32(version)
+    }
+
+    /// The Base58Check extended public key, with the SLIP-132 version of the script
+    /// the key is used for, `ypub` for `sh(wpkh(...))` and `zpub` for `wpkh(...)`,
+    /// or their testnet versions when the use info names the testnet.
+    pub fn to_xpub(&self, script_expressions: &[ScriptExpression]) -> URResult<String> {
+        if self.is_private_key() {
+            return Err(URError::ValidationError(
+                "private key is not an xpub".to_string(),
+            ));
+        }
+        if self.key.len() != 33 {
+            return Err(URError::LengthMismatch(33, self.key.len()));
+        }
+        if self.chain_code.is_none() {
+            return Err(URError::ValidationError(
+                "chain code is missing".to_string(),
+            ));
+        }
+        let testnet = self
+            .use_info
+            .as_ref()
+            .map(|use_info| use_info.get_network() == Network::TestNet)
+            .unwrap_or(false);
+        let version = match (script_expressions, testnet) {
+            ([ScriptExpression::ScriptHash, ScriptExpression::WitnessPublicKeyHash], false) => {
+                [0x04, 0x9D, 0x7C, 0xB2]
+            }
+            ([ScriptExpression::ScriptHash, ScriptExpression::WitnessPublicKeyHash], true) => {
+                [0x04, 0x4A, 0x52, 0x62]
+            }
+            ([ScriptExpression::WitnessPublicKeyHash], false) => [0x04, 0xB2, 0x47, 0x46],
+            ([ScriptExpression::WitnessPublicKeyHash], true) => [0x04, 0x5F, 0x1C, 0xF6],
+            (_, false) => [0x04, 0x88, 0xB2, 0x1E],
+            (_, true) => [0x04, 0x35, 0x87, 0xCF],
+        };
+        Ok(self.encode_bip32(version))
+    }
+
+    // the 78 byte BIP-32 serialization: version, depth, parent fingerprint,
+    // child number, chain code and key
+    fn encode_bip32(&self, version: [u8; 4]) -> String {
         let mut depth: u8 = 0;
         let mut index: u32 = 0;
         let parent_fingerprint: Fingerprint = self.parent_fingerprint.unwrap_or([0, 0, 0, 0]);
         let mut chain_code = self.get_chain_code().unwrap_or(vec![0; 32]);
         let mut key = self.get_key();
-        if self.is_master() {
-            version = vec![0x04, 0x88, 0xAD, 0xE4];
-            depth = 0;
-            index = 0;
-        } else {
-            match self.get_origin() {
-                Some(x) => {
-                    depth = x.get_components().len() as u8;
-                    index = x
-                        .get_components()
-                        .last()
-                        .unwrap()
-                        .get_canonical_index()
-                        .unwrap_or(0);
-                }
-                None => {}
-            };
-            version = match self.is_private_key() {
-                true => vec![0x04, 0x88, 0xAD, 0xE4],
-                false => vec![0x04, 0x88, 0xB2, 0x1E],
-            }
+        if let (false, Some(origin)) = (self.is_master(), self.get_origin()) {
+            let components = origin.get_components();
+            depth = components.len() as u8;
+            index = components
+                .last()
+                .and_then(|component| component.get_canonical_index())
+                .unwrap_or(0);
         }
         let mut output = vec![];
-        output.append(version.as_mut()); // 4
+        output.append(version.to_vec().as_mut()); // 4
         output.append(depth.to_be_bytes().to_vec().as_mut()); // 1
         output.append(parent_fingerprint.to_vec().as_mut()); // 4
         output.append(index.to_be_bytes().to_vec().as_mut()); // 4
@@ -142,6 +177,69 @@ impl CryptoHDKey {
         bs58::encode(output).with_check().into_string()
     }
 
+    /// Derive the compressed secp256k1 public key of the non-hardened child `index`.
+    /// ed25519 keys only have hardened children, so they can't be derived from the public key.
+    #[cfg(feature = "derive")]
+    pub fn derive_pubkey(&self, index: u32) -> URResult<Vec<u8>> {
+        use bip32::{ChildNumber, ExtendedKey, ExtendedKeyAttrs, Prefix, XPub};
+
+        if self.is_private_key() {
+            return Err(URError::KeyDerivationError(
+                "private key is not supported".to_string(),
+            ));
+        }
+        let chain_code: [u8; 32] = self
+            .get_chain_code()
+            .ok_or(URError::KeyDerivationError(
+                "chain code is missing".to_string(),
+            ))?
+            .try_into()
+            .map_err(|_| URError::KeyDerivationError("chain code is invalid".to_string()))?;
+        let key_bytes: [u8; 33] = self
+            .get_key()
+            .try_into()
+            .map_err(|_| URError::KeyDerivationError("key is invalid".to_string()))?;
+        let child_number = ChildNumber::new(index, false)
+            .map_err(|e| URError::KeyDerivationError(e.to_string()))?;
+
+        // depth, parent fingerprint and child number don't take part in the derivation
+        let extended_key = ExtendedKey {
+            prefix: Prefix::XPUB,
+            attrs: ExtendedKeyAttrs {
+                depth: 0,
+                parent_fingerprint: [0; 4],
+                child_number: ChildNumber(0),
+                chain_code,
+            },
+            key_bytes,
+        };
+        let child = XPub::try_from(extended_key)
+            .and_then(|xpub| xpub.derive_child(child_number))
+            .map_err(|e| URError::KeyDerivationError(e.to_string()))?;
+        Ok(child.to_bytes().to_vec())
+    }
+
+    /// The BIP-32 fingerprint of the key itself, the first 4 bytes of the hash160 of
+    /// its compressed secp256k1 public key.
+    #[cfg(feature = "derive")]
+    pub fn key_fingerprint(&self) -> URResult<Fingerprint> {
+        use bip32::secp256k1::ecdsa::VerifyingKey;
+        use bip32::PublicKey;
+
+        if self.is_private_key() {
+            return Err(URError::KeyDerivationError(
+                "private key is not supported".to_string(),
+            ));
+        }
+        let key_bytes: [u8; 33] = self
+            .get_key()
+            .try_into()
+            .map_err(|_| URError::KeyDerivationError("key is invalid".to_string()))?;
+        let public_key = <VerifyingKey as PublicKey>::from_bytes(key_bytes)
+            .map_err(|e| URError::KeyDerivationError(e.to_string()))?;
+        Ok(public_key.fingerprint())
+    }
+
     pub fn get_account_index(&self, level: u32) -> Option<u32> {
         self.origin
             .clone()
@@ -339,7 +437,7 @@ impl<C> minicbor::Encode<C> for CryptoHDKey {
     }
 }
 
-impl<'b, C> minicbor::Decode<'b, C> for CryptoHDKey {
+impl<'b, C: DecodeContext> minicbor::Decode<'b, C> for CryptoHDKey {
     fn decode(d: &mut Decoder<'b>, ctx: &mut C) -> Result<Self, minicbor::decode::Error> {
         let mut result = CryptoHDKey::default();
         cbor_map(d, &mut result, |key, obj, d| {
@@ -353,21 +451,36 @@ impl<'b, C> minicbor::Decode<'b, C> for CryptoHDKey {
                     obj.is_private_key = Some(d.bool()?);
                 }
                 KEY_DATA => {
-                    obj.key = d.bytes()?.to_vec();
+                    obj.key = cbor_bytes(d)?;
                 }
                 CHAIN_CODE => {
-                    obj.chain_code = Some(d.bytes()?.to_vec());
+                    obj.chain_code = Some(cbor_bytes(d)?);
                 }
                 USE_INFO => {
-                    d.tag()?;
+                    cbor_tag(
+                        d,
+                        ctx,
+                        CryptoCoinInfo::get_registry_type().get_tag(),
+                        "CryptoCoinInfo tag is invalid",
+                    )?;
                     obj.use_info = Some(CryptoCoinInfo::decode(d, ctx)?);
                 }
                 ORIGIN => {
-                    d.tag()?;
+                    cbor_tag(
+                        d,
+                        ctx,
+                        CryptoKeyPath::get_registry_type().get_tag(),
+                        "CryptoKeyPath tag is invalid",
+                    )?;
                     obj.origin = Some(CryptoKeyPath::decode(d, ctx)?)
                 }
                 CHILDREN => {
-                    d.tag()?;
+                    cbor_tag(
+                        d,
+                        ctx,
+                        CryptoKeyPath::get_registry_type().get_tag(),
+                        "CryptoKeyPath tag is invalid",
+                    )?;
                     obj.children = Some(CryptoKeyPath::decode(d, ctx)?)
                 }
                 PARENT_FINGERPRINT => {
@@ -404,6 +517,7 @@ mod tests {
     use crate::crypto_coin_info::{CoinType, CryptoCoinInfo, Network};
     use crate::crypto_hd_key::CryptoHDKey;
     use crate::crypto_key_path::{CryptoKeyPath, PathComponent};
+    use crate::script_expression::ScriptExpression;
     use crate::traits::{From as FromCbor, RegistryItem, To};
     use alloc::vec;
     use alloc::vec::Vec;
@@ -499,4 +613,148 @@ mod tests {
         );
         assert_eq!("xpub6H8Qkexp9BdSgEwPAnhiEjp7NMXVEZWoAFWwon5mSwbuPZMfSUTpPwAP1Q2q2kYMRgRQ8udBpEj89wburY1vW7AWDuYpByteGogpB6pPprX", hd_key.get_bip32_key());
     }
+
+    #[cfg(feature = "derive")]
+    #[test]
+    fn test_derive_pubkey() {
+        // bip32 test vector 1, m/0' derives m/0'/1
+        let hd_key = CryptoHDKey::new_extended_key(
+            Some(false),
+            Vec::from_hex("035a784662a4a20a65bf6aab9ae98a6c068a81c52e4b032c0fb5400c706cfccc56")
+                .unwrap(),
+            Some(
+                Vec::from_hex("47fdacbd0f1097043b78c63c20c34ef4ed9a111d980047ad16282c7ae6236141")
+                    .unwrap(),
+            ),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        );
+        assert_eq!(
+            "03501e454bf00751f24b1b489aa925215d66af2234e3891c3b21a52bedb3cd711c",
+            hex::encode(hd_key.derive_pubkey(1).unwrap())
+        );
+        assert!(hd_key.derive_pubkey(0x80000000).is_err());
+
+        let without_chain_code = CryptoHDKey::new_extended_key(
+            None,
+            hd_key.get_key(),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        );
+        assert!(without_chain_code.derive_pubkey(1).is_err());
+    }
+
+    #[cfg(feature = "derive")]
+    #[test]
+    fn test_key_fingerprint() {
+        // bip32 test vector 1, m/0' is the parent of m/0'/1 with fingerprint 5c1bd648
+        let hd_key = CryptoHDKey::new_extended_key(
+            Some(false),
+            Vec::from_hex("035a784662a4a20a65bf6aab9ae98a6c068a81c52e4b032c0fb5400c706cfccc56")
+                .unwrap(),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        );
+        assert_eq!("5c1bd648", hex::encode(hd_key.key_fingerprint().unwrap()));
+
+        let invalid = CryptoHDKey::new_extended_key(
+            Some(false),
+            vec![2; 32],
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        );
+        assert!(invalid.key_fingerprint().is_err());
+    }
+
+    #[test]
+    fn test_to_xpub() {
+        let origin =
+            CryptoKeyPath::from_path("m/84'/0'/0'".to_string(), Some([0x37, 0xb5, 0xee, 0xd4]))
+                .unwrap();
+        let hd_key = CryptoHDKey::new_extended_key(
+            Some(false),
+            Vec::from_hex("03fd433450b6924b4f7efdd5d1ed017d364be95ab2b592dc8bddb3b00c1c24f63f")
+                .unwrap(),
+            Some(
+                Vec::from_hex("72ede7334d5acf91c6fda622c205199c595a31f9218ed30792d301d5ee9e3a88")
+                    .unwrap(),
+            ),
+            None,
+            Some(origin.clone()),
+            None,
+            None,
+            None,
+            None,
+        );
+        assert_eq!(
+            "xpub6BemYiVNp19a1GyXrRxLknAMK9b6DFPP7HndvFRfskTfi6Y5ti79HGH6qhkLEPSQs9faNQmYsn9o141ch7tzg5QE7WUDT5XqZbgrbk6jjri",
+            hd_key.to_xpub(&[]).unwrap()
+        );
+        assert_eq!(hd_key.get_bip32_key(), hd_key.to_xpub(&[]).unwrap());
+        assert_eq!(
+            "zpub6qKJA3qD7NEXhsMmX9XbAxMMf5sz6VNNwWq5V3DSdmDRpJAYQ2SGXPbNt7fWECkFgRuBsMxfo6rtmdEk8Wj2GYmRrBs4cuAp73p9NoBgLmx",
+            hd_key
+                .to_xpub(&[ScriptExpression::WitnessPublicKeyHash])
+                .unwrap()
+        );
+        assert_eq!(
+            "ypub6WV2rPAHxgh3raAegnjxxsFrV7jY9sNt2QJrheKZFkqYmCMK9NGhuKwEruhvEJ6LGnnP7tN7LSWLtLdBQpK1UK5pyrAe2zMKqKkVzDaRJGr",
+            hd_key
+                .to_xpub(&[
+                    ScriptExpression::ScriptHash,
+                    ScriptExpression::WitnessPublicKeyHash
+                ])
+                .unwrap()
+        );
+
+        let testnet = CryptoHDKey::new_extended_key(
+            Some(false),
+            hd_key.get_key(),
+            hd_key.get_chain_code(),
+            Some(CryptoCoinInfo::new(None, Some(Network::TestNet))),
+            Some(origin),
+            None,
+            None,
+            None,
+            None,
+        );
+        assert_eq!(
+            "vpub5XzEwP9YWe4cJgbJBiP6LbyLyDJCL1QPH4kCMTdu7jhubtudPPn238xpoHqAEa8a3sRxsTaRxTShEUnVFj4y5c32Nq5NHFts29ZZpXnbktw",
+            testnet
+                .to_xpub(&[ScriptExpression::WitnessPublicKeyHash])
+                .unwrap()
+        );
+
+        let private_key = CryptoHDKey::new_extended_key(
+            Some(true),
+            hd_key.get_key(),
+            hd_key.get_chain_code(),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        );
+        assert!(private_key.to_xpub(&[]).is_err());
+    }
 }
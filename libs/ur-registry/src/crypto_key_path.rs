@@ -6,6 +6,7 @@ use crate::types::Fingerprint;
 use alloc::string::{String, ToString};
 use alloc::vec::Vec;
 use alloc::{format, vec};
+use core::str::FromStr;
 use minicbor::data::{Int, Type};
 use minicbor::encode::Write;
 use minicbor::Encoder;
@@ -18,6 +19,7 @@ const DEPTH: u8 = 3;
 pub struct PathComponent {
     index: Option<u32>,
     wildcard: bool,
+    range: Option<(u32, u32)>,
     hardened: bool,
 }
 
@@ -35,17 +37,53 @@ impl PathComponent {
                 Ok(PathComponent {
                     index,
                     wildcard: false,
+                    range: None,
                     hardened,
                 })
             }
             None => Ok(PathComponent {
                 index,
                 wildcard: true,
+                range: None,
                 hardened,
             }),
         }
     }
 
+    /// A `*` component, which stands for every index, as in the `/0/*` children
+    /// of output descriptors. It's encoded as an empty array in place of the index.
+    pub fn wildcard(hardened: bool) -> PathComponent {
+        PathComponent {
+            index: None,
+            wildcard: true,
+            range: None,
+            hardened,
+        }
+    }
+
+    /// The indices from `low` to `high`, written `<low;high>` and encoded as a
+    /// `[low, high]` array in place of the index.
+    pub fn range(low: u32, high: u32, hardened: bool) -> Result<PathComponent, String> {
+        if (low | high) & PathComponent::HARDEN_BIT != 0 {
+            return Err(format!(
+                "Invalid range <{};{}> - most significant bit cannot be set",
+                low, high
+            ));
+        }
+        if low >= high {
+            return Err(format!(
+                "Invalid range <{};{}> - low must be less than high",
+                low, high
+            ));
+        }
+        Ok(PathComponent {
+            index: None,
+            wildcard: false,
+            range: Some((low, high)),
+            hardened,
+        })
+    }
+
     pub fn get_index(&self) -> Option<u32> {
         self.index
     }
@@ -61,6 +99,10 @@ impl PathComponent {
         self.wildcard
     }
 
+    pub fn get_range(&self) -> Option<(u32, u32)> {
+        self.range
+    }
+
     pub fn is_hardened(&self) -> bool {
         self.hardened
     }
@@ -88,12 +130,42 @@ impl CryptoKeyPath {
     pub fn get_components(&self) -> Vec<PathComponent> {
         self.components.clone()
     }
+    /// The components without copying them, for checking e.g. the coin type
+    /// with [`PathComponent::get_index`] and [`PathComponent::is_hardened`].
+    pub fn components(&self) -> &[PathComponent] {
+        &self.components
+    }
     pub fn get_source_fingerprint(&self) -> Option<Fingerprint> {
         self.source_fingerprint
     }
+
+    /// Whether the path derives from the master key with `fingerprint`, false
+    /// when the path carries no source fingerprint.
+    pub fn matches_fingerprint(&self, fingerprint: Fingerprint) -> bool {
+        self.source_fingerprint == Some(fingerprint)
+    }
     pub fn get_depth(&self) -> Option<u32> {
         self.depth
     }
+
+    /// Sets the depth, which has to match the number of components unless
+    /// the components are omitted.
+    pub fn with_depth(mut self, depth: u32) -> URResult<Self> {
+        self.depth = Some(depth);
+        self.validate_depth()?;
+        Ok(self)
+    }
+
+    pub fn validate_depth(&self) -> URResult<()> {
+        match self.depth {
+            Some(depth)
+                if !self.components.is_empty() && depth as usize != self.components.len() =>
+            {
+                Err(URError::DepthMismatch(depth, self.components.len()))
+            }
+            _ => Ok(()),
+        }
+    }
     pub fn get_path(&self) -> Option<String> {
         if self.components.is_empty() {
             return None;
@@ -102,6 +174,10 @@ impl CryptoKeyPath {
             self.components
                 .iter()
                 .map::<String, fn(&PathComponent) -> String>(|component| {
+                    if let Some((low, high)) = component.range {
+                        let suffix = if component.hardened { "'" } else { "" };
+                        return format!("<{};{}>{}", low, high, suffix);
+                    }
                     match (component.wildcard, component.hardened) {
                         (true, true) => "*'".to_string(),
                         (true, false) => "*".to_string(),
@@ -119,6 +195,9 @@ impl CryptoKeyPath {
         let chunks = remove_prefix
             .split('/')
             .map(|split| match split.chars().last() {
+                _ if split == "*" => Ok(PathComponent::wildcard(false)),
+                _ if split == "*'" => Ok(PathComponent::wildcard(true)),
+                _ if split.starts_with('<') => parse_range(split),
                 Some('\'') => {
                     let mut remove_quote = split.to_string();
                     remove_quote.pop();
@@ -129,6 +208,7 @@ impl CryptoKeyPath {
                         hardened: true,
                         index: Some(index),
                         wildcard: false,
+                        range: None,
                     })
                 }
                 Some(_) => {
@@ -138,6 +218,7 @@ impl CryptoKeyPath {
                         hardened: false,
                         index: Some(index),
                         wildcard: false,
+                        range: None,
                     })
                 }
                 _ => Err("Invalid Path".to_string()),
@@ -151,6 +232,47 @@ impl CryptoKeyPath {
     }
 }
 
+// parses a `<low;high>` component, optionally hardened
+fn parse_range(split: &str) -> Result<PathComponent, String> {
+    let (range, hardened) = match split.strip_suffix('\'') {
+        Some(range) => (range, true),
+        None => (split, false),
+    };
+    let invalid = || format!("Invalid range: {}", split);
+    let (low, high) = range
+        .strip_prefix('<')
+        .and_then(|range| range.strip_suffix('>'))
+        .and_then(|range| range.split_once(';'))
+        .ok_or_else(invalid)?;
+    let low = low.parse().map_err(|_| invalid())?;
+    let high = high.parse().map_err(|_| invalid())?;
+    PathComponent::range(low, high, hardened)
+}
+
+/// Parses a path such as `m/44'/784'/0'`, with or without the leading `m/`,
+/// ignoring surrounding whitespace. The source fingerprint is left unset.
+impl FromStr for CryptoKeyPath {
+    type Err = URError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let path = s.trim();
+        let path = path
+            .strip_prefix(['m', 'M'])
+            .filter(|rest| rest.is_empty() || rest.starts_with('/'))
+            .unwrap_or(path);
+        let path = path.trim_start_matches('/');
+        if path.is_empty() {
+            return Err(URError::EmptyPath);
+        }
+        let normalized = path
+            .split('/')
+            .map(|component| component.trim())
+            .collect::<Vec<&str>>()
+            .join("/");
+        CryptoKeyPath::from_path(normalized, None).map_err(URError::ValidationError)
+    }
+}
+
 impl RegistryItem for CryptoKeyPath {
     fn get_registry_type() -> RegistryType<'static> {
         CRYPTO_KEYPATH
@@ -179,6 +301,8 @@ impl<C> minicbor::Encode<C> for CryptoKeyPath {
         for component in self.components.iter() {
             if component.is_wildcard() {
                 e.array(0)?;
+            } else if let Some((low, high)) = component.range {
+                e.array(2)?.u32(low)?.u32(high)?;
             } else {
                 match component.index {
                     Some(index) => {
@@ -233,11 +357,25 @@ impl<'b, C> minicbor::Decode<'b, C> for CryptoKeyPath {
                     let mut hardened = false;
                     let mut previous_type: Type = Type::Null;
                     let mut path_index: Option<u32> = None;
+                    let mut path_range: Option<(u32, u32)> = None;
                     cbor_array(d, obj, |_index, _obj, d| {
                         let data_type = cbor_type(d.datatype()?);
                         match data_type {
                             Type::Array => {
-                                d.array()?;
+                                let mut bounds: Vec<u32> = vec![];
+                                cbor_array(d, &mut bounds, |_index, array, d| {
+                                    array.push(d.u32()?);
+                                    Ok(())
+                                })?;
+                                path_range = match bounds[..] {
+                                    [] => None,
+                                    [low, high] => Some((low, high)),
+                                    _ => {
+                                        return Err(minicbor::decode::Error::message(
+                                            "path component array is invalid",
+                                        ))
+                                    }
+                                };
                                 previous_type = Type::Array;
                             }
                             Type::Int => {
@@ -249,12 +387,15 @@ impl<'b, C> minicbor::Decode<'b, C> for CryptoKeyPath {
                             Type::Bool => {
                                 hardened = d.bool()?;
                                 match previous_type {
-                                    Type::Array => {
-                                        path_component
-                                            .push(PathComponent::new(None, hardened).map_err(
-                                                |e| minicbor::decode::Error::message(e),
-                                            )?);
-                                    }
+                                    Type::Array => match path_range {
+                                        Some((low, high)) => path_component.push(
+                                            PathComponent::range(low, high, hardened)
+                                                .map_err(minicbor::decode::Error::message)?,
+                                        ),
+                                        None => {
+                                            path_component.push(PathComponent::wildcard(hardened))
+                                        }
+                                    },
                                     Type::Int => {
                                         path_component.push(
                                             PathComponent::new(path_index, hardened)
@@ -305,9 +446,12 @@ impl FromCbor<CryptoKeyPath> for CryptoKeyPath {
 #[cfg(test)]
 mod tests {
     use crate::crypto_key_path::{CryptoKeyPath, PathComponent};
+    use crate::error::URError;
     use crate::traits::{From as FromCbor, RegistryItem, To};
+    use alloc::string::ToString;
     use alloc::vec;
     use alloc::vec::Vec;
+    use core::str::FromStr;
     use hex::FromHex;
 
     #[test]
@@ -382,4 +526,163 @@ mod tests {
         let crypto = CryptoKeyPath::from_cbor(bytes).unwrap();
         assert_eq!(crypto.get_path().unwrap(), "44'/118'/0'/0/0");
     }
+
+    #[test]
+    fn test_from_str() {
+        let path = CryptoKeyPath::from_str("m/44'/784'").unwrap();
+        assert_eq!(path.get_path().unwrap(), "44'/784'");
+        assert_eq!(path.get_source_fingerprint(), None);
+
+        let path = CryptoKeyPath::from_str("44'/784'").unwrap();
+        assert_eq!(path.get_path().unwrap(), "44'/784'");
+
+        let path = CryptoKeyPath::from_str("  M/44' / 784'/0 ").unwrap();
+        assert_eq!(path.get_path().unwrap(), "44'/784'/0");
+    }
+
+    #[test]
+    fn test_from_str_empty_path() {
+        assert_eq!(CryptoKeyPath::from_str(""), Err(URError::EmptyPath));
+        assert_eq!(CryptoKeyPath::from_str(" m/ "), Err(URError::EmptyPath));
+        assert_eq!(
+            CryptoKeyPath::from_str("m/44'/x"),
+            Err(URError::ValidationError("Invalid index: x".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_source_fingerprint() {
+        let fingerprint = [0x78, 0x23, 0x08, 0x04];
+        let path =
+            CryptoKeyPath::from_path("m/44'/118'/0'".to_string(), Some(fingerprint)).unwrap();
+        assert_eq!(Some(fingerprint), path.get_source_fingerprint());
+        assert!(path.matches_fingerprint(fingerprint));
+        assert!(!path.matches_fingerprint([0x73, 0xc5, 0xda, 0x0a]));
+
+        let path = CryptoKeyPath::from_path("m/44'/118'/0'".to_string(), None).unwrap();
+        assert!(!path.matches_fingerprint(fingerprint));
+    }
+
+    #[test]
+    fn test_with_depth() {
+        let path = CryptoKeyPath::from_str("m/44'/784'/0'").unwrap();
+        let with_depth = path.clone().with_depth(3).unwrap();
+        assert_eq!(Some(3), with_depth.get_depth());
+        assert_eq!(path.get_components(), with_depth.get_components());
+
+        let bytes = with_depth.to_bytes().unwrap();
+        assert_eq!(Some(3), CryptoKeyPath::from_cbor(bytes).unwrap().get_depth());
+
+        // only the depth is known when the components are omitted
+        let hidden = CryptoKeyPath::new(vec![], Some([0x78, 0x23, 0x08, 0x04]), None);
+        assert_eq!(Some(5), hidden.with_depth(5).unwrap().get_depth());
+    }
+
+    #[test]
+    fn test_with_depth_mismatch() {
+        let path = CryptoKeyPath::from_str("m/44'/784'/0'").unwrap();
+        assert_eq!(Err(URError::DepthMismatch(2, 3)), path.with_depth(2));
+
+        let path = CryptoKeyPath::new(
+            vec![PathComponent::new(Some(44), true).unwrap()],
+            None,
+            Some(4),
+        );
+        assert_eq!(Err(URError::DepthMismatch(4, 1)), path.validate_depth());
+    }
+
+    #[test]
+    fn test_wildcard() {
+        let path = CryptoKeyPath::new(
+            vec![
+                PathComponent::new(Some(84), true).unwrap(),
+                PathComponent::new(Some(0), true).unwrap(),
+                PathComponent::wildcard(true),
+            ],
+            None,
+            None,
+        );
+        let bytes = path.to_bytes().unwrap();
+        assert_eq!("a101861854f500f580f5", hex::encode(&bytes));
+
+        let decoded = CryptoKeyPath::from_cbor(bytes).unwrap();
+        assert_eq!(path, decoded);
+        let last = decoded.get_components()[2];
+        assert!(last.is_wildcard());
+        assert!(last.is_hardened());
+        assert_eq!(None, last.get_index());
+        assert_eq!("84'/0'/*'", decoded.get_path().unwrap());
+        assert_eq!(path, CryptoKeyPath::from_str("m/84'/0'/*'").unwrap());
+        assert_eq!(
+            Ok(PathComponent::wildcard(false)),
+            PathComponent::new(None, false)
+        );
+    }
+
+    #[test]
+    fn test_range() {
+        let path = CryptoKeyPath::new(
+            vec![
+                PathComponent::new(Some(84), true).unwrap(),
+                PathComponent::range(0, 1, false).unwrap(),
+                PathComponent::wildcard(false),
+            ],
+            None,
+            None,
+        );
+        let bytes = path.to_bytes().unwrap();
+        assert_eq!("a101861854f5820001f480f4", hex::encode(&bytes));
+
+        let decoded = CryptoKeyPath::from_cbor(bytes).unwrap();
+        assert_eq!(path, decoded);
+        let range = decoded.get_components()[1];
+        assert_eq!(Some((0, 1)), range.get_range());
+        assert!(!range.is_wildcard());
+        assert_eq!(None, range.get_index());
+        assert_eq!("84'/<0;1>/*", decoded.get_path().unwrap());
+        assert_eq!(path, CryptoKeyPath::from_str("m/84'/<0;1>/*").unwrap());
+
+        let hardened = CryptoKeyPath::from_str("m/<2;5>'").unwrap();
+        assert_eq!(Some((2, 5)), hardened.get_components()[0].get_range());
+        assert!(hardened.get_components()[0].is_hardened());
+        assert_eq!("<2;5>'", hardened.get_path().unwrap());
+
+        assert!(PathComponent::range(1, 1, false).is_err());
+        assert!(PathComponent::range(0, PathComponent::HARDEN_BIT, false).is_err());
+        assert!(CryptoKeyPath::from_str("m/<0;x>").is_err());
+        assert!(CryptoKeyPath::from_str("m/<0,1>").is_err());
+    }
+
+    #[test]
+    fn test_decode_indefinite_arrays() {
+        // the path of `test_range` with the components, the range and the
+        // wildcard written as indefinite-length arrays
+        let bytes = Vec::from_hex("a1019f1854f59f0001fff49ffff4ff").unwrap();
+        let path = CryptoKeyPath::from_cbor(bytes).unwrap();
+        assert_eq!("84'/<0;1>/*", path.get_path().unwrap());
+        assert_eq!(
+            "a101861854f5820001f480f4",
+            hex::encode(path.to_bytes().unwrap())
+        );
+
+        let bytes = Vec::from_hex("a1019f1854f59f00fff4ff").unwrap();
+        assert!(CryptoKeyPath::from_cbor(bytes).is_err());
+    }
+
+    #[test]
+    fn test_components() {
+        // the derivation path of a sui-sign-request
+        let bytes = Vec::from_hex("a2018a182cf5190310f500f500f500f5021a78230804").unwrap();
+        let path = CryptoKeyPath::from_cbor(bytes).unwrap();
+        let components = path
+            .components()
+            .iter()
+            .map(|component| (component.get_index().unwrap(), component.is_hardened()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            vec![(44, true), (784, true), (0, true), (0, true), (0, true)],
+            components
+        );
+        assert_eq!(Some(784), path.components()[1].get_index());
+    }
 }
@@ -1,11 +1,13 @@
 use crate::crypto_ec_key::CryptoECKey;
 use crate::crypto_hd_key::CryptoHDKey;
+use crate::decode::DecodeContext;
 use crate::error::{URError, URResult};
 use crate::multi_key::MultiKey;
 use crate::registry_types::{RegistryType, CRYPTO_ECKEY, CRYPTO_HDKEY, CRYPTO_OUTPUT};
 use crate::script_expression::ScriptExpression;
 use crate::traits::{From as FromCbor, RegistryItem, To};
-use alloc::string::ToString;
+use alloc::format;
+use alloc::string::{String, ToString};
 use alloc::vec;
 use alloc::vec::Vec;
 use minicbor::data::{Tag, Type};
@@ -54,6 +56,70 @@ impl CryptoOutput {
     pub fn get_multi_key(&self) -> Option<MultiKey> {
         self.multi_key.clone()
     }
+
+    pub(crate) fn hd_key_ref(&self) -> Option<&CryptoHDKey> {
+        self.hd_key.as_ref()
+    }
+
+    /// The output descriptor text of the item, such as
+    /// `wpkh([37b5eed4/84'/0'/0']xpub.../0/*)`. EC keys are written as hex.
+    pub fn to_descriptor_string(&self) -> String {
+        let mut descriptor = match (&self.ec_key, &self.hd_key, &self.multi_key) {
+            (Some(ec_key), _, _) => hex::encode(ec_key.get_data()),
+            (_, Some(hd_key), _) => hd_key_descriptor(hd_key),
+            (_, _, Some(multi_key)) => {
+                let mut keys = vec![multi_key.get_threshold().to_string()];
+                keys.extend(
+                    multi_key
+                        .get_ec_keys()
+                        .unwrap_or_default()
+                        .iter()
+                        .map(|ec_key| hex::encode(ec_key.get_data())),
+                );
+                keys.extend(
+                    multi_key
+                        .get_hd_keys()
+                        .unwrap_or_default()
+                        .iter()
+                        .map(hd_key_descriptor),
+                );
+                keys.join(",")
+            }
+            _ => String::new(),
+        };
+        for script_expression in self.script_expressions.iter().rev() {
+            let name = match script_expression {
+                ScriptExpression::SortedMultiSig => "sortedmulti".to_string(),
+                _ => script_expression.clone().get_expression(),
+            };
+            descriptor = format!("{}({})", name, descriptor);
+        }
+        descriptor
+    }
+}
+
+fn hd_key_descriptor(hd_key: &CryptoHDKey) -> String {
+    let mut descriptor = String::new();
+    if let Some(origin) = hd_key.get_origin() {
+        if let Some(fingerprint) = origin.get_source_fingerprint() {
+            descriptor.push('[');
+            descriptor.push_str(&hex::encode(fingerprint));
+            if let Some(path) = origin.get_path() {
+                descriptor.push('/');
+                descriptor.push_str(&path);
+            }
+            descriptor.push(']');
+        }
+    }
+    descriptor.push_str(&hd_key.get_bip32_key());
+    if let Some(path) = hd_key
+        .get_children()
+        .and_then(|children| children.get_path())
+    {
+        descriptor.push('/');
+        descriptor.push_str(&path);
+    }
+    descriptor
 }
 
 impl RegistryItem for CryptoOutput {
@@ -89,7 +155,7 @@ impl<C> minicbor::Encode<C> for CryptoOutput {
     }
 }
 
-impl<'b, C> minicbor::Decode<'b, C> for CryptoOutput {
+impl<'b, C: DecodeContext> minicbor::Decode<'b, C> for CryptoOutput {
     fn decode(d: &mut Decoder<'b>, ctx: &mut C) -> Result<Self, minicbor::decode::Error> {
         let mut result = CryptoOutput::default();
         let mut script_expressions: Vec<ScriptExpression> = vec![];
@@ -234,4 +300,62 @@ mod tests {
             crypto.get_script_expressions()
         );
     }
+    #[test]
+    fn test_decode_wpkh_hd_key() {
+        // wpkh([37b5eed4/84'/0'/0']xpub.../0/*) from a watch-only export
+        let bytes = Vec::from_hex(
+            "d90194d9012fa403582103fd433450b6924b4f7efdd5d1ed017d364be95ab2b592dc8bddb3b00c1c24f63f04582072ede7334d5acf91c6fda622c205199c595a31f9218ed30792d301d5ee9e3a8806d90130a201861854f500f500f5021a37b5eed407d90130a1018400f480f4",
+        )
+        .unwrap();
+        let crypto = CryptoOutput::from_cbor(bytes).unwrap();
+        assert_eq!(
+            vec![ScriptExpression::WitnessPublicKeyHash],
+            crypto.get_script_expressions()
+        );
+        assert!(crypto.get_ec_key().is_none());
+        let hd_key = crypto.get_hd_key().unwrap();
+        let origin = hd_key.get_origin().unwrap();
+        assert_eq!("84'/0'/0'", origin.get_path().unwrap());
+        assert_eq!(
+            Some([0x37, 0xb5, 0xee, 0xd4]),
+            origin.get_source_fingerprint()
+        );
+        assert_eq!("0/*", hd_key.get_children().unwrap().get_path().unwrap());
+    }
+
+    #[test]
+    fn test_to_descriptor_string() {
+        let bytes = Vec::from_hex(
+            "d90194d9012fa403582103fd433450b6924b4f7efdd5d1ed017d364be95ab2b592dc8bddb3b00c1c24f63f04582072ede7334d5acf91c6fda622c205199c595a31f9218ed30792d301d5ee9e3a8806d90130a201861854f500f500f5021a37b5eed407d90130a1018400f480f4",
+        )
+        .unwrap();
+        let crypto = CryptoOutput::from_cbor(bytes).unwrap();
+        assert_eq!(
+            "wpkh([37b5eed4/84'/0'/0']xpub6BemYiVNp19a1GyXrRxLknAMK9b6DFPP7HndvFRfskTfi6Y5ti79HGH6qhkLEPSQs9faNQmYsn9o141ch7tzg5QE7WUDT5XqZbgrbk6jjri/0/*)",
+            crypto.to_descriptor_string()
+        );
+
+        let bytes = Vec::from_hex(
+            "d90190d90196a201020282d90132a1035821022f01e5e15cca351daff3843fb70f3c2f0a1bdd05e5af888a67784ef3e10a2a01d90132a103582103acd484e2f0c7f65309ad178a9f559abde09796974c57e714c35f110dfc27ccbe",
+        )
+        .unwrap();
+        let crypto = CryptoOutput::from_cbor(bytes).unwrap();
+        assert_eq!(
+            "sh(multi(2,022f01e5e15cca351daff3843fb70f3c2f0a1bdd05e5af888a67784ef3e10a2a01,03acd484e2f0c7f65309ad178a9f559abde09796974c57e714c35f110dfc27ccbe))",
+            crypto.to_descriptor_string()
+        );
+
+        let sorted = CryptoOutput::new(
+            vec![
+                ScriptExpression::WitnessScriptHash,
+                ScriptExpression::SortedMultiSig,
+            ],
+            None,
+            None,
+            crypto.get_multi_key(),
+        );
+        assert!(sorted
+            .to_descriptor_string()
+            .starts_with("wsh(sortedmulti(2,022f01e5"));
+    }
 }
@@ -1,9 +1,16 @@
+use crate::cbor::cbor_bytes;
 use crate::error::{URError, URResult};
 use crate::registry_types::{RegistryType, CRYPTO_PSBT};
 use crate::traits::{From as FromCbor, RegistryItem, To};
 use crate::types::Bytes;
+#[cfg(feature = "base64")]
+use alloc::string::String;
 use alloc::string::ToString;
 use alloc::vec::Vec;
+#[cfg(feature = "base64")]
+use base64::prelude::BASE64_STANDARD;
+#[cfg(feature = "base64")]
+use base64::Engine;
 use minicbor::encode::Write;
 use minicbor::{Decoder, Encoder};
 
@@ -24,6 +31,21 @@ impl CryptoPSBT {
     pub fn set_psbt(&mut self, psbt: Bytes) {
         self.psbt = psbt;
     }
+
+    /// A Bitcoin PSBT from its base64 form, as wallets usually pass it around.
+    #[cfg(feature = "base64")]
+    pub fn from_base64(psbt: &str) -> URResult<Self> {
+        let psbt = BASE64_STANDARD
+            .decode(psbt)
+            .map_err(|e| URError::Base64DecodeError(e.to_string()))?;
+        Ok(Self::new(psbt))
+    }
+
+    /// The PSBT in base64.
+    #[cfg(feature = "base64")]
+    pub fn to_base64(&self) -> String {
+        BASE64_STANDARD.encode(&self.psbt)
+    }
 }
 
 impl RegistryItem for CryptoPSBT {
@@ -46,7 +68,7 @@ impl<C> minicbor::Encode<C> for CryptoPSBT {
 impl<'b, C> minicbor::Decode<'b, C> for CryptoPSBT {
     fn decode(d: &mut Decoder<'b>, _ctx: &mut C) -> Result<Self, minicbor::decode::Error> {
         Ok(Self {
-            psbt: d.bytes()?.to_vec(),
+            psbt: cbor_bytes(d)?,
         })
     }
 }
@@ -68,6 +90,7 @@ mod tests {
     use crate::crypto_psbt::CryptoPSBT;
     use crate::traits::RegistryItem;
     use alloc::vec::Vec;
+    use base64::Engine;
     use hex::FromHex;
 
     #[test]
@@ -98,4 +121,36 @@ mod tests {
                 .unwrap()
         );
     }
+
+    #[test]
+    fn test_round_trip_base64_psbt() {
+        // an unsigned psbt of one p2wpkh input and output, as wallets export it
+        let psbt = base64::prelude::BASE64_STANDARD
+            .decode("cHNidP8BAFICAAAAAVjoeiG1ba8MI76OcHBFbDNvfLqlyHV5JPVFiHuyq911AAAAAAD9////AbiCAQAAAAAAFgAUAAECAwQFBgcICQoLDA0ODxAREhMAAAAAAAAA")
+            .unwrap();
+        let result: Vec<u8> = CryptoPSBT::new(psbt.clone()).try_into().unwrap();
+        assert_eq!("585d70736274ff0100", hex::encode(&result[..9]));
+        assert_eq!(psbt, result[2..]);
+
+        let ur = ur::encode(&result, CryptoPSBT::get_registry_type().get_type());
+        let (_, bytes) = ur::decode(&ur).unwrap();
+        let crypto = CryptoPSBT::try_from(bytes).unwrap();
+        assert_eq!(psbt, crypto.get_psbt());
+    }
+
+    #[cfg(feature = "base64")]
+    #[test]
+    fn test_base64() {
+        use crate::error::URError;
+
+        let base64 = "cHNidP8BAFICAAAAAVjoeiG1ba8MI76OcHBFbDNvfLqlyHV5JPVFiHuyq911AAAAAAD9////AbiCAQAAAAAAFgAUAAECAwQFBgcICQoLDA0ODxAREhMAAAAAAAAA";
+        let crypto = CryptoPSBT::from_base64(base64).unwrap();
+        assert_eq!("70736274ff", hex::encode(&crypto.get_psbt()[..5]));
+        assert_eq!(base64, crypto.to_base64());
+
+        assert!(matches!(
+            CryptoPSBT::from_base64("cHNidP8=="),
+            Err(URError::Base64DecodeError(_))
+        ));
+    }
 }
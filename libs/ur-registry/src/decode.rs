@@ -0,0 +1,155 @@
+use crate::error::{URError, URResult};
+use alloc::string::ToString;
+use minicbor::data::Type;
+use minicbor::{Decode, Decoder};
+
+#[derive(Clone, Debug)]
+pub struct DecodeOptions {
+    // accept an item wrapped in a one element array, as some emitters do
+    pub unwrap_single_array: bool,
+    // reject a value without its expected tag, such as the UUID of a request_id;
+    // some legacy firmware omits them
+    pub strict_tags: bool,
+}
+
+impl Default for DecodeOptions {
+    fn default() -> Self {
+        DecodeOptions {
+            unwrap_single_array: false,
+            strict_tags: true,
+        }
+    }
+}
+
+/// The options a decoder reads from the minicbor context. Decoding with the
+/// unit context, as `TryFrom` does, is strict.
+pub trait DecodeContext {
+    fn strict_tags(&self) -> bool {
+        true
+    }
+
+    /// Receives the typed error of a value of the wrong CBOR type, just before
+    /// the decoder fails with a plain minicbor error.
+    fn unexpected_type(&mut self, _error: URError) {}
+}
+
+impl DecodeContext for () {}
+
+impl DecodeContext for DecodeOptions {
+    fn strict_tags(&self) -> bool {
+        self.strict_tags
+    }
+}
+
+pub fn decode_with_options<T>(bytes: &[u8], options: &DecodeOptions) -> URResult<T>
+where
+    T: for<'b> Decode<'b, DecodeOptions>,
+{
+    let mut d = Decoder::new(bytes);
+    let mut ctx = options.clone();
+    let wrapped = options.unwrap_single_array
+        && matches!(
+            d.datatype()
+                .map_err(|e| URError::CborDecodeError(e.to_string()))?,
+            Type::Array
+        );
+    if wrapped {
+        match d.array() {
+            Ok(Some(1)) => {}
+            Ok(_) => {
+                return Err(URError::CborDecodeError(
+                    "expected an array with exactly one item".to_string(),
+                ))
+            }
+            Err(e) => return Err(URError::CborDecodeError(e.to_string())),
+        }
+    }
+    T::decode(&mut d, &mut ctx).map_err(|e| URError::CborDecodeError(e.to_string()))
+}
+
+/// Decodes `bytes` accepting values whose tag is missing or unexpected.
+pub fn decode_lenient<T>(bytes: &[u8]) -> URResult<T>
+where
+    T: for<'b> Decode<'b, DecodeOptions>,
+{
+    let options = DecodeOptions {
+        strict_tags: false,
+        ..Default::default()
+    };
+    decode_with_options(bytes, &options)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sui::sui_sign_request::SuiSignRequest;
+    use alloc::format;
+    use alloc::vec::Vec;
+
+    const SUI_SIGN_REQUEST: &str = "a401d825509b1deb4d3b7d4bad9bdd2b0d7b3dcb6d024800000000000200200381d90130a1018a182cf5190310f500f500f500f5068158491ff915a5e9e32fdbe0135535b6c69a00a9809aaf7f7c0275d3239ca79db20d6433b701000000000020176c4727433105da34209f04ac3f22e192a2573d7948cb2fabde7d13a7f4f149";
+
+    #[test]
+    fn test_unwrap_single_array() {
+        let options = DecodeOptions {
+            unwrap_single_array: true,
+            ..Default::default()
+        };
+        let bare = hex::decode(SUI_SIGN_REQUEST).unwrap();
+        let wrapped = hex::decode(format!("81{}", SUI_SIGN_REQUEST)).unwrap();
+
+        let from_bare: SuiSignRequest = decode_with_options(&bare, &options).unwrap();
+        let from_wrapped: SuiSignRequest = decode_with_options(&wrapped, &options).unwrap();
+        let expected: Vec<u8> = from_bare.try_into().unwrap();
+        let actual: Vec<u8> = from_wrapped.try_into().unwrap();
+        assert_eq!(expected, actual);
+        assert_eq!(bare, actual);
+    }
+
+    #[test]
+    fn test_wrapped_rejected_by_default() {
+        let wrapped = hex::decode(format!("81{}", SUI_SIGN_REQUEST)).unwrap();
+        let result: URResult<SuiSignRequest> =
+            decode_with_options(&wrapped, &DecodeOptions::default());
+        assert!(result.is_err());
+
+        let options = DecodeOptions {
+            unwrap_single_array: true,
+            ..Default::default()
+        };
+        let two_items = hex::decode(format!("82{0}{0}", SUI_SIGN_REQUEST)).unwrap();
+        let result: URResult<SuiSignRequest> = decode_with_options(&two_items, &options);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_lenient_tags() {
+        const REQUEST_ID: &str = "9b1deb4d3b7d4bad9bdd2b0d7b3dcb6d";
+        let tagged = hex::decode(SUI_SIGN_REQUEST).unwrap();
+        // the same request with the UUID tag of the request_id left out
+        let untagged = hex::decode(SUI_SIGN_REQUEST.replacen("01d82550", "0150", 1)).unwrap();
+
+        let strict: SuiSignRequest =
+            decode_with_options(&tagged, &DecodeOptions::default()).unwrap();
+        let lenient: SuiSignRequest = decode_lenient(&tagged).unwrap();
+        assert_eq!(
+            Some(hex::decode(REQUEST_ID).unwrap()),
+            strict.get_request_id()
+        );
+        assert_eq!(strict.get_request_id(), lenient.get_request_id());
+
+        let result: URResult<SuiSignRequest> =
+            decode_with_options(&untagged, &DecodeOptions::default());
+        assert!(
+            matches!(result, Err(URError::CborDecodeError(message)) if message.contains("UUID tag is invalid"))
+        );
+        assert!(SuiSignRequest::try_from(untagged.clone()).is_err());
+
+        let lenient: SuiSignRequest = decode_lenient(&untagged).unwrap();
+        assert_eq!(
+            Some(hex::decode(REQUEST_ID).unwrap()),
+            lenient.get_request_id()
+        );
+        let encoded: Vec<u8> = lenient.try_into().unwrap();
+        assert_eq!(tagged, encoded);
+    }
+}
@@ -0,0 +1,99 @@
+use alloc::string::ToString;
+use minicbor::data::Int;
+
+use crate::cbor::{cbor_bytes, cbor_map, cbor_unknown_field, cbor_unknown_fields};
+use crate::impl_template_struct;
+use crate::registry_types::{RegistryType, ERGO_HINT_BAG};
+use crate::traits::{MapSize, RegistryItem};
+use crate::types::Bytes;
+
+const HINTS: u8 = 1;
+
+impl_template_struct!(ErgoHintBag { hints: Bytes }, unknown_fields);
+
+impl RegistryItem for ErgoHintBag {
+    fn get_registry_type() -> RegistryType<'static> {
+        ERGO_HINT_BAG
+    }
+}
+
+impl MapSize for ErgoHintBag {
+    fn map_size(&self) -> u64 {
+        1 + self.unknown_fields.len() as u64
+    }
+}
+
+impl<C> minicbor::Encode<C> for ErgoHintBag {
+    fn encode<W: minicbor::encode::Write>(
+        &self,
+        e: &mut minicbor::Encoder<W>,
+        _ctx: &mut C,
+    ) -> Result<(), minicbor::encode::Error<W::Error>> {
+        e.map(self.map_size())?;
+        e.int(Int::from(HINTS))?.bytes(&self.hints)?;
+        cbor_unknown_fields(e, &self.unknown_fields)?;
+        Ok(())
+    }
+}
+
+impl<'b, C> minicbor::Decode<'b, C> for ErgoHintBag {
+    fn decode(
+        d: &mut minicbor::Decoder<'b>,
+        _ctx: &mut C,
+    ) -> Result<Self, minicbor::decode::Error> {
+        let mut result = ErgoHintBag::default();
+
+        cbor_map(d, &mut result, |key, obj, d| {
+            let key =
+                u8::try_from(key).map_err(|e| minicbor::decode::Error::message(e.to_string()))?;
+            match key {
+                HINTS => {
+                    obj.hints = cbor_bytes(d)?;
+                }
+                _ => {
+                    cbor_unknown_field(d, key, &mut obj.unknown_fields)?;
+                }
+            }
+            Ok(())
+        })?;
+
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    use crate::cbor::with_unknown_key;
+    use crate::types::UnknownFields;
+
+    use super::*;
+
+    #[test]
+    fn test_encode() {
+        let hint_bag = ErgoHintBag::new(hex::decode("0102030405").unwrap());
+        let result: Vec<u8> = hint_bag.try_into().unwrap();
+        assert_eq!("a101450102030405", hex::encode(result));
+    }
+
+    #[test]
+    fn test_decode() {
+        let result = ErgoHintBag::try_from(hex::decode("a101450102030405").unwrap()).unwrap();
+        assert_eq!(hex::decode("0102030405").unwrap(), result.get_hints());
+    }
+
+    #[test]
+    fn test_unknown_fields_round_trip() {
+        let bytes = with_unknown_key(hex::decode("a101450102030405").unwrap());
+        let result = ErgoHintBag::try_from(bytes.clone()).unwrap();
+        assert_eq!(hex::decode("0102030405").unwrap(), result.get_hints());
+        assert_eq!(
+            UnknownFields::from([(15, vec![0x82, 0x01, 0x02])]),
+            result.get_unknown_fields()
+        );
+        let encoded: Vec<u8> = result.try_into().unwrap();
+        assert_eq!(bytes, encoded);
+    }
+}
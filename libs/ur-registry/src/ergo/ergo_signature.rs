@@ -0,0 +1,143 @@
+use alloc::string::ToString;
+use minicbor::data::{Int, Tag};
+
+use crate::cbor::{cbor_bytes, cbor_map, cbor_tag, cbor_unknown_field, cbor_unknown_fields};
+use crate::decode::DecodeContext;
+use crate::ergo::ergo_hint_bag::ErgoHintBag;
+use crate::impl_template_struct;
+use crate::impl_validate_signature;
+use crate::registry_types::{RegistryType, ERGO_HINT_BAG, ERGO_SIGNATURE, UUID};
+use crate::traits::{MapSize, RegistryItem};
+use crate::types::Bytes;
+
+const REQUEST_ID: u8 = 1;
+const SIGNATURE: u8 = 2;
+const HINT_BAG: u8 = 3;
+
+impl_template_struct!(
+    ErgoSignature {
+        request_id: Option<Bytes>,
+        signature: Bytes,
+        hint_bag: Option<ErgoHintBag>
+    },
+    unknown_fields
+);
+
+impl_validate_signature!(ErgoSignature);
+
+impl RegistryItem for ErgoSignature {
+    fn get_registry_type() -> RegistryType<'static> {
+        ERGO_SIGNATURE
+    }
+}
+
+impl MapSize for ErgoSignature {
+    fn map_size(&self) -> u64 {
+        let mut size = 1;
+        if self.request_id.is_some() {
+            size += 1;
+        }
+        if self.hint_bag.is_some() {
+            size += 1;
+        }
+        size + self.unknown_fields.len() as u64
+    }
+}
+
+impl<C> minicbor::Encode<C> for ErgoSignature {
+    fn encode<W: minicbor::encode::Write>(
+        &self,
+        e: &mut minicbor::Encoder<W>,
+        ctx: &mut C,
+    ) -> Result<(), minicbor::encode::Error<W::Error>> {
+        e.map(self.map_size())?;
+        if let Some(request_id) = &self.request_id {
+            e.int(Int::from(REQUEST_ID))?
+                .tag(Tag::Unassigned(UUID.get_tag()))?
+                .bytes(request_id)?;
+        }
+        e.int(Int::from(SIGNATURE))?.bytes(&self.signature)?;
+        if let Some(hint_bag) = &self.hint_bag {
+            e.int(Int::from(HINT_BAG))?
+                .tag(Tag::Unassigned(ERGO_HINT_BAG.get_tag()))?;
+            ErgoHintBag::encode(hint_bag, e, ctx)?;
+        }
+        cbor_unknown_fields(e, &self.unknown_fields)?;
+        Ok(())
+    }
+}
+
+impl<'b, C: DecodeContext> minicbor::Decode<'b, C> for ErgoSignature {
+    fn decode(d: &mut minicbor::Decoder<'b>, ctx: &mut C) -> Result<Self, minicbor::decode::Error> {
+        let mut result = ErgoSignature::default();
+
+        cbor_map(d, &mut result, |key, obj, d| {
+            let key =
+                u8::try_from(key).map_err(|e| minicbor::decode::Error::message(e.to_string()))?;
+            match key {
+                REQUEST_ID => {
+                    cbor_tag(d, ctx, UUID.get_tag(), "UUID tag is invalid")?;
+                    obj.request_id = Some(cbor_bytes(d)?);
+                }
+                SIGNATURE => {
+                    obj.signature = cbor_bytes(d)?;
+                }
+                HINT_BAG => {
+                    let tag = d.tag()?;
+                    if !tag.eq(&Tag::Unassigned(ERGO_HINT_BAG.get_tag())) {
+                        return Err(minicbor::decode::Error::message(
+                            "ErgoHintBag tag is invalid",
+                        ));
+                    }
+                    obj.hint_bag = Some(ErgoHintBag::decode(d, ctx)?);
+                }
+                _ => {
+                    cbor_unknown_field(d, key, &mut obj.unknown_fields)?;
+                }
+            }
+            Ok(())
+        })?;
+
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        let signature = ErgoSignature::new(
+            Some(hex::decode("9b1deb4d3b7d4bad9bdd2b0d7b3dcb6d").unwrap()),
+            hex::decode("deadbeef").unwrap(),
+            Some(ErgoHintBag::new(hex::decode("0102030405").unwrap())),
+        );
+        let result: Vec<u8> = signature.try_into().unwrap();
+        assert_eq!(
+            "a301d825509b1deb4d3b7d4bad9bdd2b0d7b3dcb6d0244deadbeef03d92137a101450102030405",
+            hex::encode(&result)
+        );
+
+        let decoded = ErgoSignature::try_from(result).unwrap();
+        assert_eq!(
+            Some(hex::decode("9b1deb4d3b7d4bad9bdd2b0d7b3dcb6d").unwrap()),
+            decoded.get_request_id()
+        );
+        assert_eq!(hex::decode("deadbeef").unwrap(), decoded.get_signature());
+        assert_eq!(
+            hex::decode("0102030405").unwrap(),
+            decoded.get_hint_bag().unwrap().get_hints()
+        );
+    }
+
+    #[test]
+    fn test_decode_without_hint_bag() {
+        let result = ErgoSignature::try_from(hex::decode("a10244deadbeef").unwrap()).unwrap();
+        assert_eq!(None, result.get_request_id());
+        assert_eq!(hex::decode("deadbeef").unwrap(), result.get_signature());
+        assert!(result.get_hint_bag().is_none());
+    }
+}
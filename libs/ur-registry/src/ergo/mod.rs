@@ -0,0 +1,2 @@
+pub mod ergo_hint_bag;
+pub mod ergo_signature;
@@ -21,6 +21,9 @@ pub enum URError {
     #[error("not a ur")]
     NotAUr,
 
+    #[error("bytewords checksum mismatch")]
+    ChecksumMismatch,
+
     #[error("not specified type")]
     TypeUnspecified,
 
@@ -35,6 +38,45 @@ pub enum URError {
 
     #[error("gzip encode failed, reason: `{0}`")]
     GzipEncodeError(String),
+
+    #[error("base64 decode failed, reason: `{0}`")]
+    Base64DecodeError(String),
+
+    #[error("hex decode failed, reason: `{0}`")]
+    HexDecodeError(String),
+
+    #[error("address decode failed, reason: `{0}`")]
+    AddressDecodeError(String),
+
+    #[error("validation failed, reason: `{0}`")]
+    ValidationError(String),
+
+    #[error("key derivation failed, reason: `{0}`")]
+    KeyDerivationError(String),
+
+    #[error("signature recovery failed, reason: `{0}`")]
+    SignatureRecoveryError(String),
+
+    #[error("path is empty")]
+    EmptyPath,
+
+    #[error("depth `{0}` does not match `{1}` path components")]
+    DepthMismatch(u32, usize),
+
+    #[error("expected `{0}` bytes, received `{1}`")]
+    LengthMismatch(usize, usize),
+
+    #[error("ur of `{0}` characters exceeds the single QR limit of `{1}`")]
+    SingleQrOverflow(usize, usize),
+
+    #[error("unexpected cbor type, expected `{expected}`, received `{got}`")]
+    UnexpectedType { expected: String, got: String },
+
+    #[error("request_id is all zeros")]
+    ZeroRequestId,
+
+    #[error("plugin for `{0}` is already registered")]
+    PluginConflict(String),
 }
 
 pub type URResult<T> = Result<T, URError>;
@@ -1,13 +1,21 @@
-use crate::cbor::cbor_map;
+use crate::cbor::{
+    cbor_bytes, cbor_expect_type, cbor_map, cbor_origin, cbor_tag, cbor_unknown_field,
+    cbor_unknown_fields,
+};
 use crate::crypto_key_path::CryptoKeyPath;
+use crate::decode::DecodeContext;
 use crate::error::{URError, URResult};
+use crate::impl_with_new_request_id;
 use crate::registry_types::{RegistryType, CRYPTO_KEYPATH, ETH_SIGN_REQUEST, UUID};
-use crate::traits::{From as FromCbor, RegistryItem, To};
-use crate::types::Bytes;
+use crate::traits::{
+    Expiry, From as FromCbor, MinFirmware, RegistryItem, To, Validate, BASELINE_FIRMWARE,
+};
+use crate::types::{Bytes, UnknownFields};
+use crate::validate::{validate_path, validate_request_id, validate_sign_data};
 use alloc::format;
 use alloc::string::{String, ToString};
 use alloc::vec::Vec;
-use minicbor::data::{Int, Tag};
+use minicbor::data::{Int, Tag, Type};
 use minicbor::encode::Write;
 use minicbor::{Decoder, Encoder};
 
@@ -18,6 +26,14 @@ const CHAIN_ID: u8 = 4;
 const DERIVATION_PATH: u8 = 5;
 const ADDRESS: u8 = 6;
 const ORIGIN: u8 = 7;
+const EXPIRES_AT: u8 = 8;
+const NONCE: u8 = 9;
+const GAS_LIMIT: u8 = 10;
+const MAX_FEE_PER_GAS: u8 = 11;
+
+// keys 1 to 7 are the baseline, the fee preview keys came in one revision
+const EXPIRES_AT_FIRMWARE: &str = "1.1.0";
+const FEE_PREVIEW_FIRMWARE: &str = "1.2.0";
 
 #[derive(Clone, Debug, PartialEq, Default)]
 pub enum DataType {
@@ -52,8 +68,18 @@ pub struct EthSignRequest {
     derivation_path: CryptoKeyPath,
     address: Option<Bytes>,
     origin: Option<String>,
+    expires_at: Option<u64>,
+    // a preview of the fee fields of `sign_data`, so the device can show them
+    // without decoding the RLP
+    nonce: Option<u64>,
+    gas_limit: Option<u64>,
+    max_fee_per_gas: Option<Bytes>,
+    // keys of a newer version of the format, written back as they were read
+    unknown_fields: UnknownFields,
 }
 
+impl_with_new_request_id!(EthSignRequest);
+
 impl EthSignRequest {
     pub fn default() -> Self {
         Default::default()
@@ -87,6 +113,24 @@ impl EthSignRequest {
         self.origin = Some(origin)
     }
 
+    pub fn set_expires_at(&mut self, expires_at: u64) {
+        self.expires_at = Some(expires_at)
+    }
+
+    pub fn set_nonce(&mut self, nonce: u64) {
+        self.nonce = Some(nonce)
+    }
+
+    pub fn set_gas_limit(&mut self, gas_limit: u64) {
+        self.gas_limit = Some(gas_limit)
+    }
+
+    /// Sets the max fee per gas in wei, as big-endian bytes since it may
+    /// exceed 64 bits.
+    pub fn set_max_fee_per_gas(&mut self, max_fee_per_gas: Bytes) {
+        self.max_fee_per_gas = Some(max_fee_per_gas)
+    }
+
     pub fn new(
         request_id: Option<Bytes>,
         sign_data: Bytes,
@@ -104,6 +148,11 @@ impl EthSignRequest {
             derivation_path,
             address,
             origin,
+            expires_at: None,
+            nonce: None,
+            gas_limit: None,
+            max_fee_per_gas: None,
+            unknown_fields: UnknownFields::new(),
         }
     }
     pub fn get_request_id(&self) -> Option<Bytes> {
@@ -127,6 +176,18 @@ impl EthSignRequest {
     pub fn get_origin(&self) -> Option<String> {
         self.origin.clone()
     }
+    pub fn get_nonce(&self) -> Option<u64> {
+        self.nonce
+    }
+    pub fn get_gas_limit(&self) -> Option<u64> {
+        self.gas_limit
+    }
+    pub fn get_max_fee_per_gas(&self) -> Option<Bytes> {
+        self.max_fee_per_gas.clone()
+    }
+    pub fn get_unknown_fields(&self) -> UnknownFields {
+        self.unknown_fields.clone()
+    }
 
     fn get_map_size(&self) -> u64 {
         let mut size = 3;
@@ -142,7 +203,19 @@ impl EthSignRequest {
         if self.origin.is_some() {
             size += 1;
         }
-        size
+        if self.expires_at.is_some() {
+            size += 1;
+        }
+        if self.nonce.is_some() {
+            size += 1;
+        }
+        if self.gas_limit.is_some() {
+            size += 1;
+        }
+        if self.max_fee_per_gas.is_some() {
+            size += 1;
+        }
+        size + self.unknown_fields.len() as u64
     }
 }
 
@@ -152,6 +225,33 @@ impl RegistryItem for EthSignRequest {
     }
 }
 
+impl Expiry for EthSignRequest {
+    fn expires_at(&self) -> Option<u64> {
+        self.expires_at
+    }
+}
+
+impl MinFirmware for EthSignRequest {
+    fn min_firmware(&self) -> &'static str {
+        if self.nonce.is_some() || self.gas_limit.is_some() || self.max_fee_per_gas.is_some() {
+            return FEE_PREVIEW_FIRMWARE;
+        }
+        if self.expires_at.is_some() {
+            return EXPIRES_AT_FIRMWARE;
+        }
+        BASELINE_FIRMWARE
+    }
+}
+
+impl Validate for EthSignRequest {
+    fn validate_schema(&self) -> URResult<()> {
+        validate_request_id(&self.request_id)?;
+        validate_sign_data("sign_data", &self.sign_data)?;
+        validate_path("derivation_path", &self.derivation_path, None)?;
+        Ok(())
+    }
+}
+
 impl<C> minicbor::Encode<C> for EthSignRequest {
     fn encode<W: Write>(
         &self,
@@ -190,25 +290,44 @@ impl<C> minicbor::Encode<C> for EthSignRequest {
             e.int(Int::from(ORIGIN))?.str(origin)?;
         }
 
+        if let Some(expires_at) = self.expires_at {
+            e.int(Int::from(EXPIRES_AT))?.u64(expires_at)?;
+        }
+
+        if let Some(nonce) = self.nonce {
+            e.int(Int::from(NONCE))?.u64(nonce)?;
+        }
+
+        if let Some(gas_limit) = self.gas_limit {
+            e.int(Int::from(GAS_LIMIT))?.u64(gas_limit)?;
+        }
+
+        if let Some(max_fee_per_gas) = &self.max_fee_per_gas {
+            e.int(Int::from(MAX_FEE_PER_GAS))?.bytes(max_fee_per_gas)?;
+        }
+
+        cbor_unknown_fields(e, &self.unknown_fields)?;
         Ok(())
     }
 }
 
-impl<'b, C> minicbor::Decode<'b, C> for EthSignRequest {
-    fn decode(d: &mut Decoder<'b>, _ctx: &mut C) -> Result<Self, minicbor::decode::Error> {
+impl<'b, C: DecodeContext> minicbor::Decode<'b, C> for EthSignRequest {
+    fn decode(d: &mut Decoder<'b>, ctx: &mut C) -> Result<Self, minicbor::decode::Error> {
         let mut result = EthSignRequest::default();
         cbor_map(d, &mut result, |key, obj, d| {
             let key =
                 u8::try_from(key).map_err(|e| minicbor::decode::Error::message(e.to_string()))?;
             match key {
                 REQUEST_ID => {
-                    d.tag()?;
-                    obj.request_id = Some(d.bytes()?.to_vec());
+                    cbor_tag(d, ctx, UUID.get_tag(), "UUID tag is invalid")?;
+                    obj.request_id = Some(cbor_bytes(d)?);
                 }
                 SIGN_DATA => {
-                    obj.sign_data = d.bytes()?.to_vec();
+                    cbor_expect_type(d, ctx, Type::Bytes)?;
+                    obj.sign_data = cbor_bytes(d)?;
                 }
                 DATA_TYPE => {
+                    cbor_expect_type(d, ctx, Type::U32)?;
                     obj.data_type = DataType::from_u32(
                         u32::try_from(d.int()?)
                             .map_err(|e| minicbor::decode::Error::message(e.to_string()))?,
@@ -216,19 +335,42 @@ impl<'b, C> minicbor::Decode<'b, C> for EthSignRequest {
                     .map_err(minicbor::decode::Error::message)?;
                 }
                 CHAIN_ID => {
+                    cbor_expect_type(d, ctx, Type::Int)?;
                     obj.chain_id = Some(i128::from(d.int()?));
                 }
                 DERIVATION_PATH => {
-                    d.tag()?;
-                    obj.derivation_path = CryptoKeyPath::decode(d, _ctx)?;
+                    cbor_tag(
+                        d,
+                        ctx,
+                        CryptoKeyPath::get_registry_type().get_tag(),
+                        "CryptoKeyPath tag is invalid",
+                    )?;
+                    obj.derivation_path = CryptoKeyPath::decode(d, ctx)?;
                 }
                 ADDRESS => {
-                    obj.address = Some(d.bytes()?.to_vec());
+                    obj.address = Some(cbor_bytes(d)?);
                 }
                 ORIGIN => {
-                    obj.origin = Some(d.str()?.to_string());
+                    obj.origin = Some(cbor_origin(d)?);
+                }
+                EXPIRES_AT => {
+                    cbor_expect_type(d, ctx, Type::U64)?;
+                    obj.expires_at = Some(d.u64()?);
+                }
+                NONCE => {
+                    cbor_expect_type(d, ctx, Type::U64)?;
+                    obj.nonce = Some(d.u64()?);
+                }
+                GAS_LIMIT => {
+                    cbor_expect_type(d, ctx, Type::U64)?;
+                    obj.gas_limit = Some(d.u64()?);
+                }
+                MAX_FEE_PER_GAS => {
+                    obj.max_fee_per_gas = Some(cbor_bytes(d)?);
+                }
+                _ => {
+                    cbor_unknown_field(d, key, &mut obj.unknown_fields)?;
                 }
-                _ => {}
             }
             Ok(())
         })?;
@@ -314,6 +456,119 @@ mod tests {
         assert_eq!(DataType::Transaction, eth_sign_request.get_data_type());
     }
 
+    #[test]
+    fn test_decode_without_fee_preview() {
+        // the request of test_decode, encoded before the fee preview keys existed
+        let bytes = Vec::from_hex(
+            "a601d825509b1deb4d3b7d4bad9bdd2b0d7b3dcb6d02584bf849808609184e72a00082271094000000000000000000000000000000000000000080a47f74657374320000000000000000000000000000000000000000000000000000006000578080800301040105d90130a2018a182cf501f501f500f401f4021a1234567807686d6574616d61736b",
+        )
+        .unwrap();
+        let eth_sign_request = EthSignRequest::from_cbor(bytes).unwrap();
+        assert_eq!(None, eth_sign_request.get_nonce());
+        assert_eq!(None, eth_sign_request.get_gas_limit());
+        assert_eq!(None, eth_sign_request.get_max_fee_per_gas());
+    }
+
+    #[test]
+    fn test_fee_preview_round_trip() {
+        let crypto_key_path =
+            CryptoKeyPath::from_path("m/44'/60'/0'/0/0".to_string(), None).unwrap();
+        let mut eth_sign_request = EthSignRequest::new(
+            None,
+            vec![0x02, 0xc0],
+            DataType::TypedTransaction,
+            Some(1),
+            crypto_key_path,
+            None,
+            None,
+        );
+        eth_sign_request.set_nonce(7);
+        eth_sign_request.set_gas_limit(21000);
+        // 30 gwei
+        eth_sign_request.set_max_fee_per_gas(hex::decode("06fc23ac00").unwrap());
+
+        let bytes = eth_sign_request.to_bytes().unwrap();
+        assert_eq!(
+            "a7024202c00304040105d90130a1018a182cf5183cf500f500f400f409070a1952080b4506fc23ac00",
+            hex::encode(&bytes)
+        );
+        let decoded = EthSignRequest::from_cbor(bytes).unwrap();
+        assert_eq!(Some(7), decoded.get_nonce());
+        assert_eq!(Some(21000), decoded.get_gas_limit());
+        assert_eq!(
+            Some(hex::decode("06fc23ac00").unwrap()),
+            decoded.get_max_fee_per_gas()
+        );
+    }
+
+    #[test]
+    fn test_min_firmware() {
+        let crypto_key_path =
+            CryptoKeyPath::from_path("m/44'/60'/0'/0/0".to_string(), None).unwrap();
+        let mut eth_sign_request = EthSignRequest::new(
+            None,
+            vec![0x02, 0xc0],
+            DataType::TypedTransaction,
+            Some(1),
+            crypto_key_path,
+            None,
+            None,
+        );
+        assert_eq!("1.0.0", eth_sign_request.min_firmware());
+
+        eth_sign_request.set_expires_at(1760400000);
+        assert_eq!("1.1.0", eth_sign_request.min_firmware());
+
+        eth_sign_request.set_gas_limit(21000);
+        assert_eq!("1.2.0", eth_sign_request.min_firmware());
+    }
+
+    #[test]
+    fn test_decode_indefinite_sign_data() {
+        // sign_data as the chunks 02 and c0 of an indefinite-length byte string
+        let bytes = hex::decode(
+            "a7025f410241c0ff0304040105d90130a1018a182cf5183cf500f500f400f409070a1952080b4506fc23ac00",
+        )
+        .unwrap();
+        let eth_sign_request = EthSignRequest::from_cbor(bytes).unwrap();
+        assert_eq!(vec![0x02, 0xc0], eth_sign_request.get_sign_data());
+        assert_eq!(Some(21000), eth_sign_request.get_gas_limit());
+        assert_eq!(
+            "a7024202c00304040105d90130a1018a182cf5183cf500f500f400f409070a1952080b4506fc23ac00",
+            hex::encode(eth_sign_request.to_bytes().unwrap())
+        );
+    }
+
+    #[test]
+    fn test_decode_unexpected_type() {
+        // data_type is the float 1.0
+        let bytes = hex::decode("a202410003fa3f800000").unwrap();
+        assert_eq!(
+            Err(URError::UnexpectedType {
+                expected: "u32".to_string(),
+                got: "f32".to_string(),
+            }),
+            EthSignRequest::try_from(bytes).map(|_| ())
+        );
+
+        // chain_id is the text string "1"
+        let bytes = hex::decode("a2024100046131").unwrap();
+        assert!(matches!(
+            EthSignRequest::try_from(bytes),
+            Err(URError::UnexpectedType { .. })
+        ));
+
+        // gas_limit is the float 1.0
+        let bytes = hex::decode("a20241000afa3f800000").unwrap();
+        assert_eq!(
+            Err(URError::UnexpectedType {
+                expected: "u64".to_string(),
+                got: "f32".to_string(),
+            }),
+            EthSignRequest::try_from(bytes).map(|_| ())
+        );
+    }
+
     #[test]
     fn test_avax_c_chain_encode() {
         let path1 = PathComponent::new(Some(44), true).unwrap();
@@ -347,4 +602,17 @@ mod tests {
         let ur = ur::encode(&data, EthSignRequest::get_registry_type().get_type());
         assert_eq!(ur, "ur:eth-sign-request/oladtpdagdndcawmgtfrkigrpmndutdnbtkgfssbjnaohdktaoyajylfpdinadlrcasnihaelrmdaoytaelfgmaymwfgpdenoltllabttepynyjemegsmhglyaadkgfdsplobtsgsrguwpcpknaelartadnbfnwmswgrgrtllpiorlcxhdmswnynldcpsrwnfwenjeeyenzooyinrnonpyltgmlrnbgmmetnwlcwbehtsartuoghkkwpwnweksmhtafndrpavydsmdwnvswprfmooxdmhtaxaaaacfpdinahtaaddyoeadlecsdwykcsfnykaeykaewkamwkaocyrywyvdlfatjeiajljpihcxkthsjzjzihjyfwkouyfp");
     }
+
+    #[test]
+    fn test_unknown_fields_round_trip() {
+        // the request of `test_encode` with the array [1, 2] at the unused key 15
+        let bytes = hex::decode("a701d825509b1deb4d3b7d4bad9bdd2b0d7b3dcb6d02584bf849808609184e72a00082271094000000000000000000000000000000000000000080a47f74657374320000000000000000000000000000000000000000000000000000006000578080800301040105d90130a2018a182cf501f501f500f401f4021a1234567807686d6574616d61736b0f820102").unwrap();
+        let request = EthSignRequest::from_cbor(bytes.clone()).unwrap();
+        assert_eq!(Some("metamask".to_string()), request.get_origin());
+        assert_eq!(
+            UnknownFields::from([(15, vec![0x82, 0x01, 0x02])]),
+            request.get_unknown_fields()
+        );
+        assert_eq!(bytes, request.to_bytes().unwrap());
+    }
 }
@@ -1,8 +1,17 @@
-use crate::cbor::cbor_map;
+use crate::cbor::{
+    cbor_bytes, cbor_map, cbor_origin, cbor_tag, cbor_tx_hash, cbor_unknown_field,
+    cbor_unknown_fields, cbor_write_tx_hash,
+};
+use crate::decode::DecodeContext;
 use crate::error::{URError, URResult};
+#[cfg(feature = "crypto")]
+use crate::ethereum::eth_sign_request::{DataType, EthSignRequest};
+use crate::impl_validate_signature;
 use crate::registry_types::{RegistryType, ETH_SIGNATURE, UUID};
 use crate::traits::{From as FromCbor, RegistryItem, To};
-use crate::types::Bytes;
+use crate::types::{Bytes, UnknownFields};
+#[cfg(feature = "crypto")]
+use alloc::format;
 use alloc::string::{String, ToString};
 use alloc::vec::Vec;
 use minicbor::data::{Int, Tag};
@@ -12,14 +21,20 @@ use minicbor::{Decoder, Encoder};
 const REQUEST_ID: u8 = 1;
 const SIGNATURE: u8 = 2;
 const ORIGIN: u8 = 3;
+const TX_HASH: u8 = 4;
 
 #[derive(Clone, Debug, Default)]
 pub struct EthSignature {
     request_id: Option<Bytes>,
     signature: Bytes,
     origin: Option<String>,
+    // hash of the transaction as computed by the device
+    tx_hash: Option<Bytes>,
+    unknown_fields: UnknownFields,
 }
 
+impl_validate_signature!(EthSignature);
+
 impl EthSignature {
     pub fn default() -> Self {
         Default::default()
@@ -37,11 +52,17 @@ impl EthSignature {
         self.origin = Some(origin)
     }
 
+    pub fn set_tx_hash(&mut self, tx_hash: Bytes) {
+        self.tx_hash = Some(tx_hash)
+    }
+
     pub fn new(request_id: Option<Bytes>, signature: Bytes, origin: Option<String>) -> Self {
         EthSignature {
             request_id,
             signature,
             origin,
+            tx_hash: None,
+            unknown_fields: UnknownFields::new(),
         }
     }
 
@@ -54,6 +75,70 @@ impl EthSignature {
     pub fn get_origin(&self) -> Option<String> {
         self.origin.clone()
     }
+    pub fn get_tx_hash(&self) -> Option<Bytes> {
+        self.tx_hash.clone()
+    }
+
+    /// Recovers the address that signed the payload of `request`, so the host can
+    /// check it against the expected signer. EIP-712 typed data isn't hashed by the
+    /// crate and is rejected.
+    #[cfg(feature = "crypto")]
+    pub fn recover_address(&self, request: &EthSignRequest) -> URResult<[u8; 20]> {
+        use bip32::secp256k1::ecdsa::{RecoveryId, Signature, VerifyingKey};
+        use bip32::secp256k1::elliptic_curve::sec1::ToEncodedPoint;
+        use sha3::{Digest, Keccak256};
+
+        let sign_data = request.get_sign_data();
+        let prehash = match request.get_data_type() {
+            DataType::Transaction | DataType::TypedTransaction => Keccak256::digest(&sign_data),
+            DataType::PersonalMessage => Keccak256::new()
+                .chain_update(format!("\x19Ethereum Signed Message:\n{}", sign_data.len()))
+                .chain_update(&sign_data)
+                .finalize(),
+            DataType::TypedData => {
+                return Err(URError::SignatureRecoveryError(
+                    "typed data is not supported".to_string(),
+                ))
+            }
+        };
+
+        // r and s, followed by a v of up to 8 bytes
+        if !(65..=72).contains(&self.signature.len()) {
+            return Err(URError::SignatureRecoveryError(format!(
+                "signature of {} bytes is invalid",
+                self.signature.len()
+            )));
+        }
+        let signature = Signature::from_slice(&self.signature[..64])
+            .map_err(|e| URError::SignatureRecoveryError(e.to_string()))?;
+        let v = self.signature[64..]
+            .iter()
+            .fold(0u64, |v, byte| v << 8 | u64::from(*byte));
+        // 0/1 in typed transactions, 27/28 in messages and chain_id * 2 + 35/36 with EIP-155
+        let recovery_id = match v {
+            0 | 1 => v,
+            27 | 28 => v - 27,
+            v if v >= 35 => (v - 35) % 2,
+            v => {
+                return Err(URError::SignatureRecoveryError(format!(
+                    "v {} is invalid",
+                    v
+                )))
+            }
+        };
+        let recovery_id = RecoveryId::from_byte(recovery_id as u8)
+            .ok_or(URError::SignatureRecoveryError("v is invalid".to_string()))?;
+        let public_key = VerifyingKey::recover_from_prehash(&prehash, &signature, recovery_id)
+            .map_err(|e| URError::SignatureRecoveryError(e.to_string()))?;
+
+        let hash = Keccak256::digest(&public_key.to_encoded_point(false).as_bytes()[1..]);
+        let mut address = [0u8; 20];
+        address.copy_from_slice(&hash[12..]);
+        Ok(address)
+    }
+    pub fn get_unknown_fields(&self) -> UnknownFields {
+        self.unknown_fields.clone()
+    }
 }
 
 impl RegistryItem for EthSignature {
@@ -75,7 +160,10 @@ impl<C> minicbor::Encode<C> for EthSignature {
         if self.origin.is_some() {
             size += 1;
         }
-        e.map(size)?;
+        if self.tx_hash.is_some() {
+            size += 1;
+        }
+        e.map(size + self.unknown_fields.len() as u64)?;
         if let Some(request_id) = &self.request_id {
             e.int(Int::from(REQUEST_ID))?
                 .tag(Tag::Unassigned(UUID.get_tag()))?
@@ -86,29 +174,36 @@ impl<C> minicbor::Encode<C> for EthSignature {
         if let Some(origin) = &self.origin {
             e.int(Int::from(ORIGIN))?.str(origin)?;
         }
+        cbor_write_tx_hash(e, TX_HASH, &self.tx_hash)?;
 
+        cbor_unknown_fields(e, &self.unknown_fields)?;
         Ok(())
     }
 }
 
-impl<'b, C> minicbor::Decode<'b, C> for EthSignature {
-    fn decode(d: &mut Decoder<'b>, _ctx: &mut C) -> Result<Self, minicbor::decode::Error> {
+impl<'b, C: DecodeContext> minicbor::Decode<'b, C> for EthSignature {
+    fn decode(d: &mut Decoder<'b>, ctx: &mut C) -> Result<Self, minicbor::decode::Error> {
         let mut result = EthSignature::default();
         cbor_map(d, &mut result, |key, obj, d| {
             let key =
                 u8::try_from(key).map_err(|e| minicbor::decode::Error::message(e.to_string()))?;
             match key {
                 REQUEST_ID => {
-                    d.tag()?;
-                    obj.request_id = Some(d.bytes()?.to_vec());
+                    cbor_tag(d, ctx, UUID.get_tag(), "UUID tag is invalid")?;
+                    obj.request_id = Some(cbor_bytes(d)?);
                 }
                 SIGNATURE => {
-                    obj.signature = d.bytes()?.to_vec();
+                    obj.signature = cbor_bytes(d)?;
                 }
                 ORIGIN => {
-                    obj.origin = Some(d.str()?.to_string());
+                    obj.origin = Some(cbor_origin(d)?);
+                }
+                TX_HASH => {
+                    obj.tx_hash = Some(cbor_tx_hash(d)?);
+                }
+                _ => {
+                    cbor_unknown_field(d, key, &mut obj.unknown_fields)?;
                 }
-                _ => {}
             }
             Ok(())
         })?;
@@ -130,9 +225,12 @@ impl FromCbor<EthSignature> for EthSignature {
 
 #[cfg(test)]
 mod tests {
+    use crate::decode::decode_lenient;
+    use crate::error::URError;
     use crate::ethereum::eth_signature::EthSignature;
-    use crate::traits::{From as FromCbor, To};
+    use crate::traits::{From as FromCbor, To, Validate};
     use alloc::string::ToString;
+    use alloc::vec;
     use alloc::vec::Vec;
     use hex::FromHex;
 
@@ -181,4 +279,118 @@ mod tests {
         );
         assert_eq!("keystone", eth_signature.get_origin().unwrap())
     }
+
+    #[test]
+    fn test_decode_lenient_tags() {
+        const SIGNATURE: &str = "a301d825509b1deb4d3b7d4bad9bdd2b0d7b3dcb6d025841d4f0a7bcd95bba1fbb1051885054730e3f47064288575aacc102fbbf6a9a14daa066991e360d3e3406c20c00a40973eff37c7d641e5b351ec4a99bfe86f335f71303686b657973746f6e65";
+        // the request_id under the tag of a key path instead of a UUID
+        let mistagged = Vec::from_hex(SIGNATURE.replacen("01d82550", "01d9013050", 1)).unwrap();
+        assert!(matches!(
+            EthSignature::from_cbor(mistagged.clone()),
+            Err(URError::CborDecodeError(message)) if message.contains("UUID tag is invalid")
+        ));
+
+        let lenient: EthSignature = decode_lenient(&mistagged).unwrap();
+        assert_eq!(
+            Vec::from_hex("9b1deb4d3b7d4bad9bdd2b0d7b3dcb6d").unwrap(),
+            lenient.get_request_id().unwrap()
+        );
+        assert_eq!(
+            Vec::from_hex(SIGNATURE).unwrap(),
+            lenient.to_bytes().unwrap()
+        );
+    }
+
+    #[test]
+    fn test_validate_request_id() {
+        let signature = vec![0u8; 65];
+        let zero_id = EthSignature::new(Some(vec![0u8; 16]), signature.clone(), None);
+        assert_eq!(Err(URError::ZeroRequestId), zero_id.validate_schema());
+
+        let request_id = hex::decode("9b1deb4d3b7d4bad9bdd2b0d7b3dcb6d").unwrap();
+        let eth_signature = EthSignature::new(Some(request_id), signature.clone(), None);
+        assert!(eth_signature.validate_schema().is_ok());
+        assert!(EthSignature::new(None, signature, None)
+            .validate_schema()
+            .is_ok());
+    }
+
+    #[test]
+    fn test_tx_hash_round_trip() {
+        let mut eth_signature = EthSignature::new(
+            Some(Vec::from_hex("9b1deb4d3b7d4bad9bdd2b0d7b3dcb6d").unwrap()),
+            Vec::from_hex("deadbeef").unwrap(),
+            Some("keystone".to_string()),
+        );
+        let tx_hash = (0..32).collect::<Vec<u8>>();
+        eth_signature.set_tx_hash(tx_hash.clone());
+        let result = eth_signature.to_bytes().unwrap();
+        assert_eq!(
+            "a401d825509b1deb4d3b7d4bad9bdd2b0d7b3dcb6d0244deadbeef03686b657973746f6e65045820000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f",
+            hex::encode(&result)
+        );
+
+        let eth_signature = EthSignature::from_cbor(result).unwrap();
+        assert_eq!(Some(tx_hash), eth_signature.get_tx_hash());
+        assert_eq!("keystone", eth_signature.get_origin().unwrap());
+    }
+
+    #[test]
+    fn test_tx_hash_invalid_length() {
+        let bytes = Vec::from_hex(
+            "a20244deadbeef04581f000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e",
+        )
+        .unwrap();
+        assert!(EthSignature::from_cbor(bytes).is_err());
+
+        let mut eth_signature = EthSignature::default();
+        eth_signature.set_tx_hash([0u8; 31].to_vec());
+        assert!(eth_signature.to_bytes().is_err());
+    }
+
+    #[cfg(feature = "crypto")]
+    #[test]
+    fn test_recover_address() {
+        use crate::crypto_key_path::CryptoKeyPath;
+        use crate::ethereum::eth_sign_request::{DataType, EthSignRequest};
+
+        let path = CryptoKeyPath::from_path("m/44'/60'/0'/0/0".to_string(), None).unwrap();
+        let mut request = EthSignRequest::new(
+            None,
+            b"hello".to_vec(),
+            DataType::PersonalMessage,
+            Some(1),
+            path,
+            None,
+            None,
+        );
+        let signature = EthSignature::new(
+            None,
+            Vec::from_hex("544e77a4dbfad982648d8e4c293bc8bb5185e04417b4b073d7155c4ee55a13504fd9efb84f9f6d06e6fb7d82c18275d450d0026cd2269638ea175511cc9ccb361c").unwrap(),
+            None,
+        );
+        assert_eq!(
+            "8651d8a27970959c38556bc42a2bb7275cdc97c8",
+            hex::encode(signature.recover_address(&request).unwrap())
+        );
+
+        // the same signature over the unprefixed hash belongs to someone else
+        request.set_data_type(DataType::TypedTransaction);
+        assert_eq!(
+            "b5b5927b00e718522f61a18bc5e9cf5cb6819b0e",
+            hex::encode(signature.recover_address(&request).unwrap())
+        );
+
+        request.set_data_type(DataType::TypedData);
+        assert!(matches!(
+            signature.recover_address(&request),
+            Err(URError::SignatureRecoveryError(_))
+        ));
+        request.set_data_type(DataType::PersonalMessage);
+        let truncated = EthSignature::new(None, vec![0u8; 64], None);
+        assert!(matches!(
+            truncated.recover_address(&request),
+            Err(URError::SignatureRecoveryError(_))
+        ));
+    }
 }
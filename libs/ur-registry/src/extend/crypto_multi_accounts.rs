@@ -1,5 +1,6 @@
 use crate::cbor::{cbor_array, cbor_map};
 use crate::crypto_hd_key::CryptoHDKey;
+use crate::decode::DecodeContext;
 use crate::error::{URError, URResult};
 use crate::registry_types::{RegistryType, CRYPTO_HDKEY, CRYPTO_MULTI_ACCOUNTS};
 use crate::traits::{From as FromCbor, RegistryItem, To};
@@ -17,6 +18,14 @@ const DEVICE: u8 = 3;
 const DEVICE_ID: u8 = 4;
 const DEVICE_VERSION: u8 = 5;
 
+/// The keys that differ between two exports of the same accounts, matched by
+/// their derivation path, or by their key data when either has no path.
+#[derive(Clone, Debug, Default)]
+pub struct AccountDiff {
+    pub added: Vec<CryptoHDKey>,
+    pub removed: Vec<CryptoHDKey>,
+}
+
 #[derive(Default, Clone, Debug)]
 pub struct CryptoMultiAccounts {
     master_fingerprint: Fingerprint,
@@ -86,6 +95,26 @@ impl CryptoMultiAccounts {
     pub fn get_device_version(&self) -> Option<String> {
         self.device_version.clone()
     }
+
+    /// The keys of `other` missing from `self` are added, the keys of `self`
+    /// missing from `other` are removed.
+    pub fn diff(&self, other: &Self) -> AccountDiff {
+        let path = |key: &CryptoHDKey| key.get_origin().and_then(|origin| origin.get_path());
+        let same = |a: &CryptoHDKey, b: &CryptoHDKey| match (path(a), path(b)) {
+            (Some(a), Some(b)) => a == b,
+            _ => a.get_key() == b.get_key(),
+        };
+        let missing = |keys: &[CryptoHDKey], from: &[CryptoHDKey]| -> Vec<CryptoHDKey> {
+            keys.iter()
+                .filter(|&key| !from.iter().any(|other| same(other, key)))
+                .cloned()
+                .collect()
+        };
+        AccountDiff {
+            added: missing(&other.keys, &self.keys),
+            removed: missing(&self.keys, &other.keys),
+        }
+    }
 }
 
 impl RegistryItem for CryptoMultiAccounts {
@@ -135,7 +164,7 @@ impl<C> minicbor::Encode<C> for CryptoMultiAccounts {
     }
 }
 
-impl<'b, C> minicbor::Decode<'b, C> for CryptoMultiAccounts {
+impl<'b, C: DecodeContext> minicbor::Decode<'b, C> for CryptoMultiAccounts {
     fn decode(d: &mut Decoder<'b>, ctx: &mut C) -> Result<Self, minicbor::decode::Error> {
         let mut result = CryptoMultiAccounts::default();
         cbor_map(d, &mut result, |key, obj, d| {
@@ -151,7 +180,12 @@ impl<'b, C> minicbor::Decode<'b, C> for CryptoMultiAccounts {
                 KEYS => {
                     let mut keys: Vec<CryptoHDKey> = vec![];
                     cbor_array(d, obj, |_index, _obj, d| {
-                        d.tag()?;
+                        let tag = d.tag()?;
+                        if !tag.eq(&Tag::Unassigned(CRYPTO_HDKEY.get_tag())) {
+                            return Err(minicbor::decode::Error::message(
+                                "CryptoHDKey tag is invalid",
+                            ));
+                        }
                         keys.push(CryptoHDKey::decode(d, ctx)?);
                         Ok(())
                     })?;
@@ -310,4 +344,67 @@ mod tests {
                 .unwrap()
         );
     }
+
+    #[test]
+    fn test_diff() {
+        let key = |account: u32| {
+            let path = alloc::format!("m/44'/501'/{}'/0'", account);
+            CryptoHDKey::new_extended_key(
+                None,
+                vec![account as u8; 33],
+                None,
+                None,
+                Some(CryptoKeyPath::from_path(path, None).unwrap()),
+                None,
+                None,
+                None,
+                None,
+            )
+        };
+        let fingerprint = [0xe9, 0x18, 0x1c, 0xf3];
+        let old = CryptoMultiAccounts::new(fingerprint, vec![key(0), key(1)], None, None, None);
+        let new = CryptoMultiAccounts::new(fingerprint, vec![key(1), key(2)], None, None, None);
+
+        let diff = old.diff(&new);
+        assert_eq!(1, diff.added.len());
+        assert_eq!(vec![2u8; 33], diff.added[0].get_key());
+        assert_eq!(1, diff.removed.len());
+        assert_eq!(vec![0u8; 33], diff.removed[0].get_key());
+
+        let diff = old.diff(&old);
+        assert!(diff.added.is_empty() && diff.removed.is_empty());
+    }
+
+    #[test]
+    fn test_diff_without_origin() {
+        let key = |data: u8| {
+            CryptoHDKey::new_extended_key(
+                None,
+                vec![data; 33],
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+            )
+        };
+        let fingerprint = [0xe9, 0x18, 0x1c, 0xf3];
+        let old = CryptoMultiAccounts::new(fingerprint, vec![key(0), key(1)], None, None, None);
+        let new = CryptoMultiAccounts::new(fingerprint, vec![key(1), key(2)], None, None, None);
+
+        let diff = old.diff(&new);
+        assert_eq!(1, diff.added.len());
+        assert_eq!(vec![2u8; 33], diff.added[0].get_key());
+        assert_eq!(1, diff.removed.len());
+        assert_eq!(vec![0u8; 33], diff.removed[0].get_key());
+    }
+
+    #[test]
+    fn test_decode_invalid_key_tag() {
+        // same as test_decode with the key tagged as crypto-keypath (304) instead of crypto-hdkey (303)
+        let result = CryptoMultiAccounts::from_cbor(Vec::from_hex("a3011ae9181cf30281d90130a203582102eae4b876a8696134b868f88cc2f51f715f2dbedb7446b8e6edf3d4541c4eb67b06d90130a10188182cf51901f5f500f500f503686b657973746f6e65").unwrap());
+        assert!(result.is_err());
+    }
 }
@@ -1,4 +1,5 @@
-use crate::cbor::{cbor_array, cbor_map};
+use crate::cbor::{cbor_array, cbor_map, cbor_tag, cbor_unknown_field, cbor_unknown_fields};
+use crate::decode::DecodeContext;
 use crate::extend::key_derivation_schema::KeyDerivationSchema;
 use crate::impl_template_struct;
 use crate::registry_types::{RegistryType, KEY_DERIVATION_CALL, KEY_DERIVATION_SCHEMA};
@@ -13,9 +14,12 @@ use minicbor::{Decoder, Encoder};
 
 const SCHEMAS: u8 = 1;
 
-impl_template_struct!(KeyDerivationCall {
-    schemas: Vec<KeyDerivationSchema>
-});
+impl_template_struct!(
+    KeyDerivationCall {
+        schemas: Vec<KeyDerivationSchema>
+    },
+    unknown_fields
+);
 
 impl RegistryItem for KeyDerivationCall {
     fn get_registry_type() -> RegistryType<'static> {
@@ -25,7 +29,7 @@ impl RegistryItem for KeyDerivationCall {
 
 impl MapSize for KeyDerivationCall {
     fn map_size(&self) -> u64 {
-        1
+        1 + self.unknown_fields.len() as u64
     }
 }
 
@@ -38,11 +42,12 @@ impl<C> minicbor::Encode<C> for KeyDerivationCall {
             e.tag(Tag::Unassigned(KEY_DERIVATION_SCHEMA.get_tag()))?;
             x.encode(e, ctx)?
         }
+        cbor_unknown_fields(e, &self.unknown_fields)?;
         Ok(())
     }
 }
 
-impl<'b, C> minicbor::Decode<'b, C> for KeyDerivationCall {
+impl<'b, C: DecodeContext> minicbor::Decode<'b, C> for KeyDerivationCall {
     fn decode(d: &mut Decoder<'b>, ctx: &mut C) -> Result<Self, minicbor::decode::Error> {
         let mut result = KeyDerivationCall::default();
         cbor_map(d, &mut result, |key, obj, d| {
@@ -52,16 +57,55 @@ impl<'b, C> minicbor::Decode<'b, C> for KeyDerivationCall {
                 SCHEMAS => {
                     let mut schemas = vec![];
                     cbor_array(d, obj, |_index, _obj, d| {
-                        d.tag()?;
+                        cbor_tag(
+                            d,
+                            ctx,
+                            KeyDerivationSchema::get_registry_type().get_tag(),
+                            "KeyDerivationSchema tag is invalid",
+                        )?;
                         schemas.push(KeyDerivationSchema::decode(d, ctx)?);
                         Ok(())
                     })?;
                     obj.set_schemas(schemas)
                 }
-                _ => {}
+                _ => {
+                    cbor_unknown_field(d, key, &mut obj.unknown_fields)?;
+                }
             }
             Ok(())
         })?;
         Ok(result)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cbor::with_unknown_key;
+    use crate::crypto_key_path::{CryptoKeyPath, PathComponent};
+    use crate::types::UnknownFields;
+
+    #[test]
+    fn test_unknown_fields_round_trip() {
+        let key_path = CryptoKeyPath::new(
+            vec![
+                PathComponent::new(Some(44), true).unwrap(),
+                PathComponent::new(Some(0), true).unwrap(),
+                PathComponent::new(Some(0), true).unwrap(),
+            ],
+            None,
+            None,
+        );
+        let schema = KeyDerivationSchema::new(key_path, None, None, None);
+        let bytes: Vec<u8> = KeyDerivationCall::new(vec![schema]).try_into().unwrap();
+        let bytes = with_unknown_key(bytes);
+        let call = KeyDerivationCall::try_from(bytes.clone()).unwrap();
+        assert_eq!(
+            UnknownFields::from([(15, vec![0x82, 0x01, 0x02])]),
+            call.get_unknown_fields()
+        );
+        assert_eq!(1, call.get_schemas().len());
+        let encoded: Vec<u8> = call.try_into().unwrap();
+        assert_eq!(bytes, encoded);
+    }
+}
@@ -1,5 +1,6 @@
-use crate::cbor::cbor_map;
+use crate::cbor::{cbor_map, cbor_tag};
 use crate::crypto_key_path::CryptoKeyPath;
+use crate::decode::DecodeContext;
 use crate::error::URError;
 use crate::impl_template_struct;
 use crate::registry_types::{RegistryType, CRYPTO_KEYPATH, KEY_DERIVATION_SCHEMA};
@@ -126,7 +127,7 @@ impl<C> minicbor::Encode<C> for KeyDerivationSchema {
     }
 }
 
-impl<'b, C> minicbor::Decode<'b, C> for KeyDerivationSchema {
+impl<'b, C: DecodeContext> minicbor::Decode<'b, C> for KeyDerivationSchema {
     fn decode(d: &mut Decoder<'b>, ctx: &mut C) -> Result<Self, minicbor::decode::Error> {
         let mut result = KeyDerivationSchema::default();
         cbor_map(d, &mut result, |key, obj, d| {
@@ -134,7 +135,12 @@ impl<'b, C> minicbor::Decode<'b, C> for KeyDerivationSchema {
                 u8::try_from(key).map_err(|e| minicbor::decode::Error::message(e.to_string()))?;
             match key {
                 KEY_PATH => {
-                    d.tag()?;
+                    cbor_tag(
+                        d,
+                        ctx,
+                        CryptoKeyPath::get_registry_type().get_tag(),
+                        "CryptoKeyPath tag is invalid",
+                    )?;
                     obj.set_key_path(CryptoKeyPath::decode(d, ctx)?);
                 }
                 CURVE => {
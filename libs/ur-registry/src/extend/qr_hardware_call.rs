@@ -1,4 +1,5 @@
-use crate::cbor::cbor_map;
+use crate::cbor::{cbor_map, cbor_origin, cbor_unknown_field, cbor_unknown_fields};
+use crate::decode::DecodeContext;
 use crate::error::URError;
 use crate::error::URError::CborDecodeError;
 use crate::extend::key_derivation::KeyDerivationCall;
@@ -83,12 +84,15 @@ impl TryFrom<u8> for HardWareCallVersion {
     }
 }
 
-impl_template_struct!(QRHardwareCall {
-    call_type: CallType,
-    params: CallParams,
-    origin: Option<String>,
-    version: HardWareCallVersion
-});
+impl_template_struct!(
+    QRHardwareCall {
+        call_type: CallType,
+        params: CallParams,
+        origin: Option<String>,
+        version: HardWareCallVersion
+    },
+    unknown_fields
+);
 
 impl RegistryItem for QRHardwareCall {
     fn get_registry_type() -> RegistryType<'static> {
@@ -105,7 +109,7 @@ impl MapSize for QRHardwareCall {
             None => size,
         };
 
-        size
+        size + self.unknown_fields.len() as u64
     }
 }
 
@@ -130,11 +134,12 @@ impl<C> minicbor::Encode<C> for QRHardwareCall {
         e.int(Int::from(VERSION))?
             .int(Int::from(self.version.clone() as u8))?;
 
+        cbor_unknown_fields(e, &self.unknown_fields)?;
         Ok(())
     }
 }
 
-impl<'b, C> minicbor::Decode<'b, C> for QRHardwareCall {
+impl<'b, C: DecodeContext> minicbor::Decode<'b, C> for QRHardwareCall {
     fn decode(d: &mut Decoder<'b>, ctx: &mut C) -> Result<Self, minicbor::decode::Error> {
         let mut result = QRHardwareCall::default();
         cbor_map(d, &mut result, |key, obj, d| {
@@ -163,10 +168,7 @@ impl<'b, C> minicbor::Decode<'b, C> for QRHardwareCall {
                         "invalid QRHardwareCall params"
                     )));
                 }
-                ORIGIN => {
-                    let origin = d.str()?.to_string();
-                    obj.set_origin(Some(origin))
-                }
+                ORIGIN => obj.set_origin(Some(cbor_origin(d)?)),
 
                 VERSION => {
                     let version = HardWareCallVersion::try_from(
@@ -177,7 +179,9 @@ impl<'b, C> minicbor::Decode<'b, C> for QRHardwareCall {
                     obj.set_version(version)
                 }
 
-                _ => {}
+                _ => {
+                    cbor_unknown_field(d, key, &mut obj.unknown_fields)?;
+                }
             }
             Ok(())
         })?;
@@ -188,12 +192,14 @@ impl<'b, C> minicbor::Decode<'b, C> for QRHardwareCall {
 
 #[cfg(test)]
 mod tests {
+    use crate::cbor::with_unknown_key;
     use crate::crypto_key_path::{CryptoKeyPath, PathComponent};
     use crate::extend::key_derivation::KeyDerivationCall;
     use crate::extend::key_derivation_schema::{Curve, DerivationAlgo, KeyDerivationSchema};
     use crate::extend::qr_hardware_call::{
         CallParams, CallType, HardWareCallVersion, QRHardwareCall,
     };
+    use crate::types::UnknownFields;
     use alloc::string::ToString;
     use alloc::vec;
     use alloc::vec::Vec;
@@ -540,4 +546,34 @@ mod tests {
         let bytes: Vec<u8> = call.try_into().unwrap();
         assert_eq!("a3010002d90515a10182d90516a301d90130a1018619073cf5190717f500f502010301d90516a301d90130a1018619073cf5190717f501f5020103010400", hex::encode(bytes));
     }
+
+    #[test]
+    fn test_unknown_fields_round_trip() {
+        let key_path = CryptoKeyPath::new(
+            vec![
+                PathComponent::new(Some(44), true).unwrap(),
+                PathComponent::new(Some(0), true).unwrap(),
+                PathComponent::new(Some(0), true).unwrap(),
+            ],
+            None,
+            None,
+        );
+        let schema = KeyDerivationSchema::new(key_path, None, None, None);
+        let call = QRHardwareCall::new(
+            CallType::KeyDerivation,
+            CallParams::KeyDerivation(KeyDerivationCall::new(vec![schema])),
+            None,
+            HardWareCallVersion::V0,
+        );
+        let bytes: Vec<u8> = call.try_into().unwrap();
+        let bytes = with_unknown_key(bytes);
+        let call = QRHardwareCall::try_from(bytes.clone()).unwrap();
+        assert_eq!(
+            UnknownFields::from([(15, vec![0x82, 0x01, 0x02])]),
+            call.get_unknown_fields()
+        );
+        assert_eq!(HardWareCallVersion::V0, call.get_version());
+        let encoded: Vec<u8> = call.try_into().unwrap();
+        assert_eq!(bytes, encoded);
+    }
 }
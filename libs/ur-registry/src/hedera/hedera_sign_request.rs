@@ -0,0 +1,246 @@
+use alloc::{
+    format,
+    string::{String, ToString},
+};
+use minicbor::data::{Int, Tag, Type};
+
+use crate::cbor::cbor_bytes;
+use crate::{
+    cbor::{
+        cbor_expect_type, cbor_map, cbor_origin, cbor_tag, cbor_unknown_field, cbor_unknown_fields,
+    },
+    crypto_key_path::CryptoKeyPath,
+    decode::DecodeContext,
+    error::URResult,
+    impl_template_struct, impl_with_new_request_id,
+    registry_types::{RegistryType, CRYPTO_KEYPATH, HEDERA_SIGN_REQUEST, UUID},
+    traits::{MapSize, RegistryItem, Validate},
+    types::Bytes,
+    validate::{validate_path, validate_request_id, validate_sign_data},
+};
+
+const REQUEST_ID: u8 = 1;
+const SIGN_DATA: u8 = 2;
+const KEY_TYPE: u8 = 3;
+const DERIVATION_PATH: u8 = 4;
+const ORIGIN: u8 = 5;
+
+const HEDERA_COIN_TYPE: u32 = 3030;
+const ETH_COIN_TYPE: u32 = 60;
+
+impl_template_struct!(
+    HederaSignRequest {
+        request_id: Option<Bytes>,
+        // the protobuf encoded TransactionBody
+        sign_data: Bytes,
+        key_type: KeyType,
+        derivation_path: CryptoKeyPath,
+        origin: Option<String>
+    },
+    unknown_fields
+);
+
+impl_with_new_request_id!(HederaSignRequest);
+
+#[derive(Clone, Debug, PartialEq, Default)]
+pub enum KeyType {
+    #[default]
+    Ed25519 = 1,
+    EcdsaSecp256k1 = 2,
+}
+
+impl KeyType {
+    pub fn from_u32(i: u32) -> Result<Self, String> {
+        match i {
+            1 => Ok(KeyType::Ed25519),
+            2 => Ok(KeyType::EcdsaSecp256k1),
+            x => Err(format!(
+                "invalid value for key_type in hedera-sign-request, expected 1 or 2, received {:?}",
+                x
+            )),
+        }
+    }
+
+    // ECDSA keys are derived on the Ethereum path, as the Hedera SDKs do
+    pub fn coin_type(&self) -> u32 {
+        match self {
+            KeyType::Ed25519 => HEDERA_COIN_TYPE,
+            KeyType::EcdsaSecp256k1 => ETH_COIN_TYPE,
+        }
+    }
+}
+
+impl MapSize for HederaSignRequest {
+    fn map_size(&self) -> u64 {
+        let mut size = 3;
+        if self.request_id.is_some() {
+            size += 1;
+        }
+        if self.origin.is_some() {
+            size += 1;
+        }
+        size + self.unknown_fields.len() as u64
+    }
+}
+
+impl RegistryItem for HederaSignRequest {
+    fn get_registry_type() -> RegistryType<'static> {
+        HEDERA_SIGN_REQUEST
+    }
+}
+
+impl Validate for HederaSignRequest {
+    fn validate_schema(&self) -> URResult<()> {
+        validate_request_id(&self.request_id)?;
+        validate_sign_data("sign_data", &self.sign_data)?;
+        validate_path(
+            "derivation_path",
+            &self.derivation_path,
+            Some(self.key_type.coin_type()),
+        )?;
+        Ok(())
+    }
+}
+
+impl<C> minicbor::Encode<C> for HederaSignRequest {
+    fn encode<W: minicbor::encode::Write>(
+        &self,
+        e: &mut minicbor::Encoder<W>,
+        _ctx: &mut C,
+    ) -> Result<(), minicbor::encode::Error<W::Error>> {
+        e.map(self.map_size())?;
+
+        if let Some(request_id) = &self.request_id {
+            e.int(Int::from(REQUEST_ID))?
+                .tag(Tag::Unassigned(UUID.get_tag()))?
+                .bytes(request_id)?;
+        }
+
+        e.int(Int::from(SIGN_DATA))?.bytes(&self.sign_data)?;
+
+        e.int(Int::from(KEY_TYPE))?
+            .int(Int::from(self.key_type.clone() as u8))?;
+
+        e.int(Int::from(DERIVATION_PATH))?
+            .tag(Tag::Unassigned(CRYPTO_KEYPATH.get_tag()))?;
+        CryptoKeyPath::encode(&self.derivation_path, e, _ctx)?;
+
+        if let Some(origin) = &self.origin {
+            e.int(Int::from(ORIGIN))?.str(origin)?;
+        }
+
+        cbor_unknown_fields(e, &self.unknown_fields)?;
+        Ok(())
+    }
+}
+
+impl<'b, C: DecodeContext> minicbor::Decode<'b, C> for HederaSignRequest {
+    fn decode(d: &mut minicbor::Decoder<'b>, ctx: &mut C) -> Result<Self, minicbor::decode::Error> {
+        let mut result = HederaSignRequest::default();
+        cbor_map(d, &mut result, |key, obj, d| {
+            let key =
+                u8::try_from(key).map_err(|e| minicbor::decode::Error::message(e.to_string()))?;
+            match key {
+                REQUEST_ID => {
+                    cbor_tag(d, ctx, UUID.get_tag(), "UUID tag is invalid")?;
+                    obj.request_id = Some(cbor_bytes(d)?);
+                }
+                SIGN_DATA => {
+                    cbor_expect_type(d, ctx, Type::Bytes)?;
+                    obj.sign_data = cbor_bytes(d)?;
+                }
+                KEY_TYPE => {
+                    cbor_expect_type(d, ctx, Type::U32)?;
+                    obj.key_type = KeyType::from_u32(
+                        u32::try_from(d.int()?)
+                            .map_err(|e| minicbor::decode::Error::message(e.to_string()))?,
+                    )
+                    .map_err(minicbor::decode::Error::message)?;
+                }
+                DERIVATION_PATH => {
+                    cbor_tag(
+                        d,
+                        ctx,
+                        CRYPTO_KEYPATH.get_tag(),
+                        "CryptoKeyPath tag is invalid",
+                    )?;
+                    obj.derivation_path = CryptoKeyPath::decode(d, ctx)?;
+                }
+                ORIGIN => {
+                    obj.origin = Some(cbor_origin(d)?);
+                }
+                _ => {
+                    cbor_unknown_field(d, key, &mut obj.unknown_fields)?;
+                }
+            }
+            Ok(())
+        })?;
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use super::*;
+    use crate::error::URError;
+
+    // 0.0.1001 sends 1 hbar to 0.0.1002 through node 0.0.3, with the memo "keystone"
+    const CRYPTO_TRANSFER: &str = "0a0d0a060880a5b6c706120318e907120218031880c2d72f2202087832086b657973746f6e65721a0a180a0a0a0318e90710ff83af5f0a0a0a0318ea07108084af5f";
+
+    #[test]
+    fn test_crypto_transfer_round_trip() {
+        let path = CryptoKeyPath::from_path(
+            "m/44'/3030'/0'/0'/0'".to_string(),
+            Some([0xf2, 0x3f, 0x9f, 0xd2]),
+        )
+        .unwrap();
+        let request = HederaSignRequest::new(
+            Some(hex::decode("9b1deb4d3b7d4bad9bdd2b0d7b3dcb6d").unwrap()),
+            hex::decode(CRYPTO_TRANSFER).unwrap(),
+            KeyType::Ed25519,
+            path,
+            Some("keystone".to_string()),
+        );
+        assert!(request.validate_schema().is_ok());
+
+        let result: Vec<u8> = request.try_into().unwrap();
+        assert_eq!(
+            "a501d825509b1deb4d3b7d4bad9bdd2b0d7b3dcb6d0258420a0d0a060880a5b6c706120318e907120218031880c2d72f2202087832086b657973746f6e65721a0a180a0a0a0318e90710ff83af5f0a0a0a0318ea07108084af5f030104d90130a2018a182cf5190bd6f500f500f500f5021af23f9fd205686b657973746f6e65",
+            hex::encode(&result)
+        );
+
+        let decoded = HederaSignRequest::try_from(result).unwrap();
+        assert_eq!(
+            Some(hex::decode("9b1deb4d3b7d4bad9bdd2b0d7b3dcb6d").unwrap()),
+            decoded.get_request_id()
+        );
+        assert_eq!(
+            hex::decode(CRYPTO_TRANSFER).unwrap(),
+            decoded.get_sign_data()
+        );
+        assert_eq!(KeyType::Ed25519, decoded.get_key_type());
+        assert_eq!(
+            "44'/3030'/0'/0'/0'",
+            decoded.get_derivation_path().get_path().unwrap()
+        );
+        assert_eq!(Some("keystone".to_string()), decoded.get_origin());
+    }
+
+    #[test]
+    fn test_ecdsa_key_type() {
+        let decoded = HederaSignRequest::try_from(hex::decode("a30258420a0d0a060880a5b6c706120318e907120218031880c2d72f2202087832086b657973746f6e65721a0a180a0a0a0318e90710ff83af5f0a0a0a0318ea07108084af5f030204d90130a2018a182cf5183cf500f500f400f4021af23f9fd2").unwrap()).unwrap();
+        assert_eq!(KeyType::EcdsaSecp256k1, decoded.get_key_type());
+        assert!(decoded.validate_schema().is_ok());
+
+        let mut request = decoded;
+        request.set_key_type(KeyType::Ed25519);
+        assert_eq!(
+            Err(URError::ValidationError(
+                "derivation_path does not match coin type 3030".to_string()
+            )),
+            request.validate_schema()
+        );
+    }
+}
@@ -0,0 +1,115 @@
+use alloc::string::ToString;
+use minicbor::data::{Int, Tag};
+
+use crate::cbor::{cbor_bytes, cbor_map, cbor_tag, cbor_unknown_field, cbor_unknown_fields};
+use crate::decode::DecodeContext;
+use crate::impl_template_struct;
+use crate::impl_validate_signature;
+use crate::registry_types::{RegistryType, HEDERA_SIGNATURE, UUID};
+use crate::traits::{MapSize, RegistryItem};
+use crate::types::Bytes;
+
+const REQUEST_ID: u8 = 1;
+const SIGNATURE: u8 = 2;
+
+impl_template_struct!(
+    HederaSignature {
+        request_id: Option<Bytes>,
+        signature: Bytes
+    },
+    unknown_fields
+);
+
+impl_validate_signature!(HederaSignature);
+
+impl RegistryItem for HederaSignature {
+    fn get_registry_type() -> RegistryType<'static> {
+        HEDERA_SIGNATURE
+    }
+}
+
+impl MapSize for HederaSignature {
+    fn map_size(&self) -> u64 {
+        let mut size = 1;
+        if self.request_id.is_some() {
+            size += 1;
+        }
+        size + self.unknown_fields.len() as u64
+    }
+}
+
+impl<C> minicbor::Encode<C> for HederaSignature {
+    fn encode<W: minicbor::encode::Write>(
+        &self,
+        e: &mut minicbor::Encoder<W>,
+        _ctx: &mut C,
+    ) -> Result<(), minicbor::encode::Error<W::Error>> {
+        e.map(self.map_size())?;
+        if let Some(request_id) = &self.request_id {
+            e.int(Int::from(REQUEST_ID))?
+                .tag(Tag::Unassigned(UUID.get_tag()))?
+                .bytes(request_id)?;
+        }
+        e.int(Int::from(SIGNATURE))?.bytes(&self.signature)?;
+        cbor_unknown_fields(e, &self.unknown_fields)?;
+        Ok(())
+    }
+}
+
+impl<'b, C: DecodeContext> minicbor::Decode<'b, C> for HederaSignature {
+    fn decode(d: &mut minicbor::Decoder<'b>, ctx: &mut C) -> Result<Self, minicbor::decode::Error> {
+        let mut result = HederaSignature::default();
+
+        cbor_map(d, &mut result, |key, obj, d| {
+            let key =
+                u8::try_from(key).map_err(|e| minicbor::decode::Error::message(e.to_string()))?;
+            match key {
+                REQUEST_ID => {
+                    cbor_tag(d, ctx, UUID.get_tag(), "UUID tag is invalid")?;
+                    obj.request_id = Some(cbor_bytes(d)?);
+                }
+                SIGNATURE => {
+                    obj.signature = cbor_bytes(d)?;
+                }
+                _ => {
+                    cbor_unknown_field(d, key, &mut obj.unknown_fields)?;
+                }
+            }
+            Ok(())
+        })?;
+
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use super::*;
+
+    // the ed25519 signature of the crypto transfer body in the hedera_sign_request
+    // tests, made with the first test key of RFC 8032, whose public key is
+    // d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a
+    const SIGNATURE: &str = "a2bc2fc9e54a22480c56dceb22b435c04793a8ebd82fbe51c2203716944aa7665bac2d31dec5ed2a84ea73e9f33add28393d7a9a3d0354536ed66efa86234b0e";
+
+    #[test]
+    fn test_round_trip() {
+        let signature = HederaSignature::new(
+            Some(hex::decode("9b1deb4d3b7d4bad9bdd2b0d7b3dcb6d").unwrap()),
+            hex::decode(SIGNATURE).unwrap(),
+        );
+        let result: Vec<u8> = signature.try_into().unwrap();
+        assert_eq!(
+            "a201d825509b1deb4d3b7d4bad9bdd2b0d7b3dcb6d025840a2bc2fc9e54a22480c56dceb22b435c04793a8ebd82fbe51c2203716944aa7665bac2d31dec5ed2a84ea73e9f33add28393d7a9a3d0354536ed66efa86234b0e",
+            hex::encode(&result)
+        );
+
+        let decoded = HederaSignature::try_from(result).unwrap();
+        assert_eq!(
+            Some(hex::decode("9b1deb4d3b7d4bad9bdd2b0d7b3dcb6d").unwrap()),
+            decoded.get_request_id()
+        );
+        assert_eq!(SIGNATURE, hex::encode(decoded.get_signature()));
+    }
+}
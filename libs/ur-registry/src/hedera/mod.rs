@@ -0,0 +1,2 @@
+pub mod hedera_sign_request;
+pub mod hedera_signature;
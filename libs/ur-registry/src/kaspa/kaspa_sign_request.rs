@@ -0,0 +1,243 @@
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use minicbor::data::{Int, Tag, Type};
+
+use crate::cbor::{
+    cbor_array, cbor_bytes, cbor_expect_type, cbor_map, cbor_origin, cbor_paths, cbor_tag,
+    cbor_unknown_field, cbor_unknown_fields,
+};
+use crate::crypto_key_path::CryptoKeyPath;
+use crate::decode::DecodeContext;
+use crate::error::{URError, URResult};
+use crate::impl_template_struct;
+use crate::impl_with_new_request_id;
+use crate::registry_types::{RegistryType, CRYPTO_KEYPATH, KASPA_SIGN_REQUEST, UUID};
+use crate::traits::{MapSize, RegistryItem, Validate};
+use crate::types::Bytes;
+use crate::validate::{validate_paths, validate_request_id, validate_sign_data};
+
+const REQUEST_ID: u8 = 1;
+const SIGN_DATA: u8 = 2;
+const DERIVATION_PATHS: u8 = 3;
+const ADDRESSES: u8 = 4;
+const ORIGIN: u8 = 5;
+
+const KASPA_COIN_TYPE: u32 = 111111;
+
+impl_template_struct!(
+    KaspaSignRequest {
+        request_id: Option<Bytes>,
+        // the serialized transaction
+        sign_data: Bytes,
+        // the key of each input, in input order
+        derivation_paths: Vec<CryptoKeyPath>,
+        // the `kaspa:` address of each input
+        addresses: Option<Vec<String>>,
+        origin: Option<String>
+    },
+    unknown_fields
+);
+
+impl_with_new_request_id!(KaspaSignRequest);
+
+impl KaspaSignRequest {
+    // every derivation path signs for the address at the same index
+    fn check_addresses(&self) -> Result<(), String> {
+        match &self.addresses {
+            Some(addresses) if addresses.len() != self.derivation_paths.len() => Err(format!(
+                "addresses count {} does not match derivation_paths count {}",
+                addresses.len(),
+                self.derivation_paths.len()
+            )),
+            _ => Ok(()),
+        }
+    }
+}
+
+impl RegistryItem for KaspaSignRequest {
+    fn get_registry_type() -> RegistryType<'static> {
+        KASPA_SIGN_REQUEST
+    }
+}
+
+impl MapSize for KaspaSignRequest {
+    fn map_size(&self) -> u64 {
+        let mut size = 2;
+        if self.request_id.is_some() {
+            size += 1;
+        }
+        if self.addresses.is_some() {
+            size += 1;
+        }
+        if self.origin.is_some() {
+            size += 1;
+        }
+        size + self.unknown_fields.len() as u64
+    }
+}
+
+impl Validate for KaspaSignRequest {
+    fn validate_schema(&self) -> URResult<()> {
+        validate_request_id(&self.request_id)?;
+        validate_sign_data("sign_data", &self.sign_data)?;
+        validate_paths(
+            "derivation_paths",
+            &self.derivation_paths,
+            Some(KASPA_COIN_TYPE),
+        )?;
+        self.check_addresses().map_err(URError::ValidationError)?;
+        Ok(())
+    }
+}
+
+impl<C> minicbor::Encode<C> for KaspaSignRequest {
+    fn encode<W: minicbor::encode::Write>(
+        &self,
+        e: &mut minicbor::Encoder<W>,
+        ctx: &mut C,
+    ) -> Result<(), minicbor::encode::Error<W::Error>> {
+        e.map(self.map_size())?;
+        if let Some(request_id) = &self.request_id {
+            e.int(Int::from(REQUEST_ID))?
+                .tag(Tag::Unassigned(UUID.get_tag()))?
+                .bytes(request_id)?;
+        }
+
+        e.int(Int::from(SIGN_DATA))?.bytes(&self.sign_data)?;
+
+        e.int(Int::from(DERIVATION_PATHS))?;
+        cbor_paths(e, &self.derivation_paths, "derivation paths", ctx)?;
+
+        if let Err(message) = self.check_addresses() {
+            return Err(minicbor::encode::Error::message(message));
+        }
+        if let Some(addresses) = &self.addresses {
+            e.int(Int::from(ADDRESSES))?.array(addresses.len() as u64)?;
+            for address in addresses {
+                e.str(address)?;
+            }
+        }
+
+        if let Some(origin) = &self.origin {
+            e.int(Int::from(ORIGIN))?.str(origin)?;
+        }
+
+        cbor_unknown_fields(e, &self.unknown_fields)?;
+        Ok(())
+    }
+}
+
+impl<'b, C: DecodeContext> minicbor::Decode<'b, C> for KaspaSignRequest {
+    fn decode(d: &mut minicbor::Decoder<'b>, ctx: &mut C) -> Result<Self, minicbor::decode::Error> {
+        let mut result = KaspaSignRequest::default();
+        cbor_map(d, &mut result, |key, obj, d| {
+            let key =
+                u8::try_from(key).map_err(|e| minicbor::decode::Error::message(e.to_string()))?;
+            match key {
+                REQUEST_ID => {
+                    cbor_tag(d, ctx, UUID.get_tag(), "UUID tag is invalid")?;
+                    obj.request_id = Some(cbor_bytes(d)?);
+                }
+                SIGN_DATA => {
+                    cbor_expect_type(d, ctx, Type::Bytes)?;
+                    obj.sign_data = cbor_bytes(d)?;
+                }
+                DERIVATION_PATHS => {
+                    cbor_array(d, &mut obj.derivation_paths, |_index, obj, d| {
+                        cbor_tag(
+                            d,
+                            ctx,
+                            CRYPTO_KEYPATH.get_tag(),
+                            "CryptoKeyPath tag is invalid",
+                        )?;
+                        obj.push(CryptoKeyPath::decode(d, ctx)?);
+                        Ok(())
+                    })?;
+                }
+                ADDRESSES => {
+                    let mut addresses = Vec::new();
+                    cbor_array(d, &mut addresses, |_index, obj, d| {
+                        obj.push(d.str()?.to_string());
+                        Ok(())
+                    })?;
+                    obj.addresses = Some(addresses);
+                }
+                ORIGIN => {
+                    obj.origin = Some(cbor_origin(d)?);
+                }
+                _ => {
+                    cbor_unknown_field(d, key, &mut obj.unknown_fields)?;
+                }
+            }
+            Ok(())
+        })?;
+        result
+            .check_addresses()
+            .map_err(minicbor::decode::Error::message)?;
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+
+    use super::*;
+
+    // spends two utxos to one recipient and sends the change back to the first input
+    const TWO_INPUT_TRANSACTION: &str = "000002000000000000003892f41d2293fd8de0ac3db13079e129be752d661802950c3090aeffb3674dd5000000000000000000000000000000000000000001aee2d045a3e227f0ccf7073ff7cbfa7f05e7f181bf420695c093b821ac6605f7010000000000000000000000000000000000000001020000000000000080d1f008000000000000220000000000000020811085f5b5d1b29598e73ca51de3d712f5d3103ad50e22dc1f4d3ff1559d5115ac70c9fa02000000000000220000000000000020f1031ed241eba5adcc15a491ca77c302b9db60dacacad9e82678e9155ff92bc5ac0000000000000000000000000000000000000000000000000000000000000000000000000000000000000000";
+
+    #[test]
+    fn test_decode_two_inputs() {
+        let bytes = hex::decode("a501d825509b1deb4d3b7d4bad9bdd2b0d7b3dcb6d02590110000002000000000000003892f41d2293fd8de0ac3db13079e129be752d661802950c3090aeffb3674dd5000000000000000000000000000000000000000001aee2d045a3e227f0ccf7073ff7cbfa7f05e7f181bf420695c093b821ac6605f7010000000000000000000000000000000000000001020000000000000080d1f008000000000000220000000000000020811085f5b5d1b29598e73ca51de3d712f5d3103ad50e22dc1f4d3ff1559d5115ac70c9fa02000000000000220000000000000020f1031ed241eba5adcc15a491ca77c302b9db60dacacad9e82678e9155ff92bc5ac00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000382d90130a2018a182cf51a0001b207f500f500f400f4021af23f9fd2d90130a2018a182cf51a0001b207f500f500f401f4021af23f9fd2048278436b617370613a7172637378386b6a67383436747477767a6b6a66726a6e68637670746e6b6d716d743976346b3067796575776a39326c6c79347532666b70707067736a78436b617370613a717166386773767678616a32766e65797435673036727738616c7a70397479727a787076677537736b757573333732733470736a7666656b6839357a7205676b61737069756d").unwrap();
+        let request = KaspaSignRequest::try_from(bytes.clone()).unwrap();
+        assert!(request.validate_schema().is_ok());
+
+        assert_eq!(
+            Some(hex::decode("9b1deb4d3b7d4bad9bdd2b0d7b3dcb6d").unwrap()),
+            request.get_request_id()
+        );
+        assert_eq!(
+            hex::decode(TWO_INPUT_TRANSACTION).unwrap(),
+            request.get_sign_data()
+        );
+        let paths = request
+            .get_derivation_paths()
+            .iter()
+            .map(|path| path.get_path().unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(vec!["44'/111111'/0'/0/0", "44'/111111'/0'/0/1"], paths);
+        assert_eq!(
+            Some(vec![
+                "kaspa:qrcsx8kjg846ttwvzkjfrjnhcvptnkmqmt9v4k0gyeuwj92lly4u2fkpppgsj".to_string(),
+                "kaspa:qqf8gsvvxaj2vneyt5g06rw8alzp9tyrzxpvgu7skuus372s4psjvfekh95zr".to_string(),
+            ]),
+            request.get_addresses()
+        );
+        assert_eq!(Some("kaspium".to_string()), request.get_origin());
+
+        let result: Vec<u8> = request.try_into().unwrap();
+        assert_eq!(bytes, result);
+    }
+
+    #[test]
+    fn test_addresses_count_mismatch() {
+        let path = CryptoKeyPath::from_path("m/44'/111111'/0'/0/0".to_string(), None).unwrap();
+        let request = KaspaSignRequest::new(
+            None,
+            hex::decode(TWO_INPUT_TRANSACTION).unwrap(),
+            vec![path],
+            Some(vec![]),
+            None,
+        );
+        assert_eq!(
+            Err(URError::ValidationError(
+                "addresses count 0 does not match derivation_paths count 1".to_string()
+            )),
+            request.validate_schema()
+        );
+        let result: URResult<Vec<u8>> = request.try_into();
+        assert!(result.is_err());
+    }
+}
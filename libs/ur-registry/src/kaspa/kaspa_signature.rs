@@ -0,0 +1,131 @@
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use minicbor::data::{Int, Tag};
+
+use crate::cbor::{
+    cbor_array, cbor_bytes, cbor_map, cbor_tag, cbor_unknown_field, cbor_unknown_fields,
+};
+use crate::decode::DecodeContext;
+use crate::impl_template_struct;
+use crate::impl_validate_signature;
+use crate::registry_types::{RegistryType, KASPA_SIGNATURE, UUID};
+use crate::traits::{MapSize, RegistryItem};
+use crate::types::Bytes;
+
+const REQUEST_ID: u8 = 1;
+const SIGNATURES: u8 = 2;
+
+impl_template_struct!(
+    KaspaSignature {
+        request_id: Option<Bytes>,
+        // one schnorr signature per input, in input order
+        signatures: Vec<Bytes>
+    },
+    unknown_fields
+);
+
+impl_validate_signature!(KaspaSignature);
+
+impl RegistryItem for KaspaSignature {
+    fn get_registry_type() -> RegistryType<'static> {
+        KASPA_SIGNATURE
+    }
+}
+
+impl MapSize for KaspaSignature {
+    fn map_size(&self) -> u64 {
+        let mut size = 1;
+        if self.request_id.is_some() {
+            size += 1;
+        }
+        size + self.unknown_fields.len() as u64
+    }
+}
+
+impl<C> minicbor::Encode<C> for KaspaSignature {
+    fn encode<W: minicbor::encode::Write>(
+        &self,
+        e: &mut minicbor::Encoder<W>,
+        _ctx: &mut C,
+    ) -> Result<(), minicbor::encode::Error<W::Error>> {
+        e.map(self.map_size())?;
+        if let Some(request_id) = &self.request_id {
+            e.int(Int::from(REQUEST_ID))?
+                .tag(Tag::Unassigned(UUID.get_tag()))?
+                .bytes(request_id)?;
+        }
+        e.int(Int::from(SIGNATURES))?
+            .array(self.signatures.len() as u64)?;
+        for signature in &self.signatures {
+            e.bytes(signature)?;
+        }
+        cbor_unknown_fields(e, &self.unknown_fields)?;
+        Ok(())
+    }
+}
+
+impl<'b, C: DecodeContext> minicbor::Decode<'b, C> for KaspaSignature {
+    fn decode(d: &mut minicbor::Decoder<'b>, ctx: &mut C) -> Result<Self, minicbor::decode::Error> {
+        let mut result = KaspaSignature::default();
+
+        cbor_map(d, &mut result, |key, obj, d| {
+            let key =
+                u8::try_from(key).map_err(|e| minicbor::decode::Error::message(e.to_string()))?;
+            match key {
+                REQUEST_ID => {
+                    cbor_tag(d, ctx, UUID.get_tag(), "UUID tag is invalid")?;
+                    obj.request_id = Some(cbor_bytes(d)?);
+                }
+                SIGNATURES => {
+                    cbor_array(d, &mut obj.signatures, |_index, obj, d| {
+                        obj.push(cbor_bytes(d)?);
+                        Ok(())
+                    })?;
+                }
+                _ => {
+                    cbor_unknown_field(d, key, &mut obj.unknown_fields)?;
+                }
+            }
+            Ok(())
+        })?;
+
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // kaspa signs with BIP340 schnorr, these are the signatures of test vectors 0 and 1
+    // of BIP340, the sighash of the request fixture would also need the utxo entries
+    // it spends, which the serialized transaction doesn't carry
+    const SIGNATURES: [&str; 2] = [
+        "e907831f80848d1069a5371b402410364bdf1c5f8307b0084c55f1ce2dca821525f66a4a85ea8b71e482a74f382d2ce5ebeee8fdb2172f477df4900d310536c0",
+        "6896bd60eeae296db48a229ff71dfe071bde413e6d43f917dc8dcf8c78de33418906d11ac976abccb20b091292bff4ea897efcb639ea871cfa95f6de339e4b0a",
+    ];
+
+    #[test]
+    fn test_round_trip() {
+        let signatures: Vec<Bytes> = SIGNATURES
+            .iter()
+            .map(|signature| hex::decode(signature).unwrap())
+            .collect();
+        let signature = KaspaSignature::new(
+            Some(hex::decode("9b1deb4d3b7d4bad9bdd2b0d7b3dcb6d").unwrap()),
+            signatures.clone(),
+        );
+        let result: Vec<u8> = signature.try_into().unwrap();
+        assert_eq!(
+            "a201d825509b1deb4d3b7d4bad9bdd2b0d7b3dcb6d02825840e907831f80848d1069a5371b402410364bdf1c5f8307b0084c55f1ce2dca821525f66a4a85ea8b71e482a74f382d2ce5ebeee8fdb2172f477df4900d310536c058406896bd60eeae296db48a229ff71dfe071bde413e6d43f917dc8dcf8c78de33418906d11ac976abccb20b091292bff4ea897efcb639ea871cfa95f6de339e4b0a",
+            hex::encode(&result)
+        );
+
+        let decoded = KaspaSignature::try_from(result).unwrap();
+        assert_eq!(
+            Some(hex::decode("9b1deb4d3b7d4bad9bdd2b0d7b3dcb6d").unwrap()),
+            decoded.get_request_id()
+        );
+        assert_eq!(signatures, decoded.get_signatures());
+    }
+}
@@ -0,0 +1,2 @@
+pub mod kaspa_sign_request;
+pub mod kaspa_signature;
@@ -0,0 +1,214 @@
+use alloc::format;
+use alloc::string::{String, ToString};
+use minicbor::data::{Int, Tag, Type};
+
+use crate::cbor::{
+    cbor_bytes, cbor_expect_type, cbor_map, cbor_tag, cbor_unknown_field, cbor_unknown_fields,
+};
+use crate::decode::DecodeContext;
+use crate::impl_template_struct;
+use crate::registry_types::{RegistryType, KEYSTONE_SIGN_REJECTION, UUID};
+use crate::traits::{MapSize, RegistryItem};
+use crate::types::Bytes;
+
+const REQUEST_ID: u8 = 1;
+const REASON_CODE: u8 = 2;
+const REASON_MESSAGE: u8 = 3;
+
+/// Why the device refused to produce a signature.
+///
+/// Codes not listed here are still carried by `reason_code` so that newer
+/// firmware can report them to older software.
+#[derive(Clone, Debug, PartialEq)]
+pub enum RejectionReason {
+    /// The user declined the request on the device.
+    UserDeclined = 1,
+    /// The request was not confirmed before the device timed out.
+    Timeout = 2,
+    /// The device could not parse or does not support the request.
+    InvalidRequest = 3,
+}
+
+impl RejectionReason {
+    pub fn from_u16(i: u16) -> Result<Self, String> {
+        match i {
+            1 => Ok(RejectionReason::UserDeclined),
+            2 => Ok(RejectionReason::Timeout),
+            3 => Ok(RejectionReason::InvalidRequest),
+            x => Err(format!(
+                "invalid value for reason_code in keystone-sign-rejection, expected 1, 2 or 3, received {:?}",
+                x
+            )),
+        }
+    }
+}
+
+impl_template_struct!(
+    KeystoneSignRejection {
+        request_id: Option<Bytes>,
+        reason_code: Option<u16>,
+        reason_message: Option<String>
+    },
+    unknown_fields
+);
+
+impl KeystoneSignRejection {
+    pub fn get_reason(&self) -> Option<RejectionReason> {
+        self.reason_code
+            .and_then(|code| RejectionReason::from_u16(code).ok())
+    }
+}
+
+impl RegistryItem for KeystoneSignRejection {
+    fn get_registry_type() -> RegistryType<'static> {
+        KEYSTONE_SIGN_REJECTION
+    }
+}
+
+impl MapSize for KeystoneSignRejection {
+    fn map_size(&self) -> u64 {
+        let mut size = 0;
+        if self.request_id.is_some() {
+            size += 1;
+        }
+        if self.reason_code.is_some() {
+            size += 1;
+        }
+        if self.reason_message.is_some() {
+            size += 1;
+        }
+        size + self.unknown_fields.len() as u64
+    }
+}
+
+impl<C> minicbor::Encode<C> for KeystoneSignRejection {
+    fn encode<W: minicbor::encode::Write>(
+        &self,
+        e: &mut minicbor::Encoder<W>,
+        _ctx: &mut C,
+    ) -> Result<(), minicbor::encode::Error<W::Error>> {
+        e.map(self.map_size())?;
+        if let Some(request_id) = &self.request_id {
+            e.int(Int::from(REQUEST_ID))?
+                .tag(Tag::Unassigned(UUID.get_tag()))?
+                .bytes(request_id)?;
+        }
+        if let Some(reason_code) = self.reason_code {
+            e.int(Int::from(REASON_CODE))?.u16(reason_code)?;
+        }
+        if let Some(reason_message) = &self.reason_message {
+            e.int(Int::from(REASON_MESSAGE))?.str(reason_message)?;
+        }
+        cbor_unknown_fields(e, &self.unknown_fields)?;
+        Ok(())
+    }
+}
+
+impl<'b, C: DecodeContext> minicbor::Decode<'b, C> for KeystoneSignRejection {
+    fn decode(d: &mut minicbor::Decoder<'b>, ctx: &mut C) -> Result<Self, minicbor::decode::Error> {
+        let mut result = KeystoneSignRejection::default();
+
+        cbor_map(d, &mut result, |key, obj, d| {
+            let key =
+                u8::try_from(key).map_err(|e| minicbor::decode::Error::message(e.to_string()))?;
+            match key {
+                REQUEST_ID => {
+                    cbor_tag(d, ctx, UUID.get_tag(), "UUID tag is invalid")?;
+                    obj.request_id = Some(cbor_bytes(d)?);
+                }
+                REASON_CODE => {
+                    cbor_expect_type(d, ctx, Type::U16)?;
+                    obj.reason_code = Some(d.u16()?);
+                }
+                REASON_MESSAGE => {
+                    obj.reason_message = Some(d.str()?.to_string());
+                }
+                _ => {
+                    cbor_unknown_field(d, key, &mut obj.unknown_fields)?;
+                }
+            }
+            Ok(())
+        })?;
+
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    use crate::cbor::with_unknown_key;
+    use crate::error::URError;
+    use crate::types::UnknownFields;
+
+    use super::*;
+
+    #[test]
+    fn test_user_declined_round_trip() {
+        let rejection = KeystoneSignRejection::new(
+            Some(hex::decode("9b1deb4d3b7d4bad9bdd2b0d7b3dcb6d").unwrap()),
+            Some(RejectionReason::UserDeclined as u16),
+            Some("user declined".to_string()),
+        );
+        let result: Vec<u8> = rejection.try_into().unwrap();
+        assert_eq!(
+            "a301d825509b1deb4d3b7d4bad9bdd2b0d7b3dcb6d0201036d75736572206465636c696e6564",
+            hex::encode(&result)
+        );
+
+        let decoded = KeystoneSignRejection::try_from(result).unwrap();
+        assert_eq!(
+            Some(hex::decode("9b1deb4d3b7d4bad9bdd2b0d7b3dcb6d").unwrap()),
+            decoded.get_request_id()
+        );
+        assert_eq!(Some(1), decoded.get_reason_code());
+        assert_eq!(Some(RejectionReason::UserDeclined), decoded.get_reason());
+        assert_eq!(
+            Some("user declined".to_string()),
+            decoded.get_reason_message()
+        );
+    }
+
+    #[test]
+    fn test_unknown_reason_code() {
+        let mut rejection = KeystoneSignRejection::default();
+        rejection.set_reason_code(Some(42));
+        let result: Vec<u8> = rejection.try_into().unwrap();
+        assert_eq!("a102182a", hex::encode(&result));
+
+        let decoded = KeystoneSignRejection::try_from(result).unwrap();
+        assert_eq!(Some(42), decoded.get_reason_code());
+        assert_eq!(None, decoded.get_reason());
+        assert_eq!(None, decoded.get_request_id());
+        assert_eq!(None, decoded.get_reason_message());
+    }
+
+    #[test]
+    fn test_unknown_fields_round_trip() {
+        // a newer firmware adding a key after the reason message
+        let bytes = with_unknown_key(hex::decode("a102182a").unwrap());
+        let decoded = KeystoneSignRejection::try_from(bytes.clone()).unwrap();
+        assert_eq!(Some(42), decoded.get_reason_code());
+        assert_eq!(
+            UnknownFields::from([(15, vec![0x82, 0x01, 0x02])]),
+            decoded.get_unknown_fields()
+        );
+        let result: Vec<u8> = decoded.try_into().unwrap();
+        assert_eq!(bytes, result);
+    }
+
+    #[test]
+    fn test_decode_unexpected_type() {
+        // reason_code is the float 1.0
+        let bytes = hex::decode("a102fa3f800000").unwrap();
+        assert_eq!(
+            Err(URError::UnexpectedType {
+                expected: "u16".to_string(),
+                got: "f32".to_string(),
+            }),
+            KeystoneSignRejection::try_from(bytes).map(|_| ())
+        );
+    }
+}
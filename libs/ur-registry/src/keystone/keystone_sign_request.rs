@@ -1,21 +1,30 @@
-use crate::cbor::cbor_map;
+use crate::cbor::{
+    cbor_bytes, cbor_expect_type, cbor_map, cbor_origin, cbor_unknown_field, cbor_unknown_fields,
+};
+use crate::decode::DecodeContext;
 use crate::error::{URError, URResult};
 use crate::registry_types::{RegistryType, KEYSTONE_SIGN_REQUEST};
-use crate::traits::{From as FromCbor, RegistryItem, To};
-use crate::types::Bytes;
+use crate::traits::{From as FromCbor, RegistryItem, To, Validate};
+use crate::types::{Bytes, UnknownFields};
+use crate::validate::validate_sign_data;
 use alloc::string::{String, ToString};
 use alloc::vec::Vec;
-use minicbor::data::Int;
+use minicbor::data::{Int, Type};
 use minicbor::encode::Write;
 use minicbor::{Decoder, Encoder};
 
 const SIGN_DATA: u8 = 1;
 const ORIGIN: u8 = 2;
+const VERSION: u8 = 3;
 
 #[derive(Clone, Debug, Default)]
 pub struct KeystoneSignRequest {
     sign_data: Bytes,
     origin: Option<String>,
+    // the request format version for firmware that checks it, older firmware
+    // skips the unknown key and decodes the request as it always has
+    version: Option<u32>,
+    unknown_fields: UnknownFields,
 }
 
 impl KeystoneSignRequest {
@@ -31,8 +40,17 @@ impl KeystoneSignRequest {
         self.origin = Some(origin)
     }
 
+    pub fn set_version(&mut self, version: u32) {
+        self.version = Some(version)
+    }
+
     pub fn new(sign_data: Bytes, origin: Option<String>) -> KeystoneSignRequest {
-        KeystoneSignRequest { sign_data, origin }
+        KeystoneSignRequest {
+            sign_data,
+            origin,
+            version: None,
+            unknown_fields: UnknownFields::new(),
+        }
     }
     pub fn get_sign_data(&self) -> Bytes {
         self.sign_data.clone()
@@ -40,13 +58,22 @@ impl KeystoneSignRequest {
     pub fn get_origin(&self) -> Option<String> {
         self.origin.clone()
     }
+    pub fn get_version(&self) -> Option<u32> {
+        self.version
+    }
+    pub fn get_unknown_fields(&self) -> UnknownFields {
+        self.unknown_fields.clone()
+    }
 
     fn get_map_size(&self) -> u64 {
         let mut size = 1;
         if self.origin.is_some() {
             size += 1;
         }
-        size
+        if self.version.is_some() {
+            size += 1;
+        }
+        size + self.unknown_fields.len() as u64
     }
 }
 
@@ -56,6 +83,12 @@ impl RegistryItem for KeystoneSignRequest {
     }
 }
 
+impl Validate for KeystoneSignRequest {
+    fn validate_schema(&self) -> URResult<()> {
+        validate_sign_data("sign_data", &self.sign_data)
+    }
+}
+
 impl<C> minicbor::Encode<C> for KeystoneSignRequest {
     fn encode<W: Write>(
         &self,
@@ -70,24 +103,36 @@ impl<C> minicbor::Encode<C> for KeystoneSignRequest {
             e.int(Int::from(ORIGIN))?.str(origin)?;
         }
 
+        if let Some(version) = self.version {
+            e.int(Int::from(VERSION))?.u32(version)?;
+        }
+
+        cbor_unknown_fields(e, &self.unknown_fields)?;
         Ok(())
     }
 }
 
-impl<'b, C> minicbor::Decode<'b, C> for KeystoneSignRequest {
-    fn decode(d: &mut Decoder<'b>, _ctx: &mut C) -> Result<Self, minicbor::decode::Error> {
+impl<'b, C: DecodeContext> minicbor::Decode<'b, C> for KeystoneSignRequest {
+    fn decode(d: &mut Decoder<'b>, ctx: &mut C) -> Result<Self, minicbor::decode::Error> {
         let mut result = KeystoneSignRequest::default();
         cbor_map(d, &mut result, |key, obj, d| {
             let key =
                 u8::try_from(key).map_err(|e| minicbor::decode::Error::message(e.to_string()))?;
             match key {
                 SIGN_DATA => {
-                    obj.sign_data = d.bytes()?.to_vec();
+                    cbor_expect_type(d, ctx, Type::Bytes)?;
+                    obj.sign_data = cbor_bytes(d)?;
                 }
                 ORIGIN => {
-                    obj.origin = Some(d.str()?.to_string());
+                    obj.origin = Some(cbor_origin(d)?);
+                }
+                VERSION => {
+                    cbor_expect_type(d, ctx, Type::U32)?;
+                    obj.version = Some(d.u32()?);
+                }
+                _ => {
+                    cbor_unknown_field(d, key, &mut obj.unknown_fields)?;
                 }
-                _ => {}
             }
             Ok(())
         })?;
@@ -109,9 +154,11 @@ impl FromCbor<KeystoneSignRequest> for KeystoneSignRequest {
 
 #[cfg(test)]
 mod tests {
+    use crate::error::URError;
     use crate::keystone::keystone_sign_request::KeystoneSignRequest;
-    use crate::traits::{From as FromCbor, To};
+    use crate::traits::{From as FromCbor, To, Validate};
     use alloc::string::ToString;
+    use alloc::vec;
     use alloc::vec::Vec;
     use hex::FromHex;
 
@@ -137,4 +184,50 @@ mod tests {
         assert_eq!("1f8b08000000000000ff554d3f4b23411c256bb36c93d52aa40a8b10092c9999dfecfc812beee21214d6603410926e7e33b345305993dcc5fb187e04bf805c7f1f4041b03bacafbd43eceddc56783c788ff7270c0e9ae3cd71e57ce77c537daf6c75d57e096a371c3218ea61ce92c720da2b26c707871aa9f3c85d0a285dcad1b854a3732943e22482b3285ce7d7dbdfdfefe428c4db207cda8ffff492bb46f4d5641a2d1a633ca5580b501cb867ae66c538f8cc8366594669262c37e095f2129d25c88c2a8580b8d13e8d0684694189408568b4954a122141d61525bc744a010a2d35ad170918414de93595256619f1e8508385d6c3fdff30692efb9c77fbacdb2735faa4d78d9262b6292a9c9f70ab2793ed6a9da3dbde4caf776b3d6363287ecc647c3bea7da983733d5aad0aff53bb3cf7ebc1d6dfecd8e0b254cb6ab1b8f866a610bf3eff0b5b8da479f6f9e603ce1eec266c010000", hex::encode(keystone_sign_request.sign_data));
         assert_eq!("ltcWallet", keystone_sign_request.origin.unwrap());
     }
+
+    #[test]
+    fn test_version() {
+        let mut request = KeystoneSignRequest::new(vec![0x1f, 0x8b], None);
+        assert_eq!("a101421f8b", hex::encode(request.to_bytes().unwrap()));
+        assert_eq!(
+            None,
+            KeystoneSignRequest::from_cbor(request.to_bytes().unwrap())
+                .unwrap()
+                .get_version()
+        );
+
+        request.set_version(2);
+        let bytes = request.to_bytes().unwrap();
+        assert_eq!("a201421f8b0302", hex::encode(&bytes));
+        assert_eq!(
+            Some(2),
+            KeystoneSignRequest::from_cbor(bytes).unwrap().get_version()
+        );
+    }
+
+    #[test]
+    fn test_decode_unexpected_type() {
+        // version is the text string "1"
+        let bytes = Vec::from_hex("a2014100036131").unwrap();
+        assert_eq!(
+            Err(URError::UnexpectedType {
+                expected: "u32".to_string(),
+                got: "string".to_string(),
+            }),
+            KeystoneSignRequest::try_from(bytes).map(|_| ())
+        );
+    }
+
+    #[test]
+    fn test_validate_schema() {
+        let mut request =
+            KeystoneSignRequest::new(vec![0x1f, 0x8b, 0x08], Some("ltcWallet".to_string()));
+        assert!(request.validate_schema().is_ok());
+
+        request.set_sign_data(vec![]);
+        assert_eq!(
+            Err(URError::ValidationError("sign_data is empty".to_string())),
+            request.validate_schema()
+        );
+    }
 }
@@ -1,8 +1,8 @@
-use crate::cbor::cbor_map;
+use crate::cbor::{cbor_bytes, cbor_map, cbor_unknown_field, cbor_unknown_fields};
 use crate::error::{URError, URResult};
 use crate::registry_types::{RegistryType, KEYSTONE_SIGN_RESULT};
 use crate::traits::{From as FromCbor, RegistryItem, To};
-use crate::types::Bytes;
+use crate::types::{Bytes, UnknownFields};
 use alloc::string::ToString;
 use alloc::vec::Vec;
 use minicbor::data::Int;
@@ -14,6 +14,7 @@ const SIGN_RESULT: u8 = 1;
 #[derive(Clone, Debug, Default)]
 pub struct KeystoneSignResult {
     sign_result: Bytes,
+    unknown_fields: UnknownFields,
 }
 
 impl KeystoneSignResult {
@@ -28,12 +29,16 @@ impl KeystoneSignResult {
     pub fn new(signature: Bytes) -> Self {
         KeystoneSignResult {
             sign_result: signature,
+            unknown_fields: UnknownFields::new(),
         }
     }
 
     pub fn get_sign_result(&self) -> Bytes {
         self.sign_result.clone()
     }
+    pub fn get_unknown_fields(&self) -> UnknownFields {
+        self.unknown_fields.clone()
+    }
 }
 
 impl RegistryItem for KeystoneSignResult {
@@ -48,8 +53,9 @@ impl<C> minicbor::Encode<C> for KeystoneSignResult {
         e: &mut Encoder<W>,
         _ctx: &mut C,
     ) -> Result<(), minicbor::encode::Error<W::Error>> {
-        e.map(1)?;
+        e.map(1 + self.unknown_fields.len() as u64)?;
         e.int(Int::from(SIGN_RESULT))?.bytes(&self.sign_result)?;
+        cbor_unknown_fields(e, &self.unknown_fields)?;
         Ok(())
     }
 }
@@ -62,9 +68,11 @@ impl<'b, C> minicbor::Decode<'b, C> for KeystoneSignResult {
                 u8::try_from(key).map_err(|e| minicbor::decode::Error::message(e.to_string()))?;
             match key {
                 SIGN_RESULT => {
-                    obj.sign_result = d.bytes()?.to_vec();
+                    obj.sign_result = cbor_bytes(d)?;
+                }
+                _ => {
+                    cbor_unknown_field(d, key, &mut obj.unknown_fields)?;
                 }
-                _ => {}
             }
             Ok(())
         })?;
@@ -1,2 +1,3 @@
+pub mod keystone_sign_rejection;
 pub mod keystone_sign_request;
 pub mod keystone_sign_result;
@@ -3,12 +3,17 @@
 
 extern crate alloc;
 extern crate core;
+#[cfg(all(test, feature = "fuzz-tests"))]
+extern crate std;
 
 pub mod aptos;
 pub mod arweave;
 pub mod avalanche;
+#[cfg(feature = "base64")]
+pub mod base64url;
 pub mod bitcoin;
 pub mod bytes;
+pub mod bytewords;
 pub mod cardano;
 mod cbor;
 pub mod cosmos;
@@ -19,22 +24,37 @@ pub mod crypto_hd_key;
 pub mod crypto_key_path;
 pub mod crypto_output;
 pub mod crypto_psbt;
+pub mod decode;
+pub mod ergo;
 pub mod error;
 pub mod ethereum;
 pub mod extend;
+pub mod hedera;
+pub mod kaspa;
 pub mod keystone;
 mod macros;
 mod macros_impl;
 pub mod multi_key;
 pub mod near;
 pub mod pb;
+pub mod plugin;
+#[cfg(feature = "ss58")]
+pub mod polkadot;
+pub mod pretty;
 pub mod registry_types;
+#[cfg(all(test, feature = "fuzz-tests"))]
+mod round_trip;
 pub mod script_expression;
 pub mod solana;
 pub mod stellar;
 pub mod sui;
+pub mod tezos;
 pub mod ton;
 pub mod traits;
 pub mod monero;
 mod types;
+pub mod util;
+pub mod utxo_psbt;
+mod validate;
+mod warnings;
 pub mod zcash;
@@ -4,7 +4,14 @@ macro_rules! impl_ur_try_from_cbor_bytes {
         impl TryFrom<Vec<u8>> for $name {
             type Error = URError;
             fn try_from(value: Vec<u8>) -> URResult<Self> {
-                minicbor::decode(&value).map_err(|e| URError::CborDecodeError(e.to_string()))
+                Self::try_from(value.as_slice())
+            }
+        }
+
+        impl TryFrom<&[u8]> for $name {
+            type Error = URError;
+            fn try_from(value: &[u8]) -> URResult<Self> {
+                $crate::cbor::cbor_decode(value)
             }
         }
     };
@@ -20,6 +27,13 @@ macro_rules! impl_ur_try_into_cbor_bytes {
                 minicbor::to_vec(self.clone()).map_err(|e| URError::CborDecodeError(e.to_string()))
             }
         }
+
+        impl $name {
+            /// Encodes the item to CBOR without consuming it.
+            pub fn to_cbor(&self) -> URResult<Vec<u8>> {
+                minicbor::to_vec(self).map_err(|e| URError::CborEncodeError(e.to_string()))
+            }
+        }
     };
 }
 
@@ -33,22 +47,61 @@ macro_rules! impl_cbor_bytes {
     };
 }
 
+/// A one-line `Display` of request and signature types for logs, the full fields
+/// are in the derived `Debug`. Each type names the getters of its request id, key
+/// paths and signed data, where it has them.
+#[macro_export]
+macro_rules! impl_display {
+    ($($name: ident { $($field: ident: $getter: ident),* },) *) => {
+        $(
+            impl core::fmt::Display for $name {
+                fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                    $crate::pretty::Summary::default()
+                        $(.$field(&self.$getter()))*
+                        .write(stringify!($name), f)
+                }
+            }
+        )*
+    };
+}
+
 #[macro_export]
 macro_rules! impl_template_struct {
     ($name: ident { $($field: ident: $t: ty), *}) => {
+        $crate::impl_template_struct!(@struct $name { $($field: $t),* } {});
+    };
+    // the unknown fields are kept by the decoder, so `new` leaves them empty
+    ($name: ident { $($field: ident: $t: ty), *}, unknown_fields) => {
+        $crate::impl_template_struct!(@struct $name { $($field: $t),* } {
+            unknown_fields: $crate::types::UnknownFields
+        });
+
+        impl $name {
+            pub fn get_unknown_fields(&self) -> $crate::types::UnknownFields {
+                self.unknown_fields.clone()
+            }
+        }
+    };
+    (@struct $name: ident { $($field: ident: $t: ty), *} { $($hidden: ident: $h: ty)? }) => {
         #[derive(Debug, Clone, Default)]
         pub struct $name {
             $(
               $field: $t,
             )*
+            $(
+              $hidden: $h,
+            )?
         }
 
         impl $name {
             pub fn new($($field: $t), *) -> Self {
                 Self {
                     $(
-                        $field
-                    ), *
+                        $field,
+                    )*
+                    $(
+                        $hidden: Default::default(),
+                    )?
                 }
             }
         }
@@ -65,5 +118,51 @@ macro_rules! impl_template_struct {
                 )*
             }
         }
-    }
+    };
+}
+
+#[macro_export]
+macro_rules! impl_validate_signature {
+    ($name: ident) => {
+        impl $crate::traits::Validate for $name {
+            fn validate_schema(&self) -> $crate::error::URResult<()> {
+                $crate::validate::validate_signature_request_id(self.request_id.as_deref())
+            }
+        }
+    };
+    // for signatures where request_id is not optional
+    ($name: ident, required) => {
+        impl $crate::traits::Validate for $name {
+            fn validate_schema(&self) -> $crate::error::URResult<()> {
+                $crate::validate::validate_signature_request_id(Some(&self.request_id))
+            }
+        }
+    };
+}
+
+#[macro_export]
+macro_rules! impl_with_new_request_id {
+    ($name: ident) => {
+        impl $name {
+            /// A copy of the request with `request_id` replaced, for retrying it without
+            /// reusing the id.
+            pub fn with_new_request_id(&self, id: [u8; 16]) -> Self {
+                let mut request = self.clone();
+                request.request_id = Some(id.to_vec());
+                request
+            }
+        }
+    };
+    // for requests where request_id is not optional
+    ($name: ident, required) => {
+        impl $name {
+            /// A copy of the request with `request_id` replaced, for retrying it without
+            /// reusing the id.
+            pub fn with_new_request_id(&self, id: [u8; 16]) -> Self {
+                let mut request = self.clone();
+                request.request_id = id.to_vec();
+                request
+            }
+        }
+    };
 }
@@ -24,6 +24,7 @@ use crate::crypto_hd_key::CryptoHDKey;
 use crate::crypto_key_path::CryptoKeyPath;
 use crate::crypto_output::CryptoOutput;
 use crate::crypto_psbt::CryptoPSBT;
+use crate::ergo::{ergo_hint_bag::ErgoHintBag, ergo_signature::ErgoSignature};
 use crate::error::{URError, URResult};
 use crate::ethereum::{eth_sign_request::EthSignRequest, eth_signature::EthSignature};
 use crate::extend::crypto_multi_accounts::CryptoMultiAccounts;
@@ -31,8 +32,11 @@ use crate::extend::{
     key_derivation::KeyDerivationCall, key_derivation_schema::KeyDerivationSchema,
     qr_hardware_call::QRHardwareCall,
 };
+use crate::hedera::{hedera_sign_request::HederaSignRequest, hedera_signature::HederaSignature};
+use crate::kaspa::{kaspa_sign_request::KaspaSignRequest, kaspa_signature::KaspaSignature};
 use crate::keystone::{
-    keystone_sign_request::KeystoneSignRequest, keystone_sign_result::KeystoneSignResult,
+    keystone_sign_rejection::KeystoneSignRejection, keystone_sign_request::KeystoneSignRequest,
+    keystone_sign_result::KeystoneSignResult,
 };
 use crate::near::{near_sign_request::NearSignRequest, near_signature::NearSignature};
 use crate::solana::{sol_sign_request::SolSignRequest, sol_signature::SolSignature};
@@ -41,10 +45,15 @@ use crate::stellar::{
 };
 use crate::sui::sui_signature::SuiSignature;
 use crate::sui::{sui_sign_hash_request::SuiSignHashRequest, sui_sign_request::SuiSignRequest};
+use crate::tezos::{tezos_sign_request::TezosSignRequest, tezos_signature::TezosSignature};
 use crate::ton::{ton_sign_request::TonSignRequest, ton_signature::TonSignature};
+use crate::utxo_psbt::UtxoPSBT;
 use crate::zcash::zcash_accounts::ZcashAccounts;
 use crate::zcash::zcash_pczt::ZcashPczt;
+use crate::zcash::zcash_sign_request::ZcashSignRequest;
+use crate::zcash::zcash_signature::ZcashSignature;
 use crate::zcash::zcash_unified_full_viewing_key::ZcashUnifiedFullViewingKey;
+use crate::monero::{monero_sign_request::MoneroSignRequest, monero_signature::MoneroSignature};
 use crate::monero::{
     xmr_output::XmrOutput, xmr_keyimage::XmrKeyImage,
     xmr_txunsigned::XmrTxUnsigned, xmr_txsigned::XmrTxSigned,
@@ -53,7 +62,9 @@ use crate::{
     aptos::{aptos_sign_request::AptosSignRequest, aptos_signature::AptosSignature},
     cardano::cardano_sign_tx_hash_request::CardanoSignTxHashRequest,
 };
-use crate::{impl_cbor_bytes, impl_ur_try_from_cbor_bytes, impl_ur_try_into_cbor_bytes};
+use crate::{
+    impl_cbor_bytes, impl_display, impl_ur_try_from_cbor_bytes, impl_ur_try_into_cbor_bytes,
+};
 use alloc::string::ToString;
 use alloc::vec::Vec;
 
@@ -66,6 +77,7 @@ impl_cbor_bytes!(
     CryptoKeyPath,
     CryptoOutput,
     CryptoPSBT,
+    UtxoPSBT,
     CardanoSignature,
     CardanoUTXO,
     CardanoSignRequest,
@@ -88,9 +100,12 @@ impl_cbor_bytes!(
     CosmosSignature,
     EthSignRequest,
     EthSignature,
+    ErgoHintBag,
+    ErgoSignature,
     CryptoMultiAccounts,
     KeystoneSignRequest,
     KeystoneSignResult,
+    KeystoneSignRejection,
     NearSignRequest,
     NearSignature,
     SolSignRequest,
@@ -100,6 +115,12 @@ impl_cbor_bytes!(
     SuiSignRequest,
     SuiSignHashRequest,
     SuiSignature,
+    TezosSignRequest,
+    TezosSignature,
+    HederaSignRequest,
+    HederaSignature,
+    KaspaSignRequest,
+    KaspaSignature,
     TonSignature,
     TonSignRequest,
     KeyDerivationSchema,
@@ -113,7 +134,249 @@ impl_cbor_bytes!(
     XmrKeyImage,
     XmrTxUnsigned,
     XmrTxSigned,
+    MoneroSignRequest,
+    MoneroSignature,
     ZcashPczt,
+    ZcashSignRequest,
+    ZcashSignature,
     AvaxSignRequest,
     AvaxSignature,
 );
+
+impl_display!(
+    CardanoSignature {
+        request_id: get_request_id,
+        data: get_witness_set
+    },
+    CardanoSignRequest {
+        request_id: get_request_id,
+        paths: get_signing_paths,
+        data: get_sign_data
+    },
+    CardanoSignDataRequest {
+        request_id: get_request_id,
+        paths: get_derivation_path,
+        data: get_sign_data
+    },
+    CardanoSignDataSignature {
+        request_id: get_request_id,
+        data: get_signature
+    },
+    CardanoSignCip8DataRequest {
+        request_id: get_request_id,
+        paths: get_derivation_path,
+        data: get_sign_data
+    },
+    CardanoSignCip8DataSignature {
+        request_id: get_request_id,
+        data: get_signature
+    },
+    CardanoSignTxHashRequest {
+        request_id: get_request_id,
+        paths: get_paths
+    },
+    CardanoCatalystVotingRegistrationRequest {
+        request_id: get_request_id,
+        paths: get_derivation_path
+    },
+    CardanoCatalystSignature {
+        request_id: get_request_id,
+        data: get_signature
+    },
+    AptosSignRequest {
+        request_id: get_request_id,
+        paths: get_authentication_key_derivation_paths,
+        data: get_sign_data,
+        data: get_sign_data_list
+    },
+    AptosSignature {
+        request_id: get_request_id,
+        data: get_signature
+    },
+    ArweaveSignRequest {
+        request_id: get_request_id,
+        data: get_sign_data
+    },
+    ArweaveSignature {
+        request_id: get_request_id,
+        data: get_signature
+    },
+    CosmosSignRequest {
+        request_id: get_request_id,
+        paths: get_derivation_paths,
+        data: get_sign_data
+    },
+    EvmSignRequest {
+        request_id: get_request_id,
+        paths: get_derivation_path,
+        data: get_sign_data
+    },
+    EvmSignature {
+        request_id: get_request_id,
+        data: get_signature
+    },
+    CosmosSignature {
+        request_id: get_request_id,
+        data: get_signature
+    },
+    EthSignRequest {
+        request_id: get_request_id,
+        paths: get_derivation_path,
+        data: get_sign_data
+    },
+    EthSignature {
+        request_id: get_request_id,
+        data: get_signature
+    },
+    ErgoSignature {
+        request_id: get_request_id,
+        data: get_signature
+    },
+    KeystoneSignRequest {
+        data: get_sign_data
+    },
+    KeystoneSignResult {
+        data: get_sign_result
+    },
+    KeystoneSignRejection {
+        request_id: get_request_id
+    },
+    NearSignRequest {
+        request_id: get_request_id,
+        paths: get_derivation_path,
+        data: get_sign_data
+    },
+    NearSignature {
+        request_id: get_request_id,
+        data: get_signature
+    },
+    SolSignRequest {
+        request_id: get_request_id,
+        paths: get_derivation_path,
+        data: get_sign_data,
+        data: get_sign_data_list
+    },
+    SolSignature {
+        request_id: get_request_id,
+        data: get_signature
+    },
+    StellarSignRequest {
+        request_id: get_request_id,
+        paths: get_derivation_path,
+        data: get_sign_data
+    },
+    StellarSignature {
+        request_id: get_request_id,
+        data: get_signature
+    },
+    SuiSignRequest {
+        request_id: get_request_id,
+        paths: get_derivation_paths,
+        data: get_intent_message
+    },
+    SuiSignHashRequest {
+        request_id: get_request_id,
+        paths: get_derivation_paths
+    },
+    SuiSignature {
+        request_id: get_request_id,
+        data: get_signature
+    },
+    TezosSignRequest {
+        request_id: get_request_id,
+        paths: get_derivation_path,
+        data: get_sign_data
+    },
+    TezosSignature {
+        request_id: get_request_id,
+        data: get_signature
+    },
+    HederaSignRequest {
+        request_id: get_request_id,
+        paths: get_derivation_path,
+        data: get_sign_data
+    },
+    HederaSignature {
+        request_id: get_request_id,
+        data: get_signature
+    },
+    KaspaSignRequest {
+        request_id: get_request_id,
+        paths: get_derivation_paths,
+        data: get_sign_data
+    },
+    KaspaSignature {
+        request_id: get_request_id,
+        data: get_signatures
+    },
+    TonSignature {
+        request_id: get_request_id,
+        data: get_signature
+    },
+    TonSignRequest {
+        request_id: get_request_id,
+        paths: get_derivation_path,
+        data: get_sign_data
+    },
+    MoneroSignRequest {
+        request_id: get_request_id,
+        data: get_unsigned_tx_set
+    },
+    MoneroSignature {
+        request_id: get_request_id,
+        data: get_signed_tx_set
+    },
+    BtcSignRequest {
+        request_id: get_request_id,
+        paths: get_derivation_paths,
+        data: get_sign_data
+    },
+    BtcSignature {
+        request_id: get_request_id,
+        data: get_signature
+    },
+    ZcashSignRequest {
+        request_id: get_request_id,
+        paths: get_derivation_paths,
+        data: get_sign_data
+    },
+    ZcashSignature {
+        request_id: get_request_id,
+        data: get_signatures
+    },
+    AvaxSignRequest {
+        request_id: get_request_id,
+        paths: get_derivation_paths,
+        data: get_tx_data
+    },
+    AvaxSignature {
+        request_id: get_request_id,
+        data: get_signature
+    },
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_try_from_slice() {
+        let bytes = hex::decode(
+            "a301d825509b1deb4d3b7d4bad9bdd2b0d7b3dcb6d0244deadbeef03d92137a101450102030405",
+        )
+        .unwrap();
+        let from_slice = ErgoSignature::try_from(bytes.as_slice()).unwrap();
+        let from_vec = ErgoSignature::try_from(bytes.clone()).unwrap();
+        assert_eq!(from_vec.get_request_id(), from_slice.get_request_id());
+        assert_eq!(from_vec.get_signature(), from_slice.get_signature());
+        assert_eq!(
+            from_vec.get_hint_bag().map(|hint_bag| hint_bag.get_hints()),
+            from_slice.get_hint_bag().map(|hint_bag| hint_bag.get_hints())
+        );
+
+        assert!(matches!(
+            ErgoSignature::try_from(&bytes[..4]),
+            Err(URError::CborDecodeError(_))
+        ));
+    }
+}
@@ -1,3 +1,5 @@
+pub mod monero_sign_request;
+pub mod monero_signature;
 pub mod xmr_keyimage;
 pub mod xmr_output;
 pub mod xmr_txsigned;
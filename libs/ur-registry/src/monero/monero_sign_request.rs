@@ -0,0 +1,264 @@
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use minicbor::data::{Int, Tag};
+
+use crate::cbor::{
+    cbor_bytes, cbor_map, cbor_origin, cbor_tag, cbor_unknown_field, cbor_unknown_fields,
+};
+use crate::decode::DecodeContext;
+use crate::error::{URError, URResult};
+use crate::impl_template_struct;
+use crate::impl_with_new_request_id;
+use crate::registry_types::{RegistryType, MONERO_SIGN_REQUEST, UUID};
+use crate::traits::{DecodeWarnings, MapSize, RegistryItem, Validate};
+use crate::types::Bytes;
+use crate::validate::{validate_request_id, validate_sign_data};
+use crate::warnings::unknown_key_warnings;
+
+const REQUEST_ID: u8 = 1;
+const UNSIGNED_TX_SET: u8 = 2;
+const PRIMARY_ADDRESS: u8 = 3;
+const ACCOUNT_INDEX: u8 = 4;
+const ORIGIN: u8 = 5;
+
+// standard addresses and subaddresses are 95 base58 characters, integrated
+// addresses carry an extra payment id and are 106
+const ADDRESS_LENGTH: usize = 95;
+const INTEGRATED_ADDRESS_LENGTH: usize = 106;
+
+// the unsigned transaction set is the opaque blob exported by the wallet, the
+// device imports it together with the key images of the account
+impl_template_struct!(
+    MoneroSignRequest {
+        request_id: Option<Bytes>,
+        unsigned_tx_set: Bytes,
+        primary_address: String,
+        account_index: u32,
+        origin: Option<String>
+    },
+    unknown_fields
+);
+
+impl_with_new_request_id!(MoneroSignRequest);
+
+impl MapSize for MoneroSignRequest {
+    fn map_size(&self) -> u64 {
+        let mut size = 3;
+        if self.request_id.is_some() {
+            size += 1;
+        }
+        if self.origin.is_some() {
+            size += 1;
+        }
+        size + self.unknown_fields.len() as u64
+    }
+}
+
+impl RegistryItem for MoneroSignRequest {
+    fn get_registry_type() -> RegistryType<'static> {
+        MONERO_SIGN_REQUEST
+    }
+}
+
+impl Validate for MoneroSignRequest {
+    fn validate_schema(&self) -> URResult<()> {
+        validate_request_id(&self.request_id)?;
+        validate_sign_data("unsigned_tx_set", &self.unsigned_tx_set)?;
+        validate_primary_address(&self.primary_address)
+    }
+}
+
+fn validate_primary_address(address: &str) -> URResult<()> {
+    if address.is_empty() {
+        return Err(URError::ValidationError(
+            "primary_address is empty".to_string(),
+        ));
+    }
+    if address.len() != ADDRESS_LENGTH && address.len() != INTEGRATED_ADDRESS_LENGTH {
+        return Err(URError::ValidationError(format!(
+            "primary_address must be {} or {} characters, received {}",
+            ADDRESS_LENGTH,
+            INTEGRATED_ADDRESS_LENGTH,
+            address.len()
+        )));
+    }
+    if !address
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() && !matches!(c, '0' | 'O' | 'I' | 'l'))
+    {
+        return Err(URError::ValidationError(
+            "primary_address is not base58".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+impl DecodeWarnings for MoneroSignRequest {
+    fn decode_warnings(bytes: &[u8]) -> URResult<Vec<String>> {
+        MoneroSignRequest::try_from(bytes.to_vec())?;
+        unknown_key_warnings(
+            bytes,
+            &[
+                REQUEST_ID,
+                UNSIGNED_TX_SET,
+                PRIMARY_ADDRESS,
+                ACCOUNT_INDEX,
+                ORIGIN,
+            ],
+        )
+    }
+}
+
+impl<C> minicbor::Encode<C> for MoneroSignRequest {
+    fn encode<W: minicbor::encode::Write>(
+        &self,
+        e: &mut minicbor::Encoder<W>,
+        _ctx: &mut C,
+    ) -> Result<(), minicbor::encode::Error<W::Error>> {
+        e.map(self.map_size())?;
+        if let Some(request_id) = &self.request_id {
+            e.int(Int::from(REQUEST_ID))?
+                .tag(Tag::Unassigned(UUID.get_tag()))?
+                .bytes(request_id)?;
+        }
+        e.int(Int::from(UNSIGNED_TX_SET))?
+            .bytes(&self.unsigned_tx_set)?;
+        e.int(Int::from(PRIMARY_ADDRESS))?
+            .str(&self.primary_address)?;
+        e.int(Int::from(ACCOUNT_INDEX))?.u32(self.account_index)?;
+        if let Some(origin) = &self.origin {
+            e.int(Int::from(ORIGIN))?.str(origin)?;
+        }
+        cbor_unknown_fields(e, &self.unknown_fields)?;
+        Ok(())
+    }
+}
+
+impl<'b, C: DecodeContext> minicbor::Decode<'b, C> for MoneroSignRequest {
+    fn decode(d: &mut minicbor::Decoder<'b>, ctx: &mut C) -> Result<Self, minicbor::decode::Error> {
+        let mut result = MoneroSignRequest::default();
+        let mut has_unsigned_tx_set = false;
+        let mut has_primary_address = false;
+        cbor_map(d, &mut result, |key, obj, d| {
+            let key =
+                u8::try_from(key).map_err(|e| minicbor::decode::Error::message(e.to_string()))?;
+            match key {
+                REQUEST_ID => {
+                    cbor_tag(d, ctx, UUID.get_tag(), "UUID tag is invalid")?;
+                    obj.request_id = Some(cbor_bytes(d)?);
+                }
+                UNSIGNED_TX_SET => {
+                    obj.unsigned_tx_set = cbor_bytes(d)?;
+                    has_unsigned_tx_set = true;
+                }
+                PRIMARY_ADDRESS => {
+                    obj.primary_address = d.str()?.to_string();
+                    has_primary_address = true;
+                }
+                ACCOUNT_INDEX => {
+                    obj.account_index = d.u32()?;
+                }
+                ORIGIN => {
+                    obj.origin = Some(cbor_origin(d)?);
+                }
+                _ => {
+                    cbor_unknown_field(d, key, &mut obj.unknown_fields)?;
+                }
+            }
+            Ok(())
+        })?;
+        // without them the device has nothing to sign, or no account to sign it with
+        if !has_unsigned_tx_set {
+            return Err(minicbor::decode::Error::message(
+                "unsigned_tx_set is missing",
+            ));
+        }
+        if !has_primary_address {
+            return Err(minicbor::decode::Error::message(
+                "primary_address is missing",
+            ));
+        }
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::URError;
+
+    #[test]
+    fn test_round_trip() {
+        let request = MoneroSignRequest::new(
+            Some(hex::decode("9b1deb4d3b7d4bad9bdd2b0d7b3dcb6d").unwrap()),
+            hex::decode("4d6f6e65726f20756e7369676e65642074782073657405deadbeef").unwrap(),
+            "4AdUndXHHZ6cfufTMvppY6JwXNouMBzSkbLYfpAV5Usx3skxNgYeYTRj5UzqtReoS44qo9mtmXCqY45DJ852K5Jv2684Rge".to_string(),
+            1,
+            Some("Feather".to_string()),
+        );
+        let bytes: Vec<u8> = request.clone().try_into().unwrap();
+        let decoded = MoneroSignRequest::try_from(bytes.clone()).unwrap();
+        assert_eq!(request.get_request_id(), decoded.get_request_id());
+        assert_eq!(request.get_unsigned_tx_set(), decoded.get_unsigned_tx_set());
+        assert_eq!(request.get_primary_address(), decoded.get_primary_address());
+        assert_eq!(1, decoded.get_account_index());
+        assert_eq!(Some("Feather".to_string()), decoded.get_origin());
+        assert!(decoded.validate_schema().is_ok());
+
+        let encoded: Vec<u8> = decoded.try_into().unwrap();
+        assert_eq!(bytes, encoded);
+    }
+
+    #[test]
+    fn test_empty_tx_set() {
+        let request = MoneroSignRequest::new(None, Vec::new(), "4A".to_string(), 0, None);
+        assert_eq!(
+            Err(URError::ValidationError(
+                "unsigned_tx_set is empty".to_string()
+            )),
+            request.validate_schema()
+        );
+    }
+
+    #[test]
+    fn test_invalid_primary_address() {
+        let tx_set = hex::decode("deadbeef").unwrap();
+        let request = MoneroSignRequest::new(None, tx_set.clone(), String::new(), 0, None);
+        assert_eq!(
+            Err(URError::ValidationError(
+                "primary_address is empty".to_string()
+            )),
+            request.validate_schema()
+        );
+        let request = MoneroSignRequest::new(None, tx_set.clone(), "4A".to_string(), 0, None);
+        assert_eq!(
+            Err(URError::ValidationError(
+                "primary_address must be 95 or 106 characters, received 2".to_string()
+            )),
+            request.validate_schema()
+        );
+        let request = MoneroSignRequest::new(None, tx_set, "0".repeat(95), 0, None);
+        assert_eq!(
+            Err(URError::ValidationError(
+                "primary_address is not base58".to_string()
+            )),
+            request.validate_schema()
+        );
+    }
+
+    #[test]
+    fn test_decode_missing_key() {
+        // unsigned_tx_set and account_index, without primary_address
+        let result = MoneroSignRequest::try_from(hex::decode("a20241010400").unwrap());
+        assert!(
+            matches!(result, Err(URError::CborDecodeError(message)) if message.contains("primary_address is missing"))
+        );
+
+        // primary_address and account_index, without unsigned_tx_set
+        let result = MoneroSignRequest::try_from(hex::decode("a2036234410400").unwrap());
+        assert!(
+            matches!(result, Err(URError::CborDecodeError(message)) if message.contains("unsigned_tx_set is missing"))
+        );
+    }
+}
@@ -0,0 +1,128 @@
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use minicbor::data::{Int, Tag};
+
+use crate::cbor::{
+    cbor_bytes, cbor_map, cbor_origin, cbor_tag, cbor_unknown_field, cbor_unknown_fields,
+};
+use crate::decode::DecodeContext;
+use crate::error::URResult;
+use crate::impl_template_struct;
+use crate::impl_validate_signature;
+use crate::registry_types::{RegistryType, MONERO_SIGNATURE, UUID};
+use crate::traits::{DecodeWarnings, MapSize, RegistryItem};
+use crate::types::Bytes;
+use crate::warnings::unknown_key_warnings;
+
+const REQUEST_ID: u8 = 1;
+const SIGNED_TX_SET: u8 = 2;
+const ORIGIN: u8 = 3;
+
+impl_template_struct!(
+    MoneroSignature {
+        request_id: Option<Bytes>,
+        signed_tx_set: Bytes,
+        origin: Option<String>
+    },
+    unknown_fields
+);
+
+impl_validate_signature!(MoneroSignature);
+
+impl RegistryItem for MoneroSignature {
+    fn get_registry_type() -> RegistryType<'static> {
+        MONERO_SIGNATURE
+    }
+}
+
+impl MapSize for MoneroSignature {
+    fn map_size(&self) -> u64 {
+        let mut size = 1;
+        if self.request_id.is_some() {
+            size += 1;
+        }
+        if self.origin.is_some() {
+            size += 1;
+        }
+        size + self.unknown_fields.len() as u64
+    }
+}
+
+impl DecodeWarnings for MoneroSignature {
+    fn decode_warnings(bytes: &[u8]) -> URResult<Vec<String>> {
+        MoneroSignature::try_from(bytes.to_vec())?;
+        unknown_key_warnings(bytes, &[REQUEST_ID, SIGNED_TX_SET, ORIGIN])
+    }
+}
+
+impl<C> minicbor::Encode<C> for MoneroSignature {
+    fn encode<W: minicbor::encode::Write>(
+        &self,
+        e: &mut minicbor::Encoder<W>,
+        _ctx: &mut C,
+    ) -> Result<(), minicbor::encode::Error<W::Error>> {
+        e.map(self.map_size())?;
+        if let Some(request_id) = &self.request_id {
+            e.int(Int::from(REQUEST_ID))?
+                .tag(Tag::Unassigned(UUID.get_tag()))?
+                .bytes(request_id)?;
+        }
+        e.int(Int::from(SIGNED_TX_SET))?
+            .bytes(&self.signed_tx_set)?;
+        if let Some(origin) = &self.origin {
+            e.int(Int::from(ORIGIN))?.str(origin)?;
+        }
+        cbor_unknown_fields(e, &self.unknown_fields)?;
+        Ok(())
+    }
+}
+
+impl<'b, C: DecodeContext> minicbor::Decode<'b, C> for MoneroSignature {
+    fn decode(d: &mut minicbor::Decoder<'b>, ctx: &mut C) -> Result<Self, minicbor::decode::Error> {
+        let mut result = MoneroSignature::default();
+        cbor_map(d, &mut result, |key, obj, d| {
+            let key =
+                u8::try_from(key).map_err(|e| minicbor::decode::Error::message(e.to_string()))?;
+            match key {
+                REQUEST_ID => {
+                    cbor_tag(d, ctx, UUID.get_tag(), "UUID tag is invalid")?;
+                    obj.request_id = Some(cbor_bytes(d)?);
+                }
+                SIGNED_TX_SET => {
+                    obj.signed_tx_set = cbor_bytes(d)?;
+                }
+                ORIGIN => {
+                    obj.origin = Some(cbor_origin(d)?);
+                }
+                _ => {
+                    cbor_unknown_field(d, key, &mut obj.unknown_fields)?;
+                }
+            }
+            Ok(())
+        })?;
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        let signature = MoneroSignature::new(
+            Some(hex::decode("9b1deb4d3b7d4bad9bdd2b0d7b3dcb6d").unwrap()),
+            hex::decode("4d6f6e65726f207369676e65642074782073657405cafebabe").unwrap(),
+            None,
+        );
+        let bytes: Vec<u8> = signature.clone().try_into().unwrap();
+        assert_eq!(
+            "a201d825509b1deb4d3b7d4bad9bdd2b0d7b3dcb6d0258194d6f6e65726f207369676e65642074782073657405cafebabe",
+            hex::encode(&bytes)
+        );
+        let decoded = MoneroSignature::try_from(bytes).unwrap();
+        assert_eq!(signature.get_request_id(), decoded.get_request_id());
+        assert_eq!(signature.get_signed_tx_set(), decoded.get_signed_tx_set());
+        assert!(decoded.get_origin().is_none());
+    }
+}
@@ -1,3 +1,4 @@
+use crate::cbor::cbor_bytes;
 use crate::registry_types::{RegistryType, XMR_OUTPUT};
 use crate::traits::RegistryItem;
 use crate::types::Bytes;
@@ -20,7 +21,7 @@ impl<'b, C> minicbor::Decode<'b, C> for XmrOutput {
     fn decode(d: &mut Decoder<'b>, _ctx: &mut C) -> Result<Self, minicbor::decode::Error> {
         match d.datatype()? {
             Type::Bytes => {
-                Ok(XmrOutput::new(d.bytes()?.to_vec()))
+                Ok(XmrOutput::new(cbor_bytes(d)?))
             }
             _ => Err(minicbor::decode::Error::message("Invalid datatype for XmrOutput")),
         }
@@ -1,6 +1,7 @@
 use crate::cbor::{cbor_array, cbor_map};
 use crate::crypto_ec_key::CryptoECKey;
 use crate::crypto_hd_key::CryptoHDKey;
+use crate::decode::DecodeContext;
 use crate::registry_types::{CRYPTO_ECKEY, CRYPTO_HDKEY};
 use crate::traits::RegistryItem;
 use alloc::string::ToString;
@@ -92,7 +93,7 @@ impl<C> minicbor::Encode<C> for MultiKey {
     }
 }
 
-impl<'b, C> minicbor::Decode<'b, C> for MultiKey {
+impl<'b, C: DecodeContext> minicbor::Decode<'b, C> for MultiKey {
     fn decode(d: &mut Decoder<'b>, ctx: &mut C) -> Result<Self, minicbor::decode::Error> {
         let mut result = MultiKey::default();
         cbor_map(d, &mut result, |key, obj, d| {
@@ -1,9 +1,15 @@
-use crate::cbor::cbor_map;
+use crate::cbor::{
+    cbor_array, cbor_bytes, cbor_map, cbor_origin, cbor_tag, cbor_unknown_field,
+    cbor_unknown_fields,
+};
 use crate::crypto_key_path::CryptoKeyPath;
+use crate::decode::DecodeContext;
 use crate::error::{URError, URResult};
+use crate::impl_with_new_request_id;
 use crate::registry_types::{RegistryType, CRYPTO_KEYPATH, NEAR_SIGN_REQUEST, UUID};
-use crate::traits::{From as FromCbor, RegistryItem, To};
-use crate::types::Bytes;
+use crate::traits::{From as FromCbor, RegistryItem, To, Validate};
+use crate::types::{Bytes, UnknownFields};
+use crate::validate::{validate_path, validate_request_id, validate_sign_data};
 use alloc::string::{String, ToString};
 use alloc::vec::Vec;
 use minicbor::data::{Int, Tag};
@@ -16,6 +22,8 @@ const DERIVATION_PATH: u8 = 3;
 const ACCOUNT: u8 = 4;
 const ORIGIN: u8 = 5;
 
+const NEAR_COIN_TYPE: u32 = 397;
+
 #[derive(Clone, Debug, Default)]
 pub struct NearSignRequest {
     request_id: Option<Bytes>,
@@ -23,8 +31,11 @@ pub struct NearSignRequest {
     derivation_path: CryptoKeyPath,
     account: Option<Bytes>,
     origin: Option<String>,
+    unknown_fields: UnknownFields,
 }
 
+impl_with_new_request_id!(NearSignRequest);
+
 impl NearSignRequest {
     pub fn default() -> Self {
         Default::default()
@@ -63,6 +74,7 @@ impl NearSignRequest {
             derivation_path,
             account,
             origin,
+            unknown_fields: UnknownFields::new(),
         }
     }
     pub fn get_request_id(&self) -> Option<Bytes> {
@@ -80,6 +92,9 @@ impl NearSignRequest {
     pub fn get_origin(&self) -> Option<String> {
         self.origin.clone()
     }
+    pub fn get_unknown_fields(&self) -> UnknownFields {
+        self.unknown_fields.clone()
+    }
 
     fn get_map_size(&self) -> u64 {
         let mut size = 2;
@@ -92,7 +107,7 @@ impl NearSignRequest {
         if self.origin.is_some() {
             size += 1;
         }
-        size
+        size + self.unknown_fields.len() as u64
     }
 }
 
@@ -102,6 +117,20 @@ impl RegistryItem for NearSignRequest {
     }
 }
 
+impl Validate for NearSignRequest {
+    fn validate_schema(&self) -> URResult<()> {
+        validate_request_id(&self.request_id)?;
+        if self.sign_data.is_empty() {
+            return Err(URError::ValidationError("sign_data is empty".to_string()));
+        }
+        for sign_data in &self.sign_data {
+            validate_sign_data("sign_data", sign_data)?;
+        }
+        validate_path("derivation_path", &self.derivation_path, Some(NEAR_COIN_TYPE))?;
+        Ok(())
+    }
+}
+
 impl<C> minicbor::Encode<C> for NearSignRequest {
     fn encode<W: Write>(
         &self,
@@ -135,41 +164,47 @@ impl<C> minicbor::Encode<C> for NearSignRequest {
             e.int(Int::from(ORIGIN))?.str(origin)?;
         }
 
+        cbor_unknown_fields(e, &self.unknown_fields)?;
         Ok(())
     }
 }
 
-impl<'b, C> minicbor::Decode<'b, C> for NearSignRequest {
-    fn decode(d: &mut Decoder<'b>, _ctx: &mut C) -> Result<Self, minicbor::decode::Error> {
+impl<'b, C: DecodeContext> minicbor::Decode<'b, C> for NearSignRequest {
+    fn decode(d: &mut Decoder<'b>, ctx: &mut C) -> Result<Self, minicbor::decode::Error> {
         let mut result = NearSignRequest::default();
         cbor_map(d, &mut result, |key, obj, d| {
             let key =
                 u8::try_from(key).map_err(|e| minicbor::decode::Error::message(e.to_string()))?;
             match key {
                 REQUEST_ID => {
-                    d.tag()?;
-                    obj.request_id = Some(d.bytes()?.to_vec());
+                    cbor_tag(d, ctx, UUID.get_tag(), "UUID tag is invalid")?;
+                    obj.request_id = Some(cbor_bytes(d)?);
                 }
                 SIGN_DATA => {
-                    let sign_data_len = d.array()?;
                     obj.sign_data = Vec::new();
-                    if sign_data_len.is_some() {
-                        for _ in 0..sign_data_len.unwrap() {
-                            obj.sign_data.push(d.bytes()?.to_vec());
-                        }
-                    }
+                    cbor_array(d, &mut obj.sign_data, |_index, array, d| {
+                        array.push(cbor_bytes(d)?);
+                        Ok(())
+                    })?;
                 }
                 DERIVATION_PATH => {
-                    d.tag()?;
-                    obj.derivation_path = CryptoKeyPath::decode(d, _ctx)?;
+                    cbor_tag(
+                        d,
+                        ctx,
+                        CryptoKeyPath::get_registry_type().get_tag(),
+                        "CryptoKeyPath tag is invalid",
+                    )?;
+                    obj.derivation_path = CryptoKeyPath::decode(d, ctx)?;
                 }
                 ACCOUNT => {
-                    obj.account = Some(d.bytes()?.to_vec());
+                    obj.account = Some(cbor_bytes(d)?);
                 }
                 ORIGIN => {
-                    obj.origin = Some(d.str()?.to_string());
+                    obj.origin = Some(cbor_origin(d)?);
+                }
+                _ => {
+                    cbor_unknown_field(d, key, &mut obj.unknown_fields)?;
                 }
-                _ => {}
             }
             Ok(())
         })?;
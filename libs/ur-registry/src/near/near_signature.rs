@@ -1,8 +1,12 @@
-use crate::cbor::cbor_map;
+use crate::cbor::{
+    cbor_array, cbor_bytes, cbor_map, cbor_tag, cbor_unknown_field, cbor_unknown_fields,
+};
+use crate::decode::DecodeContext;
 use crate::error::{URError, URResult};
+use crate::impl_validate_signature;
 use crate::registry_types::{RegistryType, NEAR_SIGNATURE, UUID};
 use crate::traits::{From as FromCbor, RegistryItem, To};
-use crate::types::Bytes;
+use crate::types::{Bytes, UnknownFields};
 use alloc::string::ToString;
 use alloc::vec::Vec;
 use minicbor::data::{Int, Tag};
@@ -16,8 +20,11 @@ const SIGNATURE: u8 = 2;
 pub struct NearSignature {
     request_id: Option<Bytes>,
     signature: Vec<Bytes>,
+    unknown_fields: UnknownFields,
 }
 
+impl_validate_signature!(NearSignature);
+
 impl NearSignature {
     pub fn default() -> Self {
         Default::default()
@@ -35,6 +42,7 @@ impl NearSignature {
         NearSignature {
             request_id,
             signature,
+            unknown_fields: UnknownFields::new(),
         }
     }
 
@@ -44,6 +52,9 @@ impl NearSignature {
     pub fn get_signature(&self) -> Vec<Bytes> {
         self.signature.clone()
     }
+    pub fn get_unknown_fields(&self) -> UnknownFields {
+        self.unknown_fields.clone()
+    }
 }
 
 impl RegistryItem for NearSignature {
@@ -62,7 +73,7 @@ impl<C> minicbor::Encode<C> for NearSignature {
         if self.request_id.is_some() {
             size += 1;
         }
-        e.map(size)?;
+        e.map(size + self.unknown_fields.len() as u64)?;
         if let Some(request_id) = &self.request_id {
             e.int(Int::from(REQUEST_ID))?
                 .tag(Tag::Unassigned(UUID.get_tag()))?
@@ -74,31 +85,32 @@ impl<C> minicbor::Encode<C> for NearSignature {
         for ele in &self.signature {
             e.bytes(ele)?;
         }
+        cbor_unknown_fields(e, &self.unknown_fields)?;
         Ok(())
     }
 }
 
-impl<'b, C> minicbor::Decode<'b, C> for NearSignature {
-    fn decode(d: &mut Decoder<'b>, _ctx: &mut C) -> Result<Self, minicbor::decode::Error> {
+impl<'b, C: DecodeContext> minicbor::Decode<'b, C> for NearSignature {
+    fn decode(d: &mut Decoder<'b>, ctx: &mut C) -> Result<Self, minicbor::decode::Error> {
         let mut result = NearSignature::default();
         cbor_map(d, &mut result, |key, obj, d| {
             let key =
                 u8::try_from(key).map_err(|e| minicbor::decode::Error::message(e.to_string()))?;
             match key {
                 REQUEST_ID => {
-                    d.tag()?;
-                    obj.request_id = Some(d.bytes()?.to_vec());
+                    cbor_tag(d, ctx, UUID.get_tag(), "UUID tag is invalid")?;
+                    obj.request_id = Some(cbor_bytes(d)?);
                 }
                 SIGNATURE => {
-                    let len = d.array()?;
                     obj.signature = Vec::new();
-                    if len.is_some() {
-                        for _ in 0..len.unwrap() {
-                            obj.signature.push(d.bytes()?.to_vec());
-                        }
-                    }
+                    cbor_array(d, &mut obj.signature, |_index, array, d| {
+                        array.push(cbor_bytes(d)?);
+                        Ok(())
+                    })?;
+                }
+                _ => {
+                    cbor_unknown_field(d, key, &mut obj.unknown_fields)?;
                 }
-                _ => {}
             }
             Ok(())
         })?;
@@ -148,4 +160,15 @@ mod tests {
         assert_eq!(request_id, near_signature.get_request_id().unwrap());
         assert_eq!(signature, near_signature.get_signature());
     }
+
+    #[test]
+    fn test_decode_indefinite_signature() {
+        let cbor = hex::decode("a201d825509b1deb4d3b7d4bad9bdd2b0d7b3dcb6d029f584085c578f8ca68bf8d771f0346ed68c4170df9ee9878cb76f3e2fac425c3f5793d36a741547e245c6c7ac1b9433ad5fc523d41152cac2a3726cbe134e0a0366802ff").unwrap();
+        let near_signature = NearSignature::from_cbor(cbor).unwrap();
+        assert_eq!(1, near_signature.get_signature().len());
+        assert_eq!(
+            "a201d825509b1deb4d3b7d4bad9bdd2b0d7b3dcb6d0281584085c578f8ca68bf8d771f0346ed68c4170df9ee9878cb76f3e2fac425c3f5793d36a741547e245c6c7ac1b9433ad5fc523d41152cac2a3726cbe134e0a0366802",
+            hex::encode(near_signature.to_bytes().unwrap())
+        );
+    }
 }
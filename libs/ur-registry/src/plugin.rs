@@ -0,0 +1,241 @@
+use alloc::boxed::Box;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::any::Any;
+
+use minicbor::Decoder;
+
+use crate::error::{URError, URResult};
+use crate::pretty::pretty_print;
+use crate::registry_types::{RegistryTag, URType};
+
+/// A registry type defined outside of the crate, so that other chains take part
+/// in the generic routing without forking it. Items cross the trait as CBOR bytes
+/// and type-erased values, callers downcast the decoded value to their own type.
+pub trait ChainPlugin {
+    /// The UR type, such as `foo-sign-request`.
+    fn get_type(&self) -> &str;
+    /// The CBOR tag of the type.
+    fn get_tag(&self) -> u64;
+    fn decode(&self, bytes: &[u8]) -> URResult<Box<dyn Any>>;
+    /// Encodes an item returned by [`ChainPlugin::decode`].
+    fn encode(&self, item: &dyn Any) -> URResult<Vec<u8>>;
+    /// A multi-line dump of the item, as [`pretty_print`] writes for built-in types.
+    fn pretty_print(&self, bytes: &[u8]) -> URResult<String>;
+}
+
+/// The plugins the by-type and by-tag dispatchers fall back to once no built-in
+/// type matches.
+#[derive(Default)]
+pub struct PluginRegistry {
+    plugins: Vec<Box<dyn ChainPlugin>>,
+}
+
+impl PluginRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds `plugin`, failing when its type or tag is taken by a built-in type
+    /// or an earlier plugin.
+    pub fn register(&mut self, plugin: Box<dyn ChainPlugin>) -> URResult<()> {
+        if RegistryTag::try_from(plugin.get_tag()).is_ok()
+            || self.get_by_tag(plugin.get_tag()).is_some()
+        {
+            return Err(URError::PluginConflict(format!("tag {}", plugin.get_tag())));
+        }
+        if URType::from(plugin.get_type()).is_ok() || self.get_by_type(plugin.get_type()).is_some()
+        {
+            return Err(URError::PluginConflict(plugin.get_type().to_string()));
+        }
+        self.plugins.push(plugin);
+        Ok(())
+    }
+
+    pub fn get_by_type(&self, ur_type: &str) -> Option<&dyn ChainPlugin> {
+        self.plugins
+            .iter()
+            .find(|plugin| plugin.get_type() == ur_type)
+            .map(|plugin| plugin.as_ref())
+    }
+
+    pub fn get_by_tag(&self, tag: u64) -> Option<&dyn ChainPlugin> {
+        self.plugins
+            .iter()
+            .find(|plugin| plugin.get_tag() == tag)
+            .map(|plugin| plugin.as_ref())
+    }
+
+    /// Decodes `bytes` with the plugin registered for `ur_type`.
+    pub fn decode(&self, ur_type: &str, bytes: &[u8]) -> URResult<Box<dyn Any>> {
+        self.get_by_type(ur_type)
+            .ok_or(URError::NotSupportURTypeError(ur_type.to_string()))?
+            .decode(bytes)
+    }
+
+    /// Decodes an item prefixed with its CBOR tag, as it's embedded in other
+    /// items, with the plugin registered for that tag.
+    pub fn decode_tagged(&self, bytes: &[u8]) -> URResult<Box<dyn Any>> {
+        let mut d = Decoder::new(bytes);
+        let tag = u64::from(
+            d.tag()
+                .map_err(|e| URError::CborDecodeError(e.to_string()))?,
+        );
+        self.get_by_tag(tag)
+            .ok_or(URError::NotSupportURTypeError(format!("tag {}", tag)))?
+            .decode(&bytes[d.position()..])
+    }
+
+    /// Like [`pretty_print`], trying the registered plugins for types the crate
+    /// doesn't know.
+    pub fn pretty_print(&self, ur_type: &str, bytes: &[u8]) -> URResult<String> {
+        match pretty_print(ur_type, bytes) {
+            Err(URError::NotSupportURTypeError(_)) => self
+                .get_by_type(ur_type)
+                .ok_or(URError::NotSupportURTypeError(ur_type.to_string()))?
+                .pretty_print(bytes),
+            result => result,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, PartialEq)]
+    struct DummySignRequest {
+        sign_data: Vec<u8>,
+    }
+
+    struct DummyPlugin;
+
+    impl DummyPlugin {
+        fn decode_item(bytes: &[u8]) -> URResult<DummySignRequest> {
+            let mut d = Decoder::new(bytes);
+            let sign_data = d
+                .bytes()
+                .map_err(|e| URError::CborDecodeError(e.to_string()))?;
+            Ok(DummySignRequest {
+                sign_data: sign_data.to_vec(),
+            })
+        }
+    }
+
+    impl ChainPlugin for DummyPlugin {
+        fn get_type(&self) -> &str {
+            "dummy-sign-request"
+        }
+
+        fn get_tag(&self) -> u64 {
+            90001
+        }
+
+        fn decode(&self, bytes: &[u8]) -> URResult<Box<dyn Any>> {
+            Ok(Box::new(Self::decode_item(bytes)?))
+        }
+
+        fn encode(&self, item: &dyn Any) -> URResult<Vec<u8>> {
+            let item = item
+                .downcast_ref::<DummySignRequest>()
+                .ok_or(URError::CborEncodeError(
+                    "not a dummy-sign-request".to_string(),
+                ))?;
+            let mut e = minicbor::Encoder::new(Vec::new());
+            e.bytes(&item.sign_data)
+                .map_err(|e| URError::CborEncodeError(e.to_string()))?;
+            Ok(e.into_writer())
+        }
+
+        fn pretty_print(&self, bytes: &[u8]) -> URResult<String> {
+            Ok(format!("{:#?}", Self::decode_item(bytes)?))
+        }
+    }
+
+    #[test]
+    fn test_dispatch_to_plugin() {
+        let mut registry = PluginRegistry::new();
+        registry.register(Box::new(DummyPlugin)).unwrap();
+        let bytes = hex::decode("43010203").unwrap();
+
+        let item = registry.decode("dummy-sign-request", &bytes).unwrap();
+        let request = item.downcast_ref::<DummySignRequest>().unwrap();
+        assert_eq!(vec![1, 2, 3], request.sign_data);
+        let plugin = registry.get_by_type("dummy-sign-request").unwrap();
+        assert_eq!(bytes, plugin.encode(item.as_ref()).unwrap());
+
+        // tag 90001 prefixed
+        let tagged = hex::decode("da00015f9143010203").unwrap();
+        let item = registry.decode_tagged(&tagged).unwrap();
+        assert_eq!(
+            Some(&DummySignRequest {
+                sign_data: vec![1, 2, 3]
+            }),
+            item.downcast_ref::<DummySignRequest>()
+        );
+
+        let result = registry.pretty_print("dummy-sign-request", &bytes).unwrap();
+        assert!(result.starts_with("DummySignRequest {\n"));
+        // built-in types are still decoded by the crate
+        let result = registry
+            .pretty_print("ergo-signature", &hex::decode("a10244deadbeef").unwrap())
+            .unwrap();
+        assert!(result.starts_with("ErgoSignature {\n"));
+        assert!(matches!(
+            registry.decode("unknown-type", &bytes),
+            Err(URError::NotSupportURTypeError(_))
+        ));
+    }
+
+    #[test]
+    fn test_register_conflict() {
+        struct TestPlugin {
+            ur_type: &'static str,
+            tag: u64,
+        }
+
+        impl ChainPlugin for TestPlugin {
+            fn get_type(&self) -> &str {
+                self.ur_type
+            }
+
+            fn get_tag(&self) -> u64 {
+                self.tag
+            }
+
+            fn decode(&self, _bytes: &[u8]) -> URResult<Box<dyn Any>> {
+                Ok(Box::new(()))
+            }
+
+            fn encode(&self, _item: &dyn Any) -> URResult<Vec<u8>> {
+                Ok(Vec::new())
+            }
+
+            fn pretty_print(&self, _bytes: &[u8]) -> URResult<String> {
+                Ok(String::new())
+            }
+        }
+
+        let mut registry = PluginRegistry::new();
+        assert_eq!(
+            Err(URError::PluginConflict("tag 401".to_string())),
+            registry.register(Box::new(TestPlugin {
+                ur_type: "my-eth-sign-request",
+                tag: 401,
+            }))
+        );
+        assert_eq!(
+            Err(URError::PluginConflict("eth-sign-request".to_string())),
+            registry.register(Box::new(TestPlugin {
+                ur_type: "eth-sign-request",
+                tag: 90002,
+            }))
+        );
+        registry.register(Box::new(DummyPlugin)).unwrap();
+        assert_eq!(
+            Err(URError::PluginConflict("tag 90001".to_string())),
+            registry.register(Box::new(DummyPlugin))
+        );
+    }
+}
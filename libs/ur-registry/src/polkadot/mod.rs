@@ -0,0 +1,3 @@
+pub mod ss58;
+
+pub use ss58::{ss58_decode, ss58_encode};
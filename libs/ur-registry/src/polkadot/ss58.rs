@@ -0,0 +1,131 @@
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+
+use blake2::{Blake2b512, Digest};
+
+use crate::error::{URError, URResult};
+
+// https://docs.substrate.io/reference/address-formats/
+const CHECKSUM_PREFIX: &[u8] = b"SS58PRE";
+const CHECKSUM_LENGTH: usize = 2;
+// network prefixes are 14 bits, the ones below 64 fit in a single byte
+const MAX_SIMPLE_PREFIX: u16 = 63;
+const MAX_PREFIX: u16 = 16383;
+const ACCOUNT_ID_LENGTHS: [usize; 2] = [32, 33];
+
+pub const POLKADOT_PREFIX: u16 = 0;
+pub const KUSAMA_PREFIX: u16 = 2;
+pub const SUBSTRATE_PREFIX: u16 = 42;
+
+fn checksum(data: &[u8]) -> [u8; CHECKSUM_LENGTH] {
+    let hash = Blake2b512::new()
+        .chain_update(CHECKSUM_PREFIX)
+        .chain_update(data)
+        .finalize();
+    [hash[0], hash[1]]
+}
+
+/// Encodes a public key as an SS58 address of the `network` prefix, for example
+/// 0 for Polkadot and 2 for Kusama. Prefixes above 16383 are truncated to 14 bits.
+pub fn ss58_encode(pubkey: &[u8], network: u16) -> String {
+    let network = network & MAX_PREFIX;
+    let mut data = match network {
+        0..=MAX_SIMPLE_PREFIX => vec![network as u8],
+        _ => vec![
+            ((network & 0b1111_1100) >> 2) as u8 | 0b0100_0000,
+            ((network >> 8) as u8) | (((network & 0b11) as u8) << 6),
+        ],
+    };
+    data.extend_from_slice(pubkey);
+    let checksum = checksum(&data);
+    data.extend_from_slice(&checksum);
+    bs58::encode(data).into_string()
+}
+
+/// Decodes an SS58 address into its public key and network prefix.
+pub fn ss58_decode(address: &str) -> URResult<(Vec<u8>, u16)> {
+    let data = bs58::decode(address)
+        .into_vec()
+        .map_err(|e| URError::AddressDecodeError(e.to_string()))?;
+    let (prefix_length, network) = match data.first() {
+        Some(first) if *first as u16 <= MAX_SIMPLE_PREFIX => (1, *first as u16),
+        Some(first) if *first < 0b1000_0000 && data.len() > 1 => {
+            let lower = (first << 2) | (data[1] >> 6);
+            let upper = data[1] & 0b0011_1111;
+            (2, lower as u16 | (upper as u16) << 8)
+        }
+        _ => {
+            return Err(URError::AddressDecodeError(
+                "invalid ss58 prefix".to_string(),
+            ))
+        }
+    };
+    let payload_length = data.len().saturating_sub(prefix_length + CHECKSUM_LENGTH);
+    if !ACCOUNT_ID_LENGTHS.contains(&payload_length) {
+        return Err(URError::AddressDecodeError(format!(
+            "invalid ss58 length {}",
+            data.len()
+        )));
+    }
+    let (body, expected) = data.split_at(data.len() - CHECKSUM_LENGTH);
+    if checksum(body) != expected {
+        return Err(URError::AddressDecodeError(
+            "invalid ss58 checksum".to_string(),
+        ));
+    }
+    Ok((body[prefix_length..].to_vec(), network))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ALICE: &str = "d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d";
+
+    #[test]
+    fn test_polkadot_round_trip() {
+        let pubkey = hex::decode(ALICE).unwrap();
+        let address = ss58_encode(&pubkey, POLKADOT_PREFIX);
+        assert_eq!("15oF4uVJwmo4TdGW7VfQxNLavjCXviqxT9S1MgbjMNHr6Sp5", address);
+        assert_eq!((pubkey, POLKADOT_PREFIX), ss58_decode(&address).unwrap());
+    }
+
+    #[test]
+    fn test_kusama_round_trip() {
+        let pubkey = hex::decode(ALICE).unwrap();
+        let address = ss58_encode(&pubkey, KUSAMA_PREFIX);
+        assert_eq!("HNZata7iMYWmk5RvZRTiAsSDhV8366zq2YGb3tLH5Upf74F", address);
+        assert_eq!((pubkey, KUSAMA_PREFIX), ss58_decode(&address).unwrap());
+    }
+
+    #[test]
+    fn test_two_byte_prefix_round_trip() {
+        let pubkey = hex::decode(ALICE).unwrap();
+        let address = ss58_encode(&pubkey, 255);
+        assert_eq!("yGHXkYLYqxijLKKfd9Q2CB9shRVu8rPNBS53wvwGTutYg4zTg", address);
+        assert_eq!((pubkey.clone(), 255), ss58_decode(&address).unwrap());
+
+        let address = ss58_encode(&pubkey, MAX_PREFIX);
+        assert_eq!((pubkey, MAX_PREFIX), ss58_decode(&address).unwrap());
+    }
+
+    #[test]
+    fn test_decode_error() {
+        assert_eq!(
+            Err(URError::AddressDecodeError(
+                "invalid ss58 checksum".to_string()
+            )),
+            ss58_decode("15oF4uVJwmo4TdGW7VfQxNLavjCXviqxT9S1MgbjMNHr6Sp6")
+        );
+        assert!(matches!(
+            ss58_decode("15oF4uVJwmo4TdGW7VfQxNLav"),
+            Err(URError::AddressDecodeError(_))
+        ));
+        assert!(matches!(
+            ss58_decode("0OIl"),
+            Err(URError::AddressDecodeError(_))
+        ));
+    }
+}
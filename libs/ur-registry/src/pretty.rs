@@ -0,0 +1,735 @@
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt::{self, Debug};
+
+use crate::aptos::{aptos_sign_request::AptosSignRequest, aptos_signature::AptosSignature};
+use crate::arweave::{
+    arweave_crypto_account::ArweaveCryptoAccount, arweave_sign_request::ArweaveSignRequest,
+    arweave_signature::ArweaveSignature,
+};
+use crate::avalanche::{avax_sign_request::AvaxSignRequest, avax_signature::AvaxSignature};
+use crate::bitcoin::{btc_sign_request::BtcSignRequest, btc_signature::BtcSignature};
+use crate::bytes::Bytes;
+use crate::cardano::{
+    cardano_catalyst_signature::CardanoCatalystSignature,
+    cardano_catalyst_voting_registration::CardanoCatalystVotingRegistrationRequest,
+    cardano_cert_key::CardanoCertKey, cardano_sign_cip8_data_request::CardanoSignCip8DataRequest,
+    cardano_sign_cip8_data_signature::CardanoSignCip8DataSignature,
+    cardano_sign_data_request::CardanoSignDataRequest,
+    cardano_sign_data_signature::CardanoSignDataSignature,
+    cardano_sign_request::CardanoSignRequest,
+    cardano_sign_tx_hash_request::CardanoSignTxHashRequest, cardano_signature::CardanoSignature,
+    cardano_utxo::CardanoUTXO,
+};
+use crate::cosmos::{cosmos_sign_request::CosmosSignRequest, cosmos_signature::CosmosSignature};
+use crate::cosmos::{evm_sign_request::EvmSignRequest, evm_signature::EvmSignature};
+use crate::crypto_account::CryptoAccount;
+use crate::crypto_coin_info::CryptoCoinInfo;
+use crate::crypto_ec_key::CryptoECKey;
+use crate::crypto_hd_key::CryptoHDKey;
+use crate::crypto_key_path::CryptoKeyPath;
+use crate::crypto_output::CryptoOutput;
+use crate::crypto_psbt::CryptoPSBT;
+use crate::ergo::{ergo_hint_bag::ErgoHintBag, ergo_signature::ErgoSignature};
+use crate::error::{URError, URResult};
+use crate::ethereum::{eth_sign_request::EthSignRequest, eth_signature::EthSignature};
+use crate::extend::crypto_multi_accounts::CryptoMultiAccounts;
+use crate::extend::{
+    key_derivation::KeyDerivationCall, key_derivation_schema::KeyDerivationSchema,
+    qr_hardware_call::QRHardwareCall,
+};
+use crate::hedera::{hedera_sign_request::HederaSignRequest, hedera_signature::HederaSignature};
+use crate::kaspa::{kaspa_sign_request::KaspaSignRequest, kaspa_signature::KaspaSignature};
+use crate::keystone::{
+    keystone_sign_rejection::KeystoneSignRejection, keystone_sign_request::KeystoneSignRequest,
+    keystone_sign_result::KeystoneSignResult,
+};
+use crate::monero::{
+    monero_sign_request::MoneroSignRequest, monero_signature::MoneroSignature,
+    xmr_keyimage::XmrKeyImage, xmr_output::XmrOutput, xmr_txsigned::XmrTxSigned,
+    xmr_txunsigned::XmrTxUnsigned,
+};
+use crate::near::{near_sign_request::NearSignRequest, near_signature::NearSignature};
+use crate::registry_types::URType;
+use crate::solana::{sol_sign_request::SolSignRequest, sol_signature::SolSignature};
+use crate::stellar::{
+    stellar_sign_request::StellarSignRequest, stellar_signature::StellarSignature,
+};
+use crate::sui::{
+    sui_sign_hash_request::SuiSignHashRequest, sui_sign_request::SuiSignRequest,
+    sui_signature::SuiSignature,
+};
+use crate::tezos::{tezos_sign_request::TezosSignRequest, tezos_signature::TezosSignature};
+use crate::ton::{ton_sign_request::TonSignRequest, ton_signature::TonSignature};
+use crate::traits::RegistryItem;
+use crate::utxo_psbt::UtxoPSBT;
+use crate::zcash::{
+    zcash_accounts::ZcashAccounts, zcash_pczt::ZcashPczt, zcash_sign_request::ZcashSignRequest,
+    zcash_signature::ZcashSignature, zcash_unified_full_viewing_key::ZcashUnifiedFullViewingKey,
+};
+
+fn dump<T>(bytes: &[u8]) -> URResult<String>
+where
+    T: RegistryItem + Debug + for<'a> TryFrom<&'a [u8], Error = URError>,
+{
+    let item = T::try_from(bytes)?;
+    Ok(hex_byte_arrays(&format!("{:#?}", item)))
+}
+
+// `{:#?}` writes a byte field one decimal per line, the arrays of `u8` values
+// are joined into hex instead. No registry item has an array of other integers,
+// so an array of values that all fit in a byte is taken to be bytes
+fn hex_byte_arrays(debug: &str) -> String {
+    let lines: Vec<&str> = debug.lines().collect();
+    let mut result = Vec::with_capacity(lines.len());
+    let mut i = 0;
+    while i < lines.len() {
+        let line = lines[i];
+        if let Some(prefix) = line.strip_suffix('[') {
+            let values: Vec<Option<u8>> = lines[i + 1..]
+                .iter()
+                .take_while(|line| !line.trim_start().starts_with(']'))
+                .map(|line| line.trim().strip_suffix(',')?.parse().ok())
+                .collect();
+            let end = i + 1 + values.len();
+            if !values.is_empty() && end < lines.len() && values.iter().all(Option::is_some) {
+                let bytes: Vec<u8> = values.into_iter().flatten().collect();
+                let suffix = &lines[end].trim_start()[1..];
+                result.push(format!("{}{}{}", prefix, hex::encode(bytes), suffix));
+                i = end + 1;
+                continue;
+            }
+        }
+        result.push(line.to_string());
+        i += 1;
+    }
+    result.join("\n")
+}
+
+/// Decodes `bytes` as the registry item named by `ur_type` and returns a
+/// multi-line dump of its fields, with byte fields in hex, for inspecting
+/// payloads while debugging.
+pub fn pretty_print(ur_type: &str, bytes: &[u8]) -> URResult<String> {
+    match URType::from(ur_type)? {
+        URType::CryptoPsbt(_) => dump::<CryptoPSBT>(bytes),
+        URType::UtxoPsbt(_) => dump::<UtxoPSBT>(bytes),
+        URType::CryptoMultiAccounts(_) => dump::<CryptoMultiAccounts>(bytes),
+        URType::CryptoAccount(_) => dump::<CryptoAccount>(bytes),
+        URType::EthSignRequest(_) => dump::<EthSignRequest>(bytes),
+        URType::SolSignRequest(_) => dump::<SolSignRequest>(bytes),
+        URType::StellarSignRequest(_) => dump::<StellarSignRequest>(bytes),
+        URType::NearSignRequest(_) => dump::<NearSignRequest>(bytes),
+        URType::ArweaveSignRequest(_) => dump::<ArweaveSignRequest>(bytes),
+        URType::AptosSignRequest(_) => dump::<AptosSignRequest>(bytes),
+        URType::CardanoSignRequest(_) => dump::<CardanoSignRequest>(bytes),
+        URType::CardanoSignDataRequest(_) => dump::<CardanoSignDataRequest>(bytes),
+        URType::CardanoSignCip8DataRequest(_) => dump::<CardanoSignCip8DataRequest>(bytes),
+        URType::CardanoCatalystVotingRegistrationRequest(_) => {
+            dump::<CardanoCatalystVotingRegistrationRequest>(bytes)
+        }
+        URType::CardanoSignTxHashRequest(_) => dump::<CardanoSignTxHashRequest>(bytes),
+        URType::CosmosSignRequest(_) => dump::<CosmosSignRequest>(bytes),
+        URType::EvmSignRequest(_) => dump::<EvmSignRequest>(bytes),
+        URType::SuiSignRequest(_) => dump::<SuiSignRequest>(bytes),
+        URType::SuiSignHashRequest(_) => dump::<SuiSignHashRequest>(bytes),
+        URType::TonSignRequest(_) => dump::<TonSignRequest>(bytes),
+        URType::QRHardwareCall(_) => dump::<QRHardwareCall>(bytes),
+        URType::Bytes(_) => dump::<Bytes>(bytes),
+        URType::BtcSignRequest(_) => dump::<BtcSignRequest>(bytes),
+        URType::KeystoneSignRequest(_) => dump::<KeystoneSignRequest>(bytes),
+        URType::ZcashPczt(_) => dump::<ZcashPczt>(bytes),
+        URType::XmrOutput(_) => dump::<XmrOutput>(bytes),
+        URType::XmrTxUnsigned(_) => dump::<XmrTxUnsigned>(bytes),
+        URType::AvaxSignRequest(_) => dump::<AvaxSignRequest>(bytes),
+        URType::TezosSignRequest(_) => dump::<TezosSignRequest>(bytes),
+        URType::HederaSignRequest(_) => dump::<HederaSignRequest>(bytes),
+        URType::KaspaSignRequest(_) => dump::<KaspaSignRequest>(bytes),
+        URType::ZcashSignRequest(_) => dump::<ZcashSignRequest>(bytes),
+        URType::CryptoCoinInfo(_) => dump::<CryptoCoinInfo>(bytes),
+        URType::CryptoECKey(_) => dump::<CryptoECKey>(bytes),
+        URType::CryptoHDKey(_) => dump::<CryptoHDKey>(bytes),
+        URType::CryptoKeyPath(_) => dump::<CryptoKeyPath>(bytes),
+        URType::CryptoOutput(_) => dump::<CryptoOutput>(bytes),
+        URType::CardanoSignature(_) => dump::<CardanoSignature>(bytes),
+        URType::CardanoUTXO(_) => dump::<CardanoUTXO>(bytes),
+        URType::CardanoSignDataSignature(_) => dump::<CardanoSignDataSignature>(bytes),
+        URType::CardanoSignCip8DataSignature(_) => dump::<CardanoSignCip8DataSignature>(bytes),
+        URType::CardanoCatalystSignature(_) => dump::<CardanoCatalystSignature>(bytes),
+        URType::CardanoCertKey(_) => dump::<CardanoCertKey>(bytes),
+        URType::AptosSignature(_) => dump::<AptosSignature>(bytes),
+        URType::ArweaveCryptoAccount(_) => dump::<ArweaveCryptoAccount>(bytes),
+        URType::ArweaveSignature(_) => dump::<ArweaveSignature>(bytes),
+        URType::AvaxSignature(_) => dump::<AvaxSignature>(bytes),
+        URType::BtcSignature(_) => dump::<BtcSignature>(bytes),
+        URType::CosmosSignature(_) => dump::<CosmosSignature>(bytes),
+        URType::EvmSignature(_) => dump::<EvmSignature>(bytes),
+        URType::EthSignature(_) => dump::<EthSignature>(bytes),
+        URType::ErgoHintBag(_) => dump::<ErgoHintBag>(bytes),
+        URType::ErgoSignature(_) => dump::<ErgoSignature>(bytes),
+        URType::KeystoneSignResult(_) => dump::<KeystoneSignResult>(bytes),
+        URType::KeystoneSignRejection(_) => dump::<KeystoneSignRejection>(bytes),
+        URType::NearSignature(_) => dump::<NearSignature>(bytes),
+        URType::SolSignature(_) => dump::<SolSignature>(bytes),
+        URType::StellarSignature(_) => dump::<StellarSignature>(bytes),
+        URType::SuiSignature(_) => dump::<SuiSignature>(bytes),
+        URType::TezosSignature(_) => dump::<TezosSignature>(bytes),
+        URType::HederaSignature(_) => dump::<HederaSignature>(bytes),
+        URType::KaspaSignature(_) => dump::<KaspaSignature>(bytes),
+        URType::TonSignature(_) => dump::<TonSignature>(bytes),
+        URType::KeyDerivationSchema(_) => dump::<KeyDerivationSchema>(bytes),
+        URType::KeyDerivationCall(_) => dump::<KeyDerivationCall>(bytes),
+        URType::XmrKeyImage(_) => dump::<XmrKeyImage>(bytes),
+        URType::XmrTxSigned(_) => dump::<XmrTxSigned>(bytes),
+        URType::MoneroSignRequest(_) => dump::<MoneroSignRequest>(bytes),
+        URType::MoneroSignature(_) => dump::<MoneroSignature>(bytes),
+        URType::ZcashAccounts(_) => dump::<ZcashAccounts>(bytes),
+        URType::ZcashUnifiedFullViewingKey(_) => dump::<ZcashUnifiedFullViewingKey>(bytes),
+        URType::ZcashSignature(_) => dump::<ZcashSignature>(bytes),
+    }
+}
+
+/// The one-line summary of [`impl_display!`](crate::impl_display), such as
+/// `SuiSignRequest { request_id: 9b1deb4d, paths: 1, data: 217 bytes }`, built
+/// from the fields the macro lists for each type.
+#[derive(Default)]
+pub(crate) struct Summary {
+    request_id: Option<String>,
+    paths: usize,
+    data: Option<usize>,
+}
+
+impl Summary {
+    pub(crate) fn request_id<T: SummaryRequestId>(mut self, request_id: &T) -> Self {
+        self.request_id = request_id
+            .request_id()
+            .map(|request_id| hex::encode(&request_id[..request_id.len().min(4)]));
+        self
+    }
+
+    pub(crate) fn paths<T: SummaryPaths>(mut self, paths: &T) -> Self {
+        self.paths += paths.paths();
+        self
+    }
+
+    // a type with the data in two fields, a single item and a batch, lists both
+    // and the lengths add up
+    pub(crate) fn data<T: SummaryData>(mut self, data: &T) -> Self {
+        if let Some(len) = data.data() {
+            *self.data.get_or_insert(0) += len;
+        }
+        self
+    }
+
+    pub(crate) fn write(&self, name: &str, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut fields = Vec::new();
+        if let Some(request_id) = &self.request_id {
+            fields.push(format!("request_id: {}", request_id));
+        }
+        if self.paths > 0 {
+            fields.push(format!("paths: {}", self.paths));
+        }
+        if let Some(len) = self.data {
+            fields.push(format!("data: {} bytes", len));
+        }
+        if fields.is_empty() {
+            return write!(f, "{} {{}}", name);
+        }
+        write!(f, "{} {{ {} }}", name, fields.join(", "))
+    }
+}
+
+pub(crate) trait SummaryRequestId {
+    fn request_id(&self) -> Option<&[u8]>;
+}
+
+impl SummaryRequestId for Bytes {
+    fn request_id(&self) -> Option<&[u8]> {
+        Some(self.as_slice())
+    }
+}
+
+impl SummaryRequestId for Option<Bytes> {
+    fn request_id(&self) -> Option<&[u8]> {
+        self.as_deref()
+    }
+}
+
+pub(crate) trait SummaryPaths {
+    fn paths(&self) -> usize;
+}
+
+impl SummaryPaths for CryptoKeyPath {
+    fn paths(&self) -> usize {
+        1
+    }
+}
+
+impl<T: SummaryPaths> SummaryPaths for Vec<T> {
+    fn paths(&self) -> usize {
+        self.iter().map(SummaryPaths::paths).sum()
+    }
+}
+
+impl<T: SummaryPaths> SummaryPaths for Option<T> {
+    fn paths(&self) -> usize {
+        self.as_ref().map_or(0, SummaryPaths::paths)
+    }
+}
+
+pub(crate) trait SummaryData {
+    fn data(&self) -> Option<usize>;
+}
+
+impl SummaryData for Bytes {
+    fn data(&self) -> Option<usize> {
+        Some(self.len())
+    }
+}
+
+impl SummaryData for Vec<Bytes> {
+    fn data(&self) -> Option<usize> {
+        Some(self.iter().map(Vec::len).sum())
+    }
+}
+
+impl<T: SummaryData> SummaryData for Option<T> {
+    fn data(&self) -> Option<usize> {
+        self.as_ref().and_then(SummaryData::data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    #[test]
+    fn test_pretty_print_sui_sign_request() {
+        let bytes = hex::decode("a401d825509b1deb4d3b7d4bad9bdd2b0d7b3dcb6d024800000000000200200381d90130a1018a182cf5190310f500f500f500f5068158491ff915a5e9e32fdbe0135535b6c69a00a9809aaf7f7c0275d3239ca79db20d6433b701000000000020176c4727433105da34209f04ac3f22e192a2573d7948cb2fabde7d13a7f4f149").unwrap();
+        let result = pretty_print("sui-sign-request", &bytes).unwrap();
+
+        assert!(result.starts_with("SuiSignRequest {\n"));
+        assert!(
+            result.contains("    request_id: Some(\n        9b1deb4d3b7d4bad9bdd2b0d7b3dcb6d,\n")
+        );
+        assert!(result.contains("    derivation_paths: [\n"));
+        assert!(result.contains("        CryptoKeyPath {\n"));
+    }
+
+    #[test]
+    fn test_pretty_print_ergo_signature() {
+        let bytes = hex::decode(
+            "a301d825509b1deb4d3b7d4bad9bdd2b0d7b3dcb6d0244deadbeef03d92137a101450102030405",
+        )
+        .unwrap();
+        let result = pretty_print("ergo-signature", &bytes).unwrap();
+
+        assert!(result.starts_with("ErgoSignature {\n"));
+        assert!(result.contains("    signature: deadbeef,\n"));
+        assert!(result.contains("    hint_bag: Some(\n        ErgoHintBag {\n"));
+    }
+
+    #[test]
+    fn test_hex_byte_arrays() {
+        let debug = format!("{:#?}", (vec![vec![0xdeu8, 0xad], vec![]], vec![1u32, 300]));
+        assert_eq!(
+            "(\n    [\n        dead,\n        [],\n    ],\n    [\n        1,\n        300,\n    ],\n)",
+            hex_byte_arrays(&debug)
+        );
+    }
+
+    #[test]
+    fn test_display() {
+        let bytes = hex::decode("a401d825509b1deb4d3b7d4bad9bdd2b0d7b3dcb6d024800000000000200200381d90130a1018a182cf5190310f500f500f500f5068158491ff915a5e9e32fdbe0135535b6c69a00a9809aaf7f7c0275d3239ca79db20d6433b701000000000020176c4727433105da34209f04ac3f22e192a2573d7948cb2fabde7d13a7f4f149").unwrap();
+        let request = SuiSignRequest::try_from(bytes).unwrap();
+        assert_eq!(
+            "SuiSignRequest { request_id: 9b1deb4d, paths: 1, data: 8 bytes }",
+            format!("{}", request)
+        );
+
+        let bytes = hex::decode("a301d825509b1deb4d3b7d4bad9bdd2b0d7b3dcb6d025841d4f0a7bcd95bba1fbb1051885054730e3f47064288575aacc102fbbf6a9a14daa066991e360d3e3406c20c00a40973eff37c7d641e5b351ec4a99bfe86f335f71303686b657973746f6e65").unwrap();
+        let signature = EthSignature::try_from(bytes).unwrap();
+        assert_eq!(
+            "EthSignature { request_id: 9b1deb4d, data: 65 bytes }",
+            signature.to_string()
+        );
+    }
+
+    fn request_id() -> Bytes {
+        hex::decode("9b1deb4d3b7d4bad9bdd2b0d7b3dcb6d").unwrap()
+    }
+
+    #[test]
+    fn test_display_cardano() {
+        let mut request = CardanoSignDataRequest::default();
+        request.set_request_id(Some(request_id()));
+        request.set_sign_data(vec![0; 32]);
+        request.set_derivation_path(CryptoKeyPath::default());
+        assert_eq!(
+            "CardanoSignDataRequest { request_id: 9b1deb4d, paths: 1, data: 32 bytes }",
+            request.to_string()
+        );
+
+        let mut signature = CardanoSignDataSignature::default();
+        signature.set_signature(vec![0; 64]);
+        assert_eq!(
+            "CardanoSignDataSignature { data: 64 bytes }",
+            signature.to_string()
+        );
+    }
+
+    #[test]
+    fn test_display_aptos() {
+        let mut request = AptosSignRequest::default();
+        request.set_request_id(request_id());
+        request.set_sign_data_list(vec![vec![0; 32], vec![0; 16]]);
+        request.set_authentication_key_derivation_paths(vec![CryptoKeyPath::default(); 2]);
+        assert_eq!(
+            "AptosSignRequest { request_id: 9b1deb4d, paths: 2, data: 48 bytes }",
+            request.to_string()
+        );
+
+        let mut signature = AptosSignature::default();
+        signature.set_request_id(request_id());
+        signature.set_signature(vec![0; 64]);
+        assert_eq!(
+            "AptosSignature { request_id: 9b1deb4d, data: 64 bytes }",
+            signature.to_string()
+        );
+    }
+
+    #[test]
+    fn test_display_arweave() {
+        let mut request = ArweaveSignRequest::default();
+        request.set_sign_data(vec![0; 32]);
+        assert_eq!("ArweaveSignRequest { data: 32 bytes }", request.to_string());
+
+        let mut signature = ArweaveSignature::default();
+        signature.set_request_id(request_id());
+        signature.set_signature(vec![0; 512]);
+        assert_eq!(
+            "ArweaveSignature { request_id: 9b1deb4d, data: 512 bytes }",
+            signature.to_string()
+        );
+    }
+
+    #[test]
+    fn test_display_cosmos() {
+        let mut request = CosmosSignRequest::default();
+        request.set_request_id(request_id());
+        request.set_sign_data(vec![0; 100]);
+        request.set_derivation_paths(vec![CryptoKeyPath::default()]);
+        assert_eq!(
+            "CosmosSignRequest { request_id: 9b1deb4d, paths: 1, data: 100 bytes }",
+            request.to_string()
+        );
+
+        let mut signature = EvmSignature::default();
+        signature.set_request_id(request_id());
+        signature.set_signature(vec![0; 64]);
+        assert_eq!(
+            "EvmSignature { request_id: 9b1deb4d, data: 64 bytes }",
+            signature.to_string()
+        );
+    }
+
+    #[test]
+    fn test_display_ethereum() {
+        let mut request = EthSignRequest::default();
+        request.set_request_id(request_id());
+        request.set_sign_data(vec![0; 120]);
+        request.set_derivation_path(CryptoKeyPath::default());
+        assert_eq!(
+            "EthSignRequest { request_id: 9b1deb4d, paths: 1, data: 120 bytes }",
+            request.to_string()
+        );
+
+        let mut signature = EthSignature::default();
+        signature.set_signature(vec![0; 65]);
+        assert_eq!("EthSignature { data: 65 bytes }", signature.to_string());
+    }
+
+    #[test]
+    fn test_display_ergo() {
+        let mut signature = ErgoSignature::default();
+        signature.set_request_id(Some(request_id()));
+        signature.set_signature(vec![0; 4]);
+        assert_eq!(
+            "ErgoSignature { request_id: 9b1deb4d, data: 4 bytes }",
+            signature.to_string()
+        );
+    }
+
+    #[test]
+    fn test_display_keystone() {
+        let mut request = KeystoneSignRequest::default();
+        request.set_sign_data(vec![0; 344]);
+        assert_eq!(
+            "KeystoneSignRequest { data: 344 bytes }",
+            request.to_string()
+        );
+
+        assert_eq!(
+            "KeystoneSignResult { data: 433 bytes }",
+            KeystoneSignResult::new(vec![0; 433]).to_string()
+        );
+        assert_eq!(
+            "KeystoneSignRejection {}",
+            KeystoneSignRejection::default().to_string()
+        );
+    }
+
+    #[test]
+    fn test_display_near() {
+        let mut request = NearSignRequest::default();
+        request.set_request_id(request_id());
+        request.set_sign_data(vec![vec![0; 230], vec![0; 10]]);
+        request.set_derivation_path(CryptoKeyPath::default());
+        assert_eq!(
+            "NearSignRequest { request_id: 9b1deb4d, paths: 1, data: 240 bytes }",
+            request.to_string()
+        );
+
+        let mut signature = NearSignature::default();
+        signature.set_request_id(request_id());
+        signature.set_signature(vec![vec![0; 64]]);
+        assert_eq!(
+            "NearSignature { request_id: 9b1deb4d, data: 64 bytes }",
+            signature.to_string()
+        );
+    }
+
+    #[test]
+    fn test_display_solana() {
+        let mut request = SolSignRequest::default();
+        request.set_request_id(request_id());
+        request.set_sign_data(vec![0; 150]);
+        request.set_derivation_path(CryptoKeyPath::default());
+        assert_eq!(
+            "SolSignRequest { request_id: 9b1deb4d, paths: 1, data: 150 bytes }",
+            request.to_string()
+        );
+
+        let mut signature = SolSignature::default();
+        signature.set_request_id(request_id());
+        signature.set_signature(vec![0; 64]);
+        assert_eq!(
+            "SolSignature { request_id: 9b1deb4d, data: 64 bytes }",
+            signature.to_string()
+        );
+    }
+
+    #[test]
+    fn test_display_stellar() {
+        let mut request = StellarSignRequest::default();
+        request.set_request_id(request_id());
+        request.set_sign_data(vec![0; 150]);
+        request.set_derivation_path(CryptoKeyPath::default());
+        assert_eq!(
+            "StellarSignRequest { request_id: 9b1deb4d, paths: 1, data: 150 bytes }",
+            request.to_string()
+        );
+
+        let mut signature = StellarSignature::default();
+        signature.set_request_id(request_id());
+        signature.set_signature(vec![0; 64]);
+        assert_eq!(
+            "StellarSignature { request_id: 9b1deb4d, data: 64 bytes }",
+            signature.to_string()
+        );
+    }
+
+    #[test]
+    fn test_display_sui() {
+        let mut request = SuiSignHashRequest::default();
+        request.set_request_id(Some(request_id()));
+        request.set_derivation_paths(vec![CryptoKeyPath::default()]);
+        assert_eq!(
+            "SuiSignHashRequest { request_id: 9b1deb4d, paths: 1 }",
+            request.to_string()
+        );
+
+        let mut signature = SuiSignature::default();
+        signature.set_request_id(Some(request_id()));
+        signature.set_signature(vec![0; 64]);
+        assert_eq!(
+            "SuiSignature { request_id: 9b1deb4d, data: 64 bytes }",
+            signature.to_string()
+        );
+    }
+
+    #[test]
+    fn test_display_tezos() {
+        let mut request = TezosSignRequest::default();
+        request.set_request_id(Some(request_id()));
+        request.set_sign_data(vec![0; 90]);
+        request.set_derivation_path(CryptoKeyPath::default());
+        assert_eq!(
+            "TezosSignRequest { request_id: 9b1deb4d, paths: 1, data: 90 bytes }",
+            request.to_string()
+        );
+
+        let mut signature = TezosSignature::default();
+        signature.set_request_id(Some(request_id()));
+        signature.set_signature(vec![0; 64]);
+        assert_eq!(
+            "TezosSignature { request_id: 9b1deb4d, data: 64 bytes }",
+            signature.to_string()
+        );
+    }
+
+    #[test]
+    fn test_display_hedera() {
+        let mut request = HederaSignRequest::default();
+        request.set_request_id(Some(request_id()));
+        request.set_sign_data(vec![0; 66]);
+        request.set_derivation_path(CryptoKeyPath::default());
+        assert_eq!(
+            "HederaSignRequest { request_id: 9b1deb4d, paths: 1, data: 66 bytes }",
+            request.to_string()
+        );
+
+        let mut signature = HederaSignature::default();
+        signature.set_request_id(Some(request_id()));
+        signature.set_signature(vec![0; 64]);
+        assert_eq!(
+            "HederaSignature { request_id: 9b1deb4d, data: 64 bytes }",
+            signature.to_string()
+        );
+    }
+
+    #[test]
+    fn test_display_kaspa() {
+        let mut request = KaspaSignRequest::default();
+        request.set_request_id(Some(request_id()));
+        request.set_sign_data(vec![0; 272]);
+        request.set_derivation_paths(vec![CryptoKeyPath::default(); 3]);
+        assert_eq!(
+            "KaspaSignRequest { request_id: 9b1deb4d, paths: 3, data: 272 bytes }",
+            request.to_string()
+        );
+
+        let mut signature = KaspaSignature::default();
+        signature.set_request_id(Some(request_id()));
+        signature.set_signatures(vec![vec![0; 64]; 3]);
+        assert_eq!(
+            "KaspaSignature { request_id: 9b1deb4d, data: 192 bytes }",
+            signature.to_string()
+        );
+    }
+
+    #[test]
+    fn test_display_ton() {
+        let mut request = TonSignRequest::default();
+        request.set_request_id(Some(request_id()));
+        request.set_sign_data(vec![0; 86]);
+        assert_eq!(
+            "TonSignRequest { request_id: 9b1deb4d, data: 86 bytes }",
+            request.to_string()
+        );
+
+        let mut signature = TonSignature::default();
+        signature.set_request_id(Some(request_id()));
+        signature.set_signature(vec![0; 64]);
+        assert_eq!(
+            "TonSignature { request_id: 9b1deb4d, data: 64 bytes }",
+            signature.to_string()
+        );
+    }
+
+    #[test]
+    fn test_display_monero() {
+        let mut request = MoneroSignRequest::default();
+        request.set_request_id(Some(request_id()));
+        request.set_unsigned_tx_set(vec![0; 27]);
+        assert_eq!(
+            "MoneroSignRequest { request_id: 9b1deb4d, data: 27 bytes }",
+            request.to_string()
+        );
+
+        let mut signature = MoneroSignature::default();
+        signature.set_request_id(Some(request_id()));
+        signature.set_signed_tx_set(vec![0; 25]);
+        assert_eq!(
+            "MoneroSignature { request_id: 9b1deb4d, data: 25 bytes }",
+            signature.to_string()
+        );
+    }
+
+    #[test]
+    fn test_display_bitcoin() {
+        let mut request = BtcSignRequest::default();
+        request.set_request_id(request_id());
+        request.set_sign_data(vec![0; 19]);
+        request.set_derivation_paths(vec![CryptoKeyPath::default()]);
+        assert_eq!(
+            "BtcSignRequest { request_id: 9b1deb4d, paths: 1, data: 19 bytes }",
+            request.to_string()
+        );
+
+        let mut signature = BtcSignature::default();
+        signature.set_request_id(request_id());
+        signature.set_signature(vec![0; 65]);
+        assert_eq!(
+            "BtcSignature { request_id: 9b1deb4d, data: 65 bytes }",
+            signature.to_string()
+        );
+    }
+
+    #[test]
+    fn test_display_zcash() {
+        let mut request = ZcashSignRequest::default();
+        request.set_request_id(Some(request_id()));
+        request.set_sign_data(vec![0; 134]);
+        request.set_derivation_paths(vec![CryptoKeyPath::default()]);
+        assert_eq!(
+            "ZcashSignRequest { request_id: 9b1deb4d, paths: 1, data: 134 bytes }",
+            request.to_string()
+        );
+
+        let mut signature = ZcashSignature::default();
+        signature.set_request_id(Some(request_id()));
+        signature.set_signatures(vec![vec![0; 72], vec![0; 71]]);
+        assert_eq!(
+            "ZcashSignature { request_id: 9b1deb4d, data: 143 bytes }",
+            signature.to_string()
+        );
+    }
+
+    #[test]
+    fn test_display_avalanche() {
+        let mut request = AvaxSignRequest::default();
+        request.set_request_id(request_id());
+        request.set_tx_data(vec![0; 222]);
+        assert_eq!(
+            "AvaxSignRequest { request_id: 9b1deb4d, data: 222 bytes }",
+            request.to_string()
+        );
+        request.set_derivation_paths(vec![CryptoKeyPath::default(); 2]);
+        assert_eq!(
+            "AvaxSignRequest { request_id: 9b1deb4d, paths: 2, data: 222 bytes }",
+            request.to_string()
+        );
+
+        let mut signature = AvaxSignature::default();
+        signature.set_request_id(request_id());
+        signature.set_signature(vec![0; 512]);
+        assert_eq!(
+            "AvaxSignature { request_id: 9b1deb4d, data: 512 bytes }",
+            signature.to_string()
+        );
+    }
+
+    #[test]
+    fn test_pretty_print_error() {
+        assert!(matches!(
+            pretty_print("unknown-type", &[0xa0]),
+            Err(URError::NotSupportURTypeError(_))
+        ));
+        assert!(matches!(
+            pretty_print("ergo-signature", &[0xff]),
+            Err(URError::CborDecodeError(_))
+        ));
+    }
+}
@@ -1,9 +1,11 @@
 use crate::error::{URError, URResult};
+use alloc::format;
 use alloc::string::{String, ToString};
 
 #[derive(Clone, Debug)]
 pub enum URType {
     CryptoPsbt(String),
+    UtxoPsbt(String),
     CryptoMultiAccounts(String),
     CryptoAccount(String),
     EthSignRequest(String),
@@ -30,12 +32,57 @@ pub enum URType {
     XmrOutput(String),
     XmrTxUnsigned(String),
     AvaxSignRequest(String),
+    TezosSignRequest(String),
+    HederaSignRequest(String),
+    KaspaSignRequest(String),
+    ZcashSignRequest(String),
+    CryptoCoinInfo(String),
+    CryptoECKey(String),
+    CryptoHDKey(String),
+    CryptoKeyPath(String),
+    CryptoOutput(String),
+    CardanoSignature(String),
+    CardanoUTXO(String),
+    CardanoSignDataSignature(String),
+    CardanoSignCip8DataSignature(String),
+    CardanoCatalystSignature(String),
+    CardanoCertKey(String),
+    AptosSignature(String),
+    ArweaveCryptoAccount(String),
+    ArweaveSignature(String),
+    AvaxSignature(String),
+    BtcSignature(String),
+    CosmosSignature(String),
+    EvmSignature(String),
+    EthSignature(String),
+    ErgoHintBag(String),
+    ErgoSignature(String),
+    KeystoneSignResult(String),
+    KeystoneSignRejection(String),
+    NearSignature(String),
+    SolSignature(String),
+    StellarSignature(String),
+    SuiSignature(String),
+    TezosSignature(String),
+    HederaSignature(String),
+    KaspaSignature(String),
+    TonSignature(String),
+    KeyDerivationSchema(String),
+    KeyDerivationCall(String),
+    XmrKeyImage(String),
+    XmrTxSigned(String),
+    MoneroSignRequest(String),
+    MoneroSignature(String),
+    ZcashAccounts(String),
+    ZcashUnifiedFullViewingKey(String),
+    ZcashSignature(String),
 }
 
 impl URType {
     pub fn from(type_str: &str) -> URResult<URType> {
         match type_str {
             "crypto-psbt" => Ok(URType::CryptoPsbt(type_str.to_string())),
+            "utxo-psbt" => Ok(URType::UtxoPsbt(type_str.to_string())),
             "crypto-multi-accounts" => Ok(URType::CryptoMultiAccounts(type_str.to_string())),
             "crypto-account" => Ok(URType::CryptoAccount(type_str.to_string())),
             "bytes" => Ok(URType::Bytes(type_str.to_string())),
@@ -65,9 +112,61 @@ impl URType {
             "qr-hardware-call" => Ok(URType::QRHardwareCall(type_str.to_string())),
             "ton-sign-request" => Ok(URType::TonSignRequest(type_str.to_string())),
             "zcash-pczt" => Ok(URType::ZcashPczt(type_str.to_string())),
+            "zcash-sign-request" => Ok(URType::ZcashSignRequest(type_str.to_string())),
             "xmr-output" => Ok(URType::XmrOutput(type_str.to_string())),
             "xmr-txunsigned" => Ok(URType::XmrTxUnsigned(type_str.to_string())),
             "avax-sign-request" => Ok(URType::AvaxSignRequest(type_str.to_string())),
+            "tezos-sign-request" => Ok(URType::TezosSignRequest(type_str.to_string())),
+            "hedera-sign-request" => Ok(URType::HederaSignRequest(type_str.to_string())),
+            "kaspa-sign-request" => Ok(URType::KaspaSignRequest(type_str.to_string())),
+            "crypto-coin-info" => Ok(URType::CryptoCoinInfo(type_str.to_string())),
+            "crypto-eckey" => Ok(URType::CryptoECKey(type_str.to_string())),
+            "crypto-hdkey" => Ok(URType::CryptoHDKey(type_str.to_string())),
+            "crypto-keypath" => Ok(URType::CryptoKeyPath(type_str.to_string())),
+            "crypto-output" => Ok(URType::CryptoOutput(type_str.to_string())),
+            "cardano-signature" => Ok(URType::CardanoSignature(type_str.to_string())),
+            "cardano-utxo" => Ok(URType::CardanoUTXO(type_str.to_string())),
+            "cardano-sign-data-signature" => {
+                Ok(URType::CardanoSignDataSignature(type_str.to_string()))
+            }
+            "cardano-sign-cip8-data-signature" => {
+                Ok(URType::CardanoSignCip8DataSignature(type_str.to_string()))
+            }
+            "cardano-catalyst-voting-registration-signature" => {
+                Ok(URType::CardanoCatalystSignature(type_str.to_string()))
+            }
+            "cardano-cert-key" => Ok(URType::CardanoCertKey(type_str.to_string())),
+            "aptos-signature" => Ok(URType::AptosSignature(type_str.to_string())),
+            "arweave-crypto-account" => Ok(URType::ArweaveCryptoAccount(type_str.to_string())),
+            "arweave-signature" => Ok(URType::ArweaveSignature(type_str.to_string())),
+            "avax-signature" => Ok(URType::AvaxSignature(type_str.to_string())),
+            "btc-signature" => Ok(URType::BtcSignature(type_str.to_string())),
+            "cosmos-signature" => Ok(URType::CosmosSignature(type_str.to_string())),
+            "evm-signature" => Ok(URType::EvmSignature(type_str.to_string())),
+            "eth-signature" => Ok(URType::EthSignature(type_str.to_string())),
+            "ergo-hint-bag" => Ok(URType::ErgoHintBag(type_str.to_string())),
+            "ergo-signature" => Ok(URType::ErgoSignature(type_str.to_string())),
+            "keystone-sign-result" => Ok(URType::KeystoneSignResult(type_str.to_string())),
+            "keystone-sign-rejection" => Ok(URType::KeystoneSignRejection(type_str.to_string())),
+            "near-signature" => Ok(URType::NearSignature(type_str.to_string())),
+            "sol-signature" => Ok(URType::SolSignature(type_str.to_string())),
+            "stellar-signature" => Ok(URType::StellarSignature(type_str.to_string())),
+            "sui-signature" => Ok(URType::SuiSignature(type_str.to_string())),
+            "tezos-signature" => Ok(URType::TezosSignature(type_str.to_string())),
+            "hedera-signature" => Ok(URType::HederaSignature(type_str.to_string())),
+            "kaspa-signature" => Ok(URType::KaspaSignature(type_str.to_string())),
+            "ton-signature" => Ok(URType::TonSignature(type_str.to_string())),
+            "key-derivation-schema" => Ok(URType::KeyDerivationSchema(type_str.to_string())),
+            "key-derivation-call" => Ok(URType::KeyDerivationCall(type_str.to_string())),
+            "xmr-keyimage" => Ok(URType::XmrKeyImage(type_str.to_string())),
+            "xmr-txsigned" => Ok(URType::XmrTxSigned(type_str.to_string())),
+            "monero-sign-request" => Ok(URType::MoneroSignRequest(type_str.to_string())),
+            "monero-signature" => Ok(URType::MoneroSignature(type_str.to_string())),
+            "zcash-accounts" => Ok(URType::ZcashAccounts(type_str.to_string())),
+            "zcash-unified-full-viewing-key" => {
+                Ok(URType::ZcashUnifiedFullViewingKey(type_str.to_string()))
+            }
+            "zcash-signature" => Ok(URType::ZcashSignature(type_str.to_string())),
             _ => Err(URError::NotSupportURTypeError(type_str.to_string())),
         }
     }
@@ -75,6 +174,7 @@ impl URType {
     pub fn get_type_str(&self) -> String {
         match self {
             URType::CryptoPsbt(type_str) => type_str.to_string(),
+            URType::UtxoPsbt(type_str) => type_str.to_string(),
             URType::CryptoMultiAccounts(type_str) => type_str.to_string(),
             URType::CryptoAccount(type_str) => type_str.to_string(),
             URType::Bytes(type_str) => type_str.to_string(),
@@ -98,9 +198,53 @@ impl URType {
             URType::QRHardwareCall(type_str) => type_str.to_string(),
             URType::TonSignRequest(type_str) => type_str.to_string(),
             URType::ZcashPczt(type_str) => type_str.to_string(),
+            URType::ZcashSignRequest(type_str) => type_str.to_string(),
             URType::XmrOutput(type_str) => type_str.to_string(),
             URType::XmrTxUnsigned(type_str) => type_str.to_string(),
             URType::AvaxSignRequest(type_str) => type_str.to_string(),
+            URType::TezosSignRequest(type_str) => type_str.to_string(),
+            URType::HederaSignRequest(type_str) => type_str.to_string(),
+            URType::KaspaSignRequest(type_str) => type_str.to_string(),
+            URType::CryptoCoinInfo(type_str) => type_str.to_string(),
+            URType::CryptoECKey(type_str) => type_str.to_string(),
+            URType::CryptoHDKey(type_str) => type_str.to_string(),
+            URType::CryptoKeyPath(type_str) => type_str.to_string(),
+            URType::CryptoOutput(type_str) => type_str.to_string(),
+            URType::CardanoSignature(type_str) => type_str.to_string(),
+            URType::CardanoUTXO(type_str) => type_str.to_string(),
+            URType::CardanoSignDataSignature(type_str) => type_str.to_string(),
+            URType::CardanoSignCip8DataSignature(type_str) => type_str.to_string(),
+            URType::CardanoCatalystSignature(type_str) => type_str.to_string(),
+            URType::CardanoCertKey(type_str) => type_str.to_string(),
+            URType::AptosSignature(type_str) => type_str.to_string(),
+            URType::ArweaveCryptoAccount(type_str) => type_str.to_string(),
+            URType::ArweaveSignature(type_str) => type_str.to_string(),
+            URType::AvaxSignature(type_str) => type_str.to_string(),
+            URType::BtcSignature(type_str) => type_str.to_string(),
+            URType::CosmosSignature(type_str) => type_str.to_string(),
+            URType::EvmSignature(type_str) => type_str.to_string(),
+            URType::EthSignature(type_str) => type_str.to_string(),
+            URType::ErgoHintBag(type_str) => type_str.to_string(),
+            URType::ErgoSignature(type_str) => type_str.to_string(),
+            URType::KeystoneSignResult(type_str) => type_str.to_string(),
+            URType::KeystoneSignRejection(type_str) => type_str.to_string(),
+            URType::NearSignature(type_str) => type_str.to_string(),
+            URType::SolSignature(type_str) => type_str.to_string(),
+            URType::StellarSignature(type_str) => type_str.to_string(),
+            URType::SuiSignature(type_str) => type_str.to_string(),
+            URType::TezosSignature(type_str) => type_str.to_string(),
+            URType::HederaSignature(type_str) => type_str.to_string(),
+            URType::KaspaSignature(type_str) => type_str.to_string(),
+            URType::TonSignature(type_str) => type_str.to_string(),
+            URType::KeyDerivationSchema(type_str) => type_str.to_string(),
+            URType::KeyDerivationCall(type_str) => type_str.to_string(),
+            URType::XmrKeyImage(type_str) => type_str.to_string(),
+            URType::XmrTxSigned(type_str) => type_str.to_string(),
+            URType::MoneroSignRequest(type_str) => type_str.to_string(),
+            URType::MoneroSignature(type_str) => type_str.to_string(),
+            URType::ZcashAccounts(type_str) => type_str.to_string(),
+            URType::ZcashUnifiedFullViewingKey(type_str) => type_str.to_string(),
+            URType::ZcashSignature(type_str) => type_str.to_string(),
         }
     }
 }
@@ -116,6 +260,23 @@ impl<'a> RegistryType<'_> {
     }
 }
 
+/// Whether `ur_type` names a payload sent to the device to be signed. Most of
+/// them end in `-request`, but `cardano-catalyst-voting-registration` and
+/// `xmr-txunsigned` don't, and calls like `key-derivation-call` aren't signed.
+pub fn is_request_type(ur_type: &str) -> bool {
+    SIGN_REQUEST_TYPES
+        .iter()
+        .any(|registry_type| registry_type.0 == ur_type)
+}
+
+/// Whether `ur_type` names a payload the device returns after signing.
+/// `keystone-sign-rejection` answers a request too, but carries no signature.
+pub fn is_signature_type(ur_type: &str) -> bool {
+    SIGNATURE_TYPES
+        .iter()
+        .any(|registry_type| registry_type.0 == ur_type)
+}
+
 pub const BYTES: RegistryType = RegistryType("bytes", None);
 pub const UUID: RegistryType = RegistryType("uuid", Some(37));
 pub const CRYPTO_HDKEY: RegistryType = RegistryType("crypto-hdkey", Some(303));
@@ -163,6 +324,8 @@ pub const APTOS_SIGNATURE: RegistryType = RegistryType("aptos-signature", Some(3
 // UTXO
 pub const KEYSTONE_SIGN_REQUEST: RegistryType = RegistryType("keystone-sign-request", Some(6101));
 pub const KEYSTONE_SIGN_RESULT: RegistryType = RegistryType("keystone-sign-result", Some(6102));
+pub const KEYSTONE_SIGN_REJECTION: RegistryType =
+    RegistryType("keystone-sign-rejection", Some(6103));
 // CARDANO
 pub const CARDANO_UTXO: RegistryType = RegistryType("cardano-utxo", Some(2201));
 pub const CARDANO_SIGN_REQUEST: RegistryType = RegistryType("cardano-sign-request", Some(2202));
@@ -194,6 +357,7 @@ pub const TON_SIGNATURE: RegistryType = RegistryType("ton-signature", Some(7202)
 // BTC
 pub const BTC_SIGN_REQUEST: RegistryType = RegistryType("btc-sign-request", Some(8101));
 pub const BTC_SIGNATURE: RegistryType = RegistryType("btc-signature", Some(8102));
+pub const UTXO_PSBT: RegistryType = RegistryType("utxo-psbt", Some(8401));
 // Stellar
 pub const STELLAR_SIGN_REQUEST: RegistryType = RegistryType("stellar-sign-request", Some(8201));
 pub const STELLAR_SIGNATURE: RegistryType = RegistryType("stellar-signature", Some(8202));
@@ -202,9 +366,24 @@ pub const XMR_OUTPUT: RegistryType = RegistryType("xmr-output", Some(8301));
 pub const XMR_KEYIMAGE: RegistryType = RegistryType("xmr-keyimage", Some(8302));
 pub const XMR_TXUNSIGNED: RegistryType = RegistryType("xmr-txunsigned", Some(8303));
 pub const XMR_TXSIGNED: RegistryType = RegistryType("xmr-txsigned", Some(8304));
+pub const MONERO_SIGN_REQUEST: RegistryType = RegistryType("monero-sign-request", Some(8305));
+pub const MONERO_SIGNATURE: RegistryType = RegistryType("monero-signature", Some(8306));
 
 pub const AVAX_SIGN_REQUEST: RegistryType = RegistryType("avax-sign-request", Some(8301));
 pub const AVAX_SIGNATURE: RegistryType = RegistryType("avax-signature", Some(8302));
+// Ergo, provisional: 8502 and 8503 aren't in a published registry yet and may
+// still change, 8501 is left to an ergo-sign-request
+pub const ERGO_SIGNATURE: RegistryType = RegistryType("ergo-signature", Some(8502));
+pub const ERGO_HINT_BAG: RegistryType = RegistryType("ergo-hint-bag", Some(8503));
+// Tezos
+pub const TEZOS_SIGN_REQUEST: RegistryType = RegistryType("tezos-sign-request", Some(8601));
+pub const TEZOS_SIGNATURE: RegistryType = RegistryType("tezos-signature", Some(8602));
+// Hedera
+pub const HEDERA_SIGN_REQUEST: RegistryType = RegistryType("hedera-sign-request", Some(8701));
+pub const HEDERA_SIGNATURE: RegistryType = RegistryType("hedera-signature", Some(8702));
+// Kaspa
+pub const KASPA_SIGN_REQUEST: RegistryType = RegistryType("kaspa-sign-request", Some(8801));
+pub const KASPA_SIGNATURE: RegistryType = RegistryType("kaspa-signature", Some(8802));
 
 // Zcash
 pub const ZCASH_ACCOUNTS: RegistryType = RegistryType("zcash-accounts", Some(49201));
@@ -213,3 +392,287 @@ pub const ZCASH_FULL_VIEWING_KEY: RegistryType =
 pub const ZCASH_UNIFIED_FULL_VIEWING_KEY: RegistryType =
     RegistryType("zcash-unified-full-viewing-key", Some(49203));
 pub const ZCASH_PCZT: RegistryType = RegistryType("zcash-pczt", Some(49204));
+pub const ZCASH_SIGN_REQUEST: RegistryType = RegistryType("zcash-sign-request", Some(49205));
+pub const ZCASH_SIGNATURE: RegistryType = RegistryType("zcash-signature", Some(49206));
+
+// `utxo-psbt` is a request, its coin info only tells the signing device the
+// address format
+const SIGN_REQUEST_TYPES: &[RegistryType] = &[
+    ETH_SIGN_REQUEST,
+    SOL_SIGN_REQUEST,
+    NEAR_SIGN_REQUEST,
+    ARWEAVE_SIGN_REQUEST,
+    COSMOS_SIGN_REQUEST,
+    EVM_SIGN_REQUEST,
+    TRON_SIGN_REQUEST,
+    APTOS_SIGN_REQUEST,
+    KEYSTONE_SIGN_REQUEST,
+    CARDANO_SIGN_REQUEST,
+    CARDANO_SIGN_DATA_REQUEST,
+    CARDANO_CATALYST_VOTING_REGISTRATION,
+    CARDANO_SIGN_CIP8_DATA_REQUEST,
+    CARDANO_SIGN_TX_HASH_REQUEST,
+    SUI_SIGN_REQUEST,
+    SUI_SIGN_HASH_REQUEST,
+    TON_SIGN_REQUEST,
+    BTC_SIGN_REQUEST,
+    STELLAR_SIGN_REQUEST,
+    XMR_TXUNSIGNED,
+    MONERO_SIGN_REQUEST,
+    AVAX_SIGN_REQUEST,
+    TEZOS_SIGN_REQUEST,
+    HEDERA_SIGN_REQUEST,
+    KASPA_SIGN_REQUEST,
+    ZCASH_SIGN_REQUEST,
+    UTXO_PSBT,
+];
+
+const SIGNATURE_TYPES: &[RegistryType] = &[
+    ETH_SIGNATURE,
+    SOL_SIGNATURE,
+    NEAR_SIGNATURE,
+    ARWEAVE_SIGNATURE,
+    COSMOS_SIGNATURE,
+    EVM_SIGNATURE,
+    TRON_SIGNATURE,
+    APTOS_SIGNATURE,
+    KEYSTONE_SIGN_RESULT,
+    CARDANO_SIGNATURE,
+    CARDANO_SIGN_DATA_SIGNATURE,
+    CARDANO_CATALYST_VOTING_REGISTRATION_SIGNATURE,
+    CARDANO_SIGN_CIP8_DATA_SIGNATURE,
+    SUI_SIGNATURE,
+    TON_SIGNATURE,
+    BTC_SIGNATURE,
+    STELLAR_SIGNATURE,
+    XMR_TXSIGNED,
+    MONERO_SIGNATURE,
+    AVAX_SIGNATURE,
+    ERGO_SIGNATURE,
+    TEZOS_SIGNATURE,
+    HEDERA_SIGNATURE,
+    KASPA_SIGNATURE,
+    ZCASH_SIGNATURE,
+];
+
+// the types in neither list: keys, accounts, calls and the rejection, which
+// carry no signing payload, and `crypto-psbt` and `zcash-pczt`, which travel
+// both ways. Only the tests read it, to check that every registered type is in
+// exactly one of the three lists
+#[cfg(test)]
+const UNDIRECTED_TYPES: &[RegistryType] = &[
+    UUID,
+    CRYPTO_HDKEY,
+    CRYPTO_KEYPATH,
+    CRYPTO_COIN_INFO,
+    CRYPTO_ECKEY,
+    CRYPTO_OUTPUT,
+    CRYPTO_PSBT,
+    CRYPTO_ACCOUNT,
+    CRYPTO_MULTI_ACCOUNTS,
+    QR_HARDWARE_CALL,
+    KEY_DERIVATION_CALL,
+    KEY_DERIVATION_SCHEMA,
+    ARWEAVE_CRYPTO_ACCOUNT,
+    KEYSTONE_SIGN_REJECTION,
+    CARDANO_UTXO,
+    CARDANO_CERT_KEY,
+    CARDANO_DELEGSTION,
+    XMR_OUTPUT,
+    XMR_KEYIMAGE,
+    ERGO_HINT_BAG,
+    ZCASH_ACCOUNTS,
+    ZCASH_FULL_VIEWING_KEY,
+    ZCASH_UNIFIED_FULL_VIEWING_KEY,
+    ZCASH_PCZT,
+];
+
+macro_rules! registry_tags {
+    ($($variant: ident = $registry_type: ident,)*) => {
+        /// The CBOR tags of the registry types, for matching on an incoming tag
+        /// without magic numbers. EVM and Avalanche reuse the Cosmos and Monero
+        /// tags, so they match `CosmosSignRequest`, `XmrOutput` and so on.
+        #[repr(u64)]
+        #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+        pub enum RegistryTag {
+            $(
+                $variant = match $registry_type.1 {
+                    Some(tag) => tag,
+                    None => panic!("registry type without a tag"),
+                },
+            )*
+        }
+
+        impl TryFrom<u64> for RegistryTag {
+            type Error = URError;
+
+            fn try_from(tag: u64) -> URResult<Self> {
+                $(
+                    if tag == RegistryTag::$variant as u64 {
+                        return Ok(RegistryTag::$variant);
+                    }
+                )*
+                Err(URError::NotSupportURTypeError(format!("tag {}", tag)))
+            }
+        }
+
+        #[cfg(test)]
+        const REGISTRY_TAGS: &[(RegistryTag, RegistryType)] = &[
+            $((RegistryTag::$variant, $registry_type),)*
+        ];
+    };
+}
+
+registry_tags!(
+    Uuid = UUID,
+    CryptoHDKey = CRYPTO_HDKEY,
+    CryptoKeypath = CRYPTO_KEYPATH,
+    CryptoCoinInfo = CRYPTO_COIN_INFO,
+    CryptoECKey = CRYPTO_ECKEY,
+    CryptoOutput = CRYPTO_OUTPUT,
+    CryptoPSBT = CRYPTO_PSBT,
+    CryptoAccount = CRYPTO_ACCOUNT,
+    CryptoMultiAccounts = CRYPTO_MULTI_ACCOUNTS,
+    EthSignRequest = ETH_SIGN_REQUEST,
+    EthSignature = ETH_SIGNATURE,
+    SolSignRequest = SOL_SIGN_REQUEST,
+    SolSignature = SOL_SIGNATURE,
+    QRHardwareCall = QR_HARDWARE_CALL,
+    KeyDerivationCall = KEY_DERIVATION_CALL,
+    KeyDerivationSchema = KEY_DERIVATION_SCHEMA,
+    NearSignRequest = NEAR_SIGN_REQUEST,
+    NearSignature = NEAR_SIGNATURE,
+    ArweaveCryptoAccount = ARWEAVE_CRYPTO_ACCOUNT,
+    ArweaveSignRequest = ARWEAVE_SIGN_REQUEST,
+    ArweaveSignature = ARWEAVE_SIGNATURE,
+    CosmosSignRequest = COSMOS_SIGN_REQUEST,
+    CosmosSignature = COSMOS_SIGNATURE,
+    TronSignRequest = TRON_SIGN_REQUEST,
+    TronSignature = TRON_SIGNATURE,
+    AptosSignRequest = APTOS_SIGN_REQUEST,
+    AptosSignature = APTOS_SIGNATURE,
+    KeystoneSignRequest = KEYSTONE_SIGN_REQUEST,
+    KeystoneSignResult = KEYSTONE_SIGN_RESULT,
+    KeystoneSignRejection = KEYSTONE_SIGN_REJECTION,
+    CardanoUTXO = CARDANO_UTXO,
+    CardanoSignRequest = CARDANO_SIGN_REQUEST,
+    CardanoSignature = CARDANO_SIGNATURE,
+    CardanoCertKey = CARDANO_CERT_KEY,
+    CardanoSignDataRequest = CARDANO_SIGN_DATA_REQUEST,
+    CardanoSignDataSignature = CARDANO_SIGN_DATA_SIGNATURE,
+    CardanoCatalystVotingRegistration = CARDANO_CATALYST_VOTING_REGISTRATION,
+    CardanoCatalystVotingRegistrationSignature = CARDANO_CATALYST_VOTING_REGISTRATION_SIGNATURE,
+    CardanoDelegation = CARDANO_DELEGSTION,
+    CardanoSignCip8DataRequest = CARDANO_SIGN_CIP8_DATA_REQUEST,
+    CardanoSignCip8DataSignature = CARDANO_SIGN_CIP8_DATA_SIGNATURE,
+    CardanoSignTxHashRequest = CARDANO_SIGN_TX_HASH_REQUEST,
+    SuiSignRequest = SUI_SIGN_REQUEST,
+    SuiSignature = SUI_SIGNATURE,
+    SuiSignHashRequest = SUI_SIGN_HASH_REQUEST,
+    TonSignRequest = TON_SIGN_REQUEST,
+    TonSignature = TON_SIGNATURE,
+    BtcSignRequest = BTC_SIGN_REQUEST,
+    BtcSignature = BTC_SIGNATURE,
+    UtxoPSBT = UTXO_PSBT,
+    StellarSignRequest = STELLAR_SIGN_REQUEST,
+    StellarSignature = STELLAR_SIGNATURE,
+    XmrOutput = XMR_OUTPUT,
+    XmrKeyImage = XMR_KEYIMAGE,
+    XmrTxUnsigned = XMR_TXUNSIGNED,
+    XmrTxSigned = XMR_TXSIGNED,
+    MoneroSignRequest = MONERO_SIGN_REQUEST,
+    MoneroSignature = MONERO_SIGNATURE,
+    ErgoSignature = ERGO_SIGNATURE,
+    ErgoHintBag = ERGO_HINT_BAG,
+    TezosSignRequest = TEZOS_SIGN_REQUEST,
+    TezosSignature = TEZOS_SIGNATURE,
+    HederaSignRequest = HEDERA_SIGN_REQUEST,
+    HederaSignature = HEDERA_SIGNATURE,
+    KaspaSignRequest = KASPA_SIGN_REQUEST,
+    KaspaSignature = KASPA_SIGNATURE,
+    ZcashAccounts = ZCASH_ACCOUNTS,
+    ZcashFullViewingKey = ZCASH_FULL_VIEWING_KEY,
+    ZcashUnifiedFullViewingKey = ZCASH_UNIFIED_FULL_VIEWING_KEY,
+    ZcashPczt = ZCASH_PCZT,
+    ZcashSignRequest = ZCASH_SIGN_REQUEST,
+    ZcashSignature = ZCASH_SIGNATURE,
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_payload_direction() {
+        assert!(is_request_type(&SUI_SIGN_REQUEST.get_type()));
+        assert!(!is_signature_type(&SUI_SIGN_REQUEST.get_type()));
+        assert!(is_signature_type(&ETH_SIGNATURE.get_type()));
+        assert!(!is_request_type(&ETH_SIGNATURE.get_type()));
+
+        assert!(is_request_type(&SUI_SIGN_HASH_REQUEST.get_type()));
+        assert!(is_signature_type(&KEYSTONE_SIGN_RESULT.get_type()));
+        assert!(is_signature_type(
+            &CARDANO_CATALYST_VOTING_REGISTRATION_SIGNATURE.get_type()
+        ));
+        assert!(!is_request_type(&CRYPTO_HDKEY.get_type()));
+        assert!(!is_signature_type(&CRYPTO_HDKEY.get_type()));
+    }
+
+    #[test]
+    fn test_payload_direction_without_suffix() {
+        assert!(is_request_type(
+            &CARDANO_CATALYST_VOTING_REGISTRATION.get_type()
+        ));
+        assert!(!is_signature_type(
+            &CARDANO_CATALYST_VOTING_REGISTRATION.get_type()
+        ));
+        assert!(is_request_type(&XMR_TXUNSIGNED.get_type()));
+        assert!(is_signature_type(&XMR_TXSIGNED.get_type()));
+        assert!(!is_request_type(&KEYSTONE_SIGN_REJECTION.get_type()));
+        assert!(!is_signature_type(&KEYSTONE_SIGN_REJECTION.get_type()));
+        assert!(!is_request_type(&KEY_DERIVATION_CALL.get_type()));
+        assert!(!is_request_type(&CRYPTO_PSBT.get_type()));
+        assert!(!is_signature_type(&CRYPTO_PSBT.get_type()));
+        assert!(is_request_type(&UTXO_PSBT.get_type()));
+        assert!(!is_signature_type(&UTXO_PSBT.get_type()));
+        assert!(!is_request_type("unknown-sign-request"));
+    }
+
+    #[test]
+    fn test_registry_tags() {
+        for (tag, registry_type) in REGISTRY_TAGS {
+            assert_eq!(registry_type.get_tag(), *tag as u64);
+            assert_eq!(Ok(*tag), RegistryTag::try_from(registry_type.get_tag()));
+        }
+        assert_eq!(Ok(RegistryTag::EthSignRequest), RegistryTag::try_from(401));
+        assert_eq!(
+            Ok(RegistryTag::CosmosSignRequest),
+            RegistryTag::try_from(EVM_SIGN_REQUEST.get_tag())
+        );
+        assert!(RegistryTag::try_from(0).is_err());
+    }
+
+    #[test]
+    fn test_payload_direction_is_exhaustive() {
+        // EVM and Avalanche share their tags with Cosmos and Monero, so they
+        // aren't in `REGISTRY_TAGS`
+        let shared_tag_types = [
+            EVM_SIGN_REQUEST,
+            EVM_SIGNATURE,
+            AVAX_SIGN_REQUEST,
+            AVAX_SIGNATURE,
+        ];
+        let registry_types = REGISTRY_TAGS
+            .iter()
+            .map(|(_, registry_type)| registry_type)
+            .chain(shared_tag_types.iter());
+        for registry_type in registry_types {
+            let lists = [SIGN_REQUEST_TYPES, SIGNATURE_TYPES, UNDIRECTED_TYPES];
+            let count = lists
+                .iter()
+                .filter(|list| list.iter().any(|listed| listed.0 == registry_type.0))
+                .count();
+            assert_eq!(1, count, "{} is in {} lists", registry_type.0, count);
+        }
+    }
+}
@@ -0,0 +1,999 @@
+// Property tests that encode randomly generated registry items, decode them
+// again and check that nothing was lost, plus a check that decoding arbitrary
+// bytes never panics. Run with `cargo test --features fuzz-tests`.
+//
+// Every signature type and sign request has a strategy.
+
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt::Debug;
+
+use proptest::collection::vec;
+use proptest::option;
+use proptest::prelude::*;
+
+use crate::aptos::aptos_sign_request::{self, AptosSignRequest};
+use crate::aptos::aptos_signature::AptosSignature;
+use crate::arweave::arweave_sign_request::{self, ArweaveSignRequest, SaltLen};
+use crate::arweave::arweave_signature::ArweaveSignature;
+use crate::avalanche::avax_sign_request::{AvaxChain, AvaxSignRequest};
+use crate::avalanche::avax_signature::AvaxSignature;
+use crate::bitcoin::btc_sign_request::{self, BtcNetwork, BtcSignRequest};
+use crate::bitcoin::btc_signature::BtcSignature;
+use crate::cardano::{
+    cardano_catalyst_signature::CardanoCatalystSignature,
+    cardano_catalyst_voting_registration::CardanoCatalystVotingRegistrationRequest,
+    cardano_cert_key::CardanoCertKey,
+    cardano_delegation::CardanoDelegation,
+    cardano_sign_cip8_data_request::{CardanoSignCip8DataRequest, Cip8AddressType},
+    cardano_sign_cip8_data_signature::CardanoSignCip8DataSignature,
+    cardano_sign_data_request::CardanoSignDataRequest,
+    cardano_sign_data_signature::CardanoSignDataSignature,
+    cardano_sign_request::CardanoSignRequest,
+    cardano_sign_tx_hash_request::CardanoSignTxHashRequest,
+    cardano_signature::CardanoSignature,
+    cardano_utxo::CardanoUTXO,
+};
+use crate::cosmos::cosmos_sign_request::{CosmosSignRequest, DataType};
+use crate::cosmos::evm_sign_request::{EvmSignRequest, SignDataType};
+use crate::cosmos::{cosmos_signature::CosmosSignature, evm_signature::EvmSignature};
+use crate::crypto_key_path::{CryptoKeyPath, PathComponent};
+use crate::ergo::{ergo_hint_bag::ErgoHintBag, ergo_signature::ErgoSignature};
+use crate::error::URError;
+use crate::ethereum::eth_sign_request::{self, EthSignRequest};
+use crate::ethereum::eth_signature::EthSignature;
+use crate::hedera::hedera_sign_request::{HederaSignRequest, KeyType};
+use crate::hedera::hedera_signature::HederaSignature;
+use crate::kaspa::{kaspa_sign_request::KaspaSignRequest, kaspa_signature::KaspaSignature};
+use crate::keystone::keystone_sign_rejection::KeystoneSignRejection;
+use crate::keystone::keystone_sign_request::KeystoneSignRequest;
+use crate::monero::{monero_sign_request::MoneroSignRequest, monero_signature::MoneroSignature};
+use crate::near::{near_sign_request::NearSignRequest, near_signature::NearSignature};
+use crate::pretty::pretty_print;
+use crate::registry_types::{
+    RegistryType, APTOS_SIGNATURE, APTOS_SIGN_REQUEST, ARWEAVE_CRYPTO_ACCOUNT, ARWEAVE_SIGNATURE,
+    AVAX_SIGNATURE, AVAX_SIGN_REQUEST, BTC_SIGNATURE, BTC_SIGN_REQUEST, BYTES,
+    CARDANO_CATALYST_VOTING_REGISTRATION, CARDANO_CATALYST_VOTING_REGISTRATION_SIGNATURE,
+    CARDANO_CERT_KEY, CARDANO_SIGNATURE, CARDANO_SIGN_CIP8_DATA_REQUEST,
+    CARDANO_SIGN_CIP8_DATA_SIGNATURE, CARDANO_SIGN_DATA_REQUEST, CARDANO_SIGN_DATA_SIGNATURE,
+    CARDANO_SIGN_REQUEST, CARDANO_SIGN_TX_HASH_REQUEST, CARDANO_UTXO, COSMOS_SIGNATURE,
+    COSMOS_SIGN_REQUEST, CRYPTO_ACCOUNT, CRYPTO_COIN_INFO, CRYPTO_ECKEY, CRYPTO_HDKEY,
+    CRYPTO_KEYPATH, CRYPTO_MULTI_ACCOUNTS, CRYPTO_OUTPUT, CRYPTO_PSBT, ERGO_HINT_BAG,
+    ERGO_SIGNATURE, ETH_SIGNATURE, ETH_SIGN_REQUEST, EVM_SIGNATURE, EVM_SIGN_REQUEST,
+    HEDERA_SIGNATURE, HEDERA_SIGN_REQUEST, KASPA_SIGNATURE, KASPA_SIGN_REQUEST,
+    KEYSTONE_SIGN_REJECTION, KEYSTONE_SIGN_REQUEST, KEYSTONE_SIGN_RESULT, KEY_DERIVATION_CALL,
+    KEY_DERIVATION_SCHEMA, MONERO_SIGNATURE, MONERO_SIGN_REQUEST, NEAR_SIGNATURE,
+    NEAR_SIGN_REQUEST, QR_HARDWARE_CALL, SOL_SIGNATURE, SOL_SIGN_REQUEST, STELLAR_SIGNATURE,
+    STELLAR_SIGN_REQUEST, SUI_SIGNATURE, SUI_SIGN_HASH_REQUEST, SUI_SIGN_REQUEST, TEZOS_SIGNATURE,
+    TEZOS_SIGN_REQUEST, TON_SIGNATURE, TON_SIGN_REQUEST, UTXO_PSBT, XMR_KEYIMAGE, XMR_OUTPUT,
+    XMR_TXSIGNED, XMR_TXUNSIGNED, ZCASH_ACCOUNTS, ZCASH_PCZT, ZCASH_SIGNATURE, ZCASH_SIGN_REQUEST,
+    ZCASH_UNIFIED_FULL_VIEWING_KEY,
+};
+use crate::solana::sol_sign_request::{self, SolSignRequest};
+use crate::solana::sol_signature::SolSignature;
+use crate::stellar::stellar_sign_request::{self, StellarSignRequest};
+use crate::stellar::stellar_signature::StellarSignature;
+use crate::sui::sui_sign_hash_request::SuiSignHashRequest;
+use crate::sui::{sui_sign_request::SuiSignRequest, sui_signature::SuiSignature};
+use crate::tezos::tezos_sign_request::{self, TezosSignRequest};
+use crate::tezos::tezos_signature::TezosSignature;
+use crate::ton::ton_sign_request::{self, TonSignRequest};
+use crate::ton::ton_signature::TonSignature;
+use crate::types::{Bytes, TX_HASH_LENGTH};
+use crate::zcash::zcash_sign_request::{Component, ZcashSignRequest};
+use crate::zcash::zcash_signature::ZcashSignature;
+
+const REGISTRY_TYPES: [RegistryType; 71] = [
+    APTOS_SIGNATURE,
+    APTOS_SIGN_REQUEST,
+    ARWEAVE_CRYPTO_ACCOUNT,
+    ARWEAVE_SIGNATURE,
+    AVAX_SIGNATURE,
+    AVAX_SIGN_REQUEST,
+    BTC_SIGNATURE,
+    BTC_SIGN_REQUEST,
+    BYTES,
+    CARDANO_CATALYST_VOTING_REGISTRATION,
+    CARDANO_CATALYST_VOTING_REGISTRATION_SIGNATURE,
+    CARDANO_CERT_KEY,
+    CARDANO_SIGNATURE,
+    CARDANO_SIGN_CIP8_DATA_REQUEST,
+    CARDANO_SIGN_CIP8_DATA_SIGNATURE,
+    CARDANO_SIGN_DATA_REQUEST,
+    CARDANO_SIGN_DATA_SIGNATURE,
+    CARDANO_SIGN_REQUEST,
+    CARDANO_SIGN_TX_HASH_REQUEST,
+    CARDANO_UTXO,
+    COSMOS_SIGNATURE,
+    COSMOS_SIGN_REQUEST,
+    CRYPTO_ACCOUNT,
+    CRYPTO_COIN_INFO,
+    CRYPTO_ECKEY,
+    CRYPTO_HDKEY,
+    CRYPTO_KEYPATH,
+    CRYPTO_MULTI_ACCOUNTS,
+    CRYPTO_OUTPUT,
+    CRYPTO_PSBT,
+    ERGO_HINT_BAG,
+    ERGO_SIGNATURE,
+    ETH_SIGNATURE,
+    ETH_SIGN_REQUEST,
+    EVM_SIGNATURE,
+    EVM_SIGN_REQUEST,
+    HEDERA_SIGNATURE,
+    HEDERA_SIGN_REQUEST,
+    KASPA_SIGNATURE,
+    KASPA_SIGN_REQUEST,
+    KEYSTONE_SIGN_REJECTION,
+    KEYSTONE_SIGN_REQUEST,
+    KEYSTONE_SIGN_RESULT,
+    KEY_DERIVATION_CALL,
+    KEY_DERIVATION_SCHEMA,
+    MONERO_SIGNATURE,
+    MONERO_SIGN_REQUEST,
+    NEAR_SIGNATURE,
+    NEAR_SIGN_REQUEST,
+    QR_HARDWARE_CALL,
+    SOL_SIGNATURE,
+    SOL_SIGN_REQUEST,
+    STELLAR_SIGNATURE,
+    STELLAR_SIGN_REQUEST,
+    SUI_SIGNATURE,
+    SUI_SIGN_HASH_REQUEST,
+    SUI_SIGN_REQUEST,
+    TEZOS_SIGNATURE,
+    TEZOS_SIGN_REQUEST,
+    TON_SIGNATURE,
+    TON_SIGN_REQUEST,
+    UTXO_PSBT,
+    XMR_KEYIMAGE,
+    XMR_OUTPUT,
+    XMR_TXSIGNED,
+    XMR_TXUNSIGNED,
+    ZCASH_ACCOUNTS,
+    ZCASH_PCZT,
+    ZCASH_SIGNATURE,
+    ZCASH_SIGN_REQUEST,
+    ZCASH_UNIFIED_FULL_VIEWING_KEY,
+];
+
+// decoding drops nothing the encoder wrote if re-encoding gives the same bytes
+fn check_round_trip<T>(item: T) -> Result<(), TestCaseError>
+where
+    T: Debug + TryInto<Vec<u8>, Error = URError> + TryFrom<Vec<u8>, Error = URError>,
+{
+    let bytes: Vec<u8> = item.try_into().map_err(|e| TestCaseError::fail(e.to_string()))?;
+    let decoded = T::try_from(bytes.clone()).map_err(|e| TestCaseError::fail(e.to_string()))?;
+    let encoded: Vec<u8> = decoded
+        .try_into()
+        .map_err(|e| TestCaseError::fail(e.to_string()))?;
+    prop_assert_eq!(bytes, encoded);
+    Ok(())
+}
+
+fn bytes(max_len: usize) -> impl Strategy<Value = Bytes> {
+    vec(any::<u8>(), 1..max_len)
+}
+
+fn request_id() -> impl Strategy<Value = Option<Bytes>> {
+    option::of(vec(any::<u8>(), 16))
+}
+
+fn origin() -> impl Strategy<Value = Option<String>> {
+    option::of("[ -~]{0,64}")
+}
+
+fn path_component() -> impl Strategy<Value = PathComponent> {
+    (option::of(0u32..PathComponent::HARDEN_BIT), any::<bool>())
+        .prop_map(|(index, hardened)| PathComponent::new(index, hardened).unwrap())
+}
+
+fn key_path() -> impl Strategy<Value = CryptoKeyPath> {
+    (
+        vec(path_component(), 0..8),
+        option::of(any::<[u8; 4]>()),
+        option::of(any::<u32>()),
+    )
+        .prop_map(|(components, source_fingerprint, depth)| {
+            CryptoKeyPath::new(components, source_fingerprint, depth)
+        })
+}
+
+fn data_type() -> impl Strategy<Value = DataType> {
+    prop_oneof![
+        Just(DataType::Amino),
+        Just(DataType::Direct),
+        Just(DataType::Textual),
+        Just(DataType::Message),
+    ]
+}
+
+fn aptos_sign_type() -> impl Strategy<Value = aptos_sign_request::SignType> {
+    prop_oneof![
+        Just(aptos_sign_request::SignType::Single),
+        Just(aptos_sign_request::SignType::Multi),
+        Just(aptos_sign_request::SignType::Message),
+    ]
+}
+
+fn arweave_sign_type() -> impl Strategy<Value = arweave_sign_request::SignType> {
+    prop_oneof![
+        Just(arweave_sign_request::SignType::Transaction),
+        Just(arweave_sign_request::SignType::DataItem),
+        Just(arweave_sign_request::SignType::Message),
+    ]
+}
+
+fn salt_len() -> impl Strategy<Value = SaltLen> {
+    prop_oneof![Just(SaltLen::Zero), Just(SaltLen::Digest)]
+}
+
+fn avax_chain() -> impl Strategy<Value = AvaxChain> {
+    prop_oneof![
+        Just(AvaxChain::XChain),
+        Just(AvaxChain::PChain),
+        Just(AvaxChain::CChain),
+    ]
+}
+
+fn btc_network() -> impl Strategy<Value = BtcNetwork> {
+    prop_oneof![
+        Just(BtcNetwork::Mainnet),
+        Just(BtcNetwork::Testnet),
+        Just(BtcNetwork::Signet),
+    ]
+}
+
+fn evm_data_type() -> impl Strategy<Value = SignDataType> {
+    prop_oneof![
+        Just(SignDataType::Arbitrary),
+        Just(SignDataType::CosmosAmino),
+        Just(SignDataType::CosmosDirect),
+    ]
+}
+
+fn eth_data_type() -> impl Strategy<Value = eth_sign_request::DataType> {
+    prop_oneof![
+        Just(eth_sign_request::DataType::Transaction),
+        Just(eth_sign_request::DataType::TypedData),
+        Just(eth_sign_request::DataType::PersonalMessage),
+        Just(eth_sign_request::DataType::TypedTransaction),
+    ]
+}
+
+fn sol_sign_type() -> impl Strategy<Value = sol_sign_request::SignType> {
+    prop_oneof![
+        Just(sol_sign_request::SignType::Transaction),
+        Just(sol_sign_request::SignType::Message),
+    ]
+}
+
+fn stellar_sign_type() -> impl Strategy<Value = stellar_sign_request::SignType> {
+    prop_oneof![
+        Just(stellar_sign_request::SignType::Transaction),
+        Just(stellar_sign_request::SignType::TransactionHash),
+        Just(stellar_sign_request::SignType::Message),
+        Just(stellar_sign_request::SignType::AuthEntry),
+    ]
+}
+
+fn ton_data_type() -> impl Strategy<Value = ton_sign_request::DataType> {
+    prop_oneof![
+        Just(ton_sign_request::DataType::Transaction),
+        Just(ton_sign_request::DataType::SignProof),
+    ]
+}
+
+fn tezos_sign_type() -> impl Strategy<Value = tezos_sign_request::SignType> {
+    prop_oneof![
+        Just(tezos_sign_request::SignType::Block),
+        Just(tezos_sign_request::SignType::Endorsement),
+        Just(tezos_sign_request::SignType::Operation),
+        Just(tezos_sign_request::SignType::Message),
+    ]
+}
+
+// sign_data starting with one of the watermarks of the sign type, as the
+// decoder rejects any other
+fn tezos_sign_data() -> impl Strategy<Value = (tezos_sign_request::SignType, Bytes)> {
+    tezos_sign_type().prop_flat_map(|sign_type| {
+        let watermarks = sign_type.watermarks();
+        (
+            Just(sign_type),
+            proptest::sample::select(watermarks),
+            bytes(512),
+        )
+            .prop_map(|(sign_type, watermark, data)| {
+                let sign_data = core::iter::once(watermark).chain(data).collect();
+                (sign_type, sign_data)
+            })
+    })
+}
+
+fn cip8_address_type() -> impl Strategy<Value = Cip8AddressType> {
+    prop_oneof![
+        Just(Cip8AddressType::Address),
+        Just(Cip8AddressType::KeyHash)
+    ]
+}
+
+fn cardano_utxo() -> impl Strategy<Value = CardanoUTXO> {
+    (
+        bytes(32),
+        any::<u32>(),
+        "[0-9]{1,20}",
+        key_path(),
+        "addr1[a-z0-9]{53}",
+    )
+        .prop_map(|(transaction_hash, index, amount, key_path, address)| {
+            CardanoUTXO::new(transaction_hash, index, amount, key_path, address)
+        })
+}
+
+fn cardano_cert_key() -> impl Strategy<Value = CardanoCertKey> {
+    (bytes(28), key_path()).prop_map(|(key_hash, key_path)| CardanoCertKey::new(key_hash, key_path))
+}
+
+fn cardano_delegation() -> impl Strategy<Value = CardanoDelegation> {
+    (bytes(32), any::<u8>()).prop_map(|(pub_key, weidth)| CardanoDelegation::new(pub_key, weidth))
+}
+
+fn key_type() -> impl Strategy<Value = KeyType> {
+    prop_oneof![Just(KeyType::Ed25519), Just(KeyType::EcdsaSecp256k1)]
+}
+
+fn component() -> impl Strategy<Value = Component> {
+    prop_oneof![
+        Just(Component::Transparent),
+        Just(Component::Sapling),
+        Just(Component::Orchard),
+    ]
+}
+
+proptest! {
+    #[test]
+    fn test_crypto_key_path_round_trip(path in key_path()) {
+        let bytes: Vec<u8> = path.clone().try_into().unwrap();
+        prop_assert_eq!(path, CryptoKeyPath::try_from(bytes).unwrap());
+    }
+
+    #[test]
+    fn test_sui_signature_round_trip(
+        request_id in request_id(),
+        signature in bytes(128),
+        public_key in option::of(bytes(64)),
+        tx_hash in option::of(vec(any::<u8>(), TX_HASH_LENGTH)),
+    ) {
+        check_round_trip(SuiSignature::new(request_id, signature, public_key, tx_hash))?;
+    }
+
+    #[test]
+    fn test_sui_sign_request_round_trip(
+        request_id in request_id(),
+        intent_message in bytes(512),
+        derivation_paths in vec(key_path(), 1..4),
+        addresses in option::of(vec(bytes(32), 4)),
+        origin in origin(),
+        gas_objects in option::of(vec(bytes(73), 0..8)),
+        expires_at in option::of(any::<u64>()),
+        sponsor in option::of(bytes(32)),
+    ) {
+        // one address per derivation path
+        let addresses = addresses.map(|mut addresses| {
+            addresses.truncate(derivation_paths.len());
+            addresses
+        });
+        check_round_trip(SuiSignRequest::new(
+            request_id,
+            intent_message,
+            derivation_paths,
+            addresses,
+            origin,
+            gas_objects,
+            expires_at,
+            sponsor,
+        ))?;
+    }
+
+    #[test]
+    fn test_cosmos_sign_request_round_trip(
+        request_id in vec(any::<u8>(), 16),
+        sign_data in bytes(512),
+        data_type in data_type(),
+        derivation_paths in vec(key_path(), 1..4),
+        addresses in option::of(vec("[a-z0-9]{1,64}", 0..4)),
+        origin in origin(),
+        authz_grantee in option::of("[a-z0-9]{1,64}"),
+        authz_msg_types in option::of(vec("[A-Za-z./]{1,64}", 0..4)),
+        expires_at in option::of(any::<u64>()),
+    ) {
+        let mut request = CosmosSignRequest::new(
+            request_id,
+            sign_data,
+            data_type,
+            derivation_paths,
+            addresses,
+            origin,
+        );
+        if let Some(authz_grantee) = authz_grantee {
+            request.set_authz_grantee(authz_grantee);
+        }
+        if let Some(authz_msg_types) = authz_msg_types {
+            request.set_authz_msg_types(authz_msg_types);
+        }
+        if let Some(expires_at) = expires_at {
+            request.set_expires_at(expires_at);
+        }
+        check_round_trip(request)?;
+    }
+
+    #[test]
+    fn test_eth_signature_round_trip(
+        request_id in request_id(),
+        signature in bytes(65),
+        origin in origin(),
+        tx_hash in option::of(vec(any::<u8>(), TX_HASH_LENGTH)),
+    ) {
+        let mut signature = EthSignature::new(request_id, signature, origin);
+        if let Some(tx_hash) = tx_hash {
+            signature.set_tx_hash(tx_hash);
+        }
+        check_round_trip(signature)?;
+    }
+
+    #[test]
+    fn test_sol_signature_round_trip(
+        request_id in request_id(),
+        signature in bytes(64),
+        tx_hash in option::of(vec(any::<u8>(), TX_HASH_LENGTH)),
+    ) {
+        let mut signature = SolSignature::new(request_id, signature);
+        if let Some(tx_hash) = tx_hash {
+            signature.set_tx_hash(tx_hash);
+        }
+        check_round_trip(signature)?;
+    }
+
+    #[test]
+    fn test_ton_signature_round_trip(
+        request_id in request_id(),
+        signature in bytes(64),
+        origin in origin(),
+    ) {
+        check_round_trip(TonSignature::new(request_id, signature, origin))?;
+    }
+
+    #[test]
+    fn test_ergo_signature_round_trip(
+        request_id in request_id(),
+        signature in bytes(128),
+        hints in option::of(bytes(256)),
+    ) {
+        check_round_trip(ErgoSignature::new(
+            request_id,
+            signature,
+            hints.map(ErgoHintBag::new),
+        ))?;
+    }
+
+    #[test]
+    fn test_keystone_sign_rejection_round_trip(
+        request_id in request_id(),
+        reason_code in option::of(any::<u16>()),
+        reason_message in origin(),
+    ) {
+        check_round_trip(KeystoneSignRejection::new(request_id, reason_code, reason_message))?;
+    }
+
+    #[test]
+    fn test_aptos_sign_request_round_trip(
+        request_id in vec(any::<u8>(), 16),
+        sign_data in bytes(512),
+        derivation_paths in vec(key_path(), 1..4),
+        accounts in option::of(vec(bytes(32), 0..4)),
+        origin in origin(),
+        sign_type in aptos_sign_type(),
+        expires_at in option::of(any::<u64>()),
+    ) {
+        let mut request = AptosSignRequest::new(
+            request_id,
+            sign_data,
+            derivation_paths,
+            accounts,
+            origin,
+            sign_type,
+        );
+        if let Some(expires_at) = expires_at {
+            request.set_expires_at(expires_at);
+        }
+        check_round_trip(request)?;
+    }
+
+    #[test]
+    fn test_arweave_sign_request_round_trip(
+        master_fingerprint in any::<[u8; 4]>(),
+        request_id in request_id(),
+        sign_data in bytes(512),
+        sign_type in arweave_sign_type(),
+        salt_len in salt_len(),
+        account in option::of(bytes(32)),
+        origin in origin(),
+    ) {
+        check_round_trip(ArweaveSignRequest::new(
+            master_fingerprint,
+            request_id,
+            sign_data,
+            sign_type,
+            salt_len,
+            account,
+            origin,
+        ))?;
+    }
+
+    #[test]
+    fn test_avax_sign_request_round_trip(
+        request_id in vec(any::<u8>(), 16),
+        sign_data in bytes(512),
+        master_fingerprint in any::<[u8; 4]>(),
+        xpub in "xpub[1-9A-HJ-NP-Za-km-z]{107}",
+        wallet_index in any::<u64>(),
+        chain in option::of(avax_chain()),
+        derivation_paths in option::of(vec(key_path(), 1..4)),
+        origin in origin(),
+    ) {
+        let mut request =
+            AvaxSignRequest::new(request_id, sign_data, master_fingerprint, xpub, wallet_index);
+        if let Some(chain) = chain {
+            request.set_chain(chain);
+        }
+        if let Some(derivation_paths) = derivation_paths {
+            request.set_derivation_paths(derivation_paths);
+        }
+        if let Some(origin) = origin {
+            request.set_origin(origin);
+        }
+        check_round_trip(request)?;
+    }
+
+    #[test]
+    fn test_btc_sign_request_round_trip(
+        request_id in vec(any::<u8>(), 16),
+        sign_data in bytes(512),
+        derivation_paths in vec(key_path(), 1..4),
+        addresses in option::of(vec("[a-z0-9]{14,74}", 0..4)),
+        origin in origin(),
+        network in option::of(btc_network()),
+    ) {
+        check_round_trip(BtcSignRequest::new(
+            request_id,
+            sign_data,
+            btc_sign_request::DataType::Message,
+            derivation_paths,
+            addresses,
+            origin,
+            network,
+        ))?;
+    }
+
+    #[test]
+    fn test_evm_sign_request_round_trip(
+        request_id in vec(any::<u8>(), 16),
+        sign_data in bytes(512),
+        data_type in evm_data_type(),
+        custom_chain_identifier in any::<u32>(),
+        derivation_path in key_path(),
+        address in option::of(bytes(20)),
+        origin in origin(),
+        expires_at in option::of(any::<u64>()),
+    ) {
+        check_round_trip(EvmSignRequest::new(
+            request_id,
+            sign_data,
+            data_type,
+            custom_chain_identifier,
+            derivation_path,
+            address,
+            origin,
+            expires_at,
+        ))?;
+    }
+
+    #[test]
+    fn test_sui_sign_hash_request_round_trip(
+        request_id in request_id(),
+        message_hash in "[0-9a-f]{64}",
+        derivation_paths in vec(key_path(), 1..4),
+        addresses in option::of(vec(bytes(32), 0..4)),
+        origin in origin(),
+    ) {
+        check_round_trip(SuiSignHashRequest::new(
+            request_id,
+            message_hash,
+            derivation_paths,
+            addresses,
+            origin,
+        ))?;
+    }
+
+    #[test]
+    fn test_cardano_sign_tx_hash_request_round_trip(
+        request_id in request_id(),
+        tx_hash in "[0-9a-f]{64}",
+        paths in vec(key_path(), 1..4),
+        origin in origin(),
+        address_list in vec("addr1[a-z0-9]{53}", 0..4),
+    ) {
+        check_round_trip(CardanoSignTxHashRequest::new(
+            request_id,
+            tx_hash,
+            paths,
+            origin,
+            address_list,
+        ))?;
+    }
+
+    #[test]
+    fn test_cardano_sign_request_round_trip(
+        request_id in request_id(),
+        sign_data in bytes(512),
+        utxos in vec(cardano_utxo(), 0..4),
+        cert_keys in vec(cardano_cert_key(), 0..4),
+        origin in origin(),
+        extra_signers in option::of(vec(key_path(), 0..4)),
+    ) {
+        check_round_trip(CardanoSignRequest::new(
+            request_id,
+            sign_data,
+            utxos,
+            cert_keys,
+            origin,
+            extra_signers,
+        ))?;
+    }
+
+    #[test]
+    fn test_cardano_catalyst_voting_registration_round_trip(
+        request_id in request_id(),
+        delegations in vec(cardano_delegation(), 0..4),
+        stake_pub in bytes(32),
+        payment_address in bytes(57),
+        nonce in any::<u64>(),
+        voting_purpose in any::<u8>(),
+        derivation_path in key_path(),
+        origin in origin(),
+        sign_type in any::<u8>(),
+    ) {
+        check_round_trip(CardanoCatalystVotingRegistrationRequest::new(
+            request_id,
+            delegations,
+            stake_pub,
+            payment_address,
+            nonce,
+            voting_purpose,
+            derivation_path,
+            origin,
+            sign_type,
+        ))?;
+    }
+
+    #[test]
+    fn test_cardano_sign_data_request_round_trip(
+        request_id in request_id(),
+        sign_data in bytes(512),
+        derivation_path in key_path(),
+        origin in origin(),
+        xpub in bytes(64),
+    ) {
+        check_round_trip(CardanoSignDataRequest::new(
+            request_id,
+            sign_data,
+            derivation_path,
+            origin,
+            xpub,
+        ))?;
+    }
+
+    #[test]
+    fn test_cardano_sign_cip8_data_request_round_trip(
+        request_id in request_id(),
+        sign_data in bytes(512),
+        derivation_path in key_path(),
+        origin in origin(),
+        xpub in bytes(64),
+        hash_payload in any::<bool>(),
+        address_bench32 in option::of("addr1[a-z0-9]{53}"),
+        address_type in cip8_address_type(),
+    ) {
+        check_round_trip(CardanoSignCip8DataRequest::new(
+            request_id,
+            sign_data,
+            derivation_path,
+            origin,
+            xpub,
+            hash_payload,
+            address_bench32,
+            address_type,
+        ))?;
+    }
+
+    #[test]
+    fn test_tezos_sign_request_round_trip(
+        request_id in request_id(),
+        (sign_type, sign_data) in tezos_sign_data(),
+        derivation_path in key_path(),
+        origin in origin(),
+    ) {
+        check_round_trip(TezosSignRequest::new(
+            request_id,
+            sign_data,
+            sign_type,
+            derivation_path,
+            origin,
+        ))?;
+    }
+
+    #[test]
+    fn test_keystone_sign_request_round_trip(
+        sign_data in bytes(512),
+        origin in origin(),
+        version in option::of(any::<u32>()),
+    ) {
+        let mut request = KeystoneSignRequest::new(sign_data, origin);
+        if let Some(version) = version {
+            request.set_version(version);
+        }
+        check_round_trip(request)?;
+    }
+
+    #[test]
+    fn test_monero_sign_request_round_trip(
+        request_id in request_id(),
+        unsigned_tx_set in bytes(512),
+        primary_address in "4[1-9A-HJ-NP-Za-km-z]{94}",
+        account_index in any::<u32>(),
+        origin in origin(),
+    ) {
+        check_round_trip(MoneroSignRequest::new(
+            request_id,
+            unsigned_tx_set,
+            primary_address,
+            account_index,
+            origin,
+        ))?;
+    }
+
+    #[test]
+    fn test_eth_sign_request_round_trip(
+        request_id in request_id(),
+        sign_data in bytes(512),
+        data_type in eth_data_type(),
+        chain_id in option::of(any::<i64>()),
+        derivation_path in key_path(),
+        address in option::of(bytes(20)),
+        origin in origin(),
+    ) {
+        check_round_trip(EthSignRequest::new(
+            request_id,
+            sign_data,
+            data_type,
+            chain_id.map(i128::from),
+            derivation_path,
+            address,
+            origin,
+        ))?;
+    }
+
+    #[test]
+    fn test_sol_sign_request_round_trip(
+        request_id in request_id(),
+        sign_data in bytes(512),
+        derivation_path in key_path(),
+        address in option::of(bytes(32)),
+        origin in origin(),
+        sign_type in sol_sign_type(),
+    ) {
+        check_round_trip(SolSignRequest::new(
+            request_id,
+            sign_data,
+            derivation_path,
+            address,
+            origin,
+            sign_type,
+        ))?;
+    }
+
+    #[test]
+    fn test_near_sign_request_round_trip(
+        request_id in request_id(),
+        sign_data in vec(bytes(256), 1..4),
+        derivation_path in key_path(),
+        account in option::of(bytes(64)),
+        origin in origin(),
+    ) {
+        check_round_trip(NearSignRequest::new(
+            request_id,
+            sign_data,
+            derivation_path,
+            account,
+            origin,
+        ))?;
+    }
+
+    #[test]
+    fn test_stellar_sign_request_round_trip(
+        request_id in request_id(),
+        sign_data in bytes(512),
+        derivation_path in key_path(),
+        address in option::of(bytes(56)),
+        origin in origin(),
+        sign_type in stellar_sign_type(),
+    ) {
+        check_round_trip(StellarSignRequest::new(
+            request_id,
+            sign_data,
+            derivation_path,
+            address,
+            origin,
+            sign_type,
+        ))?;
+    }
+
+    #[test]
+    fn test_ton_sign_request_round_trip(
+        request_id in request_id(),
+        sign_data in bytes(512),
+        data_type in ton_data_type(),
+        derivation_path in option::of(key_path()),
+        address in option::of("[A-Za-z0-9_-]{48}"),
+        origin in origin(),
+    ) {
+        check_round_trip(TonSignRequest::new(
+            request_id,
+            sign_data,
+            data_type,
+            derivation_path,
+            address,
+            origin,
+        ))?;
+    }
+
+    #[test]
+    fn test_hedera_sign_request_round_trip(
+        request_id in request_id(),
+        sign_data in bytes(512),
+        key_type in key_type(),
+        derivation_path in key_path(),
+        origin in origin(),
+    ) {
+        check_round_trip(HederaSignRequest::new(
+            request_id,
+            sign_data,
+            key_type,
+            derivation_path,
+            origin,
+        ))?;
+    }
+
+    #[test]
+    fn test_kaspa_sign_request_round_trip(
+        request_id in request_id(),
+        sign_data in bytes(512),
+        derivation_paths in vec(key_path(), 1..4),
+        addresses in option::of(vec("kaspa:[a-z0-9]{61}", 4)),
+        origin in origin(),
+    ) {
+        // one address per derivation path
+        let addresses = addresses.map(|mut addresses| {
+            addresses.truncate(derivation_paths.len());
+            addresses
+        });
+        check_round_trip(KaspaSignRequest::new(
+            request_id,
+            sign_data,
+            derivation_paths,
+            addresses,
+            origin,
+        ))?;
+    }
+
+    #[test]
+    fn test_zcash_sign_request_round_trip(
+        request_id in request_id(),
+        sign_data in bytes(512),
+        component in component(),
+        derivation_paths in vec(key_path(), 1..4),
+        origin in origin(),
+    ) {
+        check_round_trip(ZcashSignRequest::new(
+            request_id,
+            sign_data,
+            component,
+            derivation_paths,
+            origin,
+        ))?;
+    }
+
+    #[test]
+    fn test_fixed_request_id_signature_round_trip(
+        request_id in vec(any::<u8>(), 16),
+        signature in bytes(128),
+        public_key in bytes(65),
+        tx_hash in option::of(vec(any::<u8>(), TX_HASH_LENGTH)),
+    ) {
+        let mut aptos_signature = AptosSignature::new(
+            request_id.clone(),
+            signature.clone(),
+            public_key.clone(),
+        );
+        if let Some(tx_hash) = tx_hash.clone() {
+            aptos_signature.set_tx_hash(tx_hash);
+        }
+        check_round_trip(aptos_signature)?;
+        check_round_trip(AvaxSignature::new(request_id.clone(), signature.clone()))?;
+        check_round_trip(BtcSignature::new(
+            request_id.clone(),
+            signature.clone(),
+            public_key.clone(),
+        ))?;
+        let mut cosmos_signature =
+            CosmosSignature::new(request_id.clone(), signature.clone(), public_key);
+        if let Some(tx_hash) = tx_hash.clone() {
+            cosmos_signature.set_tx_hash(tx_hash);
+        }
+        check_round_trip(cosmos_signature)?;
+        check_round_trip(EvmSignature::new(request_id, signature, tx_hash))?;
+    }
+
+    #[test]
+    fn test_optional_request_id_signature_round_trip(
+        request_id in request_id(),
+        signature in bytes(128),
+    ) {
+        check_round_trip(ArweaveSignature::new(request_id.clone(), signature.clone()))?;
+        check_round_trip(CardanoCatalystSignature::new(request_id.clone(), signature.clone()))?;
+        check_round_trip(HederaSignature::new(request_id.clone(), signature.clone()))?;
+        check_round_trip(StellarSignature::new(request_id.clone(), signature.clone()))?;
+        check_round_trip(TezosSignature::new(request_id, signature))?;
+    }
+
+    #[test]
+    fn test_cardano_signatures_round_trip(
+        request_id in request_id(),
+        signature in bytes(64),
+        public_key in bytes(32),
+        address_field in bytes(57),
+        witness_set in bytes(256),
+    ) {
+        check_round_trip(CardanoSignDataSignature::new(
+            request_id.clone(),
+            signature.clone(),
+            public_key.clone(),
+        ))?;
+        check_round_trip(CardanoSignCip8DataSignature::new(
+            request_id.clone(),
+            signature,
+            public_key,
+            address_field,
+        ))?;
+        check_round_trip(CardanoSignature::new(request_id, witness_set))?;
+    }
+
+    #[test]
+    fn test_multi_signature_round_trip(
+        request_id in request_id(),
+        signatures in vec(bytes(72), 1..8),
+        origin in origin(),
+    ) {
+        check_round_trip(NearSignature::new(request_id.clone(), signatures.clone()))?;
+        check_round_trip(KaspaSignature::new(request_id.clone(), signatures.clone()))?;
+        check_round_trip(ZcashSignature::new(request_id.clone(), signatures.clone()))?;
+        check_round_trip(MoneroSignature::new(request_id, signatures.concat(), origin))?;
+    }
+
+    #[test]
+    fn test_decode_never_panics(data in vec(any::<u8>(), 0..256)) {
+        for registry_type in REGISTRY_TYPES.iter() {
+            let _ = pretty_print(&registry_type.get_type(), &data);
+        }
+    }
+}
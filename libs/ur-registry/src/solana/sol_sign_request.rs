@@ -1,13 +1,19 @@
-use crate::cbor::cbor_map;
+use crate::cbor::{
+    cbor_array, cbor_bytes, cbor_decode, cbor_expect_type, cbor_map, cbor_origin, cbor_tag,
+    cbor_unknown_field, cbor_unknown_fields,
+};
 use crate::crypto_key_path::CryptoKeyPath;
+use crate::decode::DecodeContext;
 use crate::error::{URError, URResult};
+use crate::impl_with_new_request_id;
 use crate::registry_types::{RegistryType, CRYPTO_KEYPATH, SOL_SIGN_REQUEST, UUID};
-use crate::traits::{From as FromCbor, RegistryItem, To};
-use crate::types::Bytes;
+use crate::traits::{Expiry, From as FromCbor, RegistryItem, To, Validate};
+use crate::types::{Bytes, UnknownFields};
+use crate::validate::{validate_path, validate_request_id, validate_sign_data};
 use alloc::format;
 use alloc::string::{String, ToString};
 use alloc::vec::Vec;
-use minicbor::data::{Int, Tag};
+use minicbor::data::{Int, Tag, Type};
 use minicbor::encode::Write;
 use minicbor::{Decoder, Encoder};
 
@@ -17,11 +23,18 @@ const DERIVATION_PATH: u8 = 3;
 const ADDRESS: u8 = 4;
 const ORIGIN: u8 = 5;
 const SIGN_TYPE: u8 = 6;
+const EXPIRES_AT: u8 = 7;
+const ADDRESS_LOOKUP_TABLES: u8 = 8;
 
+const SOL_COIN_TYPE: u32 = 501;
+
+/// What `sign_data` holds, so that the device shows a transaction or a message.
 #[derive(Clone, Debug, PartialEq, Default)]
 pub enum SignType {
+    /// a serialized transaction message
     #[default]
     Transaction = 1,
+    /// an off-chain message, prefixed with the `\xffsolana offchain` signing domain
     Message,
 }
 
@@ -36,6 +49,11 @@ impl SignType {
             )),
         }
     }
+
+    /// The value `sign_type` is encoded as, the inverse of [`SignType::from_u32`].
+    pub fn to_u32(&self) -> u32 {
+        self.clone() as u32
+    }
 }
 
 #[derive(Clone, Debug, Default)]
@@ -46,8 +64,16 @@ pub struct SolSignRequest {
     address: Option<Bytes>,
     origin: Option<String>,
     sign_type: SignType,
+    // several transactions signed in one request, encoded in place of `sign_data`
+    sign_data_list: Option<Vec<Bytes>>,
+    expires_at: Option<u64>,
+    // the table account and its resolved addresses, for each lookup of a v0 transaction
+    address_lookup_tables: Option<Vec<(Bytes, Vec<Bytes>)>>,
+    unknown_fields: UnknownFields,
 }
 
+impl_with_new_request_id!(SolSignRequest);
+
 impl SolSignRequest {
     pub fn default() -> Self {
         Default::default()
@@ -57,8 +83,11 @@ impl SolSignRequest {
         self.request_id = Some(id);
     }
 
+    // `sign_data` mirrors the first transaction of `sign_data_list`, so each
+    // setter keeps the other field in step
     pub fn set_sign_data(&mut self, data: Bytes) {
         self.sign_data = data;
+        self.sign_data_list = None;
     }
 
     pub fn set_derivation_path(&mut self, derivation_path: CryptoKeyPath) {
@@ -77,6 +106,19 @@ impl SolSignRequest {
         self.sign_type = sign_type
     }
 
+    pub fn set_sign_data_list(&mut self, sign_data_list: Vec<Bytes>) {
+        self.sign_data = sign_data_list.first().cloned().unwrap_or_default();
+        self.sign_data_list = Some(sign_data_list)
+    }
+
+    pub fn set_expires_at(&mut self, expires_at: u64) {
+        self.expires_at = Some(expires_at)
+    }
+
+    pub fn set_address_lookup_tables(&mut self, address_lookup_tables: Vec<(Bytes, Vec<Bytes>)>) {
+        self.address_lookup_tables = Some(address_lookup_tables)
+    }
+
     pub fn new(
         request_id: Option<Bytes>,
         sign_data: Bytes,
@@ -92,6 +134,32 @@ impl SolSignRequest {
             address,
             origin,
             sign_type,
+            sign_data_list: None,
+            expires_at: None,
+            address_lookup_tables: None,
+            unknown_fields: UnknownFields::new(),
+        }
+    }
+
+    pub fn new_multi(
+        request_id: Option<Bytes>,
+        sign_data_list: Vec<Bytes>,
+        derivation_path: CryptoKeyPath,
+        address: Option<Bytes>,
+        origin: Option<String>,
+        sign_type: SignType,
+    ) -> SolSignRequest {
+        SolSignRequest {
+            request_id,
+            sign_data: sign_data_list.first().cloned().unwrap_or_default(),
+            derivation_path,
+            address,
+            origin,
+            sign_type,
+            sign_data_list: Some(sign_data_list),
+            expires_at: None,
+            address_lookup_tables: None,
+            unknown_fields: UnknownFields::new(),
         }
     }
     pub fn get_request_id(&self) -> Option<Bytes> {
@@ -112,6 +180,22 @@ impl SolSignRequest {
     pub fn get_sign_type(&self) -> SignType {
         self.sign_type.clone()
     }
+    pub fn get_sign_data_list(&self) -> Option<Vec<Bytes>> {
+        self.sign_data_list.clone()
+    }
+    pub fn get_address_lookup_tables(&self) -> Option<Vec<(Bytes, Vec<Bytes>)>> {
+        self.address_lookup_tables.clone()
+    }
+    pub fn is_multi(&self) -> bool {
+        self.sign_data_list.is_some()
+    }
+    /// The number of transactions in the request, shown as "1 of N" on the device.
+    pub fn get_sign_data_count(&self) -> usize {
+        self.sign_data_list.as_ref().map_or(1, |list| list.len())
+    }
+    pub fn get_unknown_fields(&self) -> UnknownFields {
+        self.unknown_fields.clone()
+    }
 
     fn get_map_size(&self) -> u64 {
         let mut size = 3;
@@ -124,7 +208,13 @@ impl SolSignRequest {
         if self.origin.is_some() {
             size += 1;
         }
-        size
+        if self.expires_at.is_some() {
+            size += 1;
+        }
+        if self.address_lookup_tables.is_some() {
+            size += 1;
+        }
+        size + self.unknown_fields.len() as u64
     }
 }
 
@@ -134,6 +224,33 @@ impl RegistryItem for SolSignRequest {
     }
 }
 
+impl Expiry for SolSignRequest {
+    fn expires_at(&self) -> Option<u64> {
+        self.expires_at
+    }
+}
+
+impl Validate for SolSignRequest {
+    fn validate_schema(&self) -> URResult<()> {
+        validate_request_id(&self.request_id)?;
+        match &self.sign_data_list {
+            Some(sign_data_list) => {
+                if sign_data_list.is_empty() {
+                    return Err(URError::ValidationError(
+                        "sign_data_list is empty".to_string(),
+                    ));
+                }
+                for sign_data in sign_data_list {
+                    validate_sign_data("sign_data_list", sign_data)?;
+                }
+            }
+            None => validate_sign_data("sign_data", &self.sign_data)?,
+        }
+        validate_path("derivation_path", &self.derivation_path, Some(SOL_COIN_TYPE))?;
+        Ok(())
+    }
+}
+
 impl<C> minicbor::Encode<C> for SolSignRequest {
     fn encode<W: Write>(
         &self,
@@ -148,7 +265,18 @@ impl<C> minicbor::Encode<C> for SolSignRequest {
                 .bytes(request_id)?;
         }
 
-        e.int(Int::from(SIGN_DATA))?.bytes(&self.sign_data)?;
+        e.int(Int::from(SIGN_DATA))?;
+        match &self.sign_data_list {
+            Some(sign_data_list) => {
+                e.array(sign_data_list.len() as u64)?;
+                for sign_data in sign_data_list {
+                    e.bytes(sign_data)?;
+                }
+            }
+            None => {
+                e.bytes(&self.sign_data)?;
+            }
+        }
 
         e.int(Int::from(DERIVATION_PATH))?;
         e.tag(Tag::Unassigned(CRYPTO_KEYPATH.get_tag()))?;
@@ -163,44 +291,103 @@ impl<C> minicbor::Encode<C> for SolSignRequest {
         }
 
         e.int(Int::from(SIGN_TYPE))?
-            .int(Int::from(self.sign_type.clone() as u8))?;
+            .int(Int::from(self.sign_type.to_u32()))?;
+
+        if let Some(expires_at) = self.expires_at {
+            e.int(Int::from(EXPIRES_AT))?.u64(expires_at)?;
+        }
+
+        if let Some(address_lookup_tables) = &self.address_lookup_tables {
+            e.int(Int::from(ADDRESS_LOOKUP_TABLES))?
+                .array(address_lookup_tables.len() as u64)?;
+            for (table, addresses) in address_lookup_tables {
+                e.array(2)?.bytes(table)?.array(addresses.len() as u64)?;
+                for address in addresses {
+                    e.bytes(address)?;
+                }
+            }
+        }
 
+        cbor_unknown_fields(e, &self.unknown_fields)?;
         Ok(())
     }
 }
 
-impl<'b, C> minicbor::Decode<'b, C> for SolSignRequest {
-    fn decode(d: &mut Decoder<'b>, _ctx: &mut C) -> Result<Self, minicbor::decode::Error> {
+impl<'b, C: DecodeContext> minicbor::Decode<'b, C> for SolSignRequest {
+    fn decode(d: &mut Decoder<'b>, ctx: &mut C) -> Result<Self, minicbor::decode::Error> {
         let mut result = SolSignRequest::default();
         cbor_map(d, &mut result, |key, obj, d| {
             let key =
                 u8::try_from(key).map_err(|e| minicbor::decode::Error::message(e.to_string()))?;
             match key {
                 REQUEST_ID => {
-                    d.tag()?;
-                    obj.request_id = Some(d.bytes()?.to_vec());
-                }
-                SIGN_DATA => {
-                    obj.sign_data = d.bytes()?.to_vec();
+                    cbor_tag(d, ctx, UUID.get_tag(), "UUID tag is invalid")?;
+                    obj.request_id = Some(cbor_bytes(d)?);
                 }
+                SIGN_DATA => match d.datatype()? {
+                    Type::Array | Type::ArrayIndef => {
+                        let mut sign_data_list = Vec::new();
+                        cbor_array(d, &mut sign_data_list, |_index, obj, d| {
+                            obj.push(cbor_bytes(d)?);
+                            Ok(())
+                        })?;
+                        obj.set_sign_data_list(sign_data_list);
+                    }
+                    _ => {
+                        cbor_expect_type(d, ctx, Type::Bytes)?;
+                        obj.sign_data = cbor_bytes(d)?;
+                    }
+                },
                 DERIVATION_PATH => {
-                    d.tag()?;
-                    obj.derivation_path = CryptoKeyPath::decode(d, _ctx)?;
+                    cbor_tag(
+                        d,
+                        ctx,
+                        CryptoKeyPath::get_registry_type().get_tag(),
+                        "CryptoKeyPath tag is invalid",
+                    )?;
+                    obj.derivation_path = CryptoKeyPath::decode(d, ctx)?;
                 }
                 ADDRESS => {
-                    obj.address = Some(d.bytes()?.to_vec());
+                    obj.address = Some(cbor_bytes(d)?);
                 }
                 ORIGIN => {
-                    obj.origin = Some(d.str()?.to_string());
+                    obj.origin = Some(cbor_origin(d)?);
                 }
                 SIGN_TYPE => {
+                    cbor_expect_type(d, ctx, Type::U32)?;
                     obj.sign_type = SignType::from_u32(
                         u32::try_from(d.int()?)
                             .map_err(|e| minicbor::decode::Error::message(e.to_string()))?,
                     )
                     .map_err(minicbor::decode::Error::message)?;
                 }
-                _ => {}
+                EXPIRES_AT => {
+                    obj.expires_at = Some(d.u64()?);
+                }
+                ADDRESS_LOOKUP_TABLES => {
+                    let mut address_lookup_tables = Vec::new();
+                    cbor_array(d, &mut address_lookup_tables, |_index, obj, d| {
+                        let mut table = Vec::new();
+                        let mut addresses = Vec::new();
+                        cbor_array(d, &mut (), |index, _obj, d| {
+                            match index {
+                                0 => table = cbor_bytes(d)?,
+                                1 => cbor_array(d, &mut addresses, |_index, obj, d| {
+                                    obj.push(cbor_bytes(d)?);
+                                    Ok(())
+                                })?,
+                                _ => d.skip()?,
+                            }
+                            Ok(())
+                        })?;
+                        obj.push((table, addresses));
+                        Ok(())
+                    })?;
+                    obj.address_lookup_tables = Some(address_lookup_tables);
+                }
+                _ => {
+                    cbor_unknown_field(d, key, &mut obj.unknown_fields)?;
+                }
             }
             Ok(())
         })?;
@@ -216,15 +403,16 @@ impl To for SolSignRequest {
 
 impl FromCbor<SolSignRequest> for SolSignRequest {
     fn from_cbor(bytes: Vec<u8>) -> URResult<SolSignRequest> {
-        minicbor::decode(&bytes).map_err(|e| URError::CborDecodeError(e.to_string()))
+        cbor_decode(&bytes)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use crate::crypto_key_path::{CryptoKeyPath, PathComponent};
+    use crate::error::URError;
     use crate::solana::sol_sign_request::{SignType, SolSignRequest};
-    use crate::traits::{From as FromCbor, To};
+    use crate::traits::{Expiry, From as FromCbor, To, Validate};
     use alloc::string::ToString;
     use alloc::vec;
     use alloc::vec::Vec;
@@ -284,4 +472,200 @@ mod tests {
         );
         assert_eq!(SignType::Transaction, sol_sign_request.get_sign_type());
     }
+
+    #[test]
+    fn test_decode_unexpected_type() {
+        // sign_type is the float 1.0
+        let bytes = Vec::from_hex("a202410006fa3f800000").unwrap();
+        assert_eq!(
+            Err(URError::UnexpectedType {
+                expected: "u32".to_string(),
+                got: "f32".to_string(),
+            }),
+            SolSignRequest::from_cbor(bytes.clone()).map(|_| ())
+        );
+        assert!(matches!(
+            SolSignRequest::try_from(bytes),
+            Err(URError::UnexpectedType { .. })
+        ));
+
+        // sign_data is the text string "hello"
+        let bytes = Vec::from_hex("a1026568656c6c6f").unwrap();
+        assert_eq!(
+            Err(URError::UnexpectedType {
+                expected: "bytes".to_string(),
+                got: "string".to_string(),
+            }),
+            SolSignRequest::from_cbor(bytes).map(|_| ())
+        );
+    }
+
+    #[test]
+    fn test_validate_schema() {
+        let path = CryptoKeyPath::from_path("m/44'/501'/0'/0'".to_string(), None).unwrap();
+        let request = SolSignRequest::new(
+            Some(hex::decode("9b1deb4d3b7d4bad9bdd2b0d7b3dcb6d").unwrap()),
+            vec![1, 0, 1, 3],
+            path,
+            None,
+            None,
+            SignType::Transaction,
+        );
+        assert!(request.validate_schema().is_ok());
+
+        let request = SolSignRequest::new(
+            None,
+            vec![],
+            CryptoKeyPath::from_path("m/44'/501'/0'/0'".to_string(), None).unwrap(),
+            None,
+            None,
+            SignType::Transaction,
+        );
+        assert_eq!(
+            Err(URError::ValidationError("sign_data is empty".to_string())),
+            request.validate_schema()
+        );
+
+        let request = SolSignRequest::new(
+            None,
+            vec![1, 0, 1, 3],
+            CryptoKeyPath::from_path("m/44'/60'/0'".to_string(), None).unwrap(),
+            None,
+            None,
+            SignType::Transaction,
+        );
+        assert_eq!(
+            Err(URError::ValidationError(
+                "derivation_path does not match coin type 501".to_string()
+            )),
+            request.validate_schema()
+        );
+    }
+
+    #[test]
+    fn test_single_transaction_round_trip() {
+        let bytes = hex::decode("a302410103d90130a10188182cf51901f5f500f500f50601").unwrap();
+        let request = SolSignRequest::from_cbor(bytes.clone()).unwrap();
+        assert!(!request.is_multi());
+        assert_eq!(1, request.get_sign_data_count());
+        assert_eq!(vec![1], request.get_sign_data());
+        assert_eq!(bytes, request.to_bytes().unwrap());
+    }
+
+    #[test]
+    fn test_expiry() {
+        let path = CryptoKeyPath::from_path("m/44'/501'/0'/0'".to_string(), None).unwrap();
+        let mut request =
+            SolSignRequest::new(None, vec![1], path, None, None, SignType::Transaction);
+        assert!(!request.is_expired(u64::MAX));
+
+        request.set_expires_at(1760400000);
+        let bytes = request.to_bytes().unwrap();
+        assert_eq!(
+            "a402410103d90130a10188182cf51901f5f500f500f50601071a68ed9280",
+            hex::encode(&bytes)
+        );
+
+        let decoded = SolSignRequest::from_cbor(bytes).unwrap();
+        assert_eq!(Some(1760400000), decoded.expires_at());
+        assert!(!decoded.is_expired(1760399999));
+        assert!(decoded.is_expired(1760400001));
+    }
+
+    #[test]
+    fn test_offchain_message() {
+        let path = CryptoKeyPath::from_path("m/44'/501'/0'/0'".to_string(), None).unwrap();
+        // signing domain, header version 0, format 0 (ascii) and a 5 byte length
+        let message = hex::decode("ff736f6c616e61206f6666636861696e0000050068656c6c6f").unwrap();
+        let request =
+            SolSignRequest::new(None, message.clone(), path, None, None, SignType::Message);
+        let bytes = request.to_bytes().unwrap();
+        assert_eq!(
+            "a3025819ff736f6c616e61206f6666636861696e0000050068656c6c6f03d90130a10188182cf51901f5f500f500f50602",
+            hex::encode(&bytes)
+        );
+
+        let decoded = SolSignRequest::from_cbor(bytes).unwrap();
+        assert_eq!(SignType::Message, decoded.get_sign_type());
+        assert_eq!(message, decoded.get_sign_data());
+    }
+
+    #[test]
+    fn test_multi_transaction_round_trip() {
+        let path = CryptoKeyPath::from_path("m/44'/501'/0'/0'".to_string(), None).unwrap();
+        let transactions = vec![vec![1], vec![2, 2], vec![3, 3, 3]];
+        let request = SolSignRequest::new_multi(
+            Some(hex::decode("9b1deb4d3b7d4bad9bdd2b0d7b3dcb6d").unwrap()),
+            transactions.clone(),
+            path,
+            None,
+            None,
+            SignType::Transaction,
+        );
+        assert!(request.validate_schema().is_ok());
+        assert_eq!(3, request.get_sign_data_count());
+
+        let bytes = request.to_bytes().unwrap();
+        assert_eq!(
+            "a401d825509b1deb4d3b7d4bad9bdd2b0d7b3dcb6d028341014202024303030303d90130a10188182cf51901f5f500f500f50601",
+            hex::encode(&bytes)
+        );
+
+        let decoded = SolSignRequest::from_cbor(bytes).unwrap();
+        assert!(decoded.is_multi());
+        assert_eq!(Some(transactions), decoded.get_sign_data_list());
+        assert_eq!(vec![1], decoded.get_sign_data());
+        assert_eq!(SignType::Transaction, decoded.get_sign_type());
+
+        let mut empty = decoded;
+        empty.set_sign_data_list(vec![]);
+        assert_eq!(
+            Err(URError::ValidationError(
+                "sign_data_list is empty".to_string()
+            )),
+            empty.validate_schema()
+        );
+    }
+
+    #[test]
+    fn test_set_sign_data_list() {
+        let path = CryptoKeyPath::from_path("m/44'/501'/0'/0'".to_string(), None).unwrap();
+        let mut request =
+            SolSignRequest::new(None, vec![9], path, None, None, SignType::Transaction);
+
+        request.set_sign_data_list(vec![vec![1], vec![2, 2]]);
+        assert!(request.is_multi());
+        assert_eq!(vec![1], request.get_sign_data());
+
+        request.set_sign_data(vec![3]);
+        assert!(!request.is_multi());
+        assert_eq!(1, request.get_sign_data_count());
+        assert_eq!(vec![3], request.get_sign_data());
+    }
+
+    #[test]
+    fn test_address_lookup_tables() {
+        let path = CryptoKeyPath::from_path("m/44'/501'/0'/0'".to_string(), None).unwrap();
+        let mut request =
+            SolSignRequest::new(None, vec![1], path, None, None, SignType::Transaction);
+        let tables = vec![(vec![0x11; 32], vec![vec![0x22; 32], vec![0x33; 32]])];
+        request.set_address_lookup_tables(tables.clone());
+
+        let bytes = request.to_bytes().unwrap();
+        assert_eq!(
+            "a402410103d90130a10188182cf51901f5f500f500f5060108818258201111111111111111111111111111111111111111111111111111111111111111825820222222222222222222222222222222222222222222222222222222222222222258203333333333333333333333333333333333333333333333333333333333333333",
+            hex::encode(&bytes)
+        );
+
+        let decoded = SolSignRequest::from_cbor(bytes).unwrap();
+        assert_eq!(Some(tables), decoded.get_address_lookup_tables());
+    }
+
+    #[test]
+    fn test_sign_type_u32() {
+        for sign_type in [SignType::Transaction, SignType::Message] {
+            assert_eq!(sign_type, SignType::from_u32(sign_type.to_u32()).unwrap());
+        }
+        assert_eq!(2, SignType::Message.to_u32());
+    }
 }
@@ -1,8 +1,13 @@
-use crate::cbor::cbor_map;
+use crate::cbor::{
+    cbor_bytes, cbor_map, cbor_tag, cbor_tx_hash, cbor_unknown_field, cbor_unknown_fields,
+    cbor_write_tx_hash,
+};
+use crate::decode::DecodeContext;
 use crate::error::{URError, URResult};
+use crate::impl_validate_signature;
 use crate::registry_types::{RegistryType, SOL_SIGNATURE, UUID};
 use crate::traits::{From as FromCbor, RegistryItem, To};
-use crate::types::Bytes;
+use crate::types::{Bytes, UnknownFields};
 use alloc::string::ToString;
 use alloc::vec::Vec;
 use minicbor::data::{Int, Tag};
@@ -11,13 +16,19 @@ use minicbor::{Decoder, Encoder};
 
 const REQUEST_ID: u8 = 1;
 const SIGNATURE: u8 = 2;
+const TX_HASH: u8 = 3;
 
 #[derive(Clone, Debug, Default)]
 pub struct SolSignature {
     request_id: Option<Bytes>,
     signature: Bytes,
+    // hash of the transaction as computed by the device
+    tx_hash: Option<Bytes>,
+    unknown_fields: UnknownFields,
 }
 
+impl_validate_signature!(SolSignature);
+
 impl SolSignature {
     pub fn default() -> Self {
         Default::default()
@@ -31,10 +42,16 @@ impl SolSignature {
         self.signature = signature;
     }
 
+    pub fn set_tx_hash(&mut self, tx_hash: Bytes) {
+        self.tx_hash = Some(tx_hash)
+    }
+
     pub fn new(request_id: Option<Bytes>, signature: Bytes) -> Self {
         SolSignature {
             request_id,
             signature,
+            tx_hash: None,
+            unknown_fields: UnknownFields::new(),
         }
     }
 
@@ -44,6 +61,12 @@ impl SolSignature {
     pub fn get_signature(&self) -> Bytes {
         self.signature.clone()
     }
+    pub fn get_tx_hash(&self) -> Option<Bytes> {
+        self.tx_hash.clone()
+    }
+    pub fn get_unknown_fields(&self) -> UnknownFields {
+        self.unknown_fields.clone()
+    }
 }
 
 impl RegistryItem for SolSignature {
@@ -62,32 +85,42 @@ impl<C> minicbor::Encode<C> for SolSignature {
         if self.request_id.is_some() {
             size += 1;
         }
-        e.map(size)?;
+        if self.tx_hash.is_some() {
+            size += 1;
+        }
+        e.map(size + self.unknown_fields.len() as u64)?;
         if let Some(request_id) = &self.request_id {
             e.int(Int::from(REQUEST_ID))?
                 .tag(Tag::Unassigned(UUID.get_tag()))?
                 .bytes(request_id)?;
         }
         e.int(Int::from(SIGNATURE))?.bytes(&self.signature)?;
+        cbor_write_tx_hash(e, TX_HASH, &self.tx_hash)?;
+        cbor_unknown_fields(e, &self.unknown_fields)?;
         Ok(())
     }
 }
 
-impl<'b, C> minicbor::Decode<'b, C> for SolSignature {
-    fn decode(d: &mut Decoder<'b>, _ctx: &mut C) -> Result<Self, minicbor::decode::Error> {
+impl<'b, C: DecodeContext> minicbor::Decode<'b, C> for SolSignature {
+    fn decode(d: &mut Decoder<'b>, ctx: &mut C) -> Result<Self, minicbor::decode::Error> {
         let mut result = SolSignature::default();
         cbor_map(d, &mut result, |key, obj, d| {
             let key =
                 u8::try_from(key).map_err(|e| minicbor::decode::Error::message(e.to_string()))?;
             match key {
                 REQUEST_ID => {
-                    d.tag()?;
-                    obj.request_id = Some(d.bytes()?.to_vec());
+                    cbor_tag(d, ctx, UUID.get_tag(), "UUID tag is invalid")?;
+                    obj.request_id = Some(cbor_bytes(d)?);
                 }
                 SIGNATURE => {
-                    obj.signature = d.bytes()?.to_vec();
+                    obj.signature = cbor_bytes(d)?;
+                }
+                TX_HASH => {
+                    obj.tx_hash = Some(cbor_tx_hash(d)?);
+                }
+                _ => {
+                    cbor_unknown_field(d, key, &mut obj.unknown_fields)?;
                 }
-                _ => {}
             }
             Ok(())
         })?;
@@ -111,6 +144,8 @@ impl FromCbor<SolSignature> for SolSignature {
 mod tests {
     use crate::solana::sol_signature::SolSignature;
     use crate::traits::{From as FromCbor, To};
+    use crate::types::UnknownFields;
+    use alloc::vec;
     use alloc::vec::Vec;
     use hex::FromHex;
 
@@ -158,4 +193,52 @@ mod tests {
             sol_signature.get_signature()
         );
     }
+
+    #[test]
+    fn test_unknown_fields_round_trip() {
+        // the signature of `test_decode` with the array [1, 2] at the unused key 15
+        let bytes = Vec::from_hex(
+            "a301d825509b1deb4d3b7d4bad9bdd2b0d7b3dcb6d025840d4f0a7bcd95bba1fbb1051885054730e3f47064288575aacc102fbbf6a9a14daa066991e360d3e3406c20c00a40973eff37c7d641e5b351ec4a99bfe86f335f70f820102",
+        )
+        .unwrap();
+        let sol_signature = SolSignature::from_cbor(bytes.clone()).unwrap();
+        assert_eq!(
+            UnknownFields::from([(15, vec![0x82, 0x01, 0x02])]),
+            sol_signature.get_unknown_fields()
+        );
+        assert_eq!(bytes, sol_signature.to_bytes().unwrap());
+    }
+
+    #[test]
+    fn test_tx_hash_round_trip() {
+        let mut sol_signature = SolSignature::new(
+            Some(Vec::from_hex("9b1deb4d3b7d4bad9bdd2b0d7b3dcb6d").unwrap()),
+            Vec::from_hex("deadbeef").unwrap(),
+        );
+        let tx_hash = (0..32).collect::<Vec<u8>>();
+        sol_signature.set_tx_hash(tx_hash.clone());
+        let result = sol_signature.to_bytes().unwrap();
+        assert_eq!(
+            "a301d825509b1deb4d3b7d4bad9bdd2b0d7b3dcb6d0244deadbeef035820000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f",
+            hex::encode(&result)
+        );
+
+        let sol_signature = SolSignature::from_cbor(result).unwrap();
+        assert_eq!(Some(tx_hash), sol_signature.get_tx_hash());
+    }
+
+    #[test]
+    fn test_decode_without_tx_hash() {
+        let bytes = Vec::from_hex(
+            "a201d825509b1deb4d3b7d4bad9bdd2b0d7b3dcb6d025840d4f0a7bcd95bba1fbb1051885054730e3f47064288575aacc102fbbf6a9a14daa066991e360d3e3406c20c00a40973eff37c7d641e5b351ec4a99bfe86f335f7",
+        )
+        .unwrap();
+        assert_eq!(None, SolSignature::from_cbor(bytes).unwrap().get_tx_hash());
+
+        let bytes = Vec::from_hex(
+            "a20244deadbeef03581f000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e",
+        )
+        .unwrap();
+        assert!(SolSignature::from_cbor(bytes).is_err());
+    }
 }
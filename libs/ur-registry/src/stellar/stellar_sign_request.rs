@@ -1,13 +1,19 @@
-use crate::cbor::cbor_map;
+use crate::cbor::{
+    cbor_bytes, cbor_decode, cbor_expect_type, cbor_map, cbor_origin, cbor_tag, cbor_unknown_field,
+    cbor_unknown_fields,
+};
 use crate::crypto_key_path::CryptoKeyPath;
+use crate::decode::DecodeContext;
 use crate::error::{URError, URResult};
+use crate::impl_with_new_request_id;
 use crate::registry_types::{RegistryType, CRYPTO_KEYPATH, STELLAR_SIGN_REQUEST, UUID};
-use crate::traits::{From as FromCbor, RegistryItem, To};
-use crate::types::Bytes;
+use crate::traits::{From as FromCbor, RegistryItem, To, Validate};
+use crate::types::{Bytes, UnknownFields};
+use crate::validate::{validate_path, validate_request_id, validate_sign_data};
 use alloc::format;
 use alloc::string::{String, ToString};
 use alloc::vec::Vec;
-use minicbor::data::{Int, Tag};
+use minicbor::data::{Int, Tag, Type};
 use minicbor::encode::Write;
 use minicbor::{Decoder, Encoder};
 
@@ -18,12 +24,16 @@ const ADDRESS: u8 = 4;
 const ORIGIN: u8 = 5;
 const SIGN_TYPE: u8 = 6;
 
+const STELLAR_COIN_TYPE: u32 = 148;
+
 #[derive(Clone, Debug, PartialEq, Default)]
 pub enum SignType {
     #[default]
     Transaction = 1,
     TransactionHash,
     Message,
+    // soroban authorization entry (HashIdPreimage::SorobanAuthorization)
+    AuthEntry,
 }
 
 impl SignType {
@@ -32,8 +42,9 @@ impl SignType {
             1 => Ok(SignType::Transaction),
             2 => Ok(SignType::TransactionHash),
             3 => Ok(SignType::Message),
+            4 => Ok(SignType::AuthEntry),
             x => Err(format!(
-                "invalid value for sign_type in stellar-sign-request, expected 1 or 2, received {:?}",
+                "invalid value for sign_type in stellar-sign-request, expected 1, 2, 3 or 4, received {:?}",
                 x
             )),
         }
@@ -48,8 +59,11 @@ pub struct StellarSignRequest {
     address: Option<Bytes>,
     origin: Option<String>,
     sign_type: SignType,
+    unknown_fields: UnknownFields,
 }
 
+impl_with_new_request_id!(StellarSignRequest);
+
 impl StellarSignRequest {
     pub fn default() -> Self {
         Default::default()
@@ -94,6 +108,7 @@ impl StellarSignRequest {
             address,
             origin,
             sign_type,
+            unknown_fields: UnknownFields::new(),
         }
     }
     pub fn get_request_id(&self) -> Option<Bytes> {
@@ -114,6 +129,9 @@ impl StellarSignRequest {
     pub fn get_sign_type(&self) -> SignType {
         self.sign_type.clone()
     }
+    pub fn get_unknown_fields(&self) -> UnknownFields {
+        self.unknown_fields.clone()
+    }
 
     fn get_map_size(&self) -> u64 {
         let mut size = 3;
@@ -126,7 +144,7 @@ impl StellarSignRequest {
         if self.origin.is_some() {
             size += 1;
         }
-        size
+        size + self.unknown_fields.len() as u64
     }
 }
 
@@ -136,6 +154,19 @@ impl RegistryItem for StellarSignRequest {
     }
 }
 
+impl Validate for StellarSignRequest {
+    fn validate_schema(&self) -> URResult<()> {
+        validate_request_id(&self.request_id)?;
+        validate_sign_data("sign_data", &self.sign_data)?;
+        validate_path(
+            "derivation_path",
+            &self.derivation_path,
+            Some(STELLAR_COIN_TYPE),
+        )?;
+        Ok(())
+    }
+}
+
 impl<C> minicbor::Encode<C> for StellarSignRequest {
     fn encode<W: Write>(
         &self,
@@ -167,42 +198,52 @@ impl<C> minicbor::Encode<C> for StellarSignRequest {
         e.int(Int::from(SIGN_TYPE))?
             .int(Int::from(self.sign_type.clone() as u8))?;
 
+        cbor_unknown_fields(e, &self.unknown_fields)?;
         Ok(())
     }
 }
 
-impl<'b, C> minicbor::Decode<'b, C> for StellarSignRequest {
-    fn decode(d: &mut Decoder<'b>, _ctx: &mut C) -> Result<Self, minicbor::decode::Error> {
+impl<'b, C: DecodeContext> minicbor::Decode<'b, C> for StellarSignRequest {
+    fn decode(d: &mut Decoder<'b>, ctx: &mut C) -> Result<Self, minicbor::decode::Error> {
         let mut result = StellarSignRequest::default();
         cbor_map(d, &mut result, |key, obj, d| {
             let key =
                 u8::try_from(key).map_err(|e| minicbor::decode::Error::message(e.to_string()))?;
             match key {
                 REQUEST_ID => {
-                    d.tag()?;
-                    obj.request_id = Some(d.bytes()?.to_vec());
+                    cbor_tag(d, ctx, UUID.get_tag(), "UUID tag is invalid")?;
+                    obj.request_id = Some(cbor_bytes(d)?);
                 }
                 SIGN_DATA => {
-                    obj.sign_data = d.bytes()?.to_vec();
+                    cbor_expect_type(d, ctx, Type::Bytes)?;
+                    obj.sign_data = cbor_bytes(d)?;
                 }
                 DERIVATION_PATH => {
-                    d.tag()?;
-                    obj.derivation_path = CryptoKeyPath::decode(d, _ctx)?;
+                    cbor_tag(
+                        d,
+                        ctx,
+                        CryptoKeyPath::get_registry_type().get_tag(),
+                        "CryptoKeyPath tag is invalid",
+                    )?;
+                    obj.derivation_path = CryptoKeyPath::decode(d, ctx)?;
                 }
                 ADDRESS => {
-                    obj.address = Some(d.bytes()?.to_vec());
+                    obj.address = Some(cbor_bytes(d)?);
                 }
                 ORIGIN => {
-                    obj.origin = Some(d.str()?.to_string());
+                    obj.origin = Some(cbor_origin(d)?);
                 }
                 SIGN_TYPE => {
+                    cbor_expect_type(d, ctx, Type::U32)?;
                     obj.sign_type = SignType::from_u32(
                         u32::try_from(d.int()?)
                             .map_err(|e| minicbor::decode::Error::message(e.to_string()))?,
                     )
                     .map_err(minicbor::decode::Error::message)?;
                 }
-                _ => {}
+                _ => {
+                    cbor_unknown_field(d, key, &mut obj.unknown_fields)?;
+                }
             }
             Ok(())
         })?;
@@ -218,7 +259,7 @@ impl To for StellarSignRequest {
 
 impl FromCbor<StellarSignRequest> for StellarSignRequest {
     fn from_cbor(bytes: Vec<u8>) -> URResult<StellarSignRequest> {
-        minicbor::decode(&bytes).map_err(|e| URError::CborDecodeError(e.to_string()))
+        cbor_decode(&bytes)
     }
 }
 
@@ -284,4 +325,34 @@ mod tests {
         );
         assert_eq!(SignType::Transaction, stellar_sign_request.get_sign_type());
     }
+
+    #[test]
+    fn test_xdr_payment_envelope() {
+        // ENVELOPE_TYPE_TX with a single native PAYMENT operation of 10 XLM
+        let xdr = "00000002000000003e5b6cbc5b6a8256aa9e1f3e4b0c8f0bd9b7c1d2e3f405162738495a6b7c8d9e000000640000011f71fb04cb0000000000000000000000010000000000000001000000008a7d6c5b4a39281706f5e4d3c2b1a0998877665544332211ffeeddccbbaa9988000000000000000005f5e1000000000000000000";
+        let stellar_sign_request = StellarSignRequest::new(
+            Some(hex::decode("9b1deb4d3b7d4bad9bdd2b0d7b3dcb6d").unwrap()),
+            Vec::from_hex(xdr).unwrap(),
+            CryptoKeyPath::from_path("m/44'/148'/0'".to_string(), Some([18, 18, 18, 18])).unwrap(),
+            None,
+            Some("keystone".to_string()),
+            SignType::Transaction,
+        );
+        let expect_result = "a501d825509b1deb4d3b7d4bad9bdd2b0d7b3dcb6d02588000000002000000003e5b6cbc5b6a8256aa9e1f3e4b0c8f0bd9b7c1d2e3f405162738495a6b7c8d9e000000640000011f71fb04cb0000000000000000000000010000000000000001000000008a7d6c5b4a39281706f5e4d3c2b1a0998877665544332211ffeeddccbbaa9988000000000000000005f5e100000000000000000003d90130a20186182cf51894f500f5021a1212121205686b657973746f6e650601";
+        assert_eq!(
+            expect_result,
+            hex::encode(stellar_sign_request.to_bytes().unwrap())
+        );
+
+        let decoded = StellarSignRequest::from_cbor(Vec::from_hex(expect_result).unwrap()).unwrap();
+        assert_eq!(xdr, hex::encode(decoded.get_sign_data()));
+        assert_eq!("44'/148'/0'", decoded.get_derivation_path().get_path().unwrap());
+        assert_eq!(SignType::Transaction, decoded.get_sign_type());
+    }
+
+    #[test]
+    fn test_auth_entry_sign_type() {
+        assert_eq!(SignType::AuthEntry, SignType::from_u32(4).unwrap());
+        assert!(SignType::from_u32(5).is_err());
+    }
 }
@@ -1,3 +1,6 @@
 pub mod sui_sign_hash_request;
 pub mod sui_sign_request;
 pub mod sui_signature;
+
+// the SLIP-44 coin type at index 1 of the Sui key paths
+pub(crate) const SUI_COIN_TYPE: u32 = 784;
@@ -1,27 +1,190 @@
+use alloc::format;
 use alloc::string::{String, ToString};
 use alloc::vec::Vec;
-use minicbor::data::{Int, Tag};
+use minicbor::data::{Int, Tag, Type};
 
-use crate::cbor::{cbor_array, cbor_map};
+use crate::cbor::{
+    cbor_array, cbor_bytes, cbor_expect_type, cbor_map, cbor_map_collect_errors, cbor_origin,
+    cbor_paths, cbor_tag, cbor_unknown_field, cbor_unknown_fields,
+};
 use crate::crypto_key_path::CryptoKeyPath;
+use crate::decode::DecodeContext;
+use crate::error::{URError, URResult};
 use crate::impl_template_struct;
+use crate::impl_with_new_request_id;
 use crate::registry_types::{RegistryType, SUI_SIGN_REQUEST, UUID};
-use crate::traits::{MapSize, RegistryItem};
+use crate::sui::SUI_COIN_TYPE;
+use crate::traits::{
+    DecodeCollectErrors, Expiry, MapSize, MinFirmware, RegistryItem, Validate, BASELINE_FIRMWARE,
+};
 use crate::types::Bytes;
+use crate::validate::{validate_paths, validate_request_id, validate_sign_data};
 
 const REQUEST_ID: u8 = 1;
 const INTENT_MESSAGE: u8 = 2;
 const DERIVATION_PATHS: u8 = 3;
 const ADDRESSES: u8 = 4;
 const ORIGIN: u8 = 5;
+const GAS_OBJECTS: u8 = 6;
+const EXPIRES_AT: u8 = 7;
+const SPONSOR: u8 = 8;
 
-impl_template_struct!(SuiSignRequest {
-    request_id: Option<Bytes>,
-    intent_message: Bytes,
-    derivation_paths: Vec<CryptoKeyPath>,
-    addresses: Option<Vec<Bytes>>,
-    origin: Option<String>
-});
+// keys 1 to 5 are the baseline, each later key is one revision
+const GAS_OBJECTS_FIRMWARE: &str = "1.1.0";
+const EXPIRES_AT_FIRMWARE: &str = "1.2.0";
+const SPONSOR_FIRMWARE: &str = "1.3.0";
+
+// same as the max_gas_payment_objects of the sui protocol config
+pub const MAX_GAS_OBJECTS: usize = 256;
+// scope, version and app id of the intent of a sui personal message
+const PERSONAL_MESSAGE_INTENT: [u8; 3] = [3, 0, 0];
+
+impl_template_struct!(
+    SuiSignRequest {
+        request_id: Option<Bytes>,
+        intent_message: Bytes,
+        derivation_paths: Vec<CryptoKeyPath>,
+        addresses: Option<Vec<Bytes>>,
+        // an empty origin is the same as none, it's dropped when decoding and
+        // never encoded, so that a re-encoded request matches the original
+        origin: Option<String>,
+        gas_objects: Option<Vec<Bytes>>,
+        expires_at: Option<u64>,
+        // the gas owner of a sponsored transaction, when it isn't the signer
+        sponsor: Option<Bytes>
+    },
+    unknown_fields
+);
+
+impl_with_new_request_id!(SuiSignRequest);
+
+impl SuiSignRequest {
+    pub fn builder() -> SuiSignRequestBuilder {
+        SuiSignRequestBuilder::default()
+    }
+
+    /// A request to sign `message` as a sui personal message. The intent message
+    /// is the personal message intent followed by `message` in BCS, which is its
+    /// ULEB128 length and the bytes.
+    pub fn new_message(
+        request_id: Option<Bytes>,
+        message: &[u8],
+        derivation_paths: Vec<CryptoKeyPath>,
+        addresses: Option<Vec<Bytes>>,
+        origin: Option<String>,
+    ) -> Self {
+        let mut intent_message = PERSONAL_MESSAGE_INTENT.to_vec();
+        let mut len = message.len();
+        while len >= 0x80 {
+            intent_message.push((len & 0x7f) as u8 | 0x80);
+            len >>= 7;
+        }
+        intent_message.push(len as u8);
+        intent_message.extend_from_slice(message);
+        SuiSignRequest::new(
+            request_id,
+            intent_message,
+            derivation_paths,
+            addresses,
+            origin,
+            None,
+            None,
+            None,
+        )
+    }
+
+    /// The message of a personal message request, as made by
+    /// [`SuiSignRequest::new_message`]. None for transactions.
+    pub fn get_personal_message(&self) -> Option<Bytes> {
+        let bcs = self
+            .intent_message
+            .strip_prefix(&PERSONAL_MESSAGE_INTENT[..])?;
+        let mut len = 0;
+        let mut read = 0;
+        loop {
+            // a BCS length is at most a u32, 5 bytes of ULEB128
+            let byte = *bcs.get(read).filter(|_| read < 5)?;
+            len |= usize::from(byte & 0x7f) << (7 * read);
+            read += 1;
+            if byte & 0x80 == 0 {
+                break;
+            }
+        }
+        let message = &bcs[read..];
+        (message.len() == len).then(|| message.to_vec())
+    }
+
+    fn encoded_origin(&self) -> Option<&String> {
+        self.origin.as_ref().filter(|origin| !origin.is_empty())
+    }
+
+    // every derivation path signs for the address at the same index
+    fn check_addresses(&self) -> Result<(), String> {
+        match &self.addresses {
+            Some(addresses) if addresses.len() != self.derivation_paths.len() => Err(format!(
+                "addresses count {} does not match derivation_paths count {}",
+                addresses.len(),
+                self.derivation_paths.len()
+            )),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Builds a [`SuiSignRequest`] field by field. [`SuiSignRequestBuilder::build`]
+/// validates the request, it needs an intent message and at least one path.
+#[derive(Clone, Debug, Default)]
+pub struct SuiSignRequestBuilder {
+    request: SuiSignRequest,
+}
+
+impl SuiSignRequestBuilder {
+    pub fn request_id(mut self, request_id: Bytes) -> Self {
+        self.request.request_id = Some(request_id);
+        self
+    }
+
+    pub fn intent_message(mut self, intent_message: Bytes) -> Self {
+        self.request.intent_message = intent_message;
+        self
+    }
+
+    /// Adds a path, the request is signed with every path added.
+    pub fn derivation_path(mut self, derivation_path: CryptoKeyPath) -> Self {
+        self.request.derivation_paths.push(derivation_path);
+        self
+    }
+
+    pub fn addresses(mut self, addresses: Vec<Bytes>) -> Self {
+        self.request.addresses = Some(addresses);
+        self
+    }
+
+    pub fn origin(mut self, origin: String) -> Self {
+        self.request.origin = Some(origin);
+        self
+    }
+
+    pub fn gas_objects(mut self, gas_objects: Vec<Bytes>) -> Self {
+        self.request.gas_objects = Some(gas_objects);
+        self
+    }
+
+    pub fn expires_at(mut self, expires_at: u64) -> Self {
+        self.request.expires_at = Some(expires_at);
+        self
+    }
+
+    pub fn sponsor(mut self, sponsor: Bytes) -> Self {
+        self.request.sponsor = Some(sponsor);
+        self
+    }
+
+    pub fn build(self) -> URResult<SuiSignRequest> {
+        self.request.validate_schema()?;
+        Ok(self.request)
+    }
+}
 
 impl RegistryItem for SuiSignRequest {
     fn get_registry_type() -> RegistryType<'static> {
@@ -38,10 +201,62 @@ impl MapSize for SuiSignRequest {
         if self.addresses.is_some() {
             size += 1;
         }
-        if self.origin.is_some() {
+        if self.encoded_origin().is_some() {
+            size += 1;
+        }
+        if self.gas_objects.is_some() {
+            size += 1;
+        }
+        if self.expires_at.is_some() {
+            size += 1;
+        }
+        if self.sponsor.is_some() {
             size += 1;
         }
-        size
+        size + self.unknown_fields.len() as u64
+    }
+}
+
+impl Expiry for SuiSignRequest {
+    fn expires_at(&self) -> Option<u64> {
+        self.expires_at
+    }
+}
+
+impl Validate for SuiSignRequest {
+    fn validate_schema(&self) -> URResult<()> {
+        validate_request_id(&self.request_id)?;
+        validate_sign_data("intent_message", &self.intent_message)?;
+        validate_paths(
+            "derivation_paths",
+            &self.derivation_paths,
+            Some(SUI_COIN_TYPE),
+        )?;
+        self.check_addresses().map_err(URError::ValidationError)?;
+        if let Some(gas_objects) = &self.gas_objects {
+            if gas_objects.len() > MAX_GAS_OBJECTS {
+                return Err(URError::ValidationError(format!(
+                    "gas_objects exceeds {} entries",
+                    MAX_GAS_OBJECTS
+                )));
+            }
+        }
+        Ok(())
+    }
+}
+
+impl MinFirmware for SuiSignRequest {
+    fn min_firmware(&self) -> &'static str {
+        if self.sponsor.is_some() {
+            return SPONSOR_FIRMWARE;
+        }
+        if self.expires_at.is_some() {
+            return EXPIRES_AT_FIRMWARE;
+        }
+        if self.gas_objects.is_some() {
+            return GAS_OBJECTS_FIRMWARE;
+        }
+        BASELINE_FIRMWARE
     }
 }
 
@@ -58,23 +273,14 @@ impl<C> minicbor::Encode<C> for SuiSignRequest {
                 .bytes(&request_id)?;
         }
         e.int(Int::from(INTENT_MESSAGE))?
-            .bytes(&self.get_intent_message())?;
+            .bytes(&self.intent_message)?;
 
-        let derivation_paths = self.get_derivation_paths();
-        if derivation_paths.is_empty() {
-            return Err(minicbor::encode::Error::message(
-                "derivation paths is invalid",
-            ));
-        }
-        e.int(Int::from(DERIVATION_PATHS))?
-            .array(derivation_paths.len() as u64)?;
-        for path in derivation_paths {
-            e.tag(Tag::Unassigned(
-                CryptoKeyPath::get_registry_type().get_tag(),
-            ))?;
-            CryptoKeyPath::encode(&path, e, ctx)?;
-        }
+        e.int(Int::from(DERIVATION_PATHS))?;
+        cbor_paths(e, &self.derivation_paths, "derivation paths", ctx)?;
 
+        if let Err(message) = self.check_addresses() {
+            return Err(minicbor::encode::Error::message(message));
+        }
         if let Some(addresses) = self.get_addresses() {
             e.int(Int::from(ADDRESSES))?.array(addresses.len() as u64)?;
             for addr in addresses {
@@ -82,65 +288,128 @@ impl<C> minicbor::Encode<C> for SuiSignRequest {
             }
         }
 
-        if let Some(origin) = self.get_origin() {
-            e.int(Int::from(ORIGIN))?.str(&origin)?;
+        if let Some(origin) = self.encoded_origin() {
+            e.int(Int::from(ORIGIN))?.str(origin)?;
+        }
+
+        if let Some(gas_objects) = self.get_gas_objects() {
+            e.int(Int::from(GAS_OBJECTS))?
+                .array(gas_objects.len() as u64)?;
+            for gas_object in gas_objects {
+                e.bytes(&gas_object)?;
+            }
+        }
+
+        if let Some(expires_at) = self.expires_at {
+            e.int(Int::from(EXPIRES_AT))?.u64(expires_at)?;
+        }
+
+        if let Some(sponsor) = &self.sponsor {
+            e.int(Int::from(SPONSOR))?.bytes(sponsor)?;
         }
 
+        cbor_unknown_fields(e, &self.unknown_fields)?;
         Ok(())
     }
 }
 
-impl<'b, C> minicbor::Decode<'b, C> for SuiSignRequest {
-    fn decode(d: &mut minicbor::Decoder<'b>, ctx: &mut C) -> Result<Self, minicbor::decode::Error> {
-        let mut result = SuiSignRequest::default();
-
-        cbor_map(d, &mut result, |key, obj, d| {
-            let key =
-                u8::try_from(key).map_err(|e| minicbor::decode::Error::message(e.to_string()))?;
-            match key {
-                REQUEST_ID => {
-                    let tag = d.tag()?;
-                    if !tag.eq(&Tag::Unassigned(UUID.get_tag())) {
-                        return Err(minicbor::decode::Error::message("UUID tag is invalid"));
-                    }
-                    obj.request_id = Some(d.bytes()?.to_vec());
-                }
-                INTENT_MESSAGE => {
-                    obj.intent_message = d.bytes()?.to_vec();
-                }
-                DERIVATION_PATHS => {
-                    cbor_array(d, &mut obj.derivation_paths, |_key, obj, d| {
-                        let tag = d.tag()?;
-                        if !tag.eq(&Tag::Unassigned(
-                            CryptoKeyPath::get_registry_type().get_tag(),
-                        )) {
-                            return Err(minicbor::decode::Error::message(
-                                "CryptoKeyPath tag is invalid",
-                            ));
-                        }
-                        obj.push(CryptoKeyPath::decode(d, ctx)?);
-                        Ok(())
-                    })?;
+impl SuiSignRequest {
+    fn decode_field<'b, C: DecodeContext>(
+        key: Int,
+        obj: &mut SuiSignRequest,
+        d: &mut minicbor::Decoder<'b>,
+        ctx: &mut C,
+    ) -> Result<(), minicbor::decode::Error> {
+        let key = u8::try_from(key).map_err(|e| minicbor::decode::Error::message(e.to_string()))?;
+        match key {
+            REQUEST_ID => {
+                cbor_tag(d, ctx, UUID.get_tag(), "UUID tag is invalid")?;
+                obj.request_id = Some(cbor_bytes(d)?);
+            }
+            INTENT_MESSAGE => {
+                cbor_expect_type(d, ctx, Type::Bytes)?;
+                obj.intent_message = cbor_bytes(d)?;
+            }
+            DERIVATION_PATHS => {
+                cbor_array(d, &mut obj.derivation_paths, |_key, obj, d| {
+                    cbor_tag(
+                        d,
+                        ctx,
+                        CryptoKeyPath::get_registry_type().get_tag(),
+                        "CryptoKeyPath tag is invalid",
+                    )?;
+                    obj.push(CryptoKeyPath::decode(d, ctx)?);
+                    Ok(())
+                })?;
+            }
+            ADDRESSES => {
+                if obj.addresses.is_none() {
+                    obj.addresses = Some(Vec::new())
                 }
-                ADDRESSES => {
-                    if obj.addresses.is_none() {
-                        obj.addresses = Some(Vec::new())
+                cbor_array(d, &mut obj.addresses, |_key, obj, d| {
+                    match obj {
+                        Some(v) => v.push(cbor_bytes(d)?),
+                        None => {}
                     }
-                    cbor_array(d, &mut obj.addresses, |_key, obj, d| {
-                        match obj {
-                            Some(v) => v.push(d.bytes()?.to_vec()),
-                            None => {}
-                        }
-                        Ok(())
-                    })?;
-                }
-                ORIGIN => {
-                    obj.origin = Some(d.str()?.to_string());
-                }
-                _ => {}
+                    Ok(())
+                })?;
+            }
+            ORIGIN => {
+                let origin = cbor_origin(d)?;
+                obj.origin = (!origin.is_empty()).then_some(origin);
             }
-            Ok(())
+            GAS_OBJECTS => {
+                let mut gas_objects = Vec::new();
+                cbor_array(d, &mut gas_objects, |index, obj, d| {
+                    if index as usize >= MAX_GAS_OBJECTS {
+                        return Err(minicbor::decode::Error::message("too many gas objects"));
+                    }
+                    obj.push(cbor_bytes(d)?);
+                    Ok(())
+                })?;
+                obj.gas_objects = Some(gas_objects);
+            }
+            EXPIRES_AT => {
+                cbor_expect_type(d, ctx, Type::U64)?;
+                obj.expires_at = Some(d.u64()?);
+            }
+            SPONSOR => {
+                obj.sponsor = Some(cbor_bytes(d)?);
+            }
+            _ => {
+                cbor_unknown_field(d, key, &mut obj.unknown_fields)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'b, C: DecodeContext> minicbor::Decode<'b, C> for SuiSignRequest {
+    fn decode(d: &mut minicbor::Decoder<'b>, ctx: &mut C) -> Result<Self, minicbor::decode::Error> {
+        let mut result = SuiSignRequest::default();
+        cbor_map(d, &mut result, |key, obj, d| {
+            SuiSignRequest::decode_field(key, obj, d, ctx)
         })?;
+        result
+            .check_addresses()
+            .map_err(minicbor::decode::Error::message)?;
+        Ok(result)
+    }
+}
+
+impl DecodeCollectErrors for SuiSignRequest {
+    fn decode_collect_errors(bytes: &[u8]) -> Result<Self, Vec<URError>> {
+        let mut result = SuiSignRequest::default();
+        let mut d = minicbor::Decoder::new(bytes);
+        let mut errors = cbor_map_collect_errors(&mut d, &mut result, |key, obj, d| {
+            SuiSignRequest::decode_field(key, obj, d, &mut ())
+        });
+        if let Err(message) = result.check_addresses() {
+            errors.push(URError::CborDecodeError(message));
+        }
+        if !errors.is_empty() {
+            return Err(errors);
+        }
         Ok(result)
     }
 }
@@ -150,7 +419,9 @@ mod tests {
     use alloc::vec;
     use alloc::vec::Vec;
 
+    use crate::cbor::MAX_ORIGIN_LENGTH;
     use crate::crypto_key_path::PathComponent;
+    use crate::error::URError;
 
     use super::*;
 
@@ -170,7 +441,11 @@ mod tests {
             intent_message: hex::decode("00000000000200201ff915a5e9e32fdbe0135535b6c69a00a9809aaf7f7c0275d3239ca79db20d6400081027000000000000020200010101000101020000010000ebe623e33b7307f1350f8934beb3fb16baef0fc1b3f1b92868eec3944093886901a2e3e42930675d9571a467eb5d4b22553c93ccb84e9097972e02c490b4e7a22ab73200000000000020176c4727433105da34209f04ac3f22e192a2573d7948cb2fabde7d13a7f4f149ebe623e33b7307f1350f8934beb3fb16baef0fc1b3f1b92868eec39440938869e803000000000000640000000000000000").unwrap(),
             derivation_paths: vec![crypto_key_path],
             addresses: Some(vec![hex::decode("ebe623e33b7307f1350f8934beb3fb16baef0fc1b3f1b92868eec39440938869").unwrap()]),
-            origin: Some("Sui Wallet".to_string())
+            origin: Some("Sui Wallet".to_string()),
+            gas_objects: None,
+            expires_at: None,
+            sponsor: None,
+            ..Default::default()
         };
         let result: Vec<u8> = sig.try_into().unwrap();
         let expect_result = hex::decode("a501d825509b1deb4d3b7d4bad9bdd2b0d7b3dcb6d0258dc00000000000200201ff915a5e9e32fdbe0135535b6c69a00a9809aaf7f7c0275d3239ca79db20d6400081027000000000000020200010101000101020000010000ebe623e33b7307f1350f8934beb3fb16baef0fc1b3f1b92868eec3944093886901a2e3e42930675d9571a467eb5d4b22553c93ccb84e9097972e02c490b4e7a22ab73200000000000020176c4727433105da34209f04ac3f22e192a2573d7948cb2fabde7d13a7f4f149ebe623e33b7307f1350f8934beb3fb16baef0fc1b3f1b92868eec39440938869e8030000000000006400000000000000000381d90130a2018a182cf5190310f500f500f500f5021a7823080404815820ebe623e33b7307f1350f8934beb3fb16baef0fc1b3f1b92868eec39440938869056a5375692057616c6c6574").unwrap();
@@ -194,7 +469,11 @@ mod tests {
             intent_message: hex::decode("00000000000200201ff915a5e9e32fdbe0135535b6c69a00a9809aaf7f7c0275d3239ca79db20d6400081027000000000000020200010101000101020000010000ebe623e33b7307f1350f8934beb3fb16baef0fc1b3f1b92868eec3944093886901a2e3e42930675d9571a467eb5d4b22553c93ccb84e9097972e02c490b4e7a22ab73200000000000020176c4727433105da34209f04ac3f22e192a2573d7948cb2fabde7d13a7f4f149ebe623e33b7307f1350f8934beb3fb16baef0fc1b3f1b92868eec39440938869e803000000000000640000000000000000").unwrap(),
             derivation_paths: vec![crypto_key_path],
             addresses: Some(vec![hex::decode("ebe623e33b7307f1350f8934beb3fb16baef0fc1b3f1b92868eec39440938869").unwrap()]),
-            origin: Some("Sui Wallet".to_string())
+            origin: Some("Sui Wallet".to_string()),
+            gas_objects: None,
+            expires_at: None,
+            sponsor: None,
+            ..Default::default()
         };
         let result = SuiSignRequest::try_from(hex::decode("a501d825509b1deb4d3b7d4bad9bdd2b0d7b3dcb6d0258dc00000000000200201ff915a5e9e32fdbe0135535b6c69a00a9809aaf7f7c0275d3239ca79db20d6400081027000000000000020200010101000101020000010000ebe623e33b7307f1350f8934beb3fb16baef0fc1b3f1b92868eec3944093886901a2e3e42930675d9571a467eb5d4b22553c93ccb84e9097972e02c490b4e7a22ab73200000000000020176c4727433105da34209f04ac3f22e192a2573d7948cb2fabde7d13a7f4f149ebe623e33b7307f1350f8934beb3fb16baef0fc1b3f1b92868eec39440938869e8030000000000006400000000000000000381d90130a2018a182cf5190310f500f500f500f5021a7823080404815820ebe623e33b7307f1350f8934beb3fb16baef0fc1b3f1b92868eec39440938869056a5375692057616c6c6574").unwrap()).unwrap();
 
@@ -206,5 +485,437 @@ mod tests {
         );
         assert_eq!(expect_result.addresses, result.addresses);
         assert_eq!(expect_result.origin, result.origin);
+        assert!(result.gas_objects.is_none());
+    }
+
+    #[test]
+    fn test_validate_schema() {
+        let path = CryptoKeyPath::from_path("m/44'/784'/0'/0'/0'".to_string(), None).unwrap();
+        let request = SuiSignRequest::new(
+            Some(hex::decode("9b1deb4d3b7d4bad9bdd2b0d7b3dcb6d").unwrap()),
+            hex::decode("0000000000020020").unwrap(),
+            vec![path.clone()],
+            None,
+            Some("Sui Wallet".to_string()),
+            None,
+            None,
+            None,
+        );
+        assert!(request.validate_schema().is_ok());
+
+        let mut invalid = request.clone();
+        invalid.set_intent_message(vec![]);
+        assert_eq!(
+            Err(URError::ValidationError("intent_message is empty".to_string())),
+            invalid.validate_schema()
+        );
+
+        let mut invalid = request.clone();
+        invalid.set_derivation_paths(vec![]);
+        assert_eq!(
+            Err(URError::ValidationError("derivation_paths is empty".to_string())),
+            invalid.validate_schema()
+        );
+
+        let mut invalid = request.clone();
+        invalid.set_derivation_paths(vec![CryptoKeyPath::from_path(
+            "m/44'/60'/0'/0/0".to_string(),
+            None,
+        )
+        .unwrap()]);
+        assert_eq!(
+            Err(URError::ValidationError(
+                "derivation_paths does not match coin type 784".to_string()
+            )),
+            invalid.validate_schema()
+        );
+
+        let mut invalid = request.clone();
+        invalid.set_request_id(Some(vec![1, 2, 3]));
+        assert_eq!(
+            Err(URError::ValidationError(
+                "request_id must be 16 bytes, received 3".to_string()
+            )),
+            invalid.validate_schema()
+        );
+
+        let mut invalid = request;
+        invalid.set_derivation_paths(vec![path.clone(), path]);
+        invalid.set_addresses(Some(vec![vec![0u8; 32]]));
+        assert_eq!(
+            Err(URError::ValidationError(
+                "addresses count 1 does not match derivation_paths count 2".to_string()
+            )),
+            invalid.validate_schema()
+        );
+        let result: Result<Vec<u8>, URError> = invalid.try_into();
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_addresses_count_on_decode() {
+        // two derivation paths but a single address
+        let bytes = hex::decode("a20382d90130a1018a182cf5190310f500f500f500f5d90130a1018a182cf5190310f500f500f501f504815820ebe623e33b7307f1350f8934beb3fb16baef0fc1b3f1b92868eec39440938869").unwrap();
+        assert!(matches!(
+            SuiSignRequest::try_from(bytes),
+            Err(URError::CborDecodeError(message)) if message.contains("addresses count 1 does not match derivation_paths count 2")
+        ));
+    }
+
+    #[test]
+    fn test_to_cbor() {
+        let path = CryptoKeyPath::from_path("m/44'/784'/0'/0'/0'".to_string(), None).unwrap();
+        let request = SuiSignRequest::new(
+            Some(hex::decode("9b1deb4d3b7d4bad9bdd2b0d7b3dcb6d").unwrap()),
+            hex::decode("0000000000020020").unwrap(),
+            vec![path],
+            None,
+            Some("Sui Wallet".to_string()),
+            None,
+            None,
+            None,
+        );
+        let result: Vec<u8> = request.clone().try_into().unwrap();
+        assert_eq!(result, request.to_cbor().unwrap());
+
+        let mut invalid = request;
+        invalid.set_derivation_paths(vec![]);
+        assert!(matches!(
+            invalid.to_cbor(),
+            Err(URError::CborEncodeError(message)) if message.contains("derivation paths is invalid")
+        ));
+    }
+
+    #[test]
+    fn test_expiry() {
+        let path = CryptoKeyPath::from_path("m/44'/784'/0'/0'/0'".to_string(), None).unwrap();
+        let request = SuiSignRequest::new(
+            None,
+            hex::decode("0000000000020020").unwrap(),
+            vec![path],
+            None,
+            None,
+            None,
+            Some(1760400000),
+            None,
+        );
+        let result = request.to_cbor().unwrap();
+        assert_eq!(
+            "a3024800000000000200200381d90130a1018a182cf5190310f500f500f500f5071a68ed9280",
+            hex::encode(&result)
+        );
+
+        let decoded = SuiSignRequest::try_from(result).unwrap();
+        assert_eq!(Some(1760400000), decoded.get_expires_at());
+        assert!(!decoded.is_expired(1760399999));
+        assert!(decoded.is_expired(1760400000));
+
+        let mut without_expiry = decoded;
+        without_expiry.set_expires_at(None);
+        assert!(!without_expiry.is_expired(u64::MAX));
+    }
+
+    #[test]
+    fn test_with_new_request_id() {
+        let path = CryptoKeyPath::from_path("m/44'/784'/0'/0'/0'".to_string(), None).unwrap();
+        let request = SuiSignRequest::new(
+            Some(hex::decode("9b1deb4d3b7d4bad9bdd2b0d7b3dcb6d").unwrap()),
+            hex::decode("0000000000020020").unwrap(),
+            vec![path],
+            None,
+            Some("Sui Wallet".to_string()),
+            None,
+            Some(1760400000),
+            None,
+        );
+        let retry = request.with_new_request_id([1; 16]);
+        assert_eq!(Some(vec![1; 16]), retry.get_request_id());
+
+        let mut expected = request.clone();
+        expected.set_request_id(Some(vec![1; 16]));
+        assert_eq!(expected.to_cbor().unwrap(), retry.to_cbor().unwrap());
+        assert_eq!(
+            Some(hex::decode("9b1deb4d3b7d4bad9bdd2b0d7b3dcb6d").unwrap()),
+            request.get_request_id()
+        );
+    }
+
+    #[test]
+    fn test_gas_objects_round_trip() {
+        let gas_object = hex::decode("1ff915a5e9e32fdbe0135535b6c69a00a9809aaf7f7c0275d3239ca79db20d6433b701000000000020176c4727433105da34209f04ac3f22e192a2573d7948cb2fabde7d13a7f4f149").unwrap();
+        let path = CryptoKeyPath::from_path("m/44'/784'/0'/0'/0'".to_string(), None).unwrap();
+        let request = SuiSignRequest::new(
+            Some(hex::decode("9b1deb4d3b7d4bad9bdd2b0d7b3dcb6d").unwrap()),
+            hex::decode("0000000000020020").unwrap(),
+            vec![path],
+            None,
+            None,
+            Some(vec![gas_object.clone()]),
+            None,
+            None,
+        );
+        let result: Vec<u8> = request.try_into().unwrap();
+        assert_eq!(
+            "a401d825509b1deb4d3b7d4bad9bdd2b0d7b3dcb6d024800000000000200200381d90130a1018a182cf5190310f500f500f500f5068158491ff915a5e9e32fdbe0135535b6c69a00a9809aaf7f7c0275d3239ca79db20d6433b701000000000020176c4727433105da34209f04ac3f22e192a2573d7948cb2fabde7d13a7f4f149",
+            hex::encode(&result)
+        );
+
+        let decoded = SuiSignRequest::try_from(result).unwrap();
+        assert_eq!(Some(vec![gas_object]), decoded.get_gas_objects());
+    }
+
+    #[test]
+    fn test_gas_objects_cap() {
+        let path = CryptoKeyPath::from_path("m/44'/784'/0'/0'/0'".to_string(), None).unwrap();
+        let request = SuiSignRequest::new(
+            None,
+            hex::decode("0000000000020020").unwrap(),
+            vec![path],
+            None,
+            None,
+            Some(vec![vec![0u8; 73]; MAX_GAS_OBJECTS + 1]),
+            None,
+            None,
+        );
+        assert_eq!(
+            Err(URError::ValidationError(
+                "gas_objects exceeds 256 entries".to_string()
+            )),
+            request.validate_schema()
+        );
+
+        let result: Vec<u8> = request.try_into().unwrap();
+        assert!(SuiSignRequest::try_from(result).is_err());
+    }
+
+    #[test]
+    fn test_min_firmware() {
+        let path = CryptoKeyPath::from_path("m/44'/784'/0'/0'/0'".to_string(), None).unwrap();
+        let mut request = SuiSignRequest::new(
+            None,
+            hex::decode("0000000000020020").unwrap(),
+            vec![path],
+            None,
+            Some("Sui Wallet".to_string()),
+            None,
+            None,
+            None,
+        );
+        assert_eq!("1.0.0", request.min_firmware());
+
+        request.set_gas_objects(Some(vec![vec![0u8; 73]]));
+        assert_eq!("1.1.0", request.min_firmware());
+
+        request.set_expires_at(Some(1760400000));
+        assert_eq!("1.2.0", request.min_firmware());
+
+        request.set_sponsor(Some(vec![0u8; 32]));
+        assert_eq!("1.3.0", request.min_firmware());
+    }
+
+    #[test]
+    fn test_builder() {
+        let path = CryptoKeyPath::from_path("m/44'/784'/0'/0'/0'".to_string(), None).unwrap();
+        let request = SuiSignRequest::builder()
+            .request_id(hex::decode("9b1deb4d3b7d4bad9bdd2b0d7b3dcb6d").unwrap())
+            .intent_message(hex::decode("0000000000020020").unwrap())
+            .derivation_path(path.clone())
+            .origin("Sui Wallet".to_string())
+            .expires_at(1760400000)
+            .build()
+            .unwrap();
+        let expected = SuiSignRequest::new(
+            Some(hex::decode("9b1deb4d3b7d4bad9bdd2b0d7b3dcb6d").unwrap()),
+            hex::decode("0000000000020020").unwrap(),
+            vec![path.clone()],
+            None,
+            Some("Sui Wallet".to_string()),
+            None,
+            Some(1760400000),
+            None,
+        );
+        let result: Vec<u8> = request.try_into().unwrap();
+        let expected: Vec<u8> = expected.try_into().unwrap();
+        assert_eq!(expected, result);
+
+        assert_eq!(
+            Err(URError::ValidationError(
+                "derivation_paths is empty".to_string()
+            )),
+            SuiSignRequest::builder()
+                .intent_message(hex::decode("0000000000020020").unwrap())
+                .build()
+                .map(|_| ())
+        );
+        assert_eq!(
+            Err(URError::ValidationError(
+                "intent_message is empty".to_string()
+            )),
+            SuiSignRequest::builder()
+                .derivation_path(path)
+                .build()
+                .map(|_| ())
+        );
+    }
+
+    #[test]
+    fn test_empty_origin() {
+        // origin is the empty string
+        let bytes =
+            hex::decode("a3024800000000000200200381d90130a1018a182cf5190310f500f500f500f50560")
+                .unwrap();
+        let decoded = SuiSignRequest::try_from(bytes).unwrap();
+        assert_eq!(None, decoded.get_origin());
+
+        let mut request = decoded.clone();
+        request.set_origin(Some("".to_string()));
+        let result: Vec<u8> = request.try_into().unwrap();
+        let expected: Vec<u8> = decoded.try_into().unwrap();
+        assert_eq!(expected, result);
+        assert_eq!(
+            "a2024800000000000200200381d90130a1018a182cf5190310f500f500f500f5",
+            hex::encode(result)
+        );
+    }
+
+    #[test]
+    fn test_origin_max_length() {
+        let path = CryptoKeyPath::from_path("m/44'/784'/0'/0'/0'".to_string(), None).unwrap();
+        let mut request = SuiSignRequest::new(
+            None,
+            hex::decode("0000000000020020").unwrap(),
+            vec![path],
+            None,
+            Some("a".repeat(MAX_ORIGIN_LENGTH)),
+            None,
+            None,
+            None,
+        );
+        let result: Vec<u8> = request.clone().try_into().unwrap();
+        let decoded = SuiSignRequest::try_from(result).unwrap();
+        assert_eq!(Some("a".repeat(MAX_ORIGIN_LENGTH)), decoded.get_origin());
+
+        request.set_origin(Some("a".repeat(MAX_ORIGIN_LENGTH + 1)));
+        let result: Vec<u8> = request.try_into().unwrap();
+        assert!(matches!(
+            SuiSignRequest::try_from(result),
+            Err(URError::CborDecodeError(message)) if message.contains("origin exceeds 256 bytes")
+        ));
+    }
+
+    #[test]
+    fn test_decode_collect_errors() {
+        let valid = hex::decode("a301d825509b1deb4d3b7d4bad9bdd2b0d7b3dcb6d024800000000000200200381d90130a1018a182cf5190310f500f500f500f5").unwrap();
+        let request = SuiSignRequest::decode_collect_errors(&valid).unwrap();
+        assert_eq!(
+            hex::decode("0000000000020020").unwrap(),
+            request.get_intent_message()
+        );
+
+        // a request_id tagged 36 instead of the UUID tag 37, and a 257 byte origin
+        let invalid = hex::decode(format!(
+            "a401d824509b1deb4d3b7d4bad9bdd2b0d7b3dcb6d024800000000000200200381d90130a1018a182cf5190310f500f500f500f505790101{}",
+            "61".repeat(MAX_ORIGIN_LENGTH + 1)
+        ))
+        .unwrap();
+        assert!(SuiSignRequest::try_from(invalid.clone()).is_err());
+
+        let errors = SuiSignRequest::decode_collect_errors(&invalid).unwrap_err();
+        assert_eq!(2, errors.len());
+        assert!(matches!(
+            &errors[0],
+            URError::CborDecodeError(message) if message.starts_with("key `1`") && message.contains("UUID tag is invalid")
+        ));
+        assert!(matches!(
+            &errors[1],
+            URError::CborDecodeError(message) if message.starts_with("key `5`") && message.contains("origin exceeds 256 bytes")
+        ));
+
+        let truncated = &valid[..valid.len() - 4];
+        assert!(SuiSignRequest::decode_collect_errors(truncated).is_err());
+    }
+
+    #[test]
+    fn test_sponsor_round_trip() {
+        let path = CryptoKeyPath::from_path("m/44'/784'/0'/0'/0'".to_string(), None).unwrap();
+        let sponsor =
+            hex::decode("ebe623e33b7307f1350f8934beb3fb16baef0fc1b3f1b92868eec39440938869")
+                .unwrap();
+        let mut request = SuiSignRequest::new(
+            None,
+            hex::decode("0000000000020020").unwrap(),
+            vec![path],
+            None,
+            None,
+            None,
+            None,
+            None,
+        );
+        let legacy: Vec<u8> = request.clone().try_into().unwrap();
+        assert_eq!(
+            None,
+            SuiSignRequest::try_from(legacy).unwrap().get_sponsor()
+        );
+
+        request.set_sponsor(Some(sponsor.clone()));
+        let result: Vec<u8> = request.try_into().unwrap();
+        assert_eq!(
+            "a3024800000000000200200381d90130a1018a182cf5190310f500f500f500f5085820ebe623e33b7307f1350f8934beb3fb16baef0fc1b3f1b92868eec39440938869",
+            hex::encode(&result)
+        );
+
+        let decoded = SuiSignRequest::try_from(result.clone()).unwrap();
+        assert_eq!(Some(sponsor), decoded.get_sponsor());
+        let encoded: Vec<u8> = decoded.try_into().unwrap();
+        assert_eq!(result, encoded);
+    }
+
+    #[test]
+    fn test_personal_message() {
+        let path = CryptoKeyPath::from_path("m/44'/784'/0'/0'/0'".to_string(), None).unwrap();
+        let message = "Hello, Sui! 👋".as_bytes();
+        let request = SuiSignRequest::new_message(None, message, vec![path.clone()], None, None);
+        assert_eq!(
+            "0300001048656c6c6f2c205375692120f09f918b",
+            hex::encode(request.get_intent_message())
+        );
+
+        let result: Vec<u8> = request.try_into().unwrap();
+        let decoded = SuiSignRequest::try_from(result).unwrap();
+        assert_eq!(Some(message.to_vec()), decoded.get_personal_message());
+
+        let long = vec![b'a'; 300];
+        let request = SuiSignRequest::new_message(None, &long, vec![path.clone()], None, None);
+        assert_eq!(
+            "030000ac02",
+            hex::encode(&request.get_intent_message()[..5])
+        );
+        assert_eq!(Some(long), request.get_personal_message());
+
+        // a transaction intent
+        let request = SuiSignRequest::new(
+            None,
+            hex::decode("0000000000020020").unwrap(),
+            vec![path],
+            None,
+            None,
+            None,
+            None,
+            None,
+        );
+        assert_eq!(None, request.get_personal_message());
+    }
+
+    #[test]
+    fn test_decode_unexpected_type() {
+        // expires_at is the float 1.0
+        let bytes = hex::decode("a202410007fa3f800000").unwrap();
+        assert_eq!(
+            Err(URError::UnexpectedType {
+                expected: "u64".to_string(),
+                got: "f32".to_string(),
+            }),
+            SuiSignRequest::try_from(bytes).map(|_| ())
+        );
     }
 }
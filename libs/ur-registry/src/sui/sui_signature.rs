@@ -1,8 +1,13 @@
 use alloc::string::ToString;
 use minicbor::data::{Int, Tag};
 
-use crate::cbor::cbor_map;
+use crate::cbor::{
+    cbor_bytes, cbor_map, cbor_tag, cbor_tx_hash, cbor_unknown_field, cbor_unknown_fields,
+    cbor_write_tx_hash,
+};
+use crate::decode::DecodeContext;
 use crate::impl_template_struct;
+use crate::impl_validate_signature;
 use crate::registry_types::{RegistryType, SUI_SIGNATURE, UUID};
 use crate::traits::{MapSize, RegistryItem};
 use crate::types::Bytes;
@@ -10,12 +15,20 @@ use crate::types::Bytes;
 const REQUEST_ID: u8 = 1;
 const SIGNATURE: u8 = 2;
 const PUBLIC_KEY: u8 = 3;
+const TX_HASH: u8 = 4;
 
-impl_template_struct!(SuiSignature {
-    request_id: Option<Bytes>,
-    signature: Bytes,
-    public_key: Option<Bytes>
-});
+impl_template_struct!(
+    SuiSignature {
+        request_id: Option<Bytes>,
+        signature: Bytes,
+        public_key: Option<Bytes>,
+        // hash of the transaction as computed by the device
+        tx_hash: Option<Bytes>
+    },
+    unknown_fields
+);
+
+impl_validate_signature!(SuiSignature);
 
 impl RegistryItem for SuiSignature {
     fn get_registry_type() -> RegistryType<'static> {
@@ -32,7 +45,10 @@ impl MapSize for SuiSignature {
         if self.public_key.is_some() {
             size += 1;
         }
-        size
+        if self.tx_hash.is_some() {
+            size += 1;
+        }
+        size + self.unknown_fields.len() as u64
     }
 }
 
@@ -52,15 +68,14 @@ impl<C> minicbor::Encode<C> for SuiSignature {
         if let Some(public_key) = self.get_public_key() {
             e.int(Int::from(PUBLIC_KEY))?.bytes(&public_key)?;
         }
+        cbor_write_tx_hash(e, TX_HASH, &self.tx_hash)?;
+        cbor_unknown_fields(e, &self.unknown_fields)?;
         Ok(())
     }
 }
 
-impl<'b, C> minicbor::Decode<'b, C> for SuiSignature {
-    fn decode(
-        d: &mut minicbor::Decoder<'b>,
-        _ctx: &mut C,
-    ) -> Result<Self, minicbor::decode::Error> {
+impl<'b, C: DecodeContext> minicbor::Decode<'b, C> for SuiSignature {
+    fn decode(d: &mut minicbor::Decoder<'b>, ctx: &mut C) -> Result<Self, minicbor::decode::Error> {
         let mut result = SuiSignature::default();
 
         cbor_map(d, &mut result, |key, obj, d| {
@@ -68,21 +83,21 @@ impl<'b, C> minicbor::Decode<'b, C> for SuiSignature {
                 u8::try_from(key).map_err(|e| minicbor::decode::Error::message(e.to_string()))?;
             match key {
                 REQUEST_ID => {
-                    let tag = d.tag()?;
-                    if !tag.eq(&Tag::Unassigned(UUID.get_tag())) {
-                        return Result::Err(minicbor::decode::Error::message(
-                            "UUID tag is invalid",
-                        ));
-                    }
-                    obj.request_id = Some(d.bytes()?.to_vec());
+                    cbor_tag(d, ctx, UUID.get_tag(), "UUID tag is invalid")?;
+                    obj.request_id = Some(cbor_bytes(d)?);
                 }
                 SIGNATURE => {
-                    obj.signature = d.bytes()?.to_vec();
+                    obj.signature = cbor_bytes(d)?;
                 }
                 PUBLIC_KEY => {
-                    obj.public_key = Some(d.bytes()?.to_vec());
+                    obj.public_key = Some(cbor_bytes(d)?);
+                }
+                TX_HASH => {
+                    obj.tx_hash = Some(cbor_tx_hash(d)?);
+                }
+                _ => {
+                    cbor_unknown_field(d, key, &mut obj.unknown_fields)?;
                 }
-                _ => {}
             }
             Ok(())
         })?;
@@ -93,16 +108,19 @@ impl<'b, C> minicbor::Decode<'b, C> for SuiSignature {
 
 #[cfg(test)]
 mod tests {
+    use alloc::vec;
     use alloc::vec::Vec;
 
     use super::*;
+    use crate::types::UnknownFields;
 
     #[test]
     fn test_encode() {
         let sig = SuiSignature {
             request_id: Some(hex::decode("9b1deb4d3b7d4bad9bdd2b0d7b3dcb6d").unwrap()),
             signature: hex::decode("f4b79835417490958c72492723409289b444f3af18274ba484a9eeaca9e760520e453776e5975df058b537476932a45239685f694fc6362fe5af6ba714da6505").unwrap(),
-            public_key: Some(hex::decode("aeb28ecace5c664c080e71b9efd3d071b3dac119a26f4e830dd6bd06712ed93f").unwrap())
+            public_key: Some(hex::decode("aeb28ecace5c664c080e71b9efd3d071b3dac119a26f4e830dd6bd06712ed93f").unwrap()),
+            ..Default::default()
         };
         let result: Vec<u8> = sig.try_into().unwrap();
         let expect_result = hex::decode("A301D825509B1DEB4D3B7D4BAD9BDD2B0D7B3DCB6D025840F4B79835417490958C72492723409289B444F3AF18274BA484A9EEACA9E760520E453776E5975DF058B537476932A45239685F694FC6362FE5AF6BA714DA6505035820AEB28ECACE5C664C080E71B9EFD3D071B3DAC119A26F4E830DD6BD06712ED93F").unwrap();
@@ -116,11 +134,48 @@ mod tests {
         let expect_result = SuiSignature {
             request_id: Some(hex::decode("9b1deb4d3b7d4bad9bdd2b0d7b3dcb6d").unwrap()),
             signature: hex::decode("f4b79835417490958c72492723409289b444f3af18274ba484a9eeaca9e760520e453776e5975df058b537476932a45239685f694fc6362fe5af6ba714da6505").unwrap(),
-            public_key: Some(hex::decode("aeb28ecace5c664c080e71b9efd3d071b3dac119a26f4e830dd6bd06712ed93f").unwrap())
+            public_key: Some(hex::decode("aeb28ecace5c664c080e71b9efd3d071b3dac119a26f4e830dd6bd06712ed93f").unwrap()),
+            ..Default::default()
         };
 
         assert_eq!(expect_result.request_id, result.request_id);
         assert_eq!(expect_result.signature, result.signature);
         assert_eq!(expect_result.public_key, result.public_key);
     }
+    #[test]
+    fn test_unknown_fields_round_trip() {
+        // the signature of `test_decode` with the array [1, 2] at the unused key 15
+        let bytes = hex::decode("a401d825509b1deb4d3b7d4bad9bdd2b0d7b3dcb6d025840f4b79835417490958c72492723409289b444f3af18274ba484a9eeaca9e760520e453776e5975df058b537476932a45239685f694fc6362fe5af6ba714da6505035820aeb28ecace5c664c080e71b9efd3d071b3dac119a26f4e830dd6bd06712ed93f0f820102").unwrap();
+        let result = SuiSignature::try_from(bytes.clone()).unwrap();
+        assert_eq!(
+            UnknownFields::from([(15, vec![0x82, 0x01, 0x02])]),
+            result.get_unknown_fields()
+        );
+        let encoded: Vec<u8> = result.try_into().unwrap();
+        assert_eq!(bytes, encoded);
+    }
+
+    #[test]
+    fn test_tx_hash_round_trip() {
+        let tx_hash = (0..32).collect::<Vec<u8>>();
+        let sig = SuiSignature::new(
+            Some(hex::decode("9b1deb4d3b7d4bad9bdd2b0d7b3dcb6d").unwrap()),
+            hex::decode("deadbeef").unwrap(),
+            None,
+            Some(tx_hash.clone()),
+        );
+        let result: Vec<u8> = sig.try_into().unwrap();
+        assert_eq!(
+            "a301d825509b1deb4d3b7d4bad9bdd2b0d7b3dcb6d0244deadbeef045820000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f",
+            hex::encode(&result)
+        );
+        assert_eq!(
+            Some(tx_hash),
+            SuiSignature::try_from(result).unwrap().get_tx_hash()
+        );
+
+        let sig = SuiSignature::new(None, vec![0xde], None, Some(vec![0; 31]));
+        let result: Result<Vec<u8>, _> = sig.try_into();
+        assert!(result.is_err());
+    }
 }
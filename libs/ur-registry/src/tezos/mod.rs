@@ -0,0 +1,2 @@
+pub mod tezos_sign_request;
+pub mod tezos_signature;
@@ -0,0 +1,264 @@
+use alloc::{
+    format,
+    string::{String, ToString},
+};
+use minicbor::data::{Int, Tag, Type};
+
+use crate::cbor::cbor_bytes;
+use crate::{
+    cbor::{
+        cbor_expect_type, cbor_map, cbor_origin, cbor_tag, cbor_unknown_field, cbor_unknown_fields,
+    },
+    crypto_key_path::CryptoKeyPath,
+    decode::DecodeContext,
+    error::URResult,
+    impl_template_struct, impl_with_new_request_id,
+    registry_types::{RegistryType, CRYPTO_KEYPATH, TEZOS_SIGN_REQUEST, UUID},
+    traits::{MapSize, RegistryItem, Validate},
+    types::Bytes,
+    validate::{validate_path, validate_request_id, validate_sign_data},
+};
+
+const REQUEST_ID: u8 = 1;
+const SIGN_DATA: u8 = 2;
+const SIGN_TYPE: u8 = 3;
+const DERIVATION_PATH: u8 = 4;
+const ORIGIN: u8 = 5;
+
+const TEZOS_COIN_TYPE: u32 = 1729;
+
+impl_template_struct!(
+    TezosSignRequest {
+        request_id: Option<Bytes>,
+        sign_data: Bytes,
+        sign_type: SignType,
+        derivation_path: CryptoKeyPath,
+        origin: Option<String>
+    },
+    unknown_fields
+);
+
+impl_with_new_request_id!(TezosSignRequest);
+
+#[derive(Clone, Debug, PartialEq, Default)]
+pub enum SignType {
+    Block = 1,
+    Endorsement = 2,
+    #[default]
+    Operation = 3,
+    Message = 4,
+}
+
+impl SignType {
+    pub fn from_u32(i: u32) -> Result<Self, String> {
+        match i {
+            1 => Ok(SignType::Block),
+            2 => Ok(SignType::Endorsement),
+            3 => Ok(SignType::Operation),
+            4 => Ok(SignType::Message),
+            x => Err(format!(
+                "invalid value for sign_type in tezos-sign-request, expected 1, 2, 3 or 4, received {:?}",
+                x
+            )),
+        }
+    }
+
+    // the watermark bytes allowed in front of the forged bytes, including the tenderbake ones
+    pub fn watermarks(&self) -> &'static [u8] {
+        match self {
+            SignType::Block => &[0x01, 0x11],
+            SignType::Endorsement => &[0x02, 0x12, 0x13],
+            SignType::Operation => &[0x03],
+            SignType::Message => &[0x05],
+        }
+    }
+
+    pub fn check_watermark(&self, sign_data: &[u8]) -> Result<(), String> {
+        match sign_data.first() {
+            Some(watermark) if self.watermarks().contains(watermark) => Ok(()),
+            Some(watermark) => Err(format!(
+                "watermark 0x{:02x} does not match sign_type {:?}",
+                watermark, self
+            )),
+            None => Err("sign_data is missing the watermark".to_string()),
+        }
+    }
+}
+
+impl MapSize for TezosSignRequest {
+    fn map_size(&self) -> u64 {
+        let mut size = 3;
+        if self.request_id.is_some() {
+            size += 1;
+        }
+        if self.origin.is_some() {
+            size += 1;
+        }
+        size + self.unknown_fields.len() as u64
+    }
+}
+
+impl RegistryItem for TezosSignRequest {
+    fn get_registry_type() -> RegistryType<'static> {
+        TEZOS_SIGN_REQUEST
+    }
+}
+
+impl Validate for TezosSignRequest {
+    fn validate_schema(&self) -> URResult<()> {
+        validate_request_id(&self.request_id)?;
+        validate_sign_data("sign_data", &self.sign_data)?;
+        validate_path(
+            "derivation_path",
+            &self.derivation_path,
+            Some(TEZOS_COIN_TYPE),
+        )?;
+        Ok(())
+    }
+}
+
+impl<C> minicbor::Encode<C> for TezosSignRequest {
+    fn encode<W: minicbor::encode::Write>(
+        &self,
+        e: &mut minicbor::Encoder<W>,
+        _ctx: &mut C,
+    ) -> Result<(), minicbor::encode::Error<W::Error>> {
+        e.map(self.map_size())?;
+
+        if let Some(request_id) = &self.request_id {
+            e.int(Int::from(REQUEST_ID))?
+                .tag(Tag::Unassigned(UUID.get_tag()))?
+                .bytes(request_id)?;
+        }
+
+        e.int(Int::from(SIGN_DATA))?.bytes(&self.sign_data)?;
+
+        e.int(Int::from(SIGN_TYPE))?
+            .int(Int::from(self.sign_type.clone() as u8))?;
+
+        e.int(Int::from(DERIVATION_PATH))?
+            .tag(Tag::Unassigned(CRYPTO_KEYPATH.get_tag()))?;
+        CryptoKeyPath::encode(&self.derivation_path, e, _ctx)?;
+
+        if let Some(origin) = &self.origin {
+            e.int(Int::from(ORIGIN))?.str(origin)?;
+        }
+
+        cbor_unknown_fields(e, &self.unknown_fields)?;
+        Ok(())
+    }
+}
+
+impl<'b, C: DecodeContext> minicbor::Decode<'b, C> for TezosSignRequest {
+    fn decode(d: &mut minicbor::Decoder<'b>, ctx: &mut C) -> Result<Self, minicbor::decode::Error> {
+        let mut result = TezosSignRequest::default();
+        cbor_map(d, &mut result, |key, obj, d| {
+            let key =
+                u8::try_from(key).map_err(|e| minicbor::decode::Error::message(e.to_string()))?;
+            match key {
+                REQUEST_ID => {
+                    cbor_tag(d, ctx, UUID.get_tag(), "UUID tag is invalid")?;
+                    obj.request_id = Some(cbor_bytes(d)?);
+                }
+                SIGN_DATA => {
+                    cbor_expect_type(d, ctx, Type::Bytes)?;
+                    obj.sign_data = cbor_bytes(d)?;
+                }
+                SIGN_TYPE => {
+                    cbor_expect_type(d, ctx, Type::U32)?;
+                    obj.sign_type = SignType::from_u32(
+                        u32::try_from(d.int()?)
+                            .map_err(|e| minicbor::decode::Error::message(e.to_string()))?,
+                    )
+                    .map_err(minicbor::decode::Error::message)?;
+                }
+                DERIVATION_PATH => {
+                    cbor_tag(
+                        d,
+                        ctx,
+                        CRYPTO_KEYPATH.get_tag(),
+                        "CryptoKeyPath tag is invalid",
+                    )?;
+                    obj.derivation_path = CryptoKeyPath::decode(d, ctx)?;
+                }
+                ORIGIN => {
+                    obj.origin = Some(cbor_origin(d)?);
+                }
+                _ => {
+                    cbor_unknown_field(d, key, &mut obj.unknown_fields)?;
+                }
+            }
+            Ok(())
+        })?;
+        // sign_type may come after sign_data, so the watermark is checked once the map is read
+        result
+            .sign_type
+            .check_watermark(&result.sign_data)
+            .map_err(minicbor::decode::Error::message)?;
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use super::*;
+
+    const TRANSFER: &str = "03ce69c5713dac3537254e7be59759cf59c15abd530d10501ccf9028a5786314cf6c0002298c03ed7d454a101eb7022bc95f7e5f41ac788c0b97cecd02c35000c0843d0000e7670f32038107a59a2b9cfefae36ea21f5aa63c00";
+
+    #[test]
+    fn test_transfer_round_trip() {
+        let path = CryptoKeyPath::from_path(
+            "m/44'/1729'/0'/0'".to_string(),
+            Some([0xf2, 0x3f, 0x9f, 0xd2]),
+        )
+        .unwrap();
+        let request = TezosSignRequest::new(
+            Some(hex::decode("9b1deb4d3b7d4bad9bdd2b0d7b3dcb6d").unwrap()),
+            hex::decode(TRANSFER).unwrap(),
+            SignType::Operation,
+            path,
+            Some("keystone".to_string()),
+        );
+        assert!(request.validate_schema().is_ok());
+
+        let result: Vec<u8> = request.try_into().unwrap();
+        assert_eq!(
+            "a501d825509b1deb4d3b7d4bad9bdd2b0d7b3dcb6d02585a03ce69c5713dac3537254e7be59759cf59c15abd530d10501ccf9028a5786314cf6c0002298c03ed7d454a101eb7022bc95f7e5f41ac788c0b97cecd02c35000c0843d0000e7670f32038107a59a2b9cfefae36ea21f5aa63c00030304d90130a20188182cf51906c1f500f500f5021af23f9fd205686b657973746f6e65",
+            hex::encode(&result)
+        );
+
+        let decoded = TezosSignRequest::try_from(result).unwrap();
+        assert_eq!(
+            Some(hex::decode("9b1deb4d3b7d4bad9bdd2b0d7b3dcb6d").unwrap()),
+            decoded.get_request_id()
+        );
+        assert_eq!(hex::decode(TRANSFER).unwrap(), decoded.get_sign_data());
+        assert_eq!(SignType::Operation, decoded.get_sign_type());
+        assert_eq!(
+            "44'/1729'/0'/0'",
+            decoded.get_derivation_path().get_path().unwrap()
+        );
+        assert_eq!(Some("keystone".to_string()), decoded.get_origin());
+    }
+
+    #[test]
+    fn test_decode_watermark_mismatch() {
+        // the transfer above declared as a message
+        let result = TezosSignRequest::try_from(hex::decode("a401d825509b1deb4d3b7d4bad9bdd2b0d7b3dcb6d02585a03ce69c5713dac3537254e7be59759cf59c15abd530d10501ccf9028a5786314cf6c0002298c03ed7d454a101eb7022bc95f7e5f41ac788c0b97cecd02c35000c0843d0000e7670f32038107a59a2b9cfefae36ea21f5aa63c00030404d90130a20188182cf51906c1f500f500f5021af23f9fd2").unwrap());
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_check_watermark() {
+        assert!(SignType::Block.check_watermark(&[0x11, 0x00]).is_ok());
+        assert!(SignType::Endorsement.check_watermark(&[0x13, 0x00]).is_ok());
+        assert!(SignType::Message.check_watermark(&[0x05, 0x01]).is_ok());
+        assert_eq!(
+            Err("watermark 0x03 does not match sign_type Block".to_string()),
+            SignType::Block.check_watermark(&[0x03])
+        );
+        assert!(SignType::Operation.check_watermark(&[]).is_err());
+    }
+}
@@ -0,0 +1,110 @@
+use alloc::string::ToString;
+use minicbor::data::{Int, Tag};
+
+use crate::cbor::{cbor_bytes, cbor_map, cbor_tag, cbor_unknown_field, cbor_unknown_fields};
+use crate::decode::DecodeContext;
+use crate::impl_template_struct;
+use crate::impl_validate_signature;
+use crate::registry_types::{RegistryType, TEZOS_SIGNATURE, UUID};
+use crate::traits::{MapSize, RegistryItem};
+use crate::types::Bytes;
+
+const REQUEST_ID: u8 = 1;
+const SIGNATURE: u8 = 2;
+
+impl_template_struct!(
+    TezosSignature {
+        request_id: Option<Bytes>,
+        signature: Bytes
+    },
+    unknown_fields
+);
+
+impl_validate_signature!(TezosSignature);
+
+impl RegistryItem for TezosSignature {
+    fn get_registry_type() -> RegistryType<'static> {
+        TEZOS_SIGNATURE
+    }
+}
+
+impl MapSize for TezosSignature {
+    fn map_size(&self) -> u64 {
+        let mut size = 1;
+        if self.request_id.is_some() {
+            size += 1;
+        }
+        size + self.unknown_fields.len() as u64
+    }
+}
+
+impl<C> minicbor::Encode<C> for TezosSignature {
+    fn encode<W: minicbor::encode::Write>(
+        &self,
+        e: &mut minicbor::Encoder<W>,
+        _ctx: &mut C,
+    ) -> Result<(), minicbor::encode::Error<W::Error>> {
+        e.map(self.map_size())?;
+        if let Some(request_id) = &self.request_id {
+            e.int(Int::from(REQUEST_ID))?
+                .tag(Tag::Unassigned(UUID.get_tag()))?
+                .bytes(request_id)?;
+        }
+        e.int(Int::from(SIGNATURE))?.bytes(&self.signature)?;
+        cbor_unknown_fields(e, &self.unknown_fields)?;
+        Ok(())
+    }
+}
+
+impl<'b, C: DecodeContext> minicbor::Decode<'b, C> for TezosSignature {
+    fn decode(d: &mut minicbor::Decoder<'b>, ctx: &mut C) -> Result<Self, minicbor::decode::Error> {
+        let mut result = TezosSignature::default();
+
+        cbor_map(d, &mut result, |key, obj, d| {
+            let key =
+                u8::try_from(key).map_err(|e| minicbor::decode::Error::message(e.to_string()))?;
+            match key {
+                REQUEST_ID => {
+                    cbor_tag(d, ctx, UUID.get_tag(), "UUID tag is invalid")?;
+                    obj.request_id = Some(cbor_bytes(d)?);
+                }
+                SIGNATURE => {
+                    obj.signature = cbor_bytes(d)?;
+                }
+                _ => {
+                    cbor_unknown_field(d, key, &mut obj.unknown_fields)?;
+                }
+            }
+            Ok(())
+        })?;
+
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        let signature = TezosSignature::new(
+            Some(hex::decode("9b1deb4d3b7d4bad9bdd2b0d7b3dcb6d").unwrap()),
+            (0u8..64).collect(),
+        );
+        let result: Vec<u8> = signature.try_into().unwrap();
+        assert_eq!(
+            "a201d825509b1deb4d3b7d4bad9bdd2b0d7b3dcb6d025840000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f202122232425262728292a2b2c2d2e2f303132333435363738393a3b3c3d3e3f",
+            hex::encode(&result)
+        );
+
+        let decoded = TezosSignature::try_from(result).unwrap();
+        assert_eq!(
+            Some(hex::decode("9b1deb4d3b7d4bad9bdd2b0d7b3dcb6d").unwrap()),
+            decoded.get_request_id()
+        );
+        assert_eq!((0u8..64).collect::<Vec<u8>>(), decoded.get_signature());
+    }
+}
@@ -1,16 +1,24 @@
 use alloc::{
     format,
     string::{String, ToString},
+    vec::Vec,
 };
-use minicbor::data::{Int, Tag};
+use minicbor::data::{Int, Tag, Type};
 
+use crate::cbor::cbor_bytes;
 use crate::{
-    cbor::cbor_map,
+    cbor::{
+        cbor_expect_type, cbor_map, cbor_origin, cbor_tag, cbor_unknown_field, cbor_unknown_fields,
+    },
     crypto_key_path::CryptoKeyPath,
-    impl_template_struct,
+    decode::DecodeContext,
+    error::URResult,
+    impl_template_struct, impl_with_new_request_id,
     registry_types::{RegistryType, CRYPTO_KEYPATH, TON_SIGN_REQUEST, UUID},
-    traits::{MapSize, RegistryItem},
+    traits::{DecodeWarnings, MapSize, RegistryItem, Validate},
     types::Bytes,
+    validate::{validate_path, validate_request_id, validate_sign_data},
+    warnings::{path_depth_warning, unknown_key_warnings},
 };
 
 const REQUEST_ID: u8 = 1;
@@ -20,14 +28,21 @@ const DERIVATION_PATH: u8 = 4;
 const ADDRESS: u8 = 5;
 const ORIGIN: u8 = 6;
 
-impl_template_struct!(TonSignRequest {
-    request_id: Option<Bytes>,
-    sign_data: Bytes,
-    data_type: DataType,
-    derivation_path: Option<CryptoKeyPath>,
-    address: String,
-    origin: Option<String>
-});
+const TON_COIN_TYPE: u32 = 607;
+
+impl_template_struct!(
+    TonSignRequest {
+        request_id: Option<Bytes>,
+        sign_data: Bytes,
+        data_type: DataType,
+        derivation_path: Option<CryptoKeyPath>,
+        address: Option<String>,
+        origin: Option<String>
+    },
+    unknown_fields
+);
+
+impl_with_new_request_id!(TonSignRequest);
 
 #[derive(Clone, Debug, PartialEq, Default)]
 pub enum DataType {
@@ -42,7 +57,7 @@ impl DataType {
             1 => Ok(DataType::Transaction),
             2 => Ok(DataType::SignProof),
             x => Err(format!(
-                "invalid value for data_type in eth-sign-request, expected (1, 2, 3, 4), received {:?}",
+                "invalid value for data_type in ton-sign-request, expected (1, 2), received {:?}",
                 x
             )),
         }
@@ -51,17 +66,20 @@ impl DataType {
 
 impl MapSize for TonSignRequest {
     fn map_size(&self) -> u64 {
-        let mut size = 3;
+        let mut size = 2;
         if self.request_id.is_some() {
             size += 1;
         }
         if self.derivation_path.is_some() {
             size += 1;
         }
+        if self.address.is_some() {
+            size += 1;
+        }
         if self.origin.is_some() {
             size += 1;
         }
-        size
+        size + self.unknown_fields.len() as u64
     }
 }
 
@@ -71,6 +89,31 @@ impl RegistryItem for TonSignRequest {
     }
 }
 
+impl Validate for TonSignRequest {
+    fn validate_schema(&self) -> URResult<()> {
+        validate_request_id(&self.request_id)?;
+        validate_sign_data("sign_data", &self.sign_data)?;
+        if let Some(derivation_path) = &self.derivation_path {
+            validate_path("derivation_path", derivation_path, Some(TON_COIN_TYPE))?;
+        }
+        Ok(())
+    }
+}
+
+impl DecodeWarnings for TonSignRequest {
+    fn decode_warnings(bytes: &[u8]) -> URResult<Vec<String>> {
+        let mut warnings = unknown_key_warnings(
+            bytes,
+            &[REQUEST_ID, SIGN_DATA, DATA_TYPE, DERIVATION_PATH, ADDRESS, ORIGIN],
+        )?;
+        let request = TonSignRequest::try_from(bytes.to_vec())?;
+        if let Some(derivation_path) = &request.derivation_path {
+            warnings.extend(path_depth_warning("derivation_path", derivation_path));
+        }
+        Ok(warnings)
+    }
+}
+
 impl<C> minicbor::Encode<C> for TonSignRequest {
     fn encode<W: minicbor::encode::Write>(
         &self,
@@ -96,17 +139,20 @@ impl<C> minicbor::Encode<C> for TonSignRequest {
             CryptoKeyPath::encode(derivation_path, e, _ctx)?;
         }
 
-        e.int(Int::from(ADDRESS))?.str(&self.address)?;
+        if let Some(address) = &self.address {
+            e.int(Int::from(ADDRESS))?.str(address)?;
+        }
 
         if let Some(origin) = &self.origin {
             e.int(Int::from(ORIGIN))?.str(origin)?;
         }
 
+        cbor_unknown_fields(e, &self.unknown_fields)?;
         Ok(())
     }
 }
 
-impl<'b, C> minicbor::Decode<'b, C> for TonSignRequest {
+impl<'b, C: DecodeContext> minicbor::Decode<'b, C> for TonSignRequest {
     fn decode(d: &mut minicbor::Decoder<'b>, ctx: &mut C) -> Result<Self, minicbor::decode::Error> {
         let mut result = TonSignRequest::default();
         cbor_map(d, &mut result, |key, obj, d| {
@@ -114,13 +160,15 @@ impl<'b, C> minicbor::Decode<'b, C> for TonSignRequest {
                 u8::try_from(key).map_err(|e| minicbor::decode::Error::message(e.to_string()))?;
             match key {
                 REQUEST_ID => {
-                    d.tag()?;
-                    obj.request_id = Some(d.bytes()?.to_vec());
+                    cbor_tag(d, ctx, UUID.get_tag(), "UUID tag is invalid")?;
+                    obj.request_id = Some(cbor_bytes(d)?);
                 }
                 SIGN_DATA => {
-                    obj.sign_data = d.bytes()?.to_vec();
+                    cbor_expect_type(d, ctx, Type::Bytes)?;
+                    obj.sign_data = cbor_bytes(d)?;
                 }
                 DATA_TYPE => {
+                    cbor_expect_type(d, ctx, Type::U32)?;
                     obj.data_type = DataType::from_u32(
                         u32::try_from(d.int()?)
                             .map_err(|e| minicbor::decode::Error::message(e.to_string()))?,
@@ -128,16 +176,23 @@ impl<'b, C> minicbor::Decode<'b, C> for TonSignRequest {
                     .map_err(minicbor::decode::Error::message)?;
                 }
                 DERIVATION_PATH => {
-                    d.tag()?;
+                    cbor_tag(
+                        d,
+                        ctx,
+                        CryptoKeyPath::get_registry_type().get_tag(),
+                        "CryptoKeyPath tag is invalid",
+                    )?;
                     obj.derivation_path = Some(CryptoKeyPath::decode(d, ctx)?);
                 }
                 ADDRESS => {
-                    obj.address = d.str()?.to_string();
+                    obj.address = Some(d.str()?.to_string());
                 }
                 ORIGIN => {
-                    obj.origin = Some(d.str()?.to_string());
+                    obj.origin = Some(cbor_origin(d)?);
+                }
+                _ => {
+                    cbor_unknown_field(d, key, &mut obj.unknown_fields)?;
                 }
-                _ => {}
             }
             Ok(())
         })?;
@@ -152,6 +207,7 @@ mod tests {
     use base64::Engine;
 
     use crate::crypto_key_path::PathComponent;
+    use crate::error::URError;
 
     use super::*;
     extern crate std;
@@ -166,8 +222,9 @@ mod tests {
             sign_data: payload,
             data_type: DataType::Transaction,
             derivation_path: None,
-            address: "UQC1IywyQwixSOU8pezOZDC9rv2xCV4CGJzOWH6RX8BTsGJx".to_string(),
+            address: Some("UQC1IywyQwixSOU8pezOZDC9rv2xCV4CGJzOWH6RX8BTsGJx".to_string()),
             origin: Some("TonKeeper".to_string()),
+            ..Default::default()
         };
         let result: Vec<u8> = sig.try_into().unwrap();
         let expect_result = hex::decode("a501d825509b1deb4d3b7d4bad9bdd2b0d7b3dcb6d025856b5ee9c7241010201004700011c29a9a317663b3ea500000008000301006842002b16732f1c05fdb4e8d3a78fd10dddef3f6067f311be539313b8a44a504d4da2a1dcd65000000000000000000000000000007072e06f0301057830555143314979777951776978534f553870657a4f5a4443397276327843563443474a7a4f574836525838425473474a780669546f6e4b6565706572").unwrap();
@@ -185,8 +242,9 @@ mod tests {
             sign_data: payload,
             data_type: DataType::Transaction,
             derivation_path: None,
-            address: "UQC1IywyQwixSOU8pezOZDC9rv2xCV4CGJzOWH6RX8BTsGJx".to_string(),
+            address: Some("UQC1IywyQwixSOU8pezOZDC9rv2xCV4CGJzOWH6RX8BTsGJx".to_string()),
             origin: Some("TonKeeper".to_string()),
+            ..Default::default()
         };
         let result = TonSignRequest::try_from(hex::decode("a501d825509b1deb4d3b7d4bad9bdd2b0d7b3dcb6d025856b5ee9c7241010201004700011c29a9a317663b3ea500000008000301006842002b16732f1c05fdb4e8d3a78fd10dddef3f6067f311be539313b8a44a504d4da2a1dcd65000000000000000000000000000007072e06f0301057830555143314979777951776978534f553870657a4f5a4443397276327843563443474a7a4f574836525838425473474a780669546f6e4b6565706572").unwrap()).unwrap();
 
@@ -196,4 +254,53 @@ mod tests {
         assert_eq!(expect_result.address, result.address);
         assert_eq!(expect_result.origin, result.origin);
     }
+
+    #[test]
+    fn test_sign_proof_without_address() {
+        let path = CryptoKeyPath::from_path(
+            "m/44'/607'/0'".to_string(),
+            Some([0xf2, 0x3f, 0x9f, 0xd2]),
+        )
+        .unwrap();
+        let request = TonSignRequest::new(
+            Some(hex::decode("9b1deb4d3b7d4bad9bdd2b0d7b3dcb6d").unwrap()),
+            hex::decode("deadbeef").unwrap(),
+            DataType::SignProof,
+            Some(path),
+            None,
+            None,
+        );
+        let result: Vec<u8> = request.clone().try_into().unwrap();
+        assert_eq!(
+            "a401d825509b1deb4d3b7d4bad9bdd2b0d7b3dcb6d0244deadbeef030204d90130a20186182cf519025ff500f5021af23f9fd2",
+            hex::encode(&result)
+        );
+
+        let decoded = TonSignRequest::try_from(result).unwrap();
+        assert_eq!(DataType::SignProof, decoded.get_data_type());
+        assert!(decoded.get_address().is_none());
+        assert!(decoded.get_origin().is_none());
+        assert_eq!(
+            "44'/607'/0'",
+            decoded.get_derivation_path().unwrap().get_path().unwrap()
+        );
+    }
+
+    #[test]
+    fn test_invalid_data_type() {
+        assert!(DataType::from_u32(3).is_err());
+    }
+
+    #[test]
+    fn test_decode_unexpected_type() {
+        // data_type is the float 1.0
+        let bytes = hex::decode("a202410003fa3f800000").unwrap();
+        assert_eq!(
+            Err(URError::UnexpectedType {
+                expected: "u32".to_string(),
+                got: "f32".to_string(),
+            }),
+            TonSignRequest::try_from(bytes).map(|_| ())
+        );
+    }
 }
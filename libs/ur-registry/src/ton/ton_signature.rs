@@ -1,21 +1,33 @@
 use alloc::string::{String, ToString};
+use alloc::vec::Vec;
 use minicbor::data::{Int, Tag};
 
-use crate::cbor::cbor_map;
+use crate::cbor::{
+    cbor_bytes, cbor_map, cbor_origin, cbor_tag, cbor_unknown_field, cbor_unknown_fields,
+};
+use crate::decode::DecodeContext;
+use crate::error::URResult;
 use crate::impl_template_struct;
+use crate::impl_validate_signature;
 use crate::registry_types::{RegistryType, TON_SIGNATURE, UUID};
-use crate::traits::{MapSize, RegistryItem};
+use crate::traits::{DecodeWarnings, MapSize, RegistryItem};
 use crate::types::Bytes;
+use crate::warnings::unknown_key_warnings;
 
 const REQUEST_ID: u8 = 1;
 const SIGNATURE: u8 = 2;
 const ORIGIN: u8 = 3;
 
-impl_template_struct!(TonSignature {
-    request_id: Option<Bytes>,
-    signature: Bytes,
-    origin: Option<String>
-});
+impl_template_struct!(
+    TonSignature {
+        request_id: Option<Bytes>,
+        signature: Bytes,
+        origin: Option<String>
+    },
+    unknown_fields
+);
+
+impl_validate_signature!(TonSignature);
 
 impl RegistryItem for TonSignature {
     fn get_registry_type() -> RegistryType<'static> {
@@ -32,7 +44,14 @@ impl MapSize for TonSignature {
         if self.origin.is_some() {
             size += 1;
         }
-        size
+        size + self.unknown_fields.len() as u64
+    }
+}
+
+impl DecodeWarnings for TonSignature {
+    fn decode_warnings(bytes: &[u8]) -> URResult<Vec<String>> {
+        TonSignature::try_from(bytes.to_vec())?;
+        unknown_key_warnings(bytes, &[REQUEST_ID, SIGNATURE, ORIGIN])
     }
 }
 
@@ -52,15 +71,13 @@ impl<C> minicbor::Encode<C> for TonSignature {
         if let Some(origin) = self.get_origin() {
             e.int(Int::from(ORIGIN))?.str(&origin)?;
         }
+        cbor_unknown_fields(e, &self.unknown_fields)?;
         Ok(())
     }
 }
 
-impl<'b, C> minicbor::Decode<'b, C> for TonSignature {
-    fn decode(
-        d: &mut minicbor::Decoder<'b>,
-        _ctx: &mut C,
-    ) -> Result<Self, minicbor::decode::Error> {
+impl<'b, C: DecodeContext> minicbor::Decode<'b, C> for TonSignature {
+    fn decode(d: &mut minicbor::Decoder<'b>, ctx: &mut C) -> Result<Self, minicbor::decode::Error> {
         let mut result = TonSignature::default();
 
         cbor_map(d, &mut result, |key, obj, d| {
@@ -68,21 +85,18 @@ impl<'b, C> minicbor::Decode<'b, C> for TonSignature {
                 u8::try_from(key).map_err(|e| minicbor::decode::Error::message(e.to_string()))?;
             match key {
                 REQUEST_ID => {
-                    let tag = d.tag()?;
-                    if !tag.eq(&Tag::Unassigned(UUID.get_tag())) {
-                        return Result::Err(minicbor::decode::Error::message(
-                            "UUID tag is invalid",
-                        ));
-                    }
-                    obj.request_id = Some(d.bytes()?.to_vec());
+                    cbor_tag(d, ctx, UUID.get_tag(), "UUID tag is invalid")?;
+                    obj.request_id = Some(cbor_bytes(d)?);
                 }
                 SIGNATURE => {
-                    obj.signature = d.bytes()?.to_vec();
+                    obj.signature = cbor_bytes(d)?;
                 }
                 ORIGIN => {
-                    obj.origin = Some(d.str()?.to_string());
+                    obj.origin = Some(cbor_origin(d)?);
+                }
+                _ => {
+                    cbor_unknown_field(d, key, &mut obj.unknown_fields)?;
                 }
-                _ => {}
             }
             Ok(())
         })?;
@@ -93,6 +107,7 @@ impl<'b, C> minicbor::Decode<'b, C> for TonSignature {
 
 #[cfg(test)]
 mod tests {
+    use alloc::vec;
     use alloc::vec::Vec;
 
     use super::*;
@@ -105,6 +120,7 @@ mod tests {
             request_id: Some(hex::decode("9b1deb4d3b7d4bad9bdd2b0d7b3dcb6d").unwrap()),
             signature: hex::decode("f4b79835417490958c72492723409289b444f3af18274ba484a9eeaca9e760520e453776e5975df058b537476932a45239685f694fc6362fe5af6ba714da6505").unwrap(),
             origin: Some("Keystone".to_string()),
+            ..Default::default()
         };
         let result: Vec<u8> = sig.try_into().unwrap();
         let expect_result = hex::decode("a301d825509b1deb4d3b7d4bad9bdd2b0d7b3dcb6d025840f4b79835417490958c72492723409289b444f3af18274ba484a9eeaca9e760520e453776e5975df058b537476932a45239685f694fc6362fe5af6ba714da650503684b657973746f6e65").unwrap();
@@ -119,10 +135,23 @@ mod tests {
             request_id: Some(hex::decode("9b1deb4d3b7d4bad9bdd2b0d7b3dcb6d").unwrap()),
             signature: hex::decode("f4b79835417490958c72492723409289b444f3af18274ba484a9eeaca9e760520e453776e5975df058b537476932a45239685f694fc6362fe5af6ba714da6505").unwrap(),
             origin: Some("Keystone".to_string()),
+            ..Default::default()
         };
 
         assert_eq!(expect_result.request_id, result.request_id);
         assert_eq!(expect_result.signature, result.signature);
         assert_eq!(expect_result.origin, result.origin);
     }
+
+    #[test]
+    fn test_decode_warnings() {
+        let cbor = hex::decode("a301d825509b1deb4d3b7d4bad9bdd2b0d7b3dcb6d025840f4b79835417490958c72492723409289b444f3af18274ba484a9eeaca9e760520e453776e5975df058b537476932a45239685f694fc6362fe5af6ba714da650503684b657973746f6e65").unwrap();
+        assert!(TonSignature::decode_warnings(&cbor).unwrap().is_empty());
+
+        let cbor = hex::decode("a401d825509b1deb4d3b7d4bad9bdd2b0d7b3dcb6d025840f4b79835417490958c72492723409289b444f3af18274ba484a9eeaca9e760520e453776e5975df058b537476932a45239685f694fc6362fe5af6ba714da650503684b657973746f6e65096178").unwrap();
+        assert_eq!(
+            vec!["unknown key `9` is ignored".to_string()],
+            TonSignature::decode_warnings(&cbor).unwrap()
+        );
+    }
 }
@@ -1,5 +1,6 @@
-use crate::error::URResult;
+use crate::error::{URError, URResult};
 use crate::registry_types::RegistryType;
+use alloc::string::{String, ToString};
 use alloc::vec::Vec;
 
 pub trait From<T> {
@@ -18,8 +19,58 @@ pub trait UR {
 
 pub trait RegistryItem {
     fn get_registry_type() -> RegistryType<'static>;
+
+    /// Encodes the item as a single part `ur:<type>/<bytewords>` string.
+    fn to_ur_string(&self) -> URResult<String>
+    where
+        Self: minicbor::Encode<()>,
+    {
+        let bytes = minicbor::to_vec(self).map_err(|e| URError::CborEncodeError(e.to_string()))?;
+        Ok(ur::encode(&bytes, Self::get_registry_type().get_type()))
+    }
+
+    /// Like [`RegistryItem::to_ur_string`], but fails when the UR doesn't fit in
+    /// a single QR code, for callers that never fall back to multiple parts.
+    fn to_ur_string_single(&self) -> URResult<String>
+    where
+        Self: minicbor::Encode<()>,
+    {
+        let ur = self.to_ur_string()?;
+        if ur.len() > MAX_SINGLE_QR_LENGTH {
+            return Err(URError::SingleQrOverflow(ur.len(), MAX_SINGLE_QR_LENGTH));
+        }
+        Ok(ur)
+    }
+
+    /// The length of the `ur:<type>/` prefix of a single part UR, the bytes of a
+    /// QR code not left for the bytewords of the payload.
+    fn ur_prefix_overhead() -> usize {
+        "ur:".len() + Self::get_registry_type().get_type().len() + "/".len()
+    }
+
+    /// The length of the `ur:<type>/<sequence>-<count>/` prefix of a part of a
+    /// multi part UR.
+    fn ur_part_prefix_overhead(sequence: usize, count: usize) -> usize {
+        Self::ur_prefix_overhead() + sequence.to_string().len() + count.to_string().len() + 2
+    }
+
+    /// Encodes the item's CBOR into `w`, such as a fixed slice or a reused buffer,
+    /// without allocating the `Vec<u8>` that `try_into` returns.
+    fn encode_into<W>(&self, w: W) -> URResult<()>
+    where
+        Self: minicbor::Encode<()>,
+        W: minicbor::encode::Write,
+        W::Error: core::fmt::Display,
+    {
+        minicbor::encode(self, w).map_err(|e| URError::CborEncodeError(e.to_string()))
+    }
 }
 
+/// The byte mode capacity of a version 40 QR code at the lowest error
+/// correction level. A UR string is lowercase, which the alphanumeric mode
+/// can't encode, so this is the largest one a single QR code can carry.
+pub const MAX_SINGLE_QR_LENGTH: usize = 2953;
+
 impl<N> UR for N
 where
     N: To + RegistryItem,
@@ -38,3 +89,152 @@ where
 pub trait MapSize {
     fn map_size(&self) -> u64;
 }
+
+pub trait Validate {
+    fn validate_schema(&self) -> URResult<()>;
+}
+
+/// Lists what a payload holds that decodes but looks off, such as map keys
+/// this version doesn't know or a path deeper than usual, for tooling to show
+/// next to the decoded value. Only the TON and Monero sign requests and
+/// signatures implement it so far.
+pub trait DecodeWarnings {
+    fn decode_warnings(bytes: &[u8]) -> URResult<Vec<String>>;
+}
+
+/// Decodes past recoverable field errors, such as a wrong tag or an oversized
+/// value, so that tooling can report every problem of a payload at once. Only
+/// [`SuiSignRequest`](crate::sui::sui_sign_request::SuiSignRequest) implements
+/// it so far, the other types stop at their first error.
+pub trait DecodeCollectErrors: Sized {
+    fn decode_collect_errors(bytes: &[u8]) -> Result<Self, Vec<URError>>;
+}
+
+/// The revision of a type as it was first published, with only its original
+/// map keys.
+pub const BASELINE_FIRMWARE: &str = "1.0.0";
+
+/// The oldest firmware able to render every field present in the request.
+/// Versions count the revisions of the type's map: each optional key appended
+/// after [`BASELINE_FIRMWARE`] raises the minor version, in key order, and a
+/// request reports the revision of the newest key it carries.
+pub trait MinFirmware {
+    fn min_firmware(&self) -> &'static str {
+        BASELINE_FIRMWARE
+    }
+}
+
+/// Requests that carry an `expires_at` unix timestamp, in seconds, after
+/// which a device with a clock refuses to sign them. The Ethereum, Solana,
+/// Sui, Cosmos, EVM and Aptos sign requests implement it, the other requests
+/// have no expiry and are never expired.
+pub trait Expiry {
+    fn expires_at(&self) -> Option<u64>;
+
+    fn is_expired(&self, now: u64) -> bool {
+        matches!(self.expires_at(), Some(expires_at) if now >= expires_at)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+
+    use super::*;
+    use crate::crypto_key_path::CryptoKeyPath;
+    use crate::crypto_psbt::CryptoPSBT;
+    use crate::sui::sui_sign_request::SuiSignRequest;
+
+    #[test]
+    fn test_to_ur_string() {
+        let psbt = CryptoPSBT::new(
+            hex::decode("8c05c4b4f3e88840a4f4b5f155cfd69473ea169f3d0431b7a6787a23777f08aa")
+                .unwrap(),
+        );
+        assert_eq!(
+            "ur:crypto-psbt/hdcxlkahssqzwfvslofzoxwkrewngotktbmwjkwdcmnefsaaehrlolkskncnktlbaypkvoonhknt",
+            psbt.to_ur_string().unwrap()
+        );
+
+        let path = CryptoKeyPath::from_path("m/44'/784'/0'/0'/0'".to_string(), None).unwrap();
+        let request = SuiSignRequest::new(
+            None,
+            hex::decode("0000000000020020").unwrap(),
+            vec![path],
+            None,
+            None,
+            None,
+            None,
+            None,
+        );
+        let ur_string = request.to_ur_string().unwrap();
+        let prefix = alloc::format!("ur:{}/", SuiSignRequest::get_registry_type().get_type());
+        assert!(ur_string.starts_with(&prefix));
+
+        let (_, bytes) = ur::decode(&ur_string).unwrap();
+        assert_eq!(request.to_cbor().unwrap(), bytes);
+    }
+
+    #[test]
+    fn test_to_ur_string_single() {
+        let psbt = CryptoPSBT::new(vec![0u8; 1024]);
+        let ur_string = psbt.to_ur_string_single().unwrap();
+        assert_eq!(psbt.to_ur_string().unwrap(), ur_string);
+        assert!(ur_string.len() <= MAX_SINGLE_QR_LENGTH);
+
+        // every byte becomes two bytewords letters, so 1500 bytes can't fit
+        let psbt = CryptoPSBT::new(vec![0u8; 1500]);
+        let length = psbt.to_ur_string().unwrap().len();
+        assert_eq!(
+            Err(URError::SingleQrOverflow(length, MAX_SINGLE_QR_LENGTH)),
+            psbt.to_ur_string_single()
+        );
+    }
+
+    #[test]
+    fn test_encode_into() {
+        let path = CryptoKeyPath::from_path("m/44'/784'/0'/0'/0'".to_string(), None).unwrap();
+        let request = SuiSignRequest::new(
+            None,
+            hex::decode("0000000000020020").unwrap(),
+            vec![path],
+            None,
+            None,
+            None,
+            None,
+            None,
+        );
+        let expected = request.to_cbor().unwrap();
+
+        let mut buffer = [0u8; 64];
+        let mut remaining = &mut buffer[..];
+        request.encode_into(&mut remaining).unwrap();
+        let length = 64 - remaining.len();
+        assert_eq!(expected, buffer[..length]);
+
+        let mut small = [0u8; 8];
+        assert!(matches!(
+            request.encode_into(&mut small[..]),
+            Err(URError::CborEncodeError(_))
+        ));
+    }
+
+    #[test]
+    fn test_ur_prefix_overhead() {
+        use crate::ethereum::eth_sign_request::EthSignRequest;
+
+        // "ur:eth-sign-request/"
+        assert_eq!(20, EthSignRequest::ur_prefix_overhead());
+        // "ur:eth-sign-request/1-10/"
+        assert_eq!(25, EthSignRequest::ur_part_prefix_overhead(1, 10));
+        assert_eq!(27, EthSignRequest::ur_part_prefix_overhead(100, 10));
+
+        let psbt = CryptoPSBT::new(vec![0u8; 64]);
+        let ur_string = psbt.to_ur_string().unwrap();
+        let payload = ur_string.rsplit('/').next().unwrap();
+        assert_eq!(
+            CryptoPSBT::ur_prefix_overhead(),
+            ur_string.len() - payload.len()
+        );
+    }
+}
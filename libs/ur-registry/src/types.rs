@@ -1,4 +1,14 @@
+use alloc::collections::BTreeMap;
 use alloc::vec::Vec;
 
 pub type Bytes = Vec<u8>;
 pub type Fingerprint = [u8; 4];
+/// The raw CBOR values of the map keys a decoder doesn't know, such as fields
+/// added by a newer firmware, by key.
+pub type UnknownFields = BTreeMap<u8, Bytes>;
+
+/// The length of the `tx_hash` a signature echoes of the transaction the
+/// device signed. Only the Ethereum, Solana, Cosmos, EVM, Sui and Aptos
+/// signatures carry one, a NEAR signature covers several transactions and the
+/// other signatures don't have the field yet.
+pub const TX_HASH_LENGTH: usize = 32;
@@ -0,0 +1,100 @@
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+use crate::error::{URError, URResult};
+
+const U64_LENGTH: usize = 8;
+
+/// Reads a little-endian 8 byte amount, as found inside the `sign_data` of
+/// several chains.
+pub fn le_u64(bytes: &[u8]) -> URResult<u64> {
+    let bytes: [u8; U64_LENGTH] = bytes
+        .try_into()
+        .map_err(|_| URError::LengthMismatch(U64_LENGTH, bytes.len()))?;
+    Ok(u64::from_le_bytes(bytes))
+}
+
+pub fn u64_to_le(value: u64) -> [u8; U64_LENGTH] {
+    value.to_le_bytes()
+}
+
+/// Decodes a hex string, either case, into the bytes of a `Bytes` field.
+pub fn hex_to_bytes(s: &str) -> URResult<Vec<u8>> {
+    hex::decode(s).map_err(|e| URError::HexDecodeError(e.to_string()))
+}
+
+/// Encodes `bytes` as lowercase hex.
+pub fn bytes_to_hex(bytes: &[u8]) -> String {
+    hex::encode(bytes)
+}
+
+/// Checks that `bytes` are a single well-formed CBOR item, without decoding
+/// them into a registry type.
+pub fn check_cbor(bytes: &[u8]) -> URResult<()> {
+    let mut d = minicbor::Decoder::new(bytes);
+    d.skip()
+        .map_err(|e| URError::CborDecodeError(e.to_string()))?;
+    if d.position() != bytes.len() {
+        return Err(URError::CborDecodeError(format!(
+            "{} trailing bytes",
+            bytes.len() - d.position()
+        )));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_le_u64() {
+        // 1 SUI in MIST
+        let bytes = hex::decode("00ca9a3b00000000").unwrap();
+        assert_eq!(1_000_000_000, le_u64(&bytes).unwrap());
+        assert_eq!(bytes, u64_to_le(1_000_000_000));
+        assert_eq!(u64::MAX, le_u64(&u64_to_le(u64::MAX)).unwrap());
+    }
+
+    #[test]
+    fn test_le_u64_length_mismatch() {
+        assert_eq!(Err(URError::LengthMismatch(8, 7)), le_u64(&[0u8; 7]));
+        assert_eq!(Err(URError::LengthMismatch(8, 0)), le_u64(&[]));
+    }
+
+    #[test]
+    fn test_hex() {
+        let bytes = hex_to_bytes("9b1DEB4d3b7d4bad9bdd2b0d7b3dcb6d").unwrap();
+        assert_eq!(16, bytes.len());
+        assert_eq!("9b1deb4d3b7d4bad9bdd2b0d7b3dcb6d", bytes_to_hex(&bytes));
+        assert_eq!(Vec::<u8>::new(), hex_to_bytes("").unwrap());
+        assert_eq!("", bytes_to_hex(&[]));
+    }
+
+    #[test]
+    fn test_hex_invalid() {
+        assert!(matches!(
+            hex_to_bytes("abc"),
+            Err(URError::HexDecodeError(_))
+        ));
+        assert!(matches!(
+            hex_to_bytes("0g"),
+            Err(URError::HexDecodeError(_))
+        ));
+    }
+
+    #[test]
+    fn test_check_cbor() {
+        assert!(check_cbor(&hex::decode("a10244deadbeef").unwrap()).is_ok());
+        // the byte string is cut short
+        assert!(matches!(
+            check_cbor(&hex::decode("a10244deadbe").unwrap()),
+            Err(URError::CborDecodeError(_))
+        ));
+        assert_eq!(
+            Err(URError::CborDecodeError("1 trailing bytes".to_string())),
+            check_cbor(&hex::decode("a10244deadbeef00").unwrap())
+        );
+    }
+}
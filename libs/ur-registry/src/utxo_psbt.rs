@@ -0,0 +1,150 @@
+use crate::cbor::{cbor_bytes, cbor_map, cbor_tag, cbor_unknown_field, cbor_unknown_fields};
+use crate::crypto_coin_info::CryptoCoinInfo;
+use crate::decode::DecodeContext;
+use crate::error::{URError, URResult};
+use crate::impl_template_struct;
+use crate::registry_types::{RegistryType, CRYPTO_COIN_INFO, UTXO_PSBT};
+use crate::traits::{From as FromCbor, MapSize, RegistryItem, To};
+use crate::types::Bytes;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use minicbor::data::{Int, Tag};
+use minicbor::encode::{Error, Write};
+use minicbor::{Decoder, Encoder};
+
+const PSBT: u8 = 1;
+const COIN_INFO: u8 = 2;
+
+// a PSBT of a bitcoin fork such as Litecoin or Dogecoin, the coin info tells
+// the device which address format applies. Bitcoin PSBTs stay crypto-psbt
+impl_template_struct!(
+    UtxoPSBT {
+        psbt: Bytes,
+        coin_info: CryptoCoinInfo
+    },
+    unknown_fields
+);
+
+impl MapSize for UtxoPSBT {
+    fn map_size(&self) -> u64 {
+        2 + self.unknown_fields.len() as u64
+    }
+}
+
+impl RegistryItem for UtxoPSBT {
+    fn get_registry_type() -> RegistryType<'static> {
+        UTXO_PSBT
+    }
+}
+
+impl<C> minicbor::Encode<C> for UtxoPSBT {
+    fn encode<W: Write>(&self, e: &mut Encoder<W>, _ctx: &mut C) -> Result<(), Error<W::Error>> {
+        e.map(self.map_size())?;
+
+        e.int(Int::from(PSBT))?.bytes(&self.psbt)?;
+
+        e.int(Int::from(COIN_INFO))?
+            .tag(Tag::Unassigned(CRYPTO_COIN_INFO.get_tag()))?;
+        CryptoCoinInfo::encode(&self.coin_info, e, _ctx)?;
+
+        cbor_unknown_fields(e, &self.unknown_fields)?;
+        Ok(())
+    }
+}
+
+impl<'b, C: DecodeContext> minicbor::Decode<'b, C> for UtxoPSBT {
+    fn decode(d: &mut Decoder<'b>, ctx: &mut C) -> Result<Self, minicbor::decode::Error> {
+        let mut utxo_psbt = UtxoPSBT::default();
+        cbor_map(d, &mut utxo_psbt, |key, obj, d| {
+            let key =
+                u8::try_from(key).map_err(|e| minicbor::decode::Error::message(e.to_string()))?;
+            match key {
+                PSBT => obj.set_psbt(cbor_bytes(d)?),
+                COIN_INFO => {
+                    cbor_tag(
+                        d,
+                        ctx,
+                        CRYPTO_COIN_INFO.get_tag(),
+                        "crypto-coin-info tag is invalid",
+                    )?;
+                    obj.set_coin_info(CryptoCoinInfo::decode(d, ctx)?);
+                }
+                _ => {
+                    cbor_unknown_field(d, key, &mut obj.unknown_fields)?;
+                }
+            }
+            Ok(())
+        })?;
+        Ok(utxo_psbt)
+    }
+}
+
+impl To for UtxoPSBT {
+    fn to_bytes(&self) -> URResult<Vec<u8>> {
+        minicbor::to_vec(self.clone()).map_err(|e| URError::CborEncodeError(e.to_string()))
+    }
+}
+
+impl FromCbor<UtxoPSBT> for UtxoPSBT {
+    fn from_cbor(bytes: Vec<u8>) -> URResult<UtxoPSBT> {
+        minicbor::decode(&bytes).map_err(|e| URError::CborDecodeError(e.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::cbor::with_unknown_key;
+    use crate::crypto_coin_info::{CoinType, CryptoCoinInfo, Network};
+    use crate::crypto_psbt::CryptoPSBT;
+    use crate::traits::RegistryItem;
+    use crate::types::UnknownFields;
+    use crate::utxo_psbt::UtxoPSBT;
+    use alloc::vec;
+    use alloc::vec::Vec;
+    use hex::FromHex;
+
+    #[test]
+    fn test_litecoin_psbt() {
+        let coin_info = CryptoCoinInfo::new(Some(CoinType::Litecoin), Some(Network::MainNet));
+        let utxo_psbt = UtxoPSBT::new(Vec::from_hex("70736274ff01").unwrap(), coin_info);
+        let result: Vec<u8> = utxo_psbt.try_into().unwrap();
+        assert_eq!(
+            "a2014670736274ff0102d90131a201020200",
+            hex::encode(result.clone())
+        );
+
+        let ur = ur::encode(&result, UtxoPSBT::get_registry_type().get_type());
+        assert!(ur.starts_with("ur:utxo-psbt/"));
+
+        let utxo_psbt = UtxoPSBT::try_from(result).unwrap();
+        assert_eq!(Vec::from_hex("70736274ff01").unwrap(), utxo_psbt.get_psbt());
+        let coin_info = utxo_psbt.get_coin_info();
+        assert_eq!(CoinType::Litecoin, coin_info.get_coin_type());
+        assert_eq!(Some("ltc"), coin_info.get_bech32_hrp());
+    }
+
+    #[test]
+    fn test_crypto_psbt_is_not_a_utxo_psbt() {
+        let bytes: Vec<u8> = CryptoPSBT::new(Vec::from_hex("70736274ff01").unwrap())
+            .try_into()
+            .unwrap();
+        assert!(UtxoPSBT::try_from(bytes).is_err());
+    }
+
+    #[test]
+    fn test_unknown_fields_round_trip() {
+        let bytes =
+            with_unknown_key(Vec::from_hex("a2014670736274ff0102d90131a201020200").unwrap());
+        let utxo_psbt = UtxoPSBT::try_from(bytes.clone()).unwrap();
+        assert_eq!(
+            UnknownFields::from([(15, vec![0x82, 0x01, 0x02])]),
+            utxo_psbt.get_unknown_fields()
+        );
+        assert_eq!(
+            CoinType::Litecoin,
+            utxo_psbt.get_coin_info().get_coin_type()
+        );
+        let result: Vec<u8> = utxo_psbt.try_into().unwrap();
+        assert_eq!(bytes, result);
+    }
+}
@@ -0,0 +1,168 @@
+use crate::crypto_key_path::CryptoKeyPath;
+use crate::error::{URError, URResult};
+use crate::types::Bytes;
+use alloc::format;
+
+pub(crate) const MAX_SIGN_DATA_LENGTH: usize = 1024 * 1024;
+pub(crate) const MAX_PATH_DEPTH: usize = 10;
+const UUID_LENGTH: usize = 16;
+
+pub(crate) fn validate_request_id(request_id: &Option<Bytes>) -> URResult<()> {
+    match request_id {
+        Some(id) if id.len() != UUID_LENGTH => Err(URError::ValidationError(format!(
+            "request_id must be {} bytes, received {}",
+            UUID_LENGTH,
+            id.len()
+        ))),
+        _ => Ok(()),
+    }
+}
+
+// an all-zero id in a signature usually comes from an uninitialized response
+pub(crate) fn validate_signature_request_id(request_id: Option<&[u8]>) -> URResult<()> {
+    match request_id {
+        Some(id) if id.len() == UUID_LENGTH && id.iter().all(|byte| *byte == 0) => {
+            Err(URError::ZeroRequestId)
+        }
+        _ => Ok(()),
+    }
+}
+
+pub(crate) fn validate_sign_data(field: &str, sign_data: &[u8]) -> URResult<()> {
+    if sign_data.is_empty() {
+        return Err(URError::ValidationError(format!("{} is empty", field)));
+    }
+    if sign_data.len() > MAX_SIGN_DATA_LENGTH {
+        return Err(URError::ValidationError(format!(
+            "{} exceeds {} bytes",
+            field, MAX_SIGN_DATA_LENGTH
+        )));
+    }
+    Ok(())
+}
+
+pub(crate) fn validate_path(
+    field: &str,
+    path: &CryptoKeyPath,
+    coin_type: Option<u32>,
+) -> URResult<()> {
+    let components = path.get_components();
+    if components.is_empty() {
+        return Err(URError::ValidationError(format!("{} is empty", field)));
+    }
+    if components.len() > MAX_PATH_DEPTH {
+        return Err(URError::ValidationError(format!(
+            "{} is deeper than {} levels",
+            field, MAX_PATH_DEPTH
+        )));
+    }
+    path.validate_depth()?;
+    if components.iter().any(|component| component.is_wildcard()) {
+        return Err(URError::ValidationError(format!(
+            "{} must not contain wildcard",
+            field
+        )));
+    }
+    if let Some(coin_type) = coin_type {
+        match components.get(1) {
+            Some(component) if component.get_index() == Some(coin_type) => {}
+            _ => {
+                return Err(URError::ValidationError(format!(
+                    "{} does not match coin type {}",
+                    field, coin_type
+                )))
+            }
+        }
+    }
+    Ok(())
+}
+
+pub(crate) fn validate_paths(
+    field: &str,
+    paths: &[CryptoKeyPath],
+    coin_type: Option<u32>,
+) -> URResult<()> {
+    if paths.is_empty() {
+        return Err(URError::ValidationError(format!("{} is empty", field)));
+    }
+    for path in paths {
+        validate_path(field, path, coin_type)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::crypto_key_path::PathComponent;
+    use alloc::string::ToString;
+    use alloc::vec;
+
+    #[test]
+    fn test_validate_request_id() {
+        assert!(validate_request_id(&None).is_ok());
+        assert!(validate_request_id(&Some(vec![0; 16])).is_ok());
+        assert_eq!(
+            Err(URError::ValidationError(
+                "request_id must be 16 bytes, received 3".to_string()
+            )),
+            validate_request_id(&Some(vec![0; 3]))
+        );
+    }
+
+    #[test]
+    fn test_validate_sign_data() {
+        assert!(validate_sign_data("sign_data", &[1, 2, 3]).is_ok());
+        assert_eq!(
+            Err(URError::ValidationError("sign_data is empty".to_string())),
+            validate_sign_data("sign_data", &[])
+        );
+        assert!(validate_sign_data("sign_data", &vec![0; MAX_SIGN_DATA_LENGTH + 1]).is_err());
+    }
+
+    #[test]
+    fn test_validate_path() {
+        let path = CryptoKeyPath::from_path("m/44'/501'/0'/0'".to_string(), None).unwrap();
+        assert!(validate_path("derivation_path", &path, Some(501)).is_ok());
+        assert!(validate_path("derivation_path", &path, None).is_ok());
+        assert_eq!(
+            Err(URError::ValidationError(
+                "derivation_path does not match coin type 60".to_string()
+            )),
+            validate_path("derivation_path", &path, Some(60))
+        );
+
+        let empty = CryptoKeyPath::new(vec![], None, None);
+        assert_eq!(
+            Err(URError::ValidationError(
+                "derivation_path is empty".to_string()
+            )),
+            validate_path("derivation_path", &empty, None)
+        );
+
+        let wildcard = CryptoKeyPath::new(
+            vec![
+                PathComponent::new(Some(44), true).unwrap(),
+                PathComponent::new(None, false).unwrap(),
+            ],
+            None,
+            None,
+        );
+        assert_eq!(
+            Err(URError::ValidationError(
+                "derivation_path must not contain wildcard".to_string()
+            )),
+            validate_path("derivation_path", &wildcard, None)
+        );
+    }
+
+    #[test]
+    fn test_validate_paths() {
+        assert_eq!(
+            Err(URError::ValidationError(
+                "derivation_paths is empty".to_string()
+            )),
+            validate_paths("derivation_paths", &[], None)
+        );
+    }
+}
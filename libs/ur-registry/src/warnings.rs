@@ -0,0 +1,65 @@
+use crate::cbor::cbor_map;
+use crate::crypto_key_path::CryptoKeyPath;
+use crate::error::{URError, URResult};
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+pub(crate) const COMMON_PATH_DEPTH: usize = 5;
+
+pub(crate) fn unknown_key_warnings(bytes: &[u8], known_keys: &[u8]) -> URResult<Vec<String>> {
+    let mut warnings = Vec::new();
+    let mut d = minicbor::Decoder::new(bytes);
+    cbor_map(&mut d, &mut warnings, |key, warnings, d| {
+        let key = i128::from(key);
+        if !known_keys.iter().any(|known| i128::from(*known) == key) {
+            warnings.push(format!("unknown key `{}` is ignored", key));
+        }
+        d.skip()?;
+        Ok(())
+    })
+    .map_err(|e| URError::CborDecodeError(e.to_string()))?;
+    Ok(warnings)
+}
+
+pub(crate) fn path_depth_warning(field: &str, path: &CryptoKeyPath) -> Option<String> {
+    let depth = path.get_components().len();
+    if depth > COMMON_PATH_DEPTH {
+        return Some(format!(
+            "{} has unusual depth {}, expected at most {}",
+            field, depth, COMMON_PATH_DEPTH
+        ));
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    #[test]
+    fn test_unknown_key_warnings() {
+        // {1: h'00', 2: h'00', 9: "x"}
+        let bytes = hex::decode("a3014100024100096178").unwrap();
+        assert_eq!(
+            vec!["unknown key `9` is ignored".to_string()],
+            unknown_key_warnings(&bytes, &[1, 2]).unwrap()
+        );
+        assert!(unknown_key_warnings(&bytes, &[1, 2, 9]).unwrap().is_empty());
+        assert!(unknown_key_warnings(&[0x01], &[1]).is_err());
+    }
+
+    #[test]
+    fn test_path_depth_warning() {
+        let path = CryptoKeyPath::from_path("m/44'/607'/0'".to_string(), None).unwrap();
+        assert!(path_depth_warning("derivation_path", &path).is_none());
+
+        let path =
+            CryptoKeyPath::from_path("m/44'/607'/0'/0'/0'/0'".to_string(), None).unwrap();
+        assert_eq!(
+            Some("derivation_path has unusual depth 6, expected at most 5".to_string()),
+            path_depth_warning("derivation_path", &path)
+        );
+    }
+}
@@ -1,3 +1,5 @@
 pub mod zcash_accounts;
 pub mod zcash_pczt;
+pub mod zcash_sign_request;
+pub mod zcash_signature;
 pub mod zcash_unified_full_viewing_key;
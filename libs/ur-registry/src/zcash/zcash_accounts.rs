@@ -1,6 +1,9 @@
 use alloc::{string::ToString, vec::Vec};
 use minicbor::data::{Int, Tag};
 
+use crate::cbor::{cbor_bytes, cbor_tag};
+use crate::decode::DecodeContext;
+use crate::traits::RegistryItem;
 use crate::{
     cbor::{cbor_array, cbor_map},
     impl_template_struct,
@@ -53,7 +56,7 @@ impl<C> minicbor::Encode<C> for ZcashAccounts {
     }
 }
 
-impl<'b, C> minicbor::Decode<'b, C> for ZcashAccounts {
+impl<'b, C: DecodeContext> minicbor::Decode<'b, C> for ZcashAccounts {
     fn decode(d: &mut minicbor::Decoder<'b>, ctx: &mut C) -> Result<Self, minicbor::decode::Error> {
         let mut result = ZcashAccounts::default();
         cbor_map(d, &mut result, |key, obj, d| {
@@ -61,12 +64,17 @@ impl<'b, C> minicbor::Decode<'b, C> for ZcashAccounts {
                 u8::try_from(key).map_err(|e| minicbor::decode::Error::message(e.to_string()))?;
             match key {
                 SEED_FINGERPRINT => {
-                    obj.seed_fingerprint = d.bytes()?.to_vec();
+                    obj.seed_fingerprint = cbor_bytes(d)?;
                 }
                 ACCOUNTS => {
                     let mut keys: Vec<ZcashUnifiedFullViewingKey> = alloc::vec![];
                     cbor_array(d, obj, |_index, _obj, d| {
-                        d.tag()?;
+                        cbor_tag(
+                            d,
+                            ctx,
+                            ZcashUnifiedFullViewingKey::get_registry_type().get_tag(),
+                            "ZcashUnifiedFullViewingKey tag is invalid",
+                        )?;
                         keys.push(ZcashUnifiedFullViewingKey::decode(d, ctx)?);
                         Ok(())
                     })?;
@@ -1,6 +1,7 @@
 use alloc::string::ToString;
 use minicbor::data::Int;
 
+use crate::cbor::cbor_bytes;
 use crate::{
     cbor::cbor_map,
     impl_template_struct,
@@ -47,7 +48,7 @@ impl<'b, C> minicbor::Decode<'b, C> for ZcashPczt {
                 u8::try_from(key).map_err(|e| minicbor::decode::Error::message(e.to_string()))?;
             match key {
                 DATA => {
-                    obj.data = d.bytes()?.to_vec();
+                    obj.data = cbor_bytes(d)?;
                 }
                 _ => {}
             }
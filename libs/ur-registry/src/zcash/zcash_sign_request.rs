@@ -0,0 +1,237 @@
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use minicbor::data::{Int, Tag, Type};
+
+use crate::cbor::{
+    cbor_array, cbor_bytes, cbor_expect_type, cbor_map, cbor_origin, cbor_paths, cbor_tag,
+    cbor_unknown_field, cbor_unknown_fields,
+};
+use crate::crypto_key_path::CryptoKeyPath;
+use crate::decode::DecodeContext;
+use crate::error::URResult;
+use crate::impl_template_struct;
+use crate::impl_with_new_request_id;
+use crate::registry_types::{RegistryType, CRYPTO_KEYPATH, UUID, ZCASH_SIGN_REQUEST};
+use crate::traits::{MapSize, RegistryItem, Validate};
+use crate::types::Bytes;
+use crate::validate::{validate_paths, validate_request_id, validate_sign_data};
+
+const REQUEST_ID: u8 = 1;
+const SIGN_DATA: u8 = 2;
+const COMPONENT: u8 = 3;
+const DERIVATION_PATHS: u8 = 4;
+const ORIGIN: u8 = 5;
+
+// BIP-44 transparent and ZIP-32 shielded paths share the coin type
+const ZCASH_COIN_TYPE: u32 = 133;
+
+impl_template_struct!(
+    ZcashSignRequest {
+        request_id: Option<Bytes>,
+        // a PCZT or a serialized transaction
+        sign_data: Bytes,
+        component: Component,
+        derivation_paths: Vec<CryptoKeyPath>,
+        origin: Option<String>
+    },
+    unknown_fields
+);
+
+impl_with_new_request_id!(ZcashSignRequest);
+
+/// The part of the transaction to sign, which picks the signing flow on the
+/// device: ECDSA for transparent inputs, RedJubjub spend authorization for
+/// sapling and RedPallas for orchard.
+#[derive(Clone, Debug, PartialEq, Default)]
+pub enum Component {
+    #[default]
+    Transparent = 1,
+    Sapling = 2,
+    Orchard = 3,
+}
+
+impl Component {
+    pub fn from_u32(i: u32) -> Result<Self, String> {
+        match i {
+            1 => Ok(Component::Transparent),
+            2 => Ok(Component::Sapling),
+            3 => Ok(Component::Orchard),
+            x => Err(format!(
+                "invalid value for component in zcash-sign-request, expected 1, 2 or 3, received {:?}",
+                x
+            )),
+        }
+    }
+
+    pub fn is_shielded(&self) -> bool {
+        *self != Component::Transparent
+    }
+}
+
+impl RegistryItem for ZcashSignRequest {
+    fn get_registry_type() -> RegistryType<'static> {
+        ZCASH_SIGN_REQUEST
+    }
+}
+
+impl MapSize for ZcashSignRequest {
+    fn map_size(&self) -> u64 {
+        let mut size = 3;
+        if self.request_id.is_some() {
+            size += 1;
+        }
+        if self.origin.is_some() {
+            size += 1;
+        }
+        size + self.unknown_fields.len() as u64
+    }
+}
+
+impl Validate for ZcashSignRequest {
+    fn validate_schema(&self) -> URResult<()> {
+        validate_request_id(&self.request_id)?;
+        validate_sign_data("sign_data", &self.sign_data)?;
+        validate_paths(
+            "derivation_paths",
+            &self.derivation_paths,
+            Some(ZCASH_COIN_TYPE),
+        )?;
+        Ok(())
+    }
+}
+
+impl<C> minicbor::Encode<C> for ZcashSignRequest {
+    fn encode<W: minicbor::encode::Write>(
+        &self,
+        e: &mut minicbor::Encoder<W>,
+        ctx: &mut C,
+    ) -> Result<(), minicbor::encode::Error<W::Error>> {
+        e.map(self.map_size())?;
+        if let Some(request_id) = &self.request_id {
+            e.int(Int::from(REQUEST_ID))?
+                .tag(Tag::Unassigned(UUID.get_tag()))?
+                .bytes(request_id)?;
+        }
+
+        e.int(Int::from(SIGN_DATA))?.bytes(&self.sign_data)?;
+
+        e.int(Int::from(COMPONENT))?
+            .int(Int::from(self.component.clone() as u8))?;
+
+        e.int(Int::from(DERIVATION_PATHS))?;
+        cbor_paths(e, &self.derivation_paths, "derivation paths", ctx)?;
+
+        if let Some(origin) = &self.origin {
+            e.int(Int::from(ORIGIN))?.str(origin)?;
+        }
+
+        cbor_unknown_fields(e, &self.unknown_fields)?;
+        Ok(())
+    }
+}
+
+impl<'b, C: DecodeContext> minicbor::Decode<'b, C> for ZcashSignRequest {
+    fn decode(d: &mut minicbor::Decoder<'b>, ctx: &mut C) -> Result<Self, minicbor::decode::Error> {
+        let mut result = ZcashSignRequest::default();
+        cbor_map(d, &mut result, |key, obj, d| {
+            let key =
+                u8::try_from(key).map_err(|e| minicbor::decode::Error::message(e.to_string()))?;
+            match key {
+                REQUEST_ID => {
+                    cbor_tag(d, ctx, UUID.get_tag(), "UUID tag is invalid")?;
+                    obj.request_id = Some(cbor_bytes(d)?);
+                }
+                SIGN_DATA => {
+                    cbor_expect_type(d, ctx, Type::Bytes)?;
+                    obj.sign_data = cbor_bytes(d)?;
+                }
+                COMPONENT => {
+                    cbor_expect_type(d, ctx, Type::U32)?;
+                    obj.component = Component::from_u32(
+                        u32::try_from(d.int()?)
+                            .map_err(|e| minicbor::decode::Error::message(e.to_string()))?,
+                    )
+                    .map_err(minicbor::decode::Error::message)?;
+                }
+                DERIVATION_PATHS => {
+                    cbor_array(d, &mut obj.derivation_paths, |_index, obj, d| {
+                        cbor_tag(
+                            d,
+                            ctx,
+                            CRYPTO_KEYPATH.get_tag(),
+                            "CryptoKeyPath tag is invalid",
+                        )?;
+                        obj.push(CryptoKeyPath::decode(d, ctx)?);
+                        Ok(())
+                    })?;
+                }
+                ORIGIN => {
+                    obj.origin = Some(cbor_origin(d)?);
+                }
+                _ => {
+                    cbor_unknown_field(d, key, &mut obj.unknown_fields)?;
+                }
+            }
+            Ok(())
+        })?;
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+
+    use super::*;
+
+    // a v5 transaction spending one transparent input to two p2pkh outputs,
+    // with empty sapling and orchard bundles
+    const TRANSPARENT_TRANSACTION: &str = "050000800a27a726b4d0d6c20000000080b92a000160c67e51e26ef4c2eb2f36dc60159bb66be9a4cfbacd74de8f8d54ea4a6efa100000000000ffffffff02a0860100000000001976a9141b56e27094b67facb247d55c7c05912fc4cbffd288ac88ef0500000000001976a914580a0c01538304f2b267afeb77702ab47a567a9e88ac000000";
+
+    #[test]
+    fn test_transparent_round_trip() {
+        let path = CryptoKeyPath::from_path(
+            "m/44'/133'/0'/0/0".to_string(),
+            Some([0xf2, 0x3f, 0x9f, 0xd2]),
+        )
+        .unwrap();
+        let request = ZcashSignRequest::new(
+            Some(hex::decode("9b1deb4d3b7d4bad9bdd2b0d7b3dcb6d").unwrap()),
+            hex::decode(TRANSPARENT_TRANSACTION).unwrap(),
+            Component::Transparent,
+            vec![path],
+            Some("zashi".to_string()),
+        );
+        assert!(request.validate_schema().is_ok());
+
+        let result: Vec<u8> = request.try_into().unwrap();
+        assert_eq!(
+            "a501d825509b1deb4d3b7d4bad9bdd2b0d7b3dcb6d025886050000800a27a726b4d0d6c20000000080b92a000160c67e51e26ef4c2eb2f36dc60159bb66be9a4cfbacd74de8f8d54ea4a6efa100000000000ffffffff02a0860100000000001976a9141b56e27094b67facb247d55c7c05912fc4cbffd288ac88ef0500000000001976a914580a0c01538304f2b267afeb77702ab47a567a9e88ac00000003010481d90130a2018a182cf51885f500f500f400f4021af23f9fd205657a61736869",
+            hex::encode(&result)
+        );
+
+        let decoded = ZcashSignRequest::try_from(result).unwrap();
+        assert_eq!(Component::Transparent, decoded.get_component());
+        assert!(!decoded.get_component().is_shielded());
+        assert_eq!(
+            hex::decode(TRANSPARENT_TRANSACTION).unwrap(),
+            decoded.get_sign_data()
+        );
+        assert_eq!(
+            "44'/133'/0'/0/0",
+            decoded.get_derivation_paths()[0].get_path().unwrap()
+        );
+        assert_eq!(Some("zashi".to_string()), decoded.get_origin());
+    }
+
+    #[test]
+    fn test_invalid_component() {
+        // component 4
+        let result = ZcashSignRequest::try_from(
+            hex::decode("a302410103040481d90130a10184182cf51885f5").unwrap(),
+        );
+        assert!(result.is_err());
+        assert!(Component::Orchard.is_shielded());
+    }
+}
@@ -0,0 +1,124 @@
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use minicbor::data::{Int, Tag};
+
+use crate::cbor::{
+    cbor_array, cbor_bytes, cbor_map, cbor_tag, cbor_unknown_field, cbor_unknown_fields,
+};
+use crate::decode::DecodeContext;
+use crate::impl_template_struct;
+use crate::impl_validate_signature;
+use crate::registry_types::{RegistryType, UUID, ZCASH_SIGNATURE};
+use crate::traits::{MapSize, RegistryItem};
+use crate::types::Bytes;
+
+const REQUEST_ID: u8 = 1;
+const SIGNATURES: u8 = 2;
+
+impl_template_struct!(
+    ZcashSignature {
+        request_id: Option<Bytes>,
+        // one signature per transparent input or shielded spend, in transaction order
+        signatures: Vec<Bytes>
+    },
+    unknown_fields
+);
+
+impl_validate_signature!(ZcashSignature);
+
+impl RegistryItem for ZcashSignature {
+    fn get_registry_type() -> RegistryType<'static> {
+        ZCASH_SIGNATURE
+    }
+}
+
+impl MapSize for ZcashSignature {
+    fn map_size(&self) -> u64 {
+        let mut size = 1;
+        if self.request_id.is_some() {
+            size += 1;
+        }
+        size + self.unknown_fields.len() as u64
+    }
+}
+
+impl<C> minicbor::Encode<C> for ZcashSignature {
+    fn encode<W: minicbor::encode::Write>(
+        &self,
+        e: &mut minicbor::Encoder<W>,
+        _ctx: &mut C,
+    ) -> Result<(), minicbor::encode::Error<W::Error>> {
+        e.map(self.map_size())?;
+        if let Some(request_id) = &self.request_id {
+            e.int(Int::from(REQUEST_ID))?
+                .tag(Tag::Unassigned(UUID.get_tag()))?
+                .bytes(request_id)?;
+        }
+        e.int(Int::from(SIGNATURES))?
+            .array(self.signatures.len() as u64)?;
+        for signature in &self.signatures {
+            e.bytes(signature)?;
+        }
+        cbor_unknown_fields(e, &self.unknown_fields)?;
+        Ok(())
+    }
+}
+
+impl<'b, C: DecodeContext> minicbor::Decode<'b, C> for ZcashSignature {
+    fn decode(d: &mut minicbor::Decoder<'b>, ctx: &mut C) -> Result<Self, minicbor::decode::Error> {
+        let mut result = ZcashSignature::default();
+
+        cbor_map(d, &mut result, |key, obj, d| {
+            let key =
+                u8::try_from(key).map_err(|e| minicbor::decode::Error::message(e.to_string()))?;
+            match key {
+                REQUEST_ID => {
+                    cbor_tag(d, ctx, UUID.get_tag(), "UUID tag is invalid")?;
+                    obj.request_id = Some(cbor_bytes(d)?);
+                }
+                SIGNATURES => {
+                    cbor_array(d, &mut obj.signatures, |_index, obj, d| {
+                        obj.push(cbor_bytes(d)?);
+                        Ok(())
+                    })?;
+                }
+                _ => {
+                    cbor_unknown_field(d, key, &mut obj.unknown_fields)?;
+                }
+            }
+            Ok(())
+        })?;
+
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+
+    use super::*;
+
+    // the SIGHASH_ALL signature of the transparent transaction in the zcash_sign_request
+    // tests, taken over its ZIP-244 sighash
+    // 93ce9aba0e8963d9563b1752f8eecdc4e904b4db31e0a2fe54611b7efe760123 with the input
+    // spending 498000 zatoshis from the p2pkh address of the secp256k1 key 1
+    const TRANSPARENT_SIGNATURE: &str = "3045022100cc654ba42177b698ee4a441069def9ed52c97ad2ddf0cdb1196b57d7a0af293f0220669c90213d0e587427b6964779e13d8281d7c2269903b8fd504ab6d91f320eb501";
+
+    #[test]
+    fn test_round_trip() {
+        let signatures: Vec<Bytes> = vec![hex::decode(TRANSPARENT_SIGNATURE).unwrap()];
+        let signature = ZcashSignature::new(
+            Some(hex::decode("9b1deb4d3b7d4bad9bdd2b0d7b3dcb6d").unwrap()),
+            signatures.clone(),
+        );
+        let result: Vec<u8> = signature.try_into().unwrap();
+        assert_eq!(
+            "a201d825509b1deb4d3b7d4bad9bdd2b0d7b3dcb6d028158483045022100cc654ba42177b698ee4a441069def9ed52c97ad2ddf0cdb1196b57d7a0af293f0220669c90213d0e587427b6964779e13d8281d7c2269903b8fd504ab6d91f320eb501",
+            hex::encode(&result)
+        );
+
+        let decoded = ZcashSignature::try_from(result).unwrap();
+        assert_eq!(signatures, decoded.get_signatures());
+    }
+}
@@ -0,0 +1,107 @@
+// Counts heap allocations per encode/decode against upper bounds, so that a
+// change which clones or regrows a buffer more often fails here. The bounds are
+// left some headroom over the counts, lower them when buffer reuse cuts the
+// counts. A failure prints the counts of the run.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use ur_registry::crypto_key_path::CryptoKeyPath;
+use ur_registry::crypto_psbt::CryptoPSBT;
+use ur_registry::ergo::{ergo_hint_bag::ErgoHintBag, ergo_signature::ErgoSignature};
+use ur_registry::sui::sui_sign_request::SuiSignRequest;
+
+struct CountingAllocator;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+const REQUEST_ID: [u8; 16] = [
+    0x9b, 0x1d, 0xeb, 0x4d, 0x3b, 0x7d, 0x4b, 0xad, 0x9b, 0xdd, 0x2b, 0x0d, 0x7b, 0x3d, 0xcb, 0x6d,
+];
+
+fn count<T>(f: impl FnOnce() -> T) -> usize {
+    let before = ALLOCATIONS.load(Ordering::Relaxed);
+    let result = f();
+    let after = ALLOCATIONS.load(Ordering::Relaxed);
+    drop(result);
+    after - before
+}
+
+macro_rules! check {
+    ($name: expr, $t: ty, $item: expr, $max_encode: expr, $max_decode: expr) => {{
+        let item = $item;
+        let bytes: Vec<u8> = item.clone().try_into().unwrap();
+        // the clones happen before counting, so only the codec is measured
+        let owned = item.clone();
+        let encode = count(move || -> Vec<u8> { owned.try_into().unwrap() });
+        let decode = count(move || <$t>::try_from(bytes).unwrap());
+        assert!(
+            encode <= $max_encode && decode <= $max_decode,
+            "{}: {} allocations per encode (at most {}), {} allocations per decode (at most {})",
+            $name,
+            encode,
+            $max_encode,
+            decode,
+            $max_decode
+        );
+    }};
+}
+
+// a single test, so no other test thread allocates while counting
+#[test]
+fn test_allocations_per_op() {
+    let path = CryptoKeyPath::from_path("m/44'/784'/0'/0'/0'".to_string(), None).unwrap();
+    check!(
+        "sui-sign-request",
+        SuiSignRequest,
+        SuiSignRequest::new(
+            Some(REQUEST_ID.to_vec()),
+            vec![0u8; 512],
+            vec![path],
+            None,
+            Some("Sui Wallet".to_string()),
+            Some(vec![vec![0u8; 73]; 16]),
+            None,
+            None,
+        ),
+        96,
+        48
+    );
+    check!(
+        "ergo-signature",
+        ErgoSignature,
+        ErgoSignature::new(
+            Some(REQUEST_ID.to_vec()),
+            vec![0u8; 56],
+            Some(ErgoHintBag::new(vec![0u8; 256])),
+        ),
+        16,
+        8
+    );
+    check!(
+        "crypto-psbt-100k",
+        CryptoPSBT,
+        CryptoPSBT::new(vec![0u8; 100 * 1024]),
+        4,
+        2
+    );
+}